@@ -0,0 +1,211 @@
+//! `expand()` throughput over representative definitions, to catch
+//! regressions as the expander grows.
+//!
+//! Run with `cargo bench --bench expand`. Each group reports elements per
+//! second, where an element is one `expand()` call, so runs on different
+//! machines compare by ratio rather than absolute time.
+//!
+//! - `small`: one table, two dimensions, two metrics.
+//! - `medium`: a star of one fact table and three dimension tables, 10
+//!   dimensions and 10 metrics.
+//! - `join_chain`: a `ManyToOne` chain `t0 -> t1 -> ... -> tN`; selecting a
+//!   dimension on `tN` makes the resolver pull in every intermediate table.
+//! - `join_star`: a base table with 30 directly related tables, all selected.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use semantic_views::expand::{expand, QueryRequest};
+use semantic_views::model::{Dimension, Join, Metric, SemanticViewDefinition, TableRef};
+
+fn table(alias: &str, table: &str) -> TableRef {
+    TableRef {
+        alias: alias.to_string(),
+        table: table.to_string(),
+        pk_columns: vec!["id".to_string()],
+        ..Default::default()
+    }
+}
+
+fn dimension(name: &str, expr: &str, source: &str) -> Dimension {
+    Dimension {
+        name: name.to_string(),
+        expr: expr.to_string(),
+        source_table: Some(source.to_string()),
+        ..Default::default()
+    }
+}
+
+fn metric(name: &str, expr: &str, source: &str) -> Metric {
+    Metric {
+        name: name.to_string(),
+        expr: expr.to_string(),
+        source_table: Some(source.to_string()),
+        ..Default::default()
+    }
+}
+
+fn join(name: &str, from: &str, to: &str, fk: &str) -> Join {
+    Join {
+        table: to.to_string(),
+        from_alias: from.to_string(),
+        fk_columns: vec![fk.to_string()],
+        ref_columns: vec!["id".to_string()],
+        name: Some(name.to_string()),
+        ..Default::default()
+    }
+}
+
+fn small() -> (SemanticViewDefinition, QueryRequest) {
+    let def = SemanticViewDefinition {
+        tables: vec![table("o", "orders")],
+        dimensions: vec![
+            dimension("region", "o.region", "o"),
+            dimension("month", "date_trunc('month', o.ordered_at)", "o"),
+        ],
+        metrics: vec![
+            metric("revenue", "sum(o.amount)", "o"),
+            metric("order_count", "count(*)", "o"),
+        ],
+        ..Default::default()
+    };
+    let req = QueryRequest::builder()
+        .dimensions(["region", "month"])
+        .metrics(["revenue", "order_count"])
+        .build();
+    (def, req)
+}
+
+/// Fact table `f` with dimension tables `c`, `p`, and `s`; four dimensions
+/// on `f` and two on each dimension table, ten metrics on `f`.
+fn medium() -> (SemanticViewDefinition, QueryRequest) {
+    let mut dimensions: Vec<Dimension> = (0..4)
+        .map(|i| dimension(&format!("f_dim{i}"), &format!("f.attr{i}"), "f"))
+        .collect();
+    for alias in ["c", "p", "s"] {
+        for i in 0..2 {
+            dimensions.push(dimension(
+                &format!("{alias}_dim{i}"),
+                &format!("{alias}.attr{i}"),
+                alias,
+            ));
+        }
+    }
+    let metrics: Vec<Metric> = (0..10)
+        .map(|i| metric(&format!("m{i}"), &format!("sum(f.val{i})"), "f"))
+        .collect();
+    let req = QueryRequest::builder()
+        .dimensions(dimensions.iter().map(|d| d.name.clone()))
+        .metrics(metrics.iter().map(|m| m.name.clone()))
+        .build();
+    let def = SemanticViewDefinition {
+        tables: vec![
+            table("f", "sales"),
+            table("c", "customers"),
+            table("p", "products"),
+            table("s", "stores"),
+        ],
+        dimensions,
+        metrics,
+        joins: vec![
+            join("f_to_c", "f", "c", "customer_id"),
+            join("f_to_p", "f", "p", "product_id"),
+            join("f_to_s", "f", "s", "store_id"),
+        ],
+        ..Default::default()
+    };
+    (def, req)
+}
+
+/// A chain of `depth` hops from the base `t0`, with a dimension on every
+/// table; the request selects only the far end and a base metric.
+fn join_chain(depth: usize) -> (SemanticViewDefinition, QueryRequest) {
+    let aliases: Vec<String> = (0..=depth).map(|i| format!("t{i}")).collect();
+    let def = SemanticViewDefinition {
+        tables: aliases
+            .iter()
+            .map(|a| table(a, &format!("table_{a}")))
+            .collect(),
+        dimensions: aliases
+            .iter()
+            .map(|a| dimension(&format!("{a}_name"), &format!("{a}.name"), a))
+            .collect(),
+        metrics: vec![metric("total", "sum(t0.amount)", "t0")],
+        joins: aliases
+            .windows(2)
+            .map(|w| join(&format!("{}_to_{}", w[0], w[1]), &w[0], &w[1], "parent_id"))
+            .collect(),
+        ..Default::default()
+    };
+    let req = QueryRequest::builder()
+        .dimensions([format!("t{depth}_name")])
+        .metrics(["total"])
+        .build();
+    (def, req)
+}
+
+/// The base `f` with `width` directly referenced tables `d0..`, one
+/// dimension on each; the request selects every dimension.
+fn join_star(width: usize) -> (SemanticViewDefinition, QueryRequest) {
+    let aliases: Vec<String> = (0..width).map(|i| format!("d{i}")).collect();
+    let mut tables = vec![table("f", "facts")];
+    tables.extend(aliases.iter().map(|a| table(a, &format!("table_{a}"))));
+    let def = SemanticViewDefinition {
+        tables,
+        dimensions: aliases
+            .iter()
+            .map(|a| dimension(&format!("{a}_name"), &format!("{a}.name"), a))
+            .collect(),
+        metrics: vec![metric("total", "sum(f.amount)", "f")],
+        joins: aliases
+            .iter()
+            .map(|a| join(&format!("f_to_{a}"), "f", a, &format!("{a}_id")))
+            .collect(),
+        ..Default::default()
+    };
+    let req = QueryRequest::builder()
+        .dimensions(aliases.iter().map(|a| format!("{a}_name")))
+        .metrics(["total"])
+        .build();
+    (def, req)
+}
+
+fn bench_shapes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expand");
+    group.throughput(Throughput::Elements(1));
+    for (name, (def, req)) in [("small", small()), ("medium", medium())] {
+        group.bench_function(name, |b| {
+            b.iter(|| expand("bench", black_box(&def), black_box(&req)).unwrap());
+        });
+    }
+    group.finish();
+}
+
+fn bench_join_chain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expand/join_chain");
+    group.throughput(Throughput::Elements(1));
+    for depth in [2, 8, 32] {
+        let (def, req) = join_chain(depth);
+        let sql = expand("bench", &def, &req).expect("join chain expands");
+        assert_eq!(sql.matches("LEFT JOIN").count(), depth);
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &depth, |b, _| {
+            b.iter(|| expand("bench", black_box(&def), black_box(&req)).unwrap());
+        });
+    }
+    group.finish();
+}
+
+fn bench_join_star(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expand/join_star");
+    group.throughput(Throughput::Elements(1));
+    let (def, req) = join_star(30);
+    let sql = expand("bench", &def, &req).expect("join star expands");
+    assert_eq!(sql.matches("LEFT JOIN").count(), 30);
+    group.bench_function("30", |b| {
+        b.iter(|| expand("bench", black_box(&def), black_box(&req)).unwrap());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_shapes, bench_join_chain, bench_join_star);
+criterion_main!(benches);
@@ -170,8 +170,14 @@ extern "C" {
     //        Caller MUST release via `sv_free_buffer`.
     //   1 — catalog read error; error_buf populated.
     //   2 — internal error (panic across FFI); error_buf populated.
+    //
+    // (pattern_ptr, pattern_len) carries the optional positional LIKE
+    // pattern; pattern_ptr == nullptr means "no pattern". (tag_ptr, tag_len)
+    // carries the optional `tag :=` filter the same way.
     uint8_t sv_list_semantic_views_bind_rust(
         duckdb_connection conn,
+        const uint8_t *pattern_ptr, size_t pattern_len,
+        const uint8_t *tag_ptr, size_t tag_len,
         char **out_ptr, size_t *out_len,
         char *error_buf, size_t error_buf_len);
 
@@ -247,6 +253,32 @@ extern "C" {
         const uint8_t *metric_name_ptr, size_t metric_name_len,
         char **out_ptr, size_t *out_len,
         char *error_buf, size_t error_buf_len);
+    // validate_semantic_views_json(array_json): one positional VARCHAR (a
+    // JSON array of {name, definition} objects) instead of a view name. The
+    // connection is only borrowed for the dispatcher scaffold; the catalog is
+    // never read.
+    uint8_t sv_validate_semantic_views_json_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *json_ptr, size_t json_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_semantic_view_tables_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_semantic_view_tags_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    // find_dimension_across_views(name): the argument is a dimension name,
+    // searched across every stored view.
+    uint8_t sv_find_dimension_across_views_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
 
     // Phase 65 Plan 05 Task 4 (Wave 3) — Rust dispatchers for the migrated
     // scalar functions (get_ddl, read_yaml_from_semantic_view). Per-row
@@ -270,6 +302,38 @@ extern "C" {
         const uint8_t *name_ptr, size_t name_len,
         char **out_ptr, size_t *out_len,
         char *error_buf, size_t error_buf_len);
+    uint8_t sv_semantic_view_graph_exec_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_semantic_view_doc_exec_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_define_from_dbt_exec_rust(
+        duckdb_connection conn,
+        const uint8_t *yaml_ptr, size_t yaml_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_define_semantic_views_script_exec_rust(
+        duckdb_connection conn,
+        const uint8_t *json_ptr, size_t json_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_semantic_view_to_dbt_exec_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_set_suggestion_algorithm_rust(
+        const uint8_t *name_ptr, size_t name_len,
+        char *error_buf, size_t error_buf_len);
+    uint8_t sv_set_suggestion_threshold_rust(
+        double threshold,
+        char *error_buf, size_t error_buf_len);
+    void sv_set_strict_names_rust(bool strict);
 
     // Phase 65 Plan 05 Task 5 (Wave 5) — Rust dispatcher for the migrated
     // `explain_semantic_view(view_name, dimensions := [...], metrics := [...],
@@ -279,13 +343,82 @@ extern "C" {
     // standard length-prefixed wire format (`u32 count; for each entry:
     // u32 len + bytes`) and passed as (ptr, len) pairs. A null pointer
     // with len=0 means the named parameter was not supplied (treated as
-    // an empty list).
+    // an empty list). `options` carries the explain-only named flags as
+    // bits (1 = `structured`: two-column `(section, line)` rows; 2 =
+    // `analyze`: capture the plan with EXPLAIN ANALYZE).
     uint8_t sv_explain_semantic_view_bind_rust(
         duckdb_connection conn,
         const uint8_t *name_ptr, size_t name_len,
         const uint8_t *dims_ptr, size_t dims_len,
         const uint8_t *metrics_ptr, size_t metrics_len,
         const uint8_t *facts_ptr, size_t facts_len,
+        uint32_t options,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+
+    // Rust dispatcher for `semantic_view_sql(view_name, dimensions := [...],
+    // metrics := [...], facts := [...])`. Identical signature and wire
+    // formats to `sv_explain_semantic_view_bind_rust` (`options` is
+    // ignored); the payload is a single VARCHAR row holding the expanded SQL.
+    uint8_t sv_semantic_view_sql_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        const uint8_t *dims_ptr, size_t dims_len,
+        const uint8_t *metrics_ptr, size_t metrics_len,
+        const uint8_t *facts_ptr, size_t facts_len,
+        uint32_t options,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+
+    // Rust dispatcher for `minimal_repro_json(view_name, ...)`. Same shape
+    // as the two above; the payload is a single VARCHAR row holding the
+    // trimmed definition JSON.
+    uint8_t sv_minimal_repro_json_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        const uint8_t *dims_ptr, size_t dims_len,
+        const uint8_t *metrics_ptr, size_t metrics_len,
+        const uint8_t *facts_ptr, size_t facts_len,
+        uint32_t options,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+
+    // Rust dispatcher for `create_view_from_semantic(view_name,
+    // target_view_name, ...)`. The request-inspection shape with the target
+    // name added after the view name and no `options`; the payload is a
+    // single VARCHAR row holding the CREATE VIEW statement.
+    uint8_t sv_create_view_from_semantic_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        const uint8_t *target_ptr, size_t target_len,
+        const uint8_t *dims_ptr, size_t dims_len,
+        const uint8_t *metrics_ptr, size_t metrics_len,
+        const uint8_t *facts_ptr, size_t facts_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+
+    // Rust dispatcher for `estimate_semantic_query(view_name, ...)`. Same
+    // shape as the three above; the payload is one row of two VARCHAR
+    // cells, the estimate and a message, either of which may be empty.
+    uint8_t sv_estimate_semantic_query_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        const uint8_t *dims_ptr, size_t dims_len,
+        const uint8_t *metrics_ptr, size_t metrics_len,
+        const uint8_t *facts_ptr, size_t facts_len,
+        uint32_t options,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
+
+    // Rust dispatcher for the `validate_semantic_query(view, dimensions,
+    // metrics)` scalar, called once per row. The two lists use the same
+    // length-prefixed wire format as above; the payload is `OK` or the
+    // failure message (rc 0 either way).
+    uint8_t sv_validate_semantic_query_exec_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        const uint8_t *dims_ptr, size_t dims_len,
+        const uint8_t *metrics_ptr, size_t metrics_len,
         char **out_ptr, size_t *out_len,
         char *error_buf, size_t error_buf_len);
 
@@ -316,6 +449,16 @@ extern "C" {
         const uint8_t *facts_ptr, size_t facts_len,
         char **out_ptr, size_t *out_len,
         char *error_buf, size_t error_buf_len);
+
+    // semantic_query_json(view_name, request_json): same output wire format
+    // as sv_semantic_view_bind_rust, with the whole QueryRequest decoded from
+    // one JSON VARCHAR instead of the named LIST(VARCHAR) parameters.
+    uint8_t sv_semantic_query_json_bind_rust(
+        duckdb_connection conn,
+        const uint8_t *name_ptr, size_t name_len,
+        const uint8_t *json_ptr, size_t json_len,
+        char **out_ptr, size_t *out_len,
+        char *error_buf, size_t error_buf_len);
 }
 
 // ---------------------------------------------------------------------------
@@ -560,6 +703,10 @@ struct SvTableFunctionSpec {
     // (semantic_view's per-execution global). The core enforces the XOR.
     table_function_init_local_t init_local_cb = nullptr;
     table_function_init_global_t init_global_cb = nullptr;
+    // Trailing variadic positional type (INVALID = none). Used for optional
+    // positional arguments: `arg_types` holds the required ones and the bind
+    // checks how many variadic values it received.
+    LogicalType varargs = LogicalType::INVALID;
 };
 
 // The named LIST(VARCHAR) parameters shared by explain_semantic_view and
@@ -647,6 +794,7 @@ static bool sv_register_table_function_core(
             spec.init_global_cb,
             spec.init_local_cb);
 
+        tf.varargs = spec.varargs;
         for (const auto &np : spec.named_params) {
             tf.named_parameters[np.first] = np.second;
         }
@@ -1046,6 +1194,22 @@ static uint32_t sv_read_u32_le(const char *buf, size_t buf_len, size_t &offset,
     return v;
 }
 
+static int64_t sv_read_i64_le(const char *buf, size_t buf_len, size_t &offset,
+                              const char *fn_name) {
+    if (offset + 8 > buf_len) {
+        throw BinderException(
+            std::string(fn_name) + ": FFI buffer truncated (expected i64 at offset " +
+            std::to_string(offset) + " of " + std::to_string(buf_len) + ")");
+    }
+    auto p = reinterpret_cast<const unsigned char *>(buf + offset);
+    uint64_t v = 0;
+    for (int i = 7; i >= 0; --i) {
+        v = (v << 8) | static_cast<uint64_t>(p[i]);
+    }
+    offset += 8;
+    return static_cast<int64_t>(v);
+}
+
 static std::string sv_read_string(const char *buf, size_t buf_len, size_t &offset,
                                   const char *fn_name) {
     uint32_t len = sv_read_u32_le(buf, buf_len, offset, fn_name);
@@ -1061,10 +1225,12 @@ static std::string sv_read_string(const char *buf, size_t buf_len, size_t &offse
 }
 
 // Self-describing wire-format schema tags (AR-3). Kept in sync with
-// `WIRE_TAG_VARCHAR` / `WIRE_TAG_BOOL` in `src/ddl/read_ffi.rs` — the Rust
-// serializers emit these, the parsers below assert them.
+// `WIRE_TAG_VARCHAR` / `WIRE_TAG_BOOL` / `WIRE_TAG_BIGINT` in
+// `src/ddl/read_ffi.rs` — the Rust serializers emit these, the parsers below
+// assert them.
 static constexpr uint8_t SV_WIRE_VARCHAR = 1;
 static constexpr uint8_t SV_WIRE_BOOL = 2;
+static constexpr uint8_t SV_WIRE_BIGINT = 3;
 
 // Read + validate the self-describing schema header (AR-3): `u32 col_count`
 // followed by one `u8` type tag per column. `expected_tags` is the column
@@ -1142,7 +1308,8 @@ struct SvVarcharLocalState : public LocalTableFunctionState {
     // chunks and resumes from this cursor — a single-shot `emitted` flag
     // overflowed the chunk for >2048 rows (writes past the vector's data
     // buffer; no bounds check in release builds). Shared by
-    // sv_emit_varchar_rows and sv_emit_varchar_bool_rows.
+    // sv_emit_varchar_rows, sv_emit_varchar_bool_rows and
+    // sv_emit_varchar_bigint_rows.
     idx_t next_row = 0;
 };
 
@@ -1311,6 +1478,88 @@ static void sv_emit_varchar_bool_rows(
     state.next_row += count;
 }
 
+// VARCHAR-rows-with-trailing-BIGINTs shape (used by list_semantic_views,
+// which appends per-view item counts to its 6 VARCHAR columns).
+struct SvVarcharBigintBindData : public TableFunctionData {
+    std::vector<std::pair<std::vector<std::string>, std::vector<int64_t>>> rows;
+    size_t expected_varchar_cols = 0;  // number of VARCHAR cells per row
+    size_t expected_bigint_cols = 0;   // number of trailing BIGINT cells per row
+};
+
+static void sv_parse_varchar_bigint_payload(const char *buf, size_t buf_len,
+                                            SvVarcharBigintBindData &bd,
+                                            const char *fn_name) {
+    if (buf == nullptr) {
+        return;
+    }
+    size_t offset = 0;
+    // Validate the self-describing schema header (AR-3): N VARCHAR columns
+    // followed by M BIGINT columns.
+    std::vector<uint8_t> expected_tags(bd.expected_varchar_cols, SV_WIRE_VARCHAR);
+    expected_tags.insert(expected_tags.end(), bd.expected_bigint_cols, SV_WIRE_BIGINT);
+    uint32_t schema_cols = sv_read_wire_schema(buf, buf_len, offset, expected_tags, fn_name);
+    uint32_t row_count = sv_read_u32_le(buf, buf_len, offset, fn_name);
+    if (schema_cols == 0 && row_count != 0) {
+        throw BinderException(
+            std::string(fn_name) +
+            ": FFI wire-format empty-schema header (col_count == 0) with row_count == " +
+            std::to_string(row_count) + " > 0");
+    }
+    bd.rows.reserve(row_count);
+    for (uint32_t r = 0; r < row_count; ++r) {
+        std::vector<std::string> strs;
+        strs.reserve(bd.expected_varchar_cols);
+        for (size_t c = 0; c < bd.expected_varchar_cols; ++c) {
+            strs.push_back(sv_read_string(buf, buf_len, offset, fn_name));
+        }
+        std::vector<int64_t> ints;
+        ints.reserve(bd.expected_bigint_cols);
+        for (size_t c = 0; c < bd.expected_bigint_cols; ++c) {
+            ints.push_back(sv_read_i64_le(buf, buf_len, offset, fn_name));
+        }
+        bd.rows.emplace_back(std::move(strs), std::move(ints));
+    }
+    if (offset != buf_len) {
+        throw BinderException(
+            std::string(fn_name) +
+            ": FFI buffer has trailing bytes (consumed " +
+            std::to_string(offset) + " of " + std::to_string(buf_len) + ")");
+    }
+}
+
+static void sv_emit_varchar_bigint_rows(
+    ClientContext & /*context*/,
+    TableFunctionInput &data_p,
+    DataChunk &output) {
+    auto &bd = data_p.bind_data->Cast<SvVarcharBigintBindData>();
+    auto *state_p = data_p.local_state.get();
+    if (state_p == nullptr) {
+        // See sv_emit_varchar_rows: registration refuses a null init_cb.
+        throw InternalException(
+            "sv_emit_varchar_bigint_rows: local_state missing despite init_local registration");
+    }
+    auto &state = state_p->Cast<SvVarcharLocalState>();
+    idx_t total = bd.rows.size();
+    if (state.next_row >= total) {
+        output.SetCardinality(0);
+        return;
+    }
+    idx_t remaining = total - state.next_row;
+    idx_t count = remaining < STANDARD_VECTOR_SIZE ? remaining : STANDARD_VECTOR_SIZE;
+    for (idx_t i = 0; i < count; ++i) {
+        const auto &row = bd.rows[state.next_row + i];
+        for (size_t c = 0; c < row.first.size(); ++c) {
+            output.SetValue(c, i, Value(row.first[c]));
+        }
+        // BIGINT trailing columns start at index row.first.size().
+        for (size_t c = 0; c < row.second.size(); ++c) {
+            output.SetValue(row.first.size() + c, i, Value::BIGINT(row.second[c]));
+        }
+    }
+    output.SetCardinality(count);
+    state.next_row += count;
+}
+
 // Common idiom for the bind callback: open Connection, run Rust dispatcher
 // for the zero-arg case, parse payload into bd. Used by all 5 zero-arg
 // migrated TFs (Task 2 / Wave 1) and reused by Task 3 / Wave 2 with extra
@@ -1433,38 +1682,94 @@ static void sv_run_varchar_bool_bind(ClientContext &context,
     sv_parse_varchar_bool_payload(payload.ptr, payload.len, bd, fn_name);
 }
 
+template <typename DispatcherFn>
+static void sv_run_varchar_bigint_bind(ClientContext &context,
+                                       SvVarcharBigintBindData &bd,
+                                       size_t expected_varchar_cols,
+                                       size_t expected_bigint_cols,
+                                       const char *fn_name,
+                                       DispatcherFn &&dispatcher) {
+    bd.expected_varchar_cols = expected_varchar_cols;
+    bd.expected_bigint_cols = expected_bigint_cols;
+    Connection probe(*context.db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+    SvOwnedBuffer payload;
+    char error_buf[1024];
+    std::memset(error_buf, 0, sizeof(error_buf));
+    uint8_t rc = dispatcher(borrowed,
+                            &payload.ptr, &payload.len,
+                            error_buf, sizeof(error_buf));
+    if (rc != 0) {
+        throw BinderException(std::string(fn_name) + " failed: " + error_buf);
+    }
+    sv_parse_varchar_bigint_payload(payload.ptr, payload.len, bd, fn_name);
+}
+
 // ---------------------------------------------------------------------------
 // list_semantic_views — Phase 65 Plan 05 Task 1 (Wave 0 spike), folded onto
 // the generic scaffold (C-1, code-review 2026-07-11)
 // ---------------------------------------------------------------------------
-// 6-column VARCHAR: created_on, name, kind, database_name, schema_name,
-// comment. Superset of list_terse_semantic_views (which drops `comment`);
-// both share the Rust body `list_view_rows` and the same wire format, so the
-// strict generic parser (`sv_parse_varchar_payload`, incl. the trailing-bytes
-// check) applies uniformly.
+// 6 VARCHAR columns -- created_on, name, kind, database_name, schema_name,
+// comment -- then 3 BIGINT counts: dimension_count, metric_count, join_count.
+// Superset of list_terse_semantic_views (which drops `comment` and the
+// counts); both share the Rust body `list_view_rows`.
+//
+// An optional positional VARCHAR (`list_semantic_views('ord%')`) filters view
+// names with case-insensitive LIKE semantics during bind, so unmatched
+// definitions are never parsed or materialized. It is registered as VARCHAR
+// varargs; the bind rejects more than one value and a NULL pattern. The
+// named `tag := 'finance'` keeps only views whose definition lists that tag
+// (case-insensitively), on top of any pattern.
 
 static unique_ptr<FunctionData> sv_list_semantic_views_bind(
     ClientContext &context,
-    TableFunctionBindInput & /*input*/,
+    TableFunctionBindInput &input,
     vector<LogicalType> &return_types,
     vector<string> &names) {
-    auto bd = make_uniq<SvVarcharBindData>();
-    // Column layout must match the v0.9.0 Rust VTab exactly — SELECT * FROM
-    // list_semantic_views() across the suite relies on byte-identical names
-    // and order.
+    if (input.inputs.size() > 1) {
+        throw BinderException(
+            "list_semantic_views: expected at most one argument (a name LIKE pattern)");
+    }
+    bool has_pattern = !input.inputs.empty();
+    if (has_pattern && input.inputs[0].IsNull()) {
+        throw BinderException("list_semantic_views: name pattern must not be NULL");
+    }
+    std::string pattern = has_pattern ? input.inputs[0].GetValue<std::string>() : "";
+    auto tag_it = input.named_parameters.find("tag");
+    bool has_tag = tag_it != input.named_parameters.end();
+    if (has_tag && tag_it->second.IsNull()) {
+        throw BinderException("list_semantic_views: tag must not be NULL");
+    }
+    std::string tag = has_tag ? tag_it->second.GetValue<std::string>() : "";
+    auto bd = make_uniq<SvVarcharBigintBindData>();
+    // The leading VARCHAR columns keep the v0.9.0 Rust VTab names and order;
+    // the counts are appended so existing positional readers are unaffected.
     static const char *const COL_NAMES[] = {
         "created_on", "name", "kind", "database_name", "schema_name", "comment",
     };
+    static const char *const COUNT_COL_NAMES[] = {
+        "dimension_count", "metric_count", "join_count",
+    };
     for (auto cn : COL_NAMES) {
         return_types.push_back(LogicalType::VARCHAR);
         names.emplace_back(cn);
     }
-    sv_run_varchar_bind(
-        context, *bd, /*expected_cols*/ 6, "list_semantic_views",
-        [](duckdb_connection borrowed, char **out_ptr, size_t *out_len,
-           char *error_buf, size_t error_buf_len) {
+    for (auto cn : COUNT_COL_NAMES) {
+        return_types.push_back(LogicalType::BIGINT);
+        names.emplace_back(cn);
+    }
+    sv_run_varchar_bigint_bind(
+        context, *bd, /*expected_varchar_cols*/ 6, /*expected_bigint_cols*/ 3,
+        "list_semantic_views",
+        [&](duckdb_connection borrowed, char **out_ptr, size_t *out_len,
+            char *error_buf, size_t error_buf_len) {
             return sv_list_semantic_views_bind_rust(
-                borrowed, out_ptr, out_len, error_buf, error_buf_len);
+                borrowed,
+                has_pattern ? reinterpret_cast<const uint8_t *>(pattern.data()) : nullptr,
+                pattern.size(),
+                has_tag ? reinterpret_cast<const uint8_t *>(tag.data()) : nullptr,
+                tag.size(),
+                out_ptr, out_len, error_buf, error_buf_len);
         });
     return std::move(bd);
 }
@@ -1472,15 +1777,17 @@ static unique_ptr<FunctionData> sv_list_semantic_views_bind(
 extern "C" {
     bool sv_register_list_semantic_views(duckdb_database db_handle,
                                          char *error_buf, size_t error_buf_len) {
-        // Zero-argument table function — no arg_types array.
-        return sv_register_table_function(
-            db_handle,
-            "list_semantic_views",
-            /*arg_types*/ nullptr, /*arg_count*/ 0,
-            sv_list_semantic_views_bind,
-            sv_emit_varchar_rows,
-            sv_varchar_init_local,
-            error_buf, error_buf_len);
+        // No required arguments; the optional name pattern is VARCHAR varargs.
+        SvTableFunctionSpec spec;
+        spec.name = "list_semantic_views";
+        spec.varargs = LogicalType::VARCHAR;
+        spec.named_params.emplace_back("tag", LogicalType::VARCHAR);
+        spec.bind_cb = sv_list_semantic_views_bind;
+        spec.exec_cb = sv_emit_varchar_bigint_rows;
+        spec.init_local_cb = sv_varchar_init_local;
+        return sv_register_table_function_core(
+            db_handle, spec, "sv_register_list_semantic_views", error_buf,
+            error_buf_len);
     }
 }
 
@@ -1884,53 +2191,201 @@ static unique_ptr<FunctionData> sv_show_semantic_dimensions_for_metric_bind(
     return std::move(bd);
 }
 
-extern "C" {
-    bool sv_register_show_columns_in_semantic_view(duckdb_database db_handle,
-                                                   char *error_buf, size_t error_buf_len) {
-        LogicalType args[] = {LogicalType::VARCHAR};
-        return sv_register_table_function(
-            db_handle, "show_columns_in_semantic_view",
-            args, 1,
-            sv_show_columns_in_semantic_view_bind,
-            sv_emit_varchar_rows, sv_varchar_init_local,
-            error_buf, error_buf_len);
-    }
-    bool sv_register_describe_semantic_view(duckdb_database db_handle,
-                                            char *error_buf, size_t error_buf_len) {
-        LogicalType args[] = {LogicalType::VARCHAR};
-        return sv_register_table_function(
-            db_handle, "describe_semantic_view",
-            args, 1,
-            sv_describe_semantic_view_bind,
-            sv_emit_varchar_rows, sv_varchar_init_local,
-            error_buf, error_buf_len);
+// validate_semantic_views_json(array_json): 3 VARCHAR output columns. The
+// shared single-name runner would report a NULL argument as a missing view
+// name, so the argument is guarded here with its own message before the
+// VARCHAR payload is handed to the runner.
+static unique_ptr<FunctionData> sv_validate_semantic_views_json_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    if (input.inputs.empty() || input.inputs[0].IsNull()) {
+        throw BinderException(
+            "validate_semantic_views_json: definitions JSON must not be NULL");
     }
-    bool sv_register_show_semantic_dimensions(duckdb_database db_handle,
-                                              char *error_buf, size_t error_buf_len) {
-        LogicalType args[] = {LogicalType::VARCHAR};
-        return sv_register_table_function(
-            db_handle, "show_semantic_dimensions",
-            args, 1,
-            sv_show_semantic_dimensions_bind,
-            sv_emit_varchar_rows, sv_varchar_init_local,
-            error_buf, error_buf_len);
+    auto bd = make_uniq<SvVarcharBindData>();
+    static const char *const COLS[] = {"name", "status", "error"};
+    for (auto cn : COLS) {
+        return_types.push_back(LogicalType::VARCHAR);
+        names.emplace_back(cn);
     }
-    bool sv_register_show_semantic_metrics(duckdb_database db_handle,
-                                           char *error_buf, size_t error_buf_len) {
-        LogicalType args[] = {LogicalType::VARCHAR};
-        return sv_register_table_function(
-            db_handle, "show_semantic_metrics",
-            args, 1,
-            sv_show_semantic_metrics_bind,
-            sv_emit_varchar_rows, sv_varchar_init_local,
-            error_buf, error_buf_len);
+    sv_run_varchar_bind_with_name(
+        context, input, *bd, 3, "validate_semantic_views_json",
+        [](duckdb_connection borrowed,
+           const uint8_t *jp, size_t jl,
+           char **op, size_t *ol, char *eb, size_t ebl) {
+            return sv_validate_semantic_views_json_bind_rust(
+                borrowed, jp, jl, op, ol, eb, ebl);
+        });
+    return std::move(bd);
+}
+
+// semantic_view_tables(view_name): 4 VARCHAR output columns, one row per
+// physical table the view reads.
+static unique_ptr<FunctionData> sv_semantic_view_tables_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    auto bd = make_uniq<SvVarcharBindData>();
+    static const char *const COLS[] = {
+        "semantic_view_name", "alias", "table_name", "kind",
+    };
+    for (auto cn : COLS) {
+        return_types.push_back(LogicalType::VARCHAR);
+        names.emplace_back(cn);
     }
-    bool sv_register_show_semantic_facts(duckdb_database db_handle,
-                                         char *error_buf, size_t error_buf_len) {
-        LogicalType args[] = {LogicalType::VARCHAR};
-        return sv_register_table_function(
-            db_handle, "show_semantic_facts",
-            args, 1,
+    sv_run_varchar_bind_with_name(
+        context, input, *bd, 4, "semantic_view_tables",
+        [](duckdb_connection borrowed,
+           const uint8_t *np, size_t nl,
+           char **op, size_t *ol, char *eb, size_t ebl) {
+            return sv_semantic_view_tables_bind_rust(
+                borrowed, np, nl, op, ol, eb, ebl);
+        });
+    return std::move(bd);
+}
+
+// semantic_view_tags(view_name): 4 VARCHAR output columns, one row per tag
+// and per label of the view.
+static unique_ptr<FunctionData> sv_semantic_view_tags_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    auto bd = make_uniq<SvVarcharBindData>();
+    static const char *const COLS[] = {
+        "semantic_view_name", "kind", "key", "value",
+    };
+    for (auto cn : COLS) {
+        return_types.push_back(LogicalType::VARCHAR);
+        names.emplace_back(cn);
+    }
+    sv_run_varchar_bind_with_name(
+        context, input, *bd, 4, "semantic_view_tags",
+        [](duckdb_connection borrowed,
+           const uint8_t *np, size_t nl,
+           char **op, size_t *ol, char *eb, size_t ebl) {
+            return sv_semantic_view_tags_bind_rust(
+                borrowed, np, nl, op, ol, eb, ebl);
+        });
+    return std::move(bd);
+}
+
+// find_dimension_across_views(name): 4 VARCHAR output columns, one row per
+// matching dimension across all stored views.
+static unique_ptr<FunctionData> sv_find_dimension_across_views_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    auto bd = make_uniq<SvVarcharBindData>();
+    static const char *const COLS[] = {
+        "semantic_view_name", "dimension_name", "expr", "match_kind",
+    };
+    for (auto cn : COLS) {
+        return_types.push_back(LogicalType::VARCHAR);
+        names.emplace_back(cn);
+    }
+    sv_run_varchar_bind_with_name(
+        context, input, *bd, 4, "find_dimension_across_views",
+        [](duckdb_connection borrowed,
+           const uint8_t *np, size_t nl,
+           char **op, size_t *ol, char *eb, size_t ebl) {
+            return sv_find_dimension_across_views_bind_rust(
+                borrowed, np, nl, op, ol, eb, ebl);
+        });
+    return std::move(bd);
+}
+
+extern "C" {
+    bool sv_register_show_columns_in_semantic_view(duckdb_database db_handle,
+                                                   char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "show_columns_in_semantic_view",
+            args, 1,
+            sv_show_columns_in_semantic_view_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_validate_semantic_views_json(duckdb_database db_handle,
+                                                  char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "validate_semantic_views_json",
+            args, 1,
+            sv_validate_semantic_views_json_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_semantic_view_tables(duckdb_database db_handle,
+                                          char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "semantic_view_tables",
+            args, 1,
+            sv_semantic_view_tables_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_semantic_view_tags(duckdb_database db_handle,
+                                        char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "semantic_view_tags",
+            args, 1,
+            sv_semantic_view_tags_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_find_dimension_across_views(duckdb_database db_handle,
+                                                 char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "find_dimension_across_views",
+            args, 1,
+            sv_find_dimension_across_views_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_describe_semantic_view(duckdb_database db_handle,
+                                            char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "describe_semantic_view",
+            args, 1,
+            sv_describe_semantic_view_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_show_semantic_dimensions(duckdb_database db_handle,
+                                              char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "show_semantic_dimensions",
+            args, 1,
+            sv_show_semantic_dimensions_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_show_semantic_metrics(duckdb_database db_handle,
+                                           char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "show_semantic_metrics",
+            args, 1,
+            sv_show_semantic_metrics_bind,
+            sv_emit_varchar_rows, sv_varchar_init_local,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_show_semantic_facts(duckdb_database db_handle,
+                                         char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_table_function(
+            db_handle, "show_semantic_facts",
+            args, 1,
             sv_show_semantic_facts_bind,
             sv_emit_varchar_rows, sv_varchar_init_local,
             error_buf, error_buf_len);
@@ -2082,6 +2537,172 @@ static void sv_read_yaml_from_semantic_view_exec(DataChunk &args,
     }
 }
 
+// semantic_view_graph(name VARCHAR) -> VARCHAR (DOT source)
+static void sv_semantic_view_graph_exec(DataChunk &args,
+                                        ExpressionState &state,
+                                        Vector &result) {
+    auto &name_vec = args.data[0];
+    name_vec.Flatten(args.size());
+    auto name_data = FlatVector::GetData<string_t>(name_vec);
+    auto &name_validity = FlatVector::Validity(name_vec);
+    auto &result_validity = FlatVector::Validity(result);
+
+    Connection probe(*state.GetContext().db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    for (idx_t i = 0; i < args.size(); ++i) {
+        if (!name_validity.RowIsValid(i)) {
+            result_validity.SetInvalid(i);
+            continue;
+        }
+        const string_t &n = name_data[i];
+        sv_emit_scalar_row(
+            result, i, "semantic_view_graph",
+            [&](char **op, size_t *ol, char *eb, size_t ebl) {
+                return sv_semantic_view_graph_exec_rust(
+                    borrowed,
+                    reinterpret_cast<const uint8_t *>(n.GetData()), n.GetSize(),
+                    op, ol, eb, ebl);
+            });
+    }
+    if (args.AllConstant()) {
+        result.SetVectorType(VectorType::CONSTANT_VECTOR);
+    }
+}
+
+// semantic_view_doc(name VARCHAR) -> VARCHAR (Markdown)
+static void sv_semantic_view_doc_exec(DataChunk &args,
+                                      ExpressionState &state,
+                                      Vector &result) {
+    auto &name_vec = args.data[0];
+    name_vec.Flatten(args.size());
+    auto name_data = FlatVector::GetData<string_t>(name_vec);
+    auto &name_validity = FlatVector::Validity(name_vec);
+    auto &result_validity = FlatVector::Validity(result);
+
+    Connection probe(*state.GetContext().db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    for (idx_t i = 0; i < args.size(); ++i) {
+        if (!name_validity.RowIsValid(i)) {
+            result_validity.SetInvalid(i);
+            continue;
+        }
+        const string_t &n = name_data[i];
+        sv_emit_scalar_row(
+            result, i, "semantic_view_doc",
+            [&](char **op, size_t *ol, char *eb, size_t ebl) {
+                return sv_semantic_view_doc_exec_rust(
+                    borrowed,
+                    reinterpret_cast<const uint8_t *>(n.GetData()), n.GetSize(),
+                    op, ol, eb, ebl);
+            });
+    }
+    if (args.AllConstant()) {
+        result.SetVectorType(VectorType::CONSTANT_VECTOR);
+    }
+}
+
+// define_from_dbt(yaml VARCHAR) -> VARCHAR (CREATE SEMANTIC VIEW script)
+static void sv_define_from_dbt_exec(DataChunk &args,
+                                    ExpressionState &state,
+                                    Vector &result) {
+    auto &name_vec = args.data[0];
+    name_vec.Flatten(args.size());
+    auto name_data = FlatVector::GetData<string_t>(name_vec);
+    auto &name_validity = FlatVector::Validity(name_vec);
+    auto &result_validity = FlatVector::Validity(result);
+
+    Connection probe(*state.GetContext().db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    for (idx_t i = 0; i < args.size(); ++i) {
+        if (!name_validity.RowIsValid(i)) {
+            result_validity.SetInvalid(i);
+            continue;
+        }
+        const string_t &n = name_data[i];
+        sv_emit_scalar_row(
+            result, i, "define_from_dbt",
+            [&](char **op, size_t *ol, char *eb, size_t ebl) {
+                return sv_define_from_dbt_exec_rust(
+                    borrowed,
+                    reinterpret_cast<const uint8_t *>(n.GetData()), n.GetSize(),
+                    op, ol, eb, ebl);
+            });
+    }
+    if (args.AllConstant()) {
+        result.SetVectorType(VectorType::CONSTANT_VECTOR);
+    }
+}
+
+// define_semantic_views_script(array_json VARCHAR) -> VARCHAR (one transactional
+// CREATE SEMANTIC VIEW script for the whole batch)
+static void sv_define_semantic_views_script_exec(DataChunk &args,
+                                                 ExpressionState &state,
+                                                 Vector &result) {
+    auto &json_vec = args.data[0];
+    json_vec.Flatten(args.size());
+    auto json_data = FlatVector::GetData<string_t>(json_vec);
+    auto &json_validity = FlatVector::Validity(json_vec);
+    auto &result_validity = FlatVector::Validity(result);
+
+    Connection probe(*state.GetContext().db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    for (idx_t i = 0; i < args.size(); ++i) {
+        if (!json_validity.RowIsValid(i)) {
+            result_validity.SetInvalid(i);
+            continue;
+        }
+        const string_t &j = json_data[i];
+        sv_emit_scalar_row(
+            result, i, "define_semantic_views_script",
+            [&](char **op, size_t *ol, char *eb, size_t ebl) {
+                return sv_define_semantic_views_script_exec_rust(
+                    borrowed,
+                    reinterpret_cast<const uint8_t *>(j.GetData()), j.GetSize(),
+                    op, ol, eb, ebl);
+            });
+    }
+    if (args.AllConstant()) {
+        result.SetVectorType(VectorType::CONSTANT_VECTOR);
+    }
+}
+
+// semantic_view_to_dbt(name VARCHAR) -> VARCHAR (dbt semantic-layer YAML)
+static void sv_semantic_view_to_dbt_exec(DataChunk &args,
+                                         ExpressionState &state,
+                                         Vector &result) {
+    auto &name_vec = args.data[0];
+    name_vec.Flatten(args.size());
+    auto name_data = FlatVector::GetData<string_t>(name_vec);
+    auto &name_validity = FlatVector::Validity(name_vec);
+    auto &result_validity = FlatVector::Validity(result);
+
+    Connection probe(*state.GetContext().db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    for (idx_t i = 0; i < args.size(); ++i) {
+        if (!name_validity.RowIsValid(i)) {
+            result_validity.SetInvalid(i);
+            continue;
+        }
+        const string_t &n = name_data[i];
+        sv_emit_scalar_row(
+            result, i, "semantic_view_to_dbt",
+            [&](char **op, size_t *ol, char *eb, size_t ebl) {
+                return sv_semantic_view_to_dbt_exec_rust(
+                    borrowed,
+                    reinterpret_cast<const uint8_t *>(n.GetData()), n.GetSize(),
+                    op, ol, eb, ebl);
+            });
+    }
+    if (args.AllConstant()) {
+        result.SetVectorType(VectorType::CONSTANT_VECTOR);
+    }
+}
+
 extern "C" {
     bool sv_register_get_ddl(duckdb_database db_handle,
                              char *error_buf, size_t error_buf_len) {
@@ -2103,6 +2724,147 @@ extern "C" {
             sv_read_yaml_from_semantic_view_exec,
             error_buf, error_buf_len);
     }
+    bool sv_register_semantic_view_graph(duckdb_database db_handle,
+                                         char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_scalar_function(
+            db_handle, "semantic_view_graph",
+            args, 1,
+            LogicalType::VARCHAR,
+            sv_semantic_view_graph_exec,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_semantic_view_doc(duckdb_database db_handle,
+                                       char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_scalar_function(
+            db_handle, "semantic_view_doc",
+            args, 1,
+            LogicalType::VARCHAR,
+            sv_semantic_view_doc_exec,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_define_from_dbt(duckdb_database db_handle,
+                                     char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_scalar_function(
+            db_handle, "define_from_dbt",
+            args, 1,
+            LogicalType::VARCHAR,
+            sv_define_from_dbt_exec,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_define_semantic_views_script(duckdb_database db_handle,
+                                                  char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_scalar_function(
+            db_handle, "define_semantic_views_script",
+            args, 1,
+            LogicalType::VARCHAR,
+            sv_define_semantic_views_script_exec,
+            error_buf, error_buf_len);
+    }
+    bool sv_register_semantic_view_to_dbt(duckdb_database db_handle,
+                                          char *error_buf, size_t error_buf_len) {
+        LogicalType args[] = {LogicalType::VARCHAR};
+        return sv_register_scalar_function(
+            db_handle, "semantic_view_to_dbt",
+            args, 1,
+            LogicalType::VARCHAR,
+            sv_semantic_view_to_dbt_exec,
+            error_buf, error_buf_len);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Extension settings
+// ---------------------------------------------------------------------------
+// `SET semantic_views_suggestion_algorithm = 'jaro_winkler'` (default
+// 'levenshtein') and `SET semantic_views_suggestion_threshold = 0.85`
+// (Jaro-Winkler only, default 0.8). The SET callbacks forward the value to
+// Rust, which validates it and stores it process-wide; a rejected value
+// throws, so the SET fails and the option keeps its previous value.
+// `SET semantic_views_strict_names = true` limits names in later CREATEs to
+// letters, digits, '_', ' ', and '-'.
+//
+// The Rust side is read from paths that have no ClientContext (the parser
+// override among them), so the value cannot follow DuckDB's per-session
+// lookup. The options are therefore GLOBAL-only: registered with GLOBAL as
+// their default scope, so a plain SET is global, and SET SESSION / SET LOCAL
+// are rejected before DuckDB records a session value that would never apply.
+// RESET does not reach the callbacks; SET the default value (or NULL) instead.
+static void sv_require_global_scope(const string &option, SetScope scope) {
+    if (scope == SetScope::SESSION || scope == SetScope::LOCAL) {
+        throw InvalidInputException(
+            "%s applies to the whole process and cannot be set per session; "
+            "use SET GLOBAL %s", option, option);
+    }
+}
+
+static void sv_set_suggestion_algorithm(ClientContext &context, SetScope scope,
+                                        Value &parameter) {
+    sv_require_global_scope("semantic_views_suggestion_algorithm", scope);
+    auto name = parameter.IsNull() ? string("levenshtein") : parameter.ToString();
+    char err[1024] = {0};
+    if (sv_set_suggestion_algorithm_rust(
+            reinterpret_cast<const uint8_t *>(name.data()), name.size(),
+            err, sizeof(err)) != 0) {
+        throw InvalidInputException("semantic_views_suggestion_algorithm: %s", string(err));
+    }
+}
+
+static void sv_set_suggestion_threshold(ClientContext &context, SetScope scope,
+                                        Value &parameter) {
+    sv_require_global_scope("semantic_views_suggestion_threshold", scope);
+    double threshold = parameter.IsNull() ? 0.8 : parameter.GetValue<double>();
+    char err[1024] = {0};
+    if (sv_set_suggestion_threshold_rust(threshold, err, sizeof(err)) != 0) {
+        throw InvalidInputException("semantic_views_suggestion_threshold: %s", string(err));
+    }
+}
+
+static void sv_set_strict_names(ClientContext &context, SetScope scope,
+                                Value &parameter) {
+    sv_require_global_scope("semantic_views_strict_names", scope);
+    sv_set_strict_names_rust(!parameter.IsNull() && parameter.GetValue<bool>());
+}
+
+extern "C" {
+    bool sv_register_settings(duckdb_database db_handle,
+                              char *error_buf, size_t error_buf_len) {
+        try {
+            auto *wrapper = reinterpret_cast<duckdb::DatabaseWrapper *>(
+                db_handle->internal_ptr);
+            auto &config = DBConfig::GetConfig(*wrapper->database->instance);
+            // AddExtensionOption replaces an existing entry, so a repeat LOAD
+            // is harmless.
+            config.AddExtensionOption(
+                "semantic_views_suggestion_algorithm",
+                "Algorithm for semantic_views \"did you mean\" suggestions: "
+                "'levenshtein' or 'jaro_winkler'",
+                LogicalType::VARCHAR, Value("levenshtein"),
+                sv_set_suggestion_algorithm, SetScope::GLOBAL);
+            config.AddExtensionOption(
+                "semantic_views_suggestion_threshold",
+                "Minimum Jaro-Winkler similarity (0 to 1) for a semantic_views "
+                "suggestion",
+                LogicalType::DOUBLE, Value::DOUBLE(0.8),
+                sv_set_suggestion_threshold, SetScope::GLOBAL);
+            config.AddExtensionOption(
+                "semantic_views_strict_names",
+                "Restrict semantic view, dimension, metric, and fact names to "
+                "letters, digits, '_', ' ', and '-' at CREATE",
+                LogicalType::BOOLEAN, Value::BOOLEAN(false),
+                sv_set_strict_names, SetScope::GLOBAL);
+            return true;
+        } catch (const std::exception &e) {
+            if (error_buf != nullptr && error_buf_len > 0) {
+                snprintf(error_buf, error_buf_len,
+                    "sv_register_settings failed: %s", e.what());
+            }
+            return false;
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -2145,7 +2907,7 @@ static std::vector<uint8_t> sv_serialise_string_list(
     for (const auto &c : children) {
         if (c.IsNull()) {
             throw BinderException(
-                std::string("explain_semantic_view: `") + param_name +
+                std::string("`") + param_name +
                 "` contains a NULL element (only non-NULL VARCHARs accepted)");
         }
         // c.GetValue<std::string>() applies any necessary cast. The named-
@@ -2158,42 +2920,302 @@ static std::vector<uint8_t> sv_serialise_string_list(
         buf.push_back(static_cast<uint8_t>((len >> 24) & 0xff));
         buf.insert(buf.end(), s.begin(), s.end());
     }
-    return buf;
+    return buf;
+}
+
+// Dispatcher signature shared by the request-inspection TFs
+// (`explain_semantic_view`, `semantic_view_sql`, `minimal_repro_json`): the
+// positional view name, the three serialised named lists, and a bit set of
+// TF-specific flags in, a VARCHAR-rows payload out.
+// `options` bits for explain_semantic_view's `structured := true` and
+// `analyze := true`; mirror `query::wire::EXPLAIN_STRUCTURED` /
+// `EXPLAIN_ANALYZE` on the Rust side.
+static constexpr uint32_t SV_EXPLAIN_STRUCTURED = 1u << 0;
+static constexpr uint32_t SV_EXPLAIN_ANALYZE = 1u << 1;
+
+using sv_semantic_request_dispatcher_t = uint8_t (*)(
+    duckdb_connection conn,
+    const uint8_t *name_ptr, size_t name_len,
+    const uint8_t *dims_ptr, size_t dims_len,
+    const uint8_t *metrics_ptr, size_t metrics_len,
+    const uint8_t *facts_ptr, size_t facts_len,
+    uint32_t options,
+    char **out_ptr, size_t *out_len,
+    char *error_buf, size_t error_buf_len);
+
+// Serialise the optional `dimensions` / `metrics` / `facts` named
+// LIST(VARCHAR) parameters into the wire format the Rust dispatchers decode.
+// An absent or NULL parameter leaves its buffer empty (sent as nullptr+0).
+static void sv_serialise_request_lists(const TableFunctionBindInput &input,
+                                       std::vector<uint8_t> &dims_buf,
+                                       std::vector<uint8_t> &metrics_buf,
+                                       std::vector<uint8_t> &facts_buf) {
+    auto it_d = input.named_parameters.find("dimensions");
+    if (it_d != input.named_parameters.end() && !it_d->second.IsNull()) {
+        dims_buf = sv_serialise_string_list(it_d->second, "dimensions");
+    }
+    auto it_m = input.named_parameters.find("metrics");
+    if (it_m != input.named_parameters.end() && !it_m->second.IsNull()) {
+        metrics_buf = sv_serialise_string_list(it_m->second, "metrics");
+    }
+    auto it_f = input.named_parameters.find("facts");
+    if (it_f != input.named_parameters.end() && !it_f->second.IsNull()) {
+        facts_buf = sv_serialise_string_list(it_f->second, "facts");
+    }
+}
+
+// Shared bind for the request-inspection TFs: declares one VARCHAR output
+// column per entry of `column_names` (the dispatcher must emit rows that
+// wide), pulls the positional view name and the optional named
+// LIST(VARCHAR) parameters, and bridges to `dispatcher` on a per-call
+// `Connection probe(*context.db)`. `fn_name` prefixes every diagnostic so
+// each TF keeps naming itself. The `input.named_parameters` map is
+// case-insensitive (per case_insensitive_map_t); a missing entry means the
+// user did not supply that named parameter — pass nullptr+0 to the Rust side.
+static unique_ptr<FunctionData> sv_semantic_request_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names,
+    const char *fn_name,
+    const std::vector<const char *> &column_names,
+    uint32_t options,
+    sv_semantic_request_dispatcher_t dispatcher) {
+    auto bd = make_uniq<SvVarcharBindData>();
+    bd->expected_cols = column_names.size();
+    for (const char *column_name : column_names) {
+        return_types.push_back(LogicalType::VARCHAR);
+        names.emplace_back(column_name);
+    }
+
+    if (input.inputs.empty() || input.inputs[0].IsNull()) {
+        throw BinderException(
+            std::string(fn_name) + ": view name is required (positional arg 0)");
+    }
+    std::string view_name = input.inputs[0].GetValue<std::string>();
+
+    std::vector<uint8_t> dims_buf, metrics_buf, facts_buf;
+    sv_serialise_request_lists(input, dims_buf, metrics_buf, facts_buf);
+
+    Connection probe(*context.db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    SvOwnedBuffer payload;
+    char error_buf[1024];
+    std::memset(error_buf, 0, sizeof(error_buf));
+
+    uint8_t rc = dispatcher(
+        borrowed,
+        reinterpret_cast<const uint8_t *>(view_name.data()), view_name.size(),
+        dims_buf.empty()    ? nullptr : dims_buf.data(),    dims_buf.size(),
+        metrics_buf.empty() ? nullptr : metrics_buf.data(), metrics_buf.size(),
+        facts_buf.empty()   ? nullptr : facts_buf.data(),   facts_buf.size(),
+        options,
+        &payload.ptr, &payload.len,
+        error_buf, sizeof(error_buf));
+
+    if (rc != 0) {
+        throw BinderException(std::string(fn_name) + ": " + error_buf);
+    }
+    sv_parse_varchar_payload(payload.ptr, payload.len, *bd, fn_name);
+    return std::move(bd);
+}
+
+static unique_ptr<FunctionData> sv_explain_semantic_view_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    // `structured := true` splits each line into (section, line) so callers
+    // can filter on the section instead of parsing the `-- ...:` markers.
+    // `analyze := true` runs the query under EXPLAIN ANALYZE for real timings.
+    auto flag = [&](const char *param) {
+        auto it = input.named_parameters.find(param);
+        return it != input.named_parameters.end() && !it->second.IsNull() &&
+               it->second.GetValue<bool>();
+    };
+    uint32_t options = 0;
+    if (flag("structured")) {
+        options |= SV_EXPLAIN_STRUCTURED;
+    }
+    if (flag("analyze")) {
+        options |= SV_EXPLAIN_ANALYZE;
+    }
+    if (options & SV_EXPLAIN_STRUCTURED) {
+        return sv_semantic_request_bind(context, input, return_types, names,
+                                        "explain_semantic_view",
+                                        {"section", "line"}, options,
+                                        sv_explain_semantic_view_bind_rust);
+    }
+    return sv_semantic_request_bind(context, input, return_types, names,
+                                    "explain_semantic_view", {"explain_output"},
+                                    options, sv_explain_semantic_view_bind_rust);
+}
+
+// explain_semantic_view takes named LIST(VARCHAR) parameters (`dimensions`,
+// `metrics`, `facts`) so DuckDB's binder type-checks `dimensions := [...]`.
+// It builds a spec carrying those named params (from the single shared
+// `sv_semantic_named_params`) plus an init_local state constructor, and
+// registers via `sv_register_table_function_core` (C-7) — the hand-rolled
+// TableFunction construction it used to duplicate now lives once, in the core.
+// Borrow contract is unchanged: the bind receives `ClientContext &` and opens
+// per-call Connections; no long-lived extension-owned connection. Failures
+// surface through the forwarded `error_buf` (D-08/D-09).
+static bool sv_register_explain_semantic_view_impl(duckdb_database db_handle,
+                                                   char *error_buf,
+                                                   size_t error_buf_len) {
+    const LogicalType arg_types[] = {LogicalType::VARCHAR};
+    SvTableFunctionSpec spec;
+    spec.name = "explain_semantic_view";
+    spec.arg_types = arg_types;
+    spec.arg_count = 1;
+    spec.named_params = sv_semantic_named_params();
+    spec.named_params.emplace_back("structured", LogicalType::BOOLEAN);
+    spec.named_params.emplace_back("analyze", LogicalType::BOOLEAN);
+    spec.bind_cb = sv_explain_semantic_view_bind;
+    spec.exec_cb = sv_emit_varchar_rows;
+    spec.init_local_cb = sv_varchar_init_local;
+    spec.init_global_cb = nullptr;
+    return sv_register_table_function_core(
+        db_handle, spec, "sv_register_explain_semantic_view", error_buf,
+        error_buf_len);
+}
+
+extern "C" {
+    bool sv_register_explain_semantic_view(duckdb_database db_handle,
+                                           char *error_buf, size_t error_buf_len) {
+        return sv_register_explain_semantic_view_impl(
+            db_handle, error_buf, error_buf_len);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// semantic_view_sql — expanded SQL text only
+// ---------------------------------------------------------------------------
+//
+// `semantic_view_sql(view_name, dimensions := [...], metrics := [...],
+// facts := [...])` returns one row with one VARCHAR column (`sql`) holding the
+// SQL `explain_semantic_view()` prints under `-- Expanded SQL:`. Bind wiring is
+// the shared `sv_semantic_request_bind` — same named params, same list
+// serialisation, same per-call Connection borrow — only the dispatcher and the
+// output column differ, so error messages match explain_semantic_view's.
+
+static unique_ptr<FunctionData> sv_semantic_view_sql_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    return sv_semantic_request_bind(context, input, return_types, names,
+                                    "semantic_view_sql", {"sql"}, 0,
+                                    sv_semantic_view_sql_bind_rust);
+}
+
+static bool sv_register_semantic_view_sql_impl(duckdb_database db_handle,
+                                               char *error_buf,
+                                               size_t error_buf_len) {
+    const LogicalType arg_types[] = {LogicalType::VARCHAR};
+    SvTableFunctionSpec spec;
+    spec.name = "semantic_view_sql";
+    spec.arg_types = arg_types;
+    spec.arg_count = 1;
+    spec.named_params = sv_semantic_named_params();
+    spec.bind_cb = sv_semantic_view_sql_bind;
+    spec.exec_cb = sv_emit_varchar_rows;
+    spec.init_local_cb = sv_varchar_init_local;
+    spec.init_global_cb = nullptr;
+    return sv_register_table_function_core(
+        db_handle, spec, "sv_register_semantic_view_sql", error_buf,
+        error_buf_len);
+}
+
+extern "C" {
+    bool sv_register_semantic_view_sql(duckdb_database db_handle,
+                                       char *error_buf, size_t error_buf_len) {
+        return sv_register_semantic_view_sql_impl(
+            db_handle, error_buf, error_buf_len);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// minimal_repro_json — trimmed definition JSON for one query
+// ---------------------------------------------------------------------------
+//
+// `minimal_repro_json(view_name, dimensions := [...], metrics := [...],
+// facts := [...])` returns one row with one VARCHAR column (`definition_json`):
+// the view's definition trimmed to what the request touches. Same shared bind
+// as semantic_view_sql.
+
+static unique_ptr<FunctionData> sv_minimal_repro_json_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    return sv_semantic_request_bind(context, input, return_types, names,
+                                    "minimal_repro_json", {"definition_json"}, 0,
+                                    sv_minimal_repro_json_bind_rust);
+}
+
+static bool sv_register_minimal_repro_json_impl(duckdb_database db_handle,
+                                                char *error_buf,
+                                                size_t error_buf_len) {
+    const LogicalType arg_types[] = {LogicalType::VARCHAR};
+    SvTableFunctionSpec spec;
+    spec.name = "minimal_repro_json";
+    spec.arg_types = arg_types;
+    spec.arg_count = 1;
+    spec.named_params = sv_semantic_named_params();
+    spec.bind_cb = sv_minimal_repro_json_bind;
+    spec.exec_cb = sv_emit_varchar_rows;
+    spec.init_local_cb = sv_varchar_init_local;
+    spec.init_global_cb = nullptr;
+    return sv_register_table_function_core(
+        db_handle, spec, "sv_register_minimal_repro_json", error_buf,
+        error_buf_len);
+}
+
+extern "C" {
+    bool sv_register_minimal_repro_json(duckdb_database db_handle,
+                                        char *error_buf, size_t error_buf_len) {
+        return sv_register_minimal_repro_json_impl(
+            db_handle, error_buf, error_buf_len);
+    }
 }
 
-static unique_ptr<FunctionData> sv_explain_semantic_view_bind(
+// ---------------------------------------------------------------------------
+// create_view_from_semantic — CREATE VIEW statement for a query
+// ---------------------------------------------------------------------------
+//
+// `create_view_from_semantic(view_name, target_view_name, dimensions := [...],
+// metrics := [...], facts := [...])` returns one row with one VARCHAR column
+// (`create_view_sql`). The second positional argument keeps it off the shared
+// `sv_semantic_request_bind`, but the named lists, per-call Connection borrow,
+// and error prefixing are the same. The statement is returned, not executed:
+// the bind runs while DuckDB holds the invoking query's locks.
+
+static unique_ptr<FunctionData> sv_create_view_from_semantic_bind(
     ClientContext &context,
     TableFunctionBindInput &input,
     vector<LogicalType> &return_types,
     vector<string> &names) {
+    static const char *fn_name = "create_view_from_semantic";
     auto bd = make_uniq<SvVarcharBindData>();
     bd->expected_cols = 1;
     return_types.push_back(LogicalType::VARCHAR);
-    names.emplace_back("explain_output");
+    names.emplace_back("create_view_sql");
 
-    if (input.inputs.empty() || input.inputs[0].IsNull()) {
+    if (input.inputs.size() < 2 || input.inputs[0].IsNull()) {
         throw BinderException(
-            "explain_semantic_view: view name is required (positional arg 0)");
+            std::string(fn_name) + ": view name is required (positional arg 0)");
+    }
+    if (input.inputs[1].IsNull()) {
+        throw BinderException(std::string(fn_name) +
+                              ": target view name is required (positional arg 1)");
     }
     std::string view_name = input.inputs[0].GetValue<std::string>();
+    std::string target_name = input.inputs[1].GetValue<std::string>();
 
-    // Pull the three optional named LIST(VARCHAR) parameters. The
-    // `input.named_parameters` map is case-insensitive (per
-    // case_insensitive_map_t). A missing entry means the user did not
-    // supply that named parameter — pass nullptr+0 to the Rust side.
     std::vector<uint8_t> dims_buf, metrics_buf, facts_buf;
-    auto it_d = input.named_parameters.find("dimensions");
-    if (it_d != input.named_parameters.end() && !it_d->second.IsNull()) {
-        dims_buf = sv_serialise_string_list(it_d->second, "dimensions");
-    }
-    auto it_m = input.named_parameters.find("metrics");
-    if (it_m != input.named_parameters.end() && !it_m->second.IsNull()) {
-        metrics_buf = sv_serialise_string_list(it_m->second, "metrics");
-    }
-    auto it_f = input.named_parameters.find("facts");
-    if (it_f != input.named_parameters.end() && !it_f->second.IsNull()) {
-        facts_buf = sv_serialise_string_list(it_f->second, "facts");
-    }
+    sv_serialise_request_lists(input, dims_buf, metrics_buf, facts_buf);
 
     Connection probe(*context.db);
     duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
@@ -2202,9 +3224,10 @@ static unique_ptr<FunctionData> sv_explain_semantic_view_bind(
     char error_buf[1024];
     std::memset(error_buf, 0, sizeof(error_buf));
 
-    uint8_t rc = sv_explain_semantic_view_bind_rust(
+    uint8_t rc = sv_create_view_from_semantic_bind_rust(
         borrowed,
         reinterpret_cast<const uint8_t *>(view_name.data()), view_name.size(),
+        reinterpret_cast<const uint8_t *>(target_name.data()), target_name.size(),
         dims_buf.empty()    ? nullptr : dims_buf.data(),    dims_buf.size(),
         metrics_buf.empty() ? nullptr : metrics_buf.data(), metrics_buf.size(),
         facts_buf.empty()   ? nullptr : facts_buf.data(),   facts_buf.size(),
@@ -2212,48 +3235,184 @@ static unique_ptr<FunctionData> sv_explain_semantic_view_bind(
         error_buf, sizeof(error_buf));
 
     if (rc != 0) {
-        throw BinderException(std::string("explain_semantic_view: ") + error_buf);
+        throw BinderException(std::string(fn_name) + ": " + error_buf);
     }
-    sv_parse_varchar_payload(payload.ptr, payload.len, *bd,
-                             "explain_semantic_view");
+    sv_parse_varchar_payload(payload.ptr, payload.len, *bd, fn_name);
     return std::move(bd);
 }
 
-// explain_semantic_view takes named LIST(VARCHAR) parameters (`dimensions`,
-// `metrics`, `facts`) so DuckDB's binder type-checks `dimensions := [...]`.
-// It builds a spec carrying those named params (from the single shared
-// `sv_semantic_named_params`) plus an init_local state constructor, and
-// registers via `sv_register_table_function_core` (C-7) — the hand-rolled
-// TableFunction construction it used to duplicate now lives once, in the core.
-// Borrow contract is unchanged: the bind receives `ClientContext &` and opens
-// per-call Connections; no long-lived extension-owned connection. Failures
-// surface through the forwarded `error_buf` (D-08/D-09).
-static bool sv_register_explain_semantic_view_impl(duckdb_database db_handle,
-                                                   char *error_buf,
-                                                   size_t error_buf_len) {
+static bool sv_register_create_view_from_semantic_impl(duckdb_database db_handle,
+                                                       char *error_buf,
+                                                       size_t error_buf_len) {
+    const LogicalType arg_types[] = {LogicalType::VARCHAR, LogicalType::VARCHAR};
+    SvTableFunctionSpec spec;
+    spec.name = "create_view_from_semantic";
+    spec.arg_types = arg_types;
+    spec.arg_count = 2;
+    spec.named_params = sv_semantic_named_params();
+    spec.bind_cb = sv_create_view_from_semantic_bind;
+    spec.exec_cb = sv_emit_varchar_rows;
+    spec.init_local_cb = sv_varchar_init_local;
+    spec.init_global_cb = nullptr;
+    return sv_register_table_function_core(
+        db_handle, spec, "sv_register_create_view_from_semantic", error_buf,
+        error_buf_len);
+}
+
+extern "C" {
+    bool sv_register_create_view_from_semantic(duckdb_database db_handle,
+                                               char *error_buf,
+                                               size_t error_buf_len) {
+        return sv_register_create_view_from_semantic_impl(
+            db_handle, error_buf, error_buf_len);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// estimate_semantic_query — optimizer row estimate
+// ---------------------------------------------------------------------------
+//
+// `estimate_semantic_query(view_name, dimensions := [...], metrics := [...],
+// facts := [...])` returns one row: `estimate` (BIGINT, the planner's
+// estimated cardinality for the expanded query) and `message` (VARCHAR, why
+// the estimate is NULL). The shared request bind parses the dispatcher's two
+// VARCHAR cells; this bind then retypes the first column and the exec turns
+// each empty cell into NULL and the estimate text into a BIGINT.
+
+static unique_ptr<FunctionData> sv_estimate_semantic_query_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    auto bd = sv_semantic_request_bind(context, input, return_types, names,
+                                       "estimate_semantic_query",
+                                       {"estimate", "message"}, 0,
+                                       sv_estimate_semantic_query_bind_rust);
+    return_types[0] = LogicalType::BIGINT;
+    return bd;
+}
+
+static void sv_emit_estimate_rows(
+    ClientContext & /*context*/,
+    TableFunctionInput &data_p,
+    DataChunk &output) {
+    auto &bd = data_p.bind_data->Cast<SvVarcharBindData>();
+    auto *state_p = data_p.local_state.get();
+    if (state_p == nullptr) {
+        // See sv_emit_varchar_rows: registration refuses a null init_cb.
+        throw InternalException(
+            "sv_emit_estimate_rows: local_state missing despite init_local registration");
+    }
+    auto &state = state_p->Cast<SvVarcharLocalState>();
+    idx_t count = 0;
+    while (state.next_row < bd.rows.size() && count < STANDARD_VECTOR_SIZE) {
+        const auto &row = bd.rows[state.next_row];
+        output.SetValue(0, count,
+                        row[0].empty() ? Value(LogicalType::BIGINT)
+                                       : Value::BIGINT(std::stoll(row[0])));
+        output.SetValue(1, count,
+                        row[1].empty() ? Value(LogicalType::VARCHAR) : Value(row[1]));
+        ++state.next_row;
+        ++count;
+    }
+    output.SetCardinality(count);
+}
+
+static bool sv_register_estimate_semantic_query_impl(duckdb_database db_handle,
+                                                     char *error_buf,
+                                                     size_t error_buf_len) {
     const LogicalType arg_types[] = {LogicalType::VARCHAR};
     SvTableFunctionSpec spec;
-    spec.name = "explain_semantic_view";
+    spec.name = "estimate_semantic_query";
     spec.arg_types = arg_types;
     spec.arg_count = 1;
     spec.named_params = sv_semantic_named_params();
-    spec.bind_cb = sv_explain_semantic_view_bind;
-    spec.exec_cb = sv_emit_varchar_rows;
+    spec.bind_cb = sv_estimate_semantic_query_bind;
+    spec.exec_cb = sv_emit_estimate_rows;
     spec.init_local_cb = sv_varchar_init_local;
     spec.init_global_cb = nullptr;
     return sv_register_table_function_core(
-        db_handle, spec, "sv_register_explain_semantic_view", error_buf,
+        db_handle, spec, "sv_register_estimate_semantic_query", error_buf,
         error_buf_len);
 }
 
 extern "C" {
-    bool sv_register_explain_semantic_view(duckdb_database db_handle,
-                                           char *error_buf, size_t error_buf_len) {
-        return sv_register_explain_semantic_view_impl(
+    bool sv_register_estimate_semantic_query(duckdb_database db_handle,
+                                             char *error_buf, size_t error_buf_len) {
+        return sv_register_estimate_semantic_query_impl(
             db_handle, error_buf, error_buf_len);
     }
 }
 
+// ---------------------------------------------------------------------------
+// validate_semantic_query — dry-run pass/fail scalar
+// ---------------------------------------------------------------------------
+//
+// `validate_semantic_query(view VARCHAR, dimensions LIST(VARCHAR),
+// metrics LIST(VARCHAR)) -> VARCHAR` runs the request-inspection lookup +
+// expand + EXPLAIN without executing the query, returning `OK` or the failure
+// message. It sits down here rather than with the other scalars because it
+// reuses `sv_serialise_string_list`. Same per-chunk Connection borrow as the
+// scalars above; a NULL view name yields NULL and a NULL list is empty.
+
+static void sv_validate_semantic_query_exec(DataChunk &args,
+                                            ExpressionState &state,
+                                            Vector &result) {
+    auto &name_vec = args.data[0];
+    name_vec.Flatten(args.size());
+    auto name_data = FlatVector::GetData<string_t>(name_vec);
+    auto &name_validity = FlatVector::Validity(name_vec);
+    auto &result_validity = FlatVector::Validity(result);
+
+    Connection probe(*state.GetContext().db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    for (idx_t i = 0; i < args.size(); ++i) {
+        if (!name_validity.RowIsValid(i)) {
+            result_validity.SetInvalid(i);
+            continue;
+        }
+        const string_t &n = name_data[i];
+        std::vector<uint8_t> dims_buf, metrics_buf;
+        Value dims = args.data[1].GetValue(i);
+        if (!dims.IsNull()) {
+            dims_buf = sv_serialise_string_list(dims, "dimensions");
+        }
+        Value metrics = args.data[2].GetValue(i);
+        if (!metrics.IsNull()) {
+            metrics_buf = sv_serialise_string_list(metrics, "metrics");
+        }
+        sv_emit_scalar_row(
+            result, i, "validate_semantic_query",
+            [&](char **op, size_t *ol, char *eb, size_t ebl) {
+                return sv_validate_semantic_query_exec_rust(
+                    borrowed,
+                    reinterpret_cast<const uint8_t *>(n.GetData()), n.GetSize(),
+                    dims_buf.empty() ? nullptr : dims_buf.data(), dims_buf.size(),
+                    metrics_buf.empty() ? nullptr : metrics_buf.data(),
+                    metrics_buf.size(),
+                    op, ol, eb, ebl);
+            });
+    }
+    if (args.AllConstant()) {
+        result.SetVectorType(VectorType::CONSTANT_VECTOR);
+    }
+}
+
+extern "C" {
+    bool sv_register_validate_semantic_query(duckdb_database db_handle,
+                                             char *error_buf, size_t error_buf_len) {
+        auto list_varchar = LogicalType::LIST(LogicalType::VARCHAR);
+        LogicalType args[] = {LogicalType::VARCHAR, list_varchar, list_varchar};
+        return sv_register_scalar_function(
+            db_handle, "validate_semantic_query",
+            args, 3,
+            LogicalType::VARCHAR,
+            sv_validate_semantic_query_exec,
+            error_buf, error_buf_len);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Phase 65 Plan 05 Task 6 (Wave 6) — semantic_view migration
 // ---------------------------------------------------------------------------
@@ -2457,6 +3616,49 @@ static std::vector<LogicalType> sv_resolve_output_logical_types(
     return out;
 }
 
+// Decode the schema + execution_sql payload returned by
+// sv_semantic_view_bind_rust / sv_semantic_query_json_bind_rust and declare
+// the output columns. `probe` is the Connection the dispatcher borrowed.
+static unique_ptr<FunctionData> sv_semantic_view_bind_from_payload(
+    Connection &probe,
+    const SvOwnedBuffer &payload,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    auto bd = make_uniq<SemanticViewBindData>();
+
+    // Parse the schema + execution_sql wire format.
+    size_t offset = 0;
+    uint32_t n_cols = sv_read_u32_le(payload.ptr, payload.len, offset, "semantic_view");
+    bd->columns.reserve(n_cols);
+    for (uint32_t i = 0; i < n_cols; ++i) {
+        SemanticViewColumnInfo info;
+        info.name = sv_read_string(payload.ptr, payload.len, offset, "semantic_view");
+        info.type_id = sv_read_u32_le(payload.ptr, payload.len, offset, "semantic_view");
+        bd->columns.push_back(std::move(info));
+    }
+    bd->execution_sql = sv_read_string(payload.ptr, payload.len, offset, "semantic_view");
+    if (offset != payload.len) {
+        throw BinderException(
+            "semantic_view: FFI buffer has trailing bytes (consumed " +
+            std::to_string(offset) + " of " + std::to_string(payload.len) + ")");
+    }
+    bd->expanded_sql_for_error = bd->execution_sql;
+
+    // Resolve declared logical types — runs a LIMIT-0 probe on the SAME
+    // Connection the FFI dispatcher already borrowed, if any DECIMAL/LIST
+    // column is in the schema (so width/scale/child-type can be honoured).
+    // Phase 65.1 WR-07: reusing `probe` here avoids a second Connection
+    // ctor/dtor pair and keeps both queries on the same
+    // transaction/catalog snapshot.
+    auto declared_types = sv_resolve_output_logical_types(
+        probe, bd->columns, bd->execution_sql);
+    for (idx_t i = 0; i < bd->columns.size(); ++i) {
+        return_types.push_back(declared_types[i]);
+        names.push_back(bd->columns[i].name);
+    }
+    return std::move(bd);
+}
+
 static unique_ptr<FunctionData> sv_semantic_view_bind(
     ClientContext &context,
     TableFunctionBindInput &input,
@@ -2499,40 +3701,7 @@ static unique_ptr<FunctionData> sv_semantic_view_bind(
     if (rc != 0) {
         throw BinderException(std::string("semantic_view: ") + error_buf);
     }
-
-    auto bd = make_uniq<SemanticViewBindData>();
-
-    // Parse the schema + execution_sql wire format.
-    size_t offset = 0;
-    uint32_t n_cols = sv_read_u32_le(payload.ptr, payload.len, offset, "semantic_view");
-    bd->columns.reserve(n_cols);
-    for (uint32_t i = 0; i < n_cols; ++i) {
-        SemanticViewColumnInfo info;
-        info.name = sv_read_string(payload.ptr, payload.len, offset, "semantic_view");
-        info.type_id = sv_read_u32_le(payload.ptr, payload.len, offset, "semantic_view");
-        bd->columns.push_back(std::move(info));
-    }
-    bd->execution_sql = sv_read_string(payload.ptr, payload.len, offset, "semantic_view");
-    if (offset != payload.len) {
-        throw BinderException(
-            "semantic_view: FFI buffer has trailing bytes (consumed " +
-            std::to_string(offset) + " of " + std::to_string(payload.len) + ")");
-    }
-    bd->expanded_sql_for_error = bd->execution_sql;
-
-    // Resolve declared logical types — runs a LIMIT-0 probe on the SAME
-    // Connection the FFI dispatcher already borrowed, if any DECIMAL/LIST
-    // column is in the schema (so width/scale/child-type can be honoured).
-    // Phase 65.1 WR-07: reusing `probe` here avoids a second Connection
-    // ctor/dtor pair and keeps both queries on the same
-    // transaction/catalog snapshot.
-    auto declared_types = sv_resolve_output_logical_types(
-        probe, bd->columns, bd->execution_sql);
-    for (idx_t i = 0; i < bd->columns.size(); ++i) {
-        return_types.push_back(declared_types[i]);
-        names.push_back(bd->columns[i].name);
-    }
-    return std::move(bd);
+    return sv_semantic_view_bind_from_payload(probe, payload, return_types, names);
 }
 
 static unique_ptr<GlobalTableFunctionState> sv_semantic_view_init_global(
@@ -2650,6 +3819,73 @@ extern "C" {
     }
 }
 
+// ---------------------------------------------------------------------------
+// semantic_query_json(view_name, request_json)
+// ---------------------------------------------------------------------------
+//
+// The whole QueryRequest as one JSON VARCHAR (names, dimension filters,
+// grouping mode, output aliases, ...), decoded by
+// `sv_semantic_query_json_bind_rust`. The dispatcher returns the same payload
+// as semantic_view()'s, so bind decoding, init_global and exec are shared.
+
+static unique_ptr<FunctionData> sv_semantic_query_json_bind(
+    ClientContext &context,
+    TableFunctionBindInput &input,
+    vector<LogicalType> &return_types,
+    vector<string> &names) {
+    if (input.inputs.size() != 2 || input.inputs[0].IsNull()) {
+        throw BinderException(
+            "semantic_query_json: view name is required (positional arg 0)");
+    }
+    if (input.inputs[1].IsNull()) {
+        throw BinderException("semantic_query_json: request JSON must not be NULL");
+    }
+    std::string view_name = input.inputs[0].GetValue<std::string>();
+    std::string request_json = input.inputs[1].GetValue<std::string>();
+
+    Connection probe(*context.db);
+    duckdb_connection borrowed = reinterpret_cast<duckdb_connection>(&probe);
+
+    SvOwnedBuffer payload;
+    char error_buf[1024];
+    std::memset(error_buf, 0, sizeof(error_buf));
+    uint8_t rc = sv_semantic_query_json_bind_rust(
+        borrowed,
+        reinterpret_cast<const uint8_t *>(view_name.data()), view_name.size(),
+        reinterpret_cast<const uint8_t *>(request_json.data()), request_json.size(),
+        &payload.ptr, &payload.len,
+        error_buf, sizeof(error_buf));
+    if (rc != 0) {
+        throw BinderException(std::string("semantic_query_json: ") + error_buf);
+    }
+    return sv_semantic_view_bind_from_payload(probe, payload, return_types, names);
+}
+
+static bool sv_register_semantic_query_json_impl(duckdb_database db_handle,
+                                                 char *error_buf,
+                                                 size_t error_buf_len) {
+    const LogicalType arg_types[] = {LogicalType::VARCHAR, LogicalType::VARCHAR};
+    SvTableFunctionSpec spec;
+    spec.name = "semantic_query_json";
+    spec.arg_types = arg_types;
+    spec.arg_count = 2;
+    spec.bind_cb = sv_semantic_query_json_bind;
+    spec.exec_cb = sv_semantic_view_function;
+    spec.init_local_cb = nullptr;
+    spec.init_global_cb = sv_semantic_view_init_global;
+    return sv_register_table_function_core(
+        db_handle, spec, "sv_register_semantic_query_json", error_buf,
+        error_buf_len);
+}
+
+extern "C" {
+    bool sv_register_semantic_query_json(duckdb_database db_handle,
+                                         char *error_buf, size_t error_buf_len) {
+        return sv_register_semantic_query_json_impl(
+            db_handle, error_buf, error_buf_len);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // sv_register_parser_hooks -- called from Rust after C API init
 // ---------------------------------------------------------------------------
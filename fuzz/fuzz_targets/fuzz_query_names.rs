@@ -15,7 +15,7 @@ fuzz_target!(|input: NameFuzzInput| {
     let req = QueryRequest {
         dimensions: input.dim_names.into_iter().map(Into::into).collect(),
         metrics: input.metric_names.into_iter().map(Into::into).collect(),
-        facts: vec![],
+        ..Default::default()
     };
     if let Ok(sql) = expand("fuzz_view", &def, &req) {
         assert!(!sql.is_empty());
@@ -0,0 +1,172 @@
+#![no_main]
+//! Join resolution over arbitrary relationship graphs, through `plan()` —
+//! the public entry point that reports exactly the joins `expand()` emits.
+//!
+//! Inputs are bounded (at most 8 tables, 12 relationships, 8 requested
+//! dimensions) so every run is fast; termination is the libFuzzer timeout.
+//! The structural oracles only apply to graphs `validate_graph` accepts, as
+//! CREATE never stores any other.
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use semantic_views::expand::{plan, PlannedJoin, QueryRequest};
+use semantic_views::graph::validate_graph;
+use semantic_views::model::{Dimension, Join, SemanticViewDefinition, TableRef};
+
+const MAX_TABLES: usize = 8;
+const MAX_JOINS: usize = 12;
+const MAX_DIMS: usize = 8;
+
+#[derive(Debug, Arbitrary)]
+struct JoinFuzzInput {
+    table_count: u8,
+    /// `(from, to, named)` table indices, taken modulo the table count.
+    edges: Vec<(u8, u8, bool)>,
+    /// Requested dimensions as table indices; one dimension per table.
+    requested: Vec<u8>,
+}
+
+fn alias(i: usize) -> String {
+    format!("t{i}")
+}
+
+fn definition(input: &JoinFuzzInput) -> SemanticViewDefinition {
+    let n = 1 + usize::from(input.table_count) % MAX_TABLES;
+    SemanticViewDefinition {
+        tables: (0..n)
+            .map(|i| TableRef {
+                alias: alias(i),
+                table: format!("table_{i}"),
+                pk_columns: vec!["id".to_string()],
+                ..Default::default()
+            })
+            .collect(),
+        joins: input
+            .edges
+            .iter()
+            .take(MAX_JOINS)
+            .enumerate()
+            .map(|(k, &(from, to, named))| {
+                let to = usize::from(to) % n;
+                Join {
+                    table: alias(to),
+                    from_alias: alias(usize::from(from) % n),
+                    fk_columns: vec![format!("t{to}_id")],
+                    ref_columns: vec!["id".to_string()],
+                    name: named.then(|| format!("r{k}")),
+                    ..Default::default()
+                }
+            })
+            .collect(),
+        dimensions: (0..n)
+            .map(|i| Dimension {
+                name: format!("d{i}"),
+                expr: format!("{}.x", alias(i)),
+                source_table: Some(alias(i)),
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Whether every table is reachable from the base table over relationships,
+/// followed in either direction.
+fn connected_to_base(def: &SemanticViewDefinition) -> bool {
+    let mut reached = vec![alias(0)];
+    let mut grew = true;
+    while grew {
+        grew = false;
+        for join in &def.joins {
+            let ends = [&join.from_alias, &join.table];
+            if let [Some(_), None] | [None, Some(_)] =
+                ends.map(|e| reached.iter().find(|r| *r == e))
+            {
+                let new = ends
+                    .into_iter()
+                    .find(|e| !reached.contains(e))
+                    .expect("one end is new");
+                reached.push(new.clone());
+                grew = true;
+            }
+        }
+    }
+    reached.len() == def.tables.len()
+}
+
+fn request(dims: &[String]) -> QueryRequest {
+    QueryRequest::builder()
+        .dimensions(dims.iter().cloned())
+        .build()
+}
+
+fuzz_target!(|input: JoinFuzzInput| {
+    let def = definition(&input);
+    let n = def.tables.len();
+    let mut dims: Vec<String> = Vec::new();
+    for &i in input.requested.iter().take(MAX_DIMS) {
+        let name = format!("d{}", usize::from(i) % n);
+        if !dims.contains(&name) {
+            dims.push(name);
+        }
+    }
+    if dims.is_empty() {
+        return;
+    }
+
+    // Never panics, whatever the graph looks like.
+    let Ok(planned) = plan("fuzz_view", &def, &request(&dims)) else {
+        return;
+    };
+    // The oracles below hold for a relationship tree spanning every table.
+    // Validation also accepts a component detached from the base table
+    // (`t1 -> t2` next to a lone base `t0`), which the resolver joins with
+    // its legacy reverse-edge walk; and a view with no relationships never
+    // joins at all.
+    if validate_graph(&def).is_err() || !connected_to_base(&def) {
+        return;
+    }
+    let joins: Vec<PlannedJoin> = planned.joins;
+
+    let root = alias(0);
+    let mut emitted: Vec<&str> = vec![&root];
+    for join in &joins {
+        // Every join is a declared relationship, emitted once, and attaches
+        // a new table to one already in the FROM clause — so every joined
+        // table is transitively reachable from the base table.
+        let edge = def
+            .joins
+            .get(join.join_index)
+            .unwrap_or_else(|| panic!("join index out of range: {join:?}"));
+        assert!(
+            !emitted.contains(&join.alias.as_str()),
+            "table joined twice: {joins:?}"
+        );
+        let anchor = if edge.table == join.alias {
+            &edge.from_alias
+        } else {
+            assert_eq!(edge.from_alias, join.alias, "join {join:?} is not {edge:?}");
+            &edge.table
+        };
+        assert!(
+            emitted.contains(&anchor.as_str()),
+            "join {join:?} references '{anchor}' before it is joined: {joins:?}"
+        );
+        emitted.push(&join.alias);
+    }
+
+    // Every requested dimension's table is in the FROM clause.
+    for dim in &dims {
+        let table = alias(dim[1..].parse().expect("dimension names are d<index>"));
+        assert!(
+            emitted.contains(&table.as_str()),
+            "'{table}' for {dim} not joined: {joins:?}"
+        );
+    }
+
+    // Request order does not change the joins or their order.
+    let reversed: Vec<String> = dims.iter().rev().cloned().collect();
+    let again = plan("fuzz_view", &def, &request(&reversed))
+        .expect("the same names in another order plan too")
+        .joins;
+    assert_eq!(joins, again, "join order depends on request order");
+});
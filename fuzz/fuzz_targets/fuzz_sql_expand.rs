@@ -117,7 +117,7 @@ fuzz_target!(|input: FuzzInput| {
     let req = QueryRequest {
         dimensions: input.dim_names.into_iter().map(Into::into).collect(),
         metrics: input.metric_names.into_iter().map(Into::into).collect(),
-        facts: vec![],
+        ..Default::default()
     };
     let fragments_ok = def_fragments_balanced(&input.def);
     if let Ok(sql) = expand("fuzz_view", &input.def, &req) {
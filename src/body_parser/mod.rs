@@ -182,6 +182,10 @@ pub fn parse_keyword_body(text: &str, base_offset: usize) -> Result<KeywordBody,
             expr: e.expr,
             source_table: Some(e.source_alias),
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: e.comment,
             synonyms: e.synonyms,
         })
@@ -200,6 +204,9 @@ pub fn parse_keyword_body(text: &str, base_offset: usize) -> Result<KeywordBody,
             access: m.access,
             non_additive_by: m.non_additive_by,
             window_spec: m.window_spec,
+            filter: None,
+            approx: false,
+            distinct: false,
         })
         .collect();
 
@@ -343,6 +350,22 @@ pub fn parse_keyword_body(text: &str, base_offset: usize) -> Result<KeywordBody,
                     position: None,
                 });
             }
+            // RATIO_TO_REPORT expands to `m / SUM(m) OVER (PARTITION BY ...)`,
+            // which has no use for extra arguments, ORDER BY, or a frame.
+            if ws.window_function.eq_ignore_ascii_case("RATIO_TO_REPORT")
+                && (!ws.extra_args.is_empty()
+                    || !ws.order_by.is_empty()
+                    || ws.frame_clause.is_some())
+            {
+                return Err(ParseError {
+                    message: format!(
+                        "Window metric '{}': RATIO_TO_REPORT takes a single metric argument \
+                         and only PARTITION BY [EXCLUDING] in its OVER clause.",
+                        metric.name
+                    ),
+                    position: None,
+                });
+            }
         }
     }
 
@@ -3489,6 +3512,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_keyword_body_ratio_to_report_rejects_order_by_and_args() {
+        let ok = "AS TABLES (o AS orders PRIMARY KEY (id)) \
+                  DIMENSIONS (o.region AS o.region) \
+                  METRICS (\
+                      o.total_qty AS SUM(o.qty), \
+                      o.qty_share AS RATIO_TO_REPORT(total_qty) OVER ()\
+                  )";
+        let ws = parse_keyword_body(ok, 0).unwrap().metrics[1]
+            .window_spec
+            .clone()
+            .unwrap();
+        assert_eq!(ws.window_function, "RATIO_TO_REPORT");
+
+        for over in [
+            "RATIO_TO_REPORT(total_qty) OVER (ORDER BY region)",
+            "RATIO_TO_REPORT(total_qty, 2) OVER ()",
+        ] {
+            let body = format!(
+                "AS TABLES (o AS orders PRIMARY KEY (id)) \
+                 DIMENSIONS (o.region AS o.region) \
+                 METRICS (o.total_qty AS SUM(o.qty), o.qty_share AS {over})"
+            );
+            let err = parse_keyword_body(&body, 0).unwrap_err();
+            assert!(
+                err.message
+                    .contains("RATIO_TO_REPORT takes a single metric argument"),
+                "{over}: {}",
+                err.message
+            );
+        }
+    }
+
     #[test]
     fn parse_keyword_body_window_order_by_invalid_dim_error() {
         let body = "AS TABLES (o AS orders PRIMARY KEY (id)) \
@@ -0,0 +1,228 @@
+//! `extends` resolution: merge a stored definition with its ancestors.
+//!
+//! A view that `extends` another stores only its own entries; the parent may
+//! be redefined or dropped independently. The chain is therefore followed at
+//! read time, whenever a view's content is read, rather than copied in at
+//! CREATE. The lookup is a closure so the chain walk is testable without a
+//! database connection.
+
+use std::collections::HashMap;
+
+use crate::model::SemanticViewDefinition;
+
+/// Resolve `name`'s stored definition `json` against its `extends` chain and
+/// return the merged definition as JSON.
+///
+/// `lookup` fetches a stored definition by bare view name. A definition
+/// without `extends` is returned unchanged. Otherwise each ancestor is merged
+/// in from the root down ([`SemanticViewDefinition::inherit_from`]), the
+/// merged relationships get their cardinality re-inferred against the merged
+/// tables, and the result is run through the CREATE-time checks that a child
+/// alone could not satisfy. Because the merged JSON (not the child's stored
+/// row) is what callers cache on, redefining a parent changes the key of
+/// every view that inherits from it.
+///
+/// # Errors
+///
+/// A missing parent, a cycle in the chain, an unparseable ancestor, or a
+/// merged definition that fails validation.
+pub fn resolve_extends<F>(name: &str, json: String, mut lookup: F) -> Result<String, String>
+where
+    F: FnMut(&str) -> Result<Option<String>, String>,
+{
+    let def = SemanticViewDefinition::from_json(name, &json)?;
+    if def.extends.is_none() {
+        return Ok(json);
+    }
+
+    // Walk up to the root, child first.
+    let mut chain = vec![name.to_string()];
+    let mut defs = vec![def];
+    while let Some(parent_raw) = defs.last().and_then(|d| d.extends.clone()) {
+        let parent = crate::ident::normalize_view_name(&parent_raw).map_err(|e| {
+            format!("semantic view '{name}' extends an invalid view name '{parent_raw}': {e}")
+        })?;
+        if chain.contains(&parent) {
+            chain.push(parent);
+            return Err(format!(
+                "semantic view '{name}' has a cyclic extends chain: {}",
+                chain.join(" -> ")
+            ));
+        }
+        let Some(parent_json) = lookup(&parent)? else {
+            return Err(format!(
+                "semantic view '{}' extends '{parent}', which does not exist",
+                chain.last().map_or(name, String::as_str)
+            ));
+        };
+        defs.push(SemanticViewDefinition::from_json(&parent, &parent_json)?);
+        chain.push(parent);
+    }
+
+    let mut merged = defs.pop().expect("chain holds at least the child");
+    while let Some(mut child) = defs.pop() {
+        child.inherit_from(&merged);
+        merged = child;
+    }
+    merged.extends = None;
+
+    crate::graph::infer_cardinality(&merged.tables, &mut merged.joins)
+        .map_err(|e| format!("semantic view '{name}': {}", e.message))?;
+    if let Err(errors) = merged.validate() {
+        return Err(format!(
+            "semantic view '{name}' is invalid once merged with '{}': {}",
+            chain[1..].join("' -> '"),
+            errors[0]
+        ));
+    }
+    serde_json::to_string(&merged).map_err(|e| e.to_string())
+}
+
+/// [`resolve_extends`] for every `(name, json)` row of a catalog listing,
+/// looking ancestors up among the same rows instead of querying per parent.
+/// Returns one result per row, in order, so a listing can skip (or fall back
+/// to the stored row for) a view whose chain does not resolve.
+#[must_use]
+pub fn resolve_listed(entries: &[(String, String)]) -> Vec<Result<String, String>> {
+    let by_name: HashMap<&str, &str> = entries
+        .iter()
+        .map(|(name, json)| (name.as_str(), json.as_str()))
+        .collect();
+    entries
+        .iter()
+        .map(|(name, json)| {
+            resolve_extends(name, json.clone(), |parent| {
+                Ok(by_name.get(parent).map(|j| (*j).to_string()))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{resolve_extends, resolve_listed};
+    use crate::model::SemanticViewDefinition;
+
+    const PARENT: &str = r#"{
+        "tables": [{"alias": "o", "table": "orders", "pk_columns": ["id"]}],
+        "dimensions": [
+            {"name": "region", "expr": "o.region", "source_table": "o"},
+            {"name": "status", "expr": "o.status", "source_table": "o"}
+        ],
+        "metrics": [{"name": "revenue", "expr": "SUM(o.amount)", "source_table": "o"}]
+    }"#;
+
+    fn resolve(name: &str, views: &[(&str, &str)]) -> Result<SemanticViewDefinition, String> {
+        let views: HashMap<&str, &str> = views.iter().copied().collect();
+        let json = resolve_extends(name, views[name].to_string(), |n| {
+            Ok(views.get(n).map(|j| (*j).to_string()))
+        })?;
+        SemanticViewDefinition::from_json(name, &json)
+    }
+
+    #[test]
+    fn child_inherits_and_overrides_by_name() {
+        let child = r#"{
+            "extends": "Parent",
+            "dimensions": [{"name": "REGION", "expr": "upper(o.region)", "source_table": "o"}],
+            "metrics": [{"name": "orders", "expr": "COUNT(*)", "source_table": "o"}]
+        }"#;
+        let def = resolve("child", &[("parent", PARENT), ("child", child)]).unwrap();
+        assert_eq!(def.extends, None);
+        assert_eq!(def.base_table(), "orders");
+        let dims: Vec<(&str, &str)> = def
+            .dimensions
+            .iter()
+            .map(|d| (d.name.as_str(), d.expr.as_str()))
+            .collect();
+        assert_eq!(
+            dims,
+            [("REGION", "upper(o.region)"), ("status", "o.status")]
+        );
+        let metrics: Vec<&str> = def.metrics.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(metrics, ["revenue", "orders"]);
+    }
+
+    #[test]
+    fn chain_merges_from_the_root_down() {
+        let middle = r#"{"extends": "parent", "dimensions": [],
+            "metrics": [{"name": "revenue", "expr": "SUM(o.net)", "source_table": "o"}]}"#;
+        let leaf = r#"{"extends": "middle", "dimensions": [], "metrics": []}"#;
+        let def = resolve(
+            "leaf",
+            &[("parent", PARENT), ("middle", middle), ("leaf", leaf)],
+        )
+        .unwrap();
+        assert_eq!(def.metrics[0].expr, "SUM(o.net)");
+        assert_eq!(def.dimensions.len(), 2);
+    }
+
+    #[test]
+    fn definition_without_extends_is_returned_verbatim() {
+        let json = resolve_extends("parent", PARENT.to_string(), |_| {
+            panic!("no lookup expected")
+        })
+        .unwrap();
+        assert_eq!(json, PARENT);
+    }
+
+    #[test]
+    fn missing_parent_is_an_error() {
+        let child = r#"{"extends": "gone", "dimensions": [], "metrics": []}"#;
+        let err = resolve("child", &[("child", child)]).unwrap_err();
+        assert_eq!(
+            err,
+            "semantic view 'child' extends 'gone', which does not exist"
+        );
+    }
+
+    #[test]
+    fn cycle_is_an_error() {
+        let a = r#"{"extends": "b", "dimensions": [], "metrics": []}"#;
+        let b = r#"{"extends": "a", "dimensions": [], "metrics": []}"#;
+        let err = resolve("a", &[("a", a), ("b", b)]).unwrap_err();
+        assert_eq!(
+            err,
+            "semantic view 'a' has a cyclic extends chain: a -> b -> a"
+        );
+
+        let own = r#"{"extends": "self_ref", "dimensions": [], "metrics": []}"#;
+        let err = resolve("self_ref", &[("self_ref", own)]).unwrap_err();
+        assert!(err.contains("self_ref -> self_ref"), "{err}");
+    }
+
+    #[test]
+    fn merged_definition_is_validated() {
+        // Fine on its own, but collides with the parent's `revenue` metric.
+        let child = r#"{"extends": "parent", "metrics": [],
+            "dimensions": [{"name": "revenue", "expr": "o.amount", "source_table": "o"}]}"#;
+        let err = resolve("child", &[("parent", PARENT), ("child", child)]).unwrap_err();
+        assert!(
+            err.starts_with("semantic view 'child' is invalid once merged with 'parent': "),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn listed_rows_resolve_against_each_other() {
+        let child = r#"{"extends": "parent", "dimensions": [], "metrics": []}"#;
+        let orphan = r#"{"extends": "gone", "dimensions": [], "metrics": []}"#;
+        let rows: Vec<(String, String)> =
+            [("child", child), ("orphan", orphan), ("parent", PARENT)]
+                .iter()
+                .map(|(n, j)| ((*n).to_string(), (*j).to_string()))
+                .collect();
+        let resolved = resolve_listed(&rows);
+        let child =
+            SemanticViewDefinition::from_json("child", resolved[0].as_ref().unwrap()).unwrap();
+        assert_eq!(child.dimensions.len(), 2);
+        assert_eq!(child.metrics.len(), 1);
+        assert!(resolved[1]
+            .as_ref()
+            .unwrap_err()
+            .contains("'gone', which does not exist"));
+        assert_eq!(resolved[2].as_ref().unwrap(), PARENT);
+    }
+}
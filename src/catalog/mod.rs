@@ -8,10 +8,13 @@
 //! `INSERT/DELETE/UPDATE` against `_definitions` directly on the caller's
 //! connection, and any cached mirror would diverge across rollback.
 
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use duckdb::Connection;
 
+/// `extends` chain resolution for definitions fetched for expansion.
+pub mod extends;
 /// Write-side SQL builders (existence/collision guards) for `_definitions`.
 pub(crate) mod writes;
 
@@ -41,11 +44,98 @@ pub fn view_not_found_msg(name: &str) -> String {
     format!("semantic view '{name}' does not exist")
 }
 
+/// What [`init_catalog`] did on this load.
+///
+/// The companion-file import and the AR-4 version stamping otherwise happen
+/// silently; these counts make them observable when diagnosing a load (and to
+/// tests). A read-only load touches nothing and reports all zeros.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatalogLoad {
+    /// Whether a v0.1.0 companion file was found, imported, and deleted.
+    pub migrated_companion: bool,
+    /// Definitions imported from the companion file: the views not yet in
+    /// `_definitions`. An existing row is never replaced.
+    pub imported: usize,
+    /// How the companion file disagreed with the rows already in
+    /// `_definitions`. Empty when no companion file was found.
+    pub divergence: CompanionDivergence,
+    /// A companion file that was left in place, unimported, because some of
+    /// its views differ from the stored rows (`divergence.differing`).
+    pub conflicting_companion: Option<PathBuf>,
+    /// Rows stamped to the current `schema_version` by the upgrade pass.
+    pub upgraded: usize,
+    /// Rows in `_definitions` once loading finished.
+    pub definitions: usize,
+}
+
+/// Differences between a v0.1.0 companion file and the `_definitions` rows it
+/// is imported over. Each list is sorted by view name.
+///
+/// A view in both with different definitions blocks the import (see
+/// [`CatalogLoad::conflicting_companion`]); the other differences are
+/// harmless and only recorded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompanionDivergence {
+    /// Views stored in the table but absent from the companion file (kept).
+    pub only_in_table: Vec<String>,
+    /// Views only in the companion file (added by the import).
+    pub only_in_companion: Vec<String>,
+    /// Views in both whose definitions differ (nothing is imported).
+    pub differing: Vec<String>,
+}
+
+impl CompanionDivergence {
+    /// Compare the table's rows against the companion file's entries.
+    #[must_use]
+    pub fn between(table: &BTreeMap<String, String>, companion: &BTreeMap<String, String>) -> Self {
+        let mut divergence = Self::default();
+        for (name, def) in table {
+            match companion.get(name) {
+                None => divergence.only_in_table.push(name.clone()),
+                Some(other) if other != def => divergence.differing.push(name.clone()),
+                Some(_) => {}
+            }
+        }
+        divergence.only_in_companion = companion
+            .keys()
+            .filter(|name| !table.contains_key(*name))
+            .cloned()
+            .collect();
+        divergence
+    }
+
+    /// True when the companion file matched the table exactly.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.only_in_table.is_empty()
+            && self.only_in_companion.is_empty()
+            && self.differing.is_empty()
+    }
+}
+
+impl CatalogLoad {
+    /// The error to fail the load with when a companion file was left in
+    /// place over conflicting rows, naming the views that differ.
+    #[must_use]
+    pub fn companion_conflict(&self) -> Option<String> {
+        let path = self.conflicting_companion.as_ref()?;
+        Some(format!(
+            "semantic_views: v0.1.0 companion file '{}' has different definitions \
+             for views already stored in {DEFINITIONS_TABLE}: {}. Nothing was \
+             imported and the file was left in place — keep the stored views by \
+             deleting the file, or drop them to take the file's versions, and re-LOAD.",
+            path.display(),
+            self.divergence.differing.join(", ")
+        ))
+    }
+}
+
 /// Create the `semantic_layer` schema and `_definitions` table if they do not
 /// exist, and run the v0.1.0 companion-file migration once for file-backed
 /// databases.
 ///
-/// Idempotent: safe to call on every extension load.
+/// Idempotent: safe to call on every extension load. Returns a
+/// [`CatalogLoad`] summary of the work done.
 ///
 /// Phase 63 (v0.9.0): when `is_read_only=true`, skips the entire body —
 /// the host DB is read-only so neither the schema/table CREATE nor the
@@ -56,9 +146,10 @@ pub fn init_catalog(
     con: &Connection,
     db_path: &str,
     is_read_only: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<CatalogLoad, Box<dyn std::error::Error>> {
+    let mut load = CatalogLoad::default();
     if is_read_only {
-        return Ok(());
+        return Ok(load);
     }
     // FF-10: `definition` is `NOT NULL`. A SQL-NULL definition is an
     // unrecoverable-looking state — readers treat a NULL definition as
@@ -75,7 +166,10 @@ pub fn init_catalog(
     ))?;
 
     // One-time migration: if a v0.1.0 companion file exists alongside the database,
-    // import its contents into the table then delete the file.
+    // import the views the table does not have yet, then delete the file. A view
+    // stored in both with different definitions blocks the whole import: the
+    // file is kept and `CatalogLoad::companion_conflict` reports it, since
+    // neither side can be assumed newer.
     if db_path != ":memory:" {
         let migration_path: PathBuf = {
             let mut p = PathBuf::from(db_path);
@@ -95,7 +189,7 @@ pub fn init_catalog(
                     migration_path.display()
                 )
             })?;
-            let migrated: std::collections::HashMap<String, String> =
+            let migrated: BTreeMap<String, String> =
                 serde_json::from_str(&contents).map_err(|e| {
                     format!(
                         "semantic_views: v0.1.0 companion file '{}' is not valid JSON: {e}. \
@@ -104,39 +198,100 @@ pub fn init_catalog(
                         migration_path.display()
                     )
                 })?;
-            for (name, def) in &migrated {
-                con.execute(
-                    &format!(
-                        "INSERT OR REPLACE INTO {DEFINITIONS_TABLE} (name, definition) VALUES (?, ?)"
-                    ),
-                    duckdb::params![name, def],
-                )?;
+            let existing: BTreeMap<String, String> = {
+                let mut stmt =
+                    con.prepare(&format!("SELECT name, definition FROM {DEFINITIONS_TABLE}"))?;
+                let rows = stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?;
+                rows.collect::<Result<_, _>>()?
+            };
+            load.divergence = CompanionDivergence::between(&existing, &migrated);
+            if load.divergence.differing.is_empty() {
+                import_companion(con, &migration_path, &migrated, &mut load)?;
+            } else {
+                load.conflicting_companion = Some(migration_path);
             }
-            // Delete ONLY after a fully successful import. Pre-fix the file was
-            // removed even when unreadable or corrupt, permanently destroying
-            // the user's pre-v0.2 definitions. A failed delete must also be an
-            // error: if the file survives, every subsequent LOAD re-imports
-            // this (now stale) snapshot over newer definitions via
-            // INSERT OR REPLACE.
-            std::fs::remove_file(&migration_path).map_err(|e| {
-                format!(
-                    "semantic_views: imported v0.1.0 companion file '{}' but could \
-                     not delete it: {e}. Delete it manually before the next LOAD to \
-                     avoid re-importing stale definitions.",
-                    migration_path.display()
-                )
-            })?;
         }
     }
 
     // AR-4: one-time storage-format upgrade pass. Runs after the v0.1.0
     // companion import so freshly-imported rows are considered too. Only on
     // writable DBs (guarded by the is_read_only early-return above).
-    upgrade_definitions_schema(con)?;
+    load.upgraded = upgrade_definitions_schema(con)?;
+
+    let definitions: i64 = con.query_row(
+        &format!("SELECT count(*) FROM {DEFINITIONS_TABLE}"),
+        [],
+        |row| row.get(0),
+    )?;
+    load.definitions = usize::try_from(definitions).unwrap_or_default();
+
+    Ok(load)
+}
 
+/// Insert the companion file's views that `_definitions` does not have yet,
+/// then delete the file. Called only when no stored row conflicts with it.
+fn import_companion(
+    con: &Connection,
+    migration_path: &Path,
+    migrated: &BTreeMap<String, String>,
+    load: &mut CatalogLoad,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for name in &load.divergence.only_in_companion {
+        con.execute(
+            &format!("INSERT INTO {DEFINITIONS_TABLE} (name, definition) VALUES (?, ?)"),
+            duckdb::params![name, migrated[name]],
+        )?;
+    }
+    // Delete ONLY after a fully successful import. Pre-fix the file was
+    // removed even when unreadable or corrupt, permanently destroying
+    // the user's pre-v0.2 definitions. A failed delete must also be an
+    // error: if the file survives, every subsequent LOAD compares this
+    // (now stale) snapshot against newer definitions again.
+    std::fs::remove_file(migration_path).map_err(|e| {
+        format!(
+            "semantic_views: imported v0.1.0 companion file '{}' but could \
+             not delete it: {e}. Delete it manually before the next LOAD to \
+             avoid re-importing stale definitions.",
+            migration_path.display()
+        )
+    })?;
+    load.migrated_companion = true;
+    load.imported = load.divergence.only_in_companion.len();
     Ok(())
 }
 
+/// Read and parse one stored definition through a `duckdb` [`Connection`], for
+/// library consumers working with a catalog outside the loaded extension.
+///
+/// Returns `Ok(None)` when no view has this name. The definition is returned
+/// as stored: an `extends` parent is not merged in. Expects the table
+/// [`init_catalog`] creates.
+pub fn catalog_get(
+    con: &Connection,
+    name: &str,
+) -> Result<Option<crate::model::SemanticViewDefinition>, Box<dyn std::error::Error>> {
+    let mut stmt = con.prepare(&format!(
+        "SELECT definition FROM {DEFINITIONS_TABLE} WHERE name = ?"
+    ))?;
+    let mut rows = stmt.query(duckdb::params![name])?;
+    let Some(row) = rows.next()? else {
+        return Ok(None);
+    };
+    let json: String = row.get(0)?;
+    Ok(Some(crate::model::SemanticViewDefinition::from_json(
+        name, &json,
+    )?))
+}
+
+/// Every registered view name, sorted. Companion to [`catalog_get`].
+pub fn catalog_names(con: &Connection) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut stmt = con.prepare(&format!(
+        "SELECT name FROM {DEFINITIONS_TABLE} ORDER BY name"
+    ))?;
+    let names = stmt.query_map([], |r| r.get::<_, String>(0))?;
+    Ok(names.collect::<Result<_, _>>()?)
+}
+
 /// One-time `schema_version` upgrade pass over `_definitions` (AR-4).
 ///
 /// For every stored row still below [`crate::model::CURRENT_SCHEMA_VERSION`]:
@@ -151,7 +306,8 @@ pub fn init_catalog(
 /// Idempotent: rows already at the current version are skipped, so subsequent
 /// loads are no-ops. The `schema_version` integer is inlined from a
 /// compile-time constant (no user input), so the `json_object` embed is safe.
-fn upgrade_definitions_schema(con: &Connection) -> Result<(), Box<dyn std::error::Error>> {
+/// Returns the number of rows stamped.
+fn upgrade_definitions_schema(con: &Connection) -> Result<usize, Box<dyn std::error::Error>> {
     let rows: Vec<(String, String)> = {
         let mut stmt = con.prepare(&format!("SELECT name, definition FROM {DEFINITIONS_TABLE}"))?;
         let mapped =
@@ -159,6 +315,7 @@ fn upgrade_definitions_schema(con: &Connection) -> Result<(), Box<dyn std::error
         mapped.collect::<Result<Vec<_>, _>>()?
     };
 
+    let mut upgraded = 0;
     for (name, json) in rows {
         if crate::model::SemanticViewDefinition::stored_schema_version(&json)
             >= crate::model::CURRENT_SCHEMA_VERSION
@@ -183,9 +340,10 @@ fn upgrade_definitions_schema(con: &Connection) -> Result<(), Box<dyn std::error
             ),
             duckdb::params![name],
         )?;
+        upgraded += 1;
     }
 
-    Ok(())
+    Ok(upgraded)
 }
 
 // ---------------------------------------------------------------------------
@@ -277,6 +435,21 @@ mod reader {
             unsafe { prepared_lookup(self.conn, name) }
         }
 
+        /// Like [`Self::lookup`], but with the view's `extends` chain merged
+        /// in (see [`crate::catalog::extends::resolve_extends`]). Used wherever
+        /// a view's content is read (expansion, DESCRIBE, SHOW, the documentation
+        /// and export functions); only `GET_DDL` and the YAML export read the
+        /// stored row through [`Self::lookup`].
+        pub fn lookup_resolved(&self, name: &str) -> Result<Option<String>, String> {
+            match self.lookup(name)? {
+                Some(json) => crate::catalog::extends::resolve_extends(name, json, |parent| {
+                    self.lookup(parent)
+                })
+                .map(Some),
+                None => Ok(None),
+            }
+        }
+
         /// Whether a view with this name exists.
         pub fn exists(&self, name: &str) -> Result<bool, String> {
             Ok(self.lookup(name)?.is_some())
@@ -620,7 +793,18 @@ mod tests {
         }
 
         // Re-run init_catalog: triggers the one-time upgrade pass.
-        init_catalog(&con, ":memory:", false).unwrap();
+        let load = init_catalog(&con, ":memory:", false).unwrap();
+        assert_eq!(
+            load,
+            CatalogLoad {
+                migrated_companion: false,
+                imported: 0,
+                upgraded: 2,
+                definitions: 3,
+                divergence: CompanionDivergence::default(),
+                conflicting_companion: None,
+            }
+        );
 
         let stored = |name: &str| -> String {
             con.query_row(
@@ -647,7 +831,8 @@ mod tests {
         );
 
         // Idempotent: a second pass changes nothing and does not error.
-        init_catalog(&con, ":memory:", false).unwrap();
+        let load = init_catalog(&con, ":memory:", false).unwrap();
+        assert_eq!(load.upgraded, 0);
         assert_eq!(
             SemanticViewDefinition::stored_schema_version(&stored("complete_v")),
             CURRENT_SCHEMA_VERSION
@@ -703,6 +888,54 @@ mod tests {
         let _ = std::fs::remove_file(&companion);
     }
 
+    #[cfg(not(feature = "extension"))]
+    #[test]
+    fn migration_corrupt_companion_file_leaves_table_rows_intact() {
+        // A corrupt companion file must not be mistaken for "no definitions":
+        // the load errors out before any INSERT OR REPLACE, so rows already in
+        // `_definitions` survive untouched.
+        let tmp = std::env::temp_dir();
+        let db_path_buf = tmp.join("test_corrupt_companion_rows.duckdb");
+        let db_path = db_path_buf.to_str().expect("temp dir is UTF-8");
+        let companion = tmp.join("test_corrupt_companion_rows.duckdb.semantic_views");
+        let _ = std::fs::remove_file(db_path);
+        let _ = std::fs::remove_file(&companion);
+
+        let con = Connection::open(db_path).expect("open file-backed DB");
+        init_catalog(&con, db_path, false).unwrap();
+        con.execute(
+            &format!("INSERT INTO {DEFINITIONS_TABLE} (name, definition) VALUES (?, ?)"),
+            duckdb::params!["orders", r#"{"tables":[],"dimensions":[],"metrics":[]}"#],
+        )
+        .unwrap();
+
+        std::fs::write(&companion, "\u{0}garbage").unwrap();
+        let err = init_catalog(&con, db_path, false)
+            .expect_err("corrupt companion file must fail the load");
+        assert!(err.to_string().contains("not valid JSON"), "{err}");
+
+        let rows: Vec<(String, String)> = {
+            let mut stmt = con
+                .prepare(&format!("SELECT name, definition FROM {DEFINITIONS_TABLE}"))
+                .unwrap();
+            stmt.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap()
+        };
+        assert_eq!(
+            rows,
+            vec![(
+                "orders".to_string(),
+                r#"{"tables":[],"dimensions":[],"metrics":[]}"#.to_string()
+            )]
+        );
+
+        drop(con);
+        let _ = std::fs::remove_file(db_path);
+        let _ = std::fs::remove_file(&companion);
+    }
+
     #[cfg(not(feature = "extension"))]
     #[test]
     fn migration_valid_companion_file_imports_then_deletes() {
@@ -719,7 +952,10 @@ mod tests {
         )
         .unwrap();
         let con = Connection::open(db_path).expect("open file-backed DB");
-        init_catalog(&con, db_path, false).expect("valid companion file imports cleanly");
+        let load =
+            init_catalog(&con, db_path, false).expect("valid companion file imports cleanly");
+        assert!(load.migrated_companion);
+        assert_eq!((load.imported, load.definitions), (1, 1));
 
         let count: i64 = con
             .query_row(
@@ -737,6 +973,89 @@ mod tests {
         let _ = std::fs::remove_file(db_path);
     }
 
+    #[cfg(not(feature = "extension"))]
+    #[test]
+    fn migration_reports_divergence_from_existing_rows() {
+        let tmp = std::env::temp_dir();
+        let db_path_buf = tmp.join("test_companion_divergence.duckdb");
+        let db_path = db_path_buf.to_str().expect("temp dir is UTF-8");
+        let companion = tmp.join("test_companion_divergence.duckdb.semantic_views");
+        let _ = std::fs::remove_file(db_path);
+        let _ = std::fs::remove_file(&companion);
+
+        let con = Connection::open(db_path).expect("open file-backed DB");
+        init_catalog(&con, db_path, false).unwrap();
+        for (name, def) in [("kept", "k"), ("orders", "table-version"), ("same", "s")] {
+            con.execute(
+                &format!("INSERT INTO {DEFINITIONS_TABLE} (name, definition) VALUES (?, ?)"),
+                duckdb::params![name, def],
+            )
+            .unwrap();
+        }
+        std::fs::write(
+            &companion,
+            r#"{"orders": "companion-version", "same": "s", "added": "a"}"#,
+        )
+        .unwrap();
+
+        let load = init_catalog(&con, db_path, false).unwrap();
+        assert_eq!(
+            load.divergence,
+            CompanionDivergence {
+                only_in_table: vec!["kept".to_string()],
+                only_in_companion: vec!["added".to_string()],
+                differing: vec!["orders".to_string()],
+            }
+        );
+        // The conflicting `orders` blocks the import: nothing is written, the
+        // stored row is kept, and the file stays for the user to reconcile.
+        assert_eq!((load.imported, load.definitions), (0, 3));
+        assert_eq!(
+            load.conflicting_companion.as_deref(),
+            Some(companion.as_path())
+        );
+        let err = load.companion_conflict().expect("conflict is reported");
+        assert!(
+            err.contains("stored in semantic_layer._definitions: orders."),
+            "{err}"
+        );
+        let orders: String = con
+            .query_row(
+                &format!("SELECT definition FROM {DEFINITIONS_TABLE} WHERE name = 'orders'"),
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(orders, "table-version");
+        assert!(companion.exists());
+
+        // Once the conflict is resolved (here by dropping the stored row), the
+        // next load imports what the table lacks and deletes the file.
+        con.execute(
+            &format!("DELETE FROM {DEFINITIONS_TABLE} WHERE name = 'orders'"),
+            [],
+        )
+        .unwrap();
+        let load = init_catalog(&con, db_path, false).unwrap();
+        assert_eq!(load.companion_conflict(), None);
+        assert_eq!((load.imported, load.definitions), (2, 4));
+        assert!(!companion.exists());
+
+        // A third load finds no companion file and reports nothing.
+        let load = init_catalog(&con, db_path, false).unwrap();
+        assert!(load.divergence.is_empty());
+
+        drop(con);
+        let _ = std::fs::remove_file(db_path);
+    }
+
+    #[test]
+    fn companion_divergence_of_identical_sources_is_empty() {
+        let rows: BTreeMap<String, String> = [("a".to_string(), "x".to_string())].into();
+        assert!(CompanionDivergence::between(&rows, &rows).is_empty());
+        assert!(CompanionDivergence::between(&BTreeMap::new(), &BTreeMap::new()).is_empty());
+    }
+
     #[cfg(not(feature = "extension"))]
     #[test]
     fn pragma_database_list_returns_file_path() {
@@ -959,6 +1278,45 @@ mod tests {
         );
     }
 
+    #[cfg(not(feature = "extension"))]
+    #[test]
+    fn catalog_get_parses_stored_definitions() {
+        let con = in_memory_con();
+        init_catalog(&con, ":memory:", false).unwrap();
+        let def = crate::model::SemanticViewDefinition {
+            time_dimension: Some("order_date".to_string()),
+            tags: vec!["finance".to_string()],
+            ..Default::default()
+        };
+        for name in ["sales", "returns"] {
+            con.execute(
+                &format!("INSERT INTO {DEFINITIONS_TABLE} (name, definition) VALUES (?, ?)"),
+                duckdb::params![name, serde_json::to_string(&def).unwrap()],
+            )
+            .unwrap();
+        }
+
+        let got = catalog_get(&con, "sales").unwrap().expect("stored view");
+        assert_eq!(got.time_dimension.as_deref(), Some("order_date"));
+        assert!(got.has_tag("finance"));
+        assert!(catalog_get(&con, "missing").unwrap().is_none());
+        assert_eq!(catalog_names(&con).unwrap(), ["returns", "sales"]);
+    }
+
+    #[cfg(not(feature = "extension"))]
+    #[test]
+    fn catalog_get_reports_unparseable_definitions() {
+        let con = in_memory_con();
+        init_catalog(&con, ":memory:", false).unwrap();
+        con.execute(
+            &format!("INSERT INTO {DEFINITIONS_TABLE} (name, definition) VALUES ('bad', '{{')"),
+            [],
+        )
+        .unwrap();
+        assert!(catalog_get(&con, "bad").is_err());
+        assert_eq!(catalog_names(&con).unwrap(), ["bad"]);
+    }
+
     #[cfg(feature = "extension")]
     #[test]
     fn lookup_returns_none_when_table_missing() {
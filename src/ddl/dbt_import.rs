@@ -0,0 +1,42 @@
+//! `define_from_dbt(yaml)` scalar function: wraps
+//! [`crate::interop::dbt::from_dbt_yaml`] so that
+//! `SELECT define_from_dbt('<dbt YAML>')` returns a script of
+//! `CREATE OR REPLACE SEMANTIC VIEW` statements (preceded by `-- warning:`
+//! lines for every skipped construct) to review and run.
+//!
+//! The function does not write the catalog itself: view creation stays on
+//! the `parser_override` DDL path, so the output goes through the same
+//! CREATE-time validation as hand-written DDL.
+
+#![cfg(feature = "extension")]
+
+use crate::ddl::read_ffi::{read_str_arg, run_dispatcher};
+use crate::interop::dbt::from_dbt_yaml;
+
+/// # Safety
+///
+/// `conn` is a borrowed handle (do NOT disconnect). `yaml_ptr` must point
+/// to `yaml_len` UTF-8 bytes (not NUL-terminated).
+#[no_mangle]
+pub unsafe extern "C" fn sv_define_from_dbt_exec_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    yaml_ptr: *const u8,
+    yaml_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_define_from_dbt_exec_rust",
+        |_borrowed| {
+            let yaml = unsafe { read_str_arg(yaml_ptr, yaml_len, "dbt YAML") }?;
+            Ok(from_dbt_yaml(&yaml)?.to_sql()?.into_bytes())
+        },
+    )
+}
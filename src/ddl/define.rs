@@ -56,7 +56,7 @@
 /// Called by both `parse::rewrite_create` (inline AS-body) and
 /// `parse::rewrite_yaml_file_create` (FROM YAML FILE) under `parser_override`.
 pub fn enrich_definition_for_create(
-    _name: &str,
+    name: &str,
     mut def: crate::model::SemanticViewDefinition,
 ) -> Result<String, crate::errors::ParseError> {
     // 1. Re-run cardinality inference. Phase 65: no longer preceded by
@@ -127,11 +127,34 @@ pub fn enrich_definition_for_create(
     //    collisions -- within a kind or across kinds, case-insensitive --
     //    are rejected at define time. Read paths keep first-match behavior
     //    for legacy catalog rows that predate this check.
+    //    Name characters are checked only in strict mode
+    //    (`semantic_views_strict_names`).
+    //    Expression syntax (balanced brackets, terminated quotes) is checked
+    //    next so a malformed expression is reported as such rather than as
+    //    whatever the reference scanners make of it.
+    //    A view that `extends` another may refer to tables, facts, and
+    //    metrics it only inherits, so only its self-contained checks run
+    //    here; the rest run on the merged definition when the chain is
+    //    resolved at query time (`catalog::extends::resolve_extends`).
     crate::graph::validate_name_uniqueness(&def)?;
+    crate::graph::validate_name_characters(name, &def, crate::graph::strict_names())?;
+    crate::graph::validate_expression_syntax(&def)?;
+    if def.extends.is_some() {
+        return serde_json::to_string(&def)
+            .map_err(|e| crate::errors::ParseError::positionless(e.to_string()));
+    }
+    crate::graph::validate_base_sql(&def)?;
     crate::graph::validate_graph(&def)?;
     crate::graph::validate_facts(&def)?;
     crate::graph::validate_derived_metrics(&def)?;
     crate::graph::validate_using_relationships(&def)?;
+    crate::graph::validate_collations(&def)?;
+    crate::graph::validate_null_values(&def)?;
+    crate::graph::validate_buckets(&def)?;
+    crate::graph::validate_metric_filters(&def)?;
+    crate::graph::validate_approx_metrics(&def)?;
+    crate::graph::validate_distinct_metrics(&def)?;
+    crate::graph::validate_defaults(&def)?;
 
     // 4. Serialize. Metadata (created_on, database_name, schema_name) is
     //    populated by SQL inside the rewritten INSERT — not here. Column
@@ -0,0 +1,171 @@
+//! `define_semantic_views_script(array_json)` scalar function: validates a JSON
+//! array of `{name, definition}` objects as a whole and returns one script
+//! that creates every view inside a single transaction.
+//!
+//! Each entry gets the same checks as `validate_semantic_views_json()`; if
+//! any entry fails, the call fails listing every failure and no script is
+//! produced. Like `define_from_dbt()` the function does not write the catalog
+//! itself — view creation stays on the `parser_override` DDL path — so the
+//! script is run afterwards, and its `BEGIN` / `COMMIT` make the batch
+//! all-or-nothing. Views are emitted as `FROM YAML` so fields without an
+//! `AS`-body spelling (`base_sql`, `extends`, tags, ...) survive.
+
+#![cfg(feature = "extension")]
+
+use std::fmt::Write as _;
+
+use crate::ddl::read_ffi::{read_str_arg, run_dispatcher};
+use crate::ddl::validate_json::validate_array;
+use crate::render_yaml::render_yaml_export;
+
+/// Validate `array_json` and build the transactional CREATE script.
+fn define_script(array_json: &str) -> Result<String, String> {
+    let entries = validate_array("define_semantic_views_script", array_json)?;
+    let failures: Vec<String> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, entry)| {
+            let e = entry.outcome.as_ref().err()?;
+            Some(format!("entry {i} ('{}'): {e}", entry.name))
+        })
+        .collect();
+    if !failures.is_empty() {
+        return Err(format!(
+            "define_semantic_views_script: {} of {} definitions are invalid; no script was produced:\n{}",
+            failures.len(),
+            entries.len(),
+            failures.join("\n")
+        ));
+    }
+
+    let mut out = format!(
+        "-- define_semantic_views_script: {} semantic view(s)\nBEGIN TRANSACTION;\n",
+        entries.len()
+    );
+    for (name, def) in entries.into_iter().filter_map(|entry| entry.outcome.ok()) {
+        let yaml = render_yaml_export(&def)?;
+        let tag = dollar_tag(&yaml);
+        let _ = writeln!(
+            out,
+            "CREATE SEMANTIC VIEW {} FROM YAML {tag}\n{}\n{tag};",
+            crate::expand::quote_ident_if_needed(&name),
+            yaml.trim_end()
+        );
+    }
+    out.push_str("COMMIT;\n");
+    Ok(out)
+}
+
+/// A dollar-quote tag that does not occur in `body`: `$$`, else `$sv$`,
+/// `$sv1$`, `$sv2$`, ...
+fn dollar_tag(body: &str) -> String {
+    ["$$".to_string(), "$sv$".to_string()]
+        .into_iter()
+        .chain((1..=body.len() + 1).map(|n| format!("$sv{n}$")))
+        .find(|tag| !body.contains(tag.as_str()))
+        .expect("body cannot contain more distinct tags than it has bytes")
+}
+
+/// # Safety
+///
+/// `conn` is a borrowed handle (do NOT disconnect); it is not queried.
+/// `json_ptr` must point to `json_len` UTF-8 bytes (not NUL-terminated).
+#[no_mangle]
+pub unsafe extern "C" fn sv_define_semantic_views_script_exec_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    json_ptr: *const u8,
+    json_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_define_semantic_views_script_exec_rust",
+        |_borrowed| {
+            let json = unsafe { read_str_arg(json_ptr, json_len, "definitions JSON") }?;
+            Ok(define_script(&json)?.into_bytes())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{define_script, dollar_tag};
+    use crate::model::SemanticViewDefinition;
+
+    const ORDERS: &str = r#"{"tables":[{"alias":"o","table":"orders","pk_columns":["id"]}],
+        "dimensions":[{"name":"region","expr":"o.region","source_table":"o"}],
+        "metrics":[{"name":"revenue","expr":"sum(o.amount)","source_table":"o"}],
+        "tags":["finance"]}"#;
+
+    #[test]
+    fn valid_batch_becomes_one_transaction() {
+        let json = format!(
+            r#"[{{"name":"sales","definition":{ORDERS}}},
+                {{"name":"\"Sales EU\"","definition":{ORDERS}}}]"#
+        );
+        let script = define_script(&json).unwrap();
+        assert!(
+            script.starts_with(
+                "-- define_semantic_views_script: 2 semantic view(s)\nBEGIN TRANSACTION;\n"
+            ),
+            "{script}"
+        );
+        assert!(script.ends_with("$$;\nCOMMIT;\n"), "{script}");
+        assert!(
+            script.contains("CREATE SEMANTIC VIEW sales FROM YAML $$\n"),
+            "{script}"
+        );
+        assert!(
+            script.contains("CREATE SEMANTIC VIEW \"Sales EU\" FROM YAML $$\n"),
+            "{script}"
+        );
+
+        // The emitted YAML reparses to the same definition, tags included.
+        let body = script
+            .split("FROM YAML $$\n")
+            .nth(1)
+            .and_then(|rest| rest.split("\n$$;").next())
+            .unwrap();
+        let def = SemanticViewDefinition::from_yaml("sales", body).unwrap();
+        assert!(def.has_tag("finance"));
+        assert_eq!(def.metrics[0].expr, "sum(o.amount)");
+    }
+
+    #[test]
+    fn one_invalid_entry_fails_the_whole_batch() {
+        let json = format!(
+            r#"[{{"name":"good","definition":{ORDERS}}},
+                {{"name":"bad","definition":{{"tables":"nope"}}}},
+                {{"name":"GOOD","definition":{ORDERS}}}]"#
+        );
+        let err = define_script(&json).unwrap_err();
+        assert!(
+            err.starts_with("define_semantic_views_script: 2 of 3 definitions are invalid"),
+            "{err}"
+        );
+        assert!(err.contains("entry 1 ('bad')"), "{err}");
+        assert!(err.contains("entry 2 ('GOOD')"), "{err}");
+        assert!(err.contains("more than once"), "{err}");
+        assert!(!err.contains("entry 0"), "{err}");
+    }
+
+    #[test]
+    fn non_array_input_is_rejected() {
+        let err = define_script("{}").unwrap_err();
+        assert!(err.starts_with("define_semantic_views_script: expected a JSON array"));
+    }
+
+    #[test]
+    fn dollar_tag_avoids_the_body() {
+        assert_eq!(dollar_tag("expr: a || b"), "$$");
+        assert_eq!(dollar_tag("filter: x = '$$'"), "$sv$");
+        assert_eq!(dollar_tag("$$ and $sv$"), "$sv1$");
+    }
+}
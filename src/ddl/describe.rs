@@ -57,7 +57,7 @@ unsafe fn describe_view_rows(
     let present = probe_catalog_table_present(borrowed)?;
     let reader = CatalogReader::new(borrowed, present);
     let json = reader
-        .lookup(&name)?
+        .lookup_resolved(&name)?
         .ok_or_else(|| crate::catalog::view_not_found_msg(&name))?;
     let def = SemanticViewDefinition::from_json(&name, &json)?;
     let alias_map = def.alias_to_table_map();
@@ -0,0 +1,154 @@
+//! `find_dimension_across_views(name)` dispatcher: where a dimension lives.
+//!
+//! Scans every stored view for dimensions named `name` (case- and
+//! quote-insensitive, like a `dimensions := [...]` request). A view with no
+//! such dimension still contributes its closest dimension name when one is
+//! within [`crate::util::suggest_closest`]'s cutoff, so `regoin` finds
+//! `region` — those rows are marked `fuzzy` and can be filtered out.
+//!
+//! Columns: `semantic_view_name, dimension_name, expr, match_kind`, with
+//! `match_kind` `exact` or `fuzzy`; rows are sorted exact first, then by view
+//! and dimension name. Like the other cross-view listings, a view whose
+//! stored JSON does not parse is skipped.
+
+#![cfg(feature = "extension")]
+
+use crate::catalog::extends::resolve_listed;
+use crate::catalog::CatalogReader;
+use crate::ddl::read_ffi::{
+    probe_catalog_table_present, read_str_arg, run_dispatcher, serialize_varchar_rows,
+};
+use crate::model::SemanticViewDefinition;
+
+/// Build the sorted `[semantic_view_name, dimension_name, expr, match_kind]`
+/// rows for `name` over `(view_name, definition)` pairs.
+fn find_dimension_rows<'a>(
+    name: &str,
+    views: impl IntoIterator<Item = (&'a str, &'a SemanticViewDefinition)>,
+) -> Vec<Vec<String>> {
+    let mut rows: Vec<(bool, Vec<String>)> = Vec::new();
+    for (view_name, def) in views {
+        let row = |dim_name: &str, expr: &str, kind: &str| {
+            vec![
+                view_name.to_string(),
+                dim_name.to_string(),
+                expr.to_string(),
+                kind.to_string(),
+            ]
+        };
+        let exact: Vec<_> = def
+            .dimensions
+            .iter()
+            .filter(|d| crate::ident::ident_matches(&d.name, name))
+            .collect();
+        if exact.is_empty() {
+            let names: Vec<String> = def.dimensions.iter().map(|d| d.name.clone()).collect();
+            let closest = crate::util::suggest_closest(name, &names)
+                .and_then(|n| def.dimensions.iter().find(|d| d.name == n));
+            if let Some(d) = closest {
+                rows.push((true, row(&d.name, &d.expr, "fuzzy")));
+            }
+        } else {
+            rows.extend(
+                exact
+                    .into_iter()
+                    .map(|d| (false, row(&d.name, &d.expr, "exact"))),
+            );
+        }
+    }
+    rows.sort();
+    rows.into_iter().map(|(_, cells)| cells).collect()
+}
+
+/// # Safety
+///
+/// `conn` is a borrowed handle (see `read_ffi` borrow contract); `name_ptr`
+/// must point to `name_len` UTF-8 bytes. The caller releases the returned
+/// buffer via `sv_free_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn sv_find_dimension_across_views_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_find_dimension_across_views_bind_rust",
+        |borrowed| {
+            let name = unsafe { read_str_arg(name_ptr, name_len, "dimension name") }?;
+            let present = unsafe { probe_catalog_table_present(borrowed) }?;
+            let reader = CatalogReader::new(borrowed, present);
+            let entries = reader.list_all()?;
+            // A child view matches on inherited dimensions too; a view whose
+            // `extends` chain does not resolve is skipped like an unparseable one.
+            let defs: Vec<(&str, SemanticViewDefinition)> = entries
+                .iter()
+                .zip(resolve_listed(&entries))
+                .filter_map(|((view, _), json)| {
+                    let def = SemanticViewDefinition::from_json(view, &json.ok()?).ok()?;
+                    Some((view.as_str(), def))
+                })
+                .collect();
+            let rows = find_dimension_rows(&name, defs.iter().map(|(v, d)| (*v, d)));
+            serialize_varchar_rows(&rows)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_dimension_rows;
+    use crate::model::{Dimension, SemanticViewDefinition};
+
+    fn view(dim: &str, expr: &str) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            dimensions: vec![Dimension {
+                name: dim.to_string(),
+                expr: expr.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn views() -> Vec<(&'static str, SemanticViewDefinition)> {
+        vec![
+            ("sales", view("region", "o.region")),
+            ("inventory", view("Region", "upper(w.region)")),
+            ("returns", view("regions", "r.region")),
+            ("hr", view("department", "e.dept")),
+        ]
+    }
+
+    #[test]
+    fn shared_dimension_found_in_every_view() {
+        let views = views();
+        let rows = find_dimension_rows("REGION", views.iter().map(|(v, d)| (*v, d)));
+        assert_eq!(
+            rows,
+            vec![
+                vec!["inventory", "Region", "upper(w.region)", "exact"],
+                vec!["sales", "region", "o.region", "exact"],
+                vec!["returns", "regions", "r.region", "fuzzy"],
+            ]
+        );
+    }
+
+    #[test]
+    fn no_close_name_yields_no_rows() {
+        let views = views();
+        let rows = find_dimension_rows(
+            "customer_lifetime_value",
+            views.iter().map(|(v, d)| (*v, d)),
+        );
+        assert!(rows.is_empty(), "{rows:?}");
+    }
+}
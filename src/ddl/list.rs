@@ -39,11 +39,25 @@ use crate::model::SemanticViewDefinition;
 /// by the length-prefixed cells). See that function for the authoritative
 /// byte layout; it is intentionally NOT duplicated here to avoid drift.
 ///
-/// The 6 columns match the v0.9.0 Rust `VTab` shape exactly:
-/// (`created_on`, name, kind, `database_name`, `schema_name`, comment).
+/// The first 6 VARCHAR columns match the v0.9.0 Rust `VTab` shape exactly:
+/// (`created_on`, name, kind, `database_name`, `schema_name`, comment). They
+/// are followed by 3 BIGINT overview counts (`dimension_count`,
+/// `metric_count`, `join_count`), serialized via
+/// [`crate::ddl::read_ffi::serialize_varchar_bigint_rows`].
+///
+/// `(pattern_ptr, pattern_len)` is the optional positional name pattern: a
+/// null pointer means "no pattern" (every view); otherwise the UTF-8 pattern
+/// is matched against each view name with case-insensitive SQL `LIKE`
+/// semantics ([`crate::util::ilike_matches`]) before definitions are parsed.
+/// `(tag_ptr, tag_len)` is the optional `tag :=` named parameter, null for
+/// none: only views whose definition carries that tag are listed
+/// ([`SemanticViewDefinition::has_tag`]). Both filters apply when both are
+/// given.
 ///
 /// # Safety
 ///
+/// `pattern_ptr` and `tag_ptr` are each either null or point to
+/// `pattern_len` / `tag_len` readable bytes.
 /// The `conn` parameter is a BORROWED handle (bridge lifecycle, critical) — the
 /// underlying C++ `Connection` is owned by a stack local in the C++ bind
 /// callback. This function MUST NOT:
@@ -78,6 +92,10 @@ use crate::model::SemanticViewDefinition;
 #[no_mangle]
 pub unsafe extern "C" fn sv_list_semantic_views_bind_rust(
     conn: libduckdb_sys::duckdb_connection,
+    pattern_ptr: *const u8,
+    pattern_len: usize,
+    tag_ptr: *const u8,
+    tag_len: usize,
     out_ptr: *mut *mut u8,
     out_len: *mut usize,
     error_buf: *mut u8,
@@ -91,15 +109,39 @@ pub unsafe extern "C" fn sv_list_semantic_views_bind_rust(
         error_buf_len,
         "sv_list_semantic_views_bind_rust",
         |borrowed| unsafe {
-            list_view_rows(borrowed, /* include_comment = */ true)
+            let read_optional = |ptr: *const u8, len: usize, what: &str| {
+                if ptr.is_null() {
+                    Ok(None)
+                } else {
+                    crate::ddl::read_ffi::read_str_arg(ptr, len, what).map(Some)
+                }
+            };
+            let pattern = read_optional(pattern_ptr, pattern_len, "name pattern")?;
+            let tag = read_optional(tag_ptr, tag_len, "tag")?;
+            let rows = list_view_rows(
+                borrowed,
+                /* include_comment = */ true,
+                pattern.as_deref(),
+                tag.as_deref(),
+            )?;
+            crate::ddl::read_ffi::serialize_varchar_bigint_rows(&rows)
         },
     )
 }
 
-/// Shared body for both `list_semantic_views()` (6 columns) and
-/// `list_terse_semantic_views()` (5 columns — no trailing `comment`): probe
-/// the catalog, read every definition, and serialize the rows over the shared
-/// varchar wire format, name-sorted for byte-stable output.
+/// One listing row: its VARCHAR cells and its BIGINT counts.
+#[cfg(feature = "extension")]
+type ListRow = (Vec<String>, Vec<i64>);
+
+/// Shared body for both `list_semantic_views()` (6 VARCHAR columns + 3
+/// BIGINT counts) and `list_terse_semantic_views()` (5 VARCHAR columns — no
+/// trailing `comment`, counts dropped by the caller): probe the catalog, read
+/// every definition whose name matches `pattern` and that carries `tag` (no
+/// filter when `None`), and build the rows name-sorted for byte-stable output.
+/// A definition that fails to parse has no tags, so a tag filter drops it.
+/// Each row is its VARCHAR cells plus `[dimension_count, metric_count,
+/// join_count]`, counted over the `extends`-resolved definition; a definition
+/// that fails to parse reports empty strings and zero counts.
 ///
 /// FF-9: a genuine probe-query failure surfaces as an error rather than being
 /// folded into "no views" (an attached read-only DB without a bootstrapped
@@ -113,16 +155,30 @@ pub unsafe extern "C" fn sv_list_semantic_views_bind_rust(
 unsafe fn list_view_rows(
     borrowed: &crate::ddl::read_ffi::BorrowedConnection,
     include_comment: bool,
-) -> Result<Vec<u8>, String> {
-    use crate::ddl::read_ffi::{probe_catalog_table_present, serialize_varchar_rows};
+    pattern: Option<&str>,
+    tag: Option<&str>,
+) -> Result<Vec<ListRow>, String> {
+    use crate::ddl::read_ffi::probe_catalog_table_present;
 
     let table_present = probe_catalog_table_present(borrowed)?;
     let reader = CatalogReader::new(borrowed, table_present);
     let entries = reader.list_all()?;
 
-    let mut rows: Vec<Vec<String>> = Vec::with_capacity(entries.len());
-    for (name, json) in &entries {
+    let resolved = crate::catalog::extends::resolve_listed(&entries);
+
+    let mut rows: Vec<ListRow> = Vec::with_capacity(entries.len());
+    for ((name, json), resolved) in entries.iter().zip(&resolved) {
+        if pattern.is_some_and(|p| !crate::util::ilike_matches(name, p)) {
+            continue;
+        }
+        // Counts include inherited items; the metadata and tags are the
+        // child's own either way. A chain that won't resolve falls back to
+        // the stored row's own counts.
+        let json = resolved.as_ref().unwrap_or(json);
         let def = SemanticViewDefinition::from_json(name, json).ok();
+        if tag.is_some_and(|t| !def.as_ref().is_some_and(|d| d.has_tag(t))) {
+            continue;
+        }
         let (created_on, database_name, schema_name, comment) = match &def {
             Some(d) => (
                 d.created_on.clone().unwrap_or_default(),
@@ -142,14 +198,19 @@ unsafe fn list_view_rows(
         if include_comment {
             row.push(comment);
         }
-        rows.push(row);
+        let counts = def.as_ref().map_or_else(
+            || vec![0; 3],
+            |d| {
+                [d.dimensions.len(), d.metrics.len(), d.joins.len()]
+                    .into_iter()
+                    .map(|n| i64::try_from(n).unwrap_or(i64::MAX))
+                    .collect()
+            },
+        );
+        rows.push((row, counts));
     }
-    rows.sort_by(|a, b| a[1].cmp(&b[1]));
-
-    // FF-6: the shared serializer errors (rather than clamping a length to
-    // u32::MAX and desyncing the header from the payload) if a cell or the
-    // row count overflows the wire format's u32 fields.
-    serialize_varchar_rows(&rows)
+    rows.sort_by(|a, b| a.0[1].cmp(&b.0[1]));
+    Ok(rows)
 }
 
 // Phase 65.1 Plan 03a (IN-06 / D-26): the module-local duplicates of
@@ -197,7 +258,12 @@ pub unsafe extern "C" fn sv_list_terse_semantic_views_bind_rust(
         error_buf_len,
         "sv_list_terse_semantic_views_bind_rust",
         |borrowed| unsafe {
-            list_view_rows(borrowed, /* include_comment = */ false)
+            let rows = list_view_rows(borrowed, /* include_comment = */ false, None, None)?;
+            let rows: Vec<Vec<String>> = rows.into_iter().map(|(strs, _)| strs).collect();
+            // FF-6: the shared serializer errors (rather than clamping a length
+            // to u32::MAX and desyncing the header from the payload) if a cell
+            // or the row count overflows the wire format's u32 fields.
+            crate::ddl::read_ffi::serialize_varchar_rows(&rows)
         },
     )
 }
@@ -4,13 +4,23 @@
 // architectural unification. Only `define::enrich_definition_for_create`
 // remains — called by the parser_override CREATE rewrite.
 pub mod alter_helpers_ffi;
+pub mod dbt_export;
+pub mod dbt_import;
 pub mod define;
+pub mod define_batch;
 pub mod describe;
+pub mod find_dimension;
 pub mod get_ddl;
 pub mod list;
 pub mod read_ffi;
 pub mod read_yaml;
+pub mod settings;
 pub mod show_columns;
 pub mod show_dims_for_metric;
 pub mod show_entities;
 pub mod show_materializations;
+pub mod validate_json;
+pub mod view_doc;
+pub mod view_graph;
+pub mod view_tables;
+pub mod view_tags;
@@ -11,13 +11,14 @@
 //!
 //! ```text
 //! u32 col_count                 ─┐ self-describing schema header (AR-3)
-//! col_count × u8 type_tag        │ 1 = VARCHAR, 2 = BOOLEAN
+//! col_count × u8 type_tag        │ 1 = VARCHAR, 2 = BOOLEAN, 3 = BIGINT
 //!                               ─┘
 //! u32 row_count
 //! for each row:
 //!   for each column (in type_tag order):
 //!     VARCHAR: u32 byte_len + byte_len bytes (UTF-8 payload)
 //!     BOOLEAN: u8 (1 = TRUE, 0 = FALSE)
+//!     BIGINT:  i64 (8 bytes)
 //! ```
 //!
 //! ## Self-describing schema header (AR-3)
@@ -46,6 +47,12 @@
 //! BOOLEAN (1 = TRUE, 0 = FALSE). C++ side parses with `sv_read_u8` after the
 //! string reads.
 //!
+//! ## Variant: VARCHAR with trailing BIGINT columns
+//!
+//! `list_semantic_views` appends per-view item counts to its VARCHAR columns.
+//! Its header carries the VARCHAR tags followed by one BIGINT tag per count;
+//! each row emits the VARCHAR cells first, then the `i64` cells in order.
+//!
 //! # Borrow contract (critical)
 //!
 //! Every dispatcher in this module receives a `duckdb_connection` BORROWED
@@ -200,6 +207,8 @@ pub unsafe fn write_err(buf: *mut u8, buf_len: usize, msg: &str) {
 const WIRE_TAG_VARCHAR: u8 = 1;
 /// See [`WIRE_TAG_VARCHAR`].
 const WIRE_TAG_BOOL: u8 = 2;
+/// See [`WIRE_TAG_VARCHAR`].
+const WIRE_TAG_BIGINT: u8 = 3;
 
 /// Write the self-describing schema header (AR-3): `u32 col_count` followed by
 /// one `u8` type tag per column. Emitting the header for every non-empty
@@ -303,6 +312,63 @@ pub fn serialize_varchar_bool_rows(rows: &[(Vec<String>, bool)]) -> Result<Vec<u
     Ok(buf)
 }
 
+/// Serialize a vector of (VARCHAR-cells, BIGINT-cells) rows. Each row's
+/// strings are emitted first (same shape as `serialize_varchar_rows`),
+/// followed by its `i64` cells in little-endian order.
+///
+/// The self-describing header (AR-3) carries the VARCHAR tags followed by the
+/// BIGINT tags; both counts are taken from the first row. An empty row set
+/// emits a zero-column header. Rows whose VARCHAR or BIGINT cell counts differ
+/// from the first row are rejected, as in [`serialize_varchar_rows`].
+///
+/// Returns `Err` on non-rectangular rows or the same overflow conditions as
+/// [`serialize_varchar_rows`].
+pub fn serialize_varchar_bigint_rows(rows: &[(Vec<String>, Vec<i64>)]) -> Result<Vec<u8>, String> {
+    let (n_varchar, n_bigint) = rows
+        .first()
+        .map_or((0, 0), |(strs, ints)| (strs.len(), ints.len()));
+    if let Some(bad) = rows
+        .iter()
+        .position(|(strs, ints)| strs.len() != n_varchar || ints.len() != n_bigint)
+    {
+        return Err(format!(
+            "non-rectangular row set: row {bad} has {} VARCHAR + {} BIGINT columns, \
+             expected {n_varchar} + {n_bigint}",
+            rows[bad].0.len(),
+            rows[bad].1.len()
+        ));
+    }
+    let cap = 4
+        + (n_varchar + n_bigint)
+        + 4
+        + rows
+            .iter()
+            .map(|(strs, _)| strs.iter().map(|s| 4 + s.len()).sum::<usize>() + 8 * n_bigint)
+            .sum::<usize>();
+    let mut buf = Vec::with_capacity(cap);
+    if rows.is_empty() {
+        // No rows → zero-column header (C++ skips the schema assertion).
+        write_wire_schema(&mut buf, &[])?;
+    } else {
+        let mut tags = vec![WIRE_TAG_VARCHAR; n_varchar];
+        tags.extend(std::iter::repeat_n(WIRE_TAG_BIGINT, n_bigint));
+        write_wire_schema(&mut buf, &tags)?;
+    }
+    let row_count = wire_len(rows.len(), "row count")?;
+    buf.extend_from_slice(&row_count.to_le_bytes());
+    for (strs, ints) in rows {
+        for col in strs {
+            let len = wire_len(col.len(), "cell")?;
+            buf.extend_from_slice(&len.to_le_bytes());
+            buf.extend_from_slice(col.as_bytes());
+        }
+        for n in ints {
+            buf.extend_from_slice(&n.to_le_bytes());
+        }
+    }
+    Ok(buf)
+}
+
 /// Hand a heap-owned `Vec<u8>` to the C++ side via the (ptr, len)
 /// out-parameters. The C++ side MUST release the buffer with
 /// `sv_free_buffer(ptr, len)` using the exact `(ptr, len)` pair this function
@@ -465,6 +531,37 @@ mod tests {
         assert_eq!(buf, vec![0, 0, 0, 0, 0, 0, 0, 0]);
     }
 
+    #[test]
+    fn serialize_bigint_suffix() {
+        let rows = vec![(vec!["v".to_string()], vec![3, -1])];
+        let buf = serialize_varchar_bigint_rows(&rows).unwrap();
+        #[rustfmt::skip]
+        let expected: Vec<u8> = vec![
+            3, 0, 0, 0, // col_count = 3 (1 VARCHAR + 2 BIGINT)
+            WIRE_TAG_VARCHAR, WIRE_TAG_BIGINT, WIRE_TAG_BIGINT,
+            1, 0, 0, 0, // row_count = 1
+            1, 0, 0, 0, b'v', // "v"
+            3, 0, 0, 0, 0, 0, 0, 0, // 3_i64
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // -1_i64
+        ];
+        assert_eq!(buf, expected);
+        assert_eq!(
+            serialize_varchar_bigint_rows(&[]).unwrap(),
+            vec![0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn serialize_varchar_bigint_rows_rejects_ragged() {
+        let rows = vec![
+            (vec!["a".to_string()], vec![1, 2]),
+            (vec!["b".to_string()], vec![1]),
+        ];
+        let err = serialize_varchar_bigint_rows(&rows).unwrap_err();
+        assert!(err.contains("non-rectangular"), "unexpected error: {err}");
+        assert!(err.contains("row 1"), "unexpected error: {err}");
+    }
+
     #[test]
     fn serialize_varchar_rows_rejects_ragged() {
         // Second row has a different column count than the first — the schema
@@ -0,0 +1,62 @@
+//! Extension-option callbacks: the C++ `SET` hooks forward the new value
+//! here, and it is stored process-wide. The options are GLOBAL-only (the
+//! shim rejects `SET SESSION` / `SET LOCAL`), since the readers below run
+//! where no `ClientContext` is available to look up a session value.
+//!
+//! - `semantic_views_suggestion_algorithm` / `semantic_views_suggestion_threshold`
+//!   → [`crate::util::suggest_closest`]
+//! - `semantic_views_strict_names` → [`crate::graph::validate_name_characters`]
+//!   at CREATE
+
+#![cfg(feature = "extension")]
+
+use crate::ddl::read_ffi::{read_str_arg, write_err};
+
+/// Store a validation outcome in the C++ diagnostic slot: `0` = accepted,
+/// `1` = rejected (message in `error_buf`).
+unsafe fn report(result: Result<(), String>, error_buf: *mut u8, error_buf_len: usize) -> u8 {
+    match result {
+        Ok(()) => 0,
+        Err(msg) => {
+            write_err(error_buf, error_buf_len, &msg);
+            1
+        }
+    }
+}
+
+/// # Safety
+///
+/// `name_ptr` must point to `name_len` UTF-8 bytes (not NUL-terminated);
+/// `error_buf` must be null or writable for `error_buf_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn sv_set_suggestion_algorithm_rust(
+    name_ptr: *const u8,
+    name_len: usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    let result = read_str_arg(name_ptr, name_len, "suggestion algorithm")
+        .and_then(|name| crate::util::set_suggestion_algorithm(&name));
+    report(result, error_buf, error_buf_len)
+}
+
+/// # Safety
+///
+/// `error_buf` must be null or writable for `error_buf_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn sv_set_suggestion_threshold_rust(
+    threshold: f64,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    report(
+        crate::util::set_suggestion_threshold(threshold),
+        error_buf,
+        error_buf_len,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn sv_set_strict_names_rust(strict: bool) {
+    crate::graph::set_strict_names(strict);
+}
@@ -61,7 +61,7 @@ unsafe fn show_columns_rows(
     let reader = CatalogReader::new(borrowed, present);
     // C-4 (code-review 2026-07-11): canonical wording via view_not_found_msg.
     let json = reader
-        .lookup(&view_name)?
+        .lookup_resolved(&view_name)?
         .ok_or_else(|| crate::catalog::view_not_found_msg(&view_name))?;
     let def = SemanticViewDefinition::from_json(&view_name, &json)?;
     let rows: Vec<Vec<String>> = collect_column_rows(&def, &view_name)
@@ -85,7 +85,7 @@ unsafe fn show_dims_for_metric(
 
     let present = probe_catalog_table_present(borrowed)?;
     let reader = CatalogReader::new(borrowed, present);
-    let Some(json) = reader.lookup(&view_name)? else {
+    let Some(json) = reader.lookup_resolved(&view_name)? else {
         let available = reader.list_names().unwrap_or_default();
         let not_found = crate::catalog::view_not_found_msg(&view_name);
         return Err(match suggest_closest(&view_name, &available) {
@@ -19,6 +19,7 @@
 
 #![cfg(feature = "extension")]
 
+use crate::catalog::extends::resolve_listed;
 use crate::catalog::CatalogReader;
 use crate::ddl::describe::format_json_array;
 use crate::ddl::read_ffi::{
@@ -147,12 +148,18 @@ fn show_entities_all(kind: EntityKind, borrowed: &BorrowedConnection) -> Result<
     let present = unsafe { probe_catalog_table_present(borrowed) }?;
     let reader = CatalogReader::new(borrowed, present);
     let entries = reader.list_all()?;
+    let resolved = resolve_listed(&entries);
     let mut rows: Vec<Vec<String>> = Vec::new();
-    for (name, json) in &entries {
+    for ((name, _), json) in entries.iter().zip(&resolved) {
         // FF-9: the cross-view `_all` listing stays tolerant — a single view
-        // whose stored JSON won't parse is skipped rather than failing the
-        // whole listing. The named single-view path below is the strict one.
-        let Ok(def) = SemanticViewDefinition::from_json(name, json) else {
+        // whose stored JSON won't parse (or whose `extends` chain won't
+        // resolve) is skipped rather than failing the whole listing. The
+        // named single-view path below is the strict one.
+        let Ok(def) = json
+            .as_deref()
+            .map_err(String::clone)
+            .and_then(|json| SemanticViewDefinition::from_json(name, json))
+        else {
             continue;
         };
         for r in collect_entities(kind, name, &def) {
@@ -177,7 +184,7 @@ fn show_entities_one(
         .map_err(|e| format!("Invalid view name '{view_name}': {e}"))?;
     let present = unsafe { probe_catalog_table_present(borrowed) }?;
     let reader = CatalogReader::new(borrowed, present);
-    let Some(json) = reader.lookup(&view_name)? else {
+    let Some(json) = reader.lookup_resolved(&view_name)? else {
         return Err(crate::catalog::view_not_found_msg(&view_name));
     };
     // FF-9: named single-view SHOW propagates a parse error — the user asked
@@ -9,6 +9,7 @@
 
 #![cfg(feature = "extension")]
 
+use crate::catalog::extends::resolve_listed;
 use crate::catalog::CatalogReader;
 use crate::ddl::describe::format_json_array;
 use crate::ddl::read_ffi::{
@@ -85,11 +86,17 @@ pub unsafe extern "C" fn sv_show_semantic_materializations_all_bind_rust(
             let present = unsafe { probe_catalog_table_present(borrowed) }?;
             let reader = CatalogReader::new(borrowed, present);
             let entries = reader.list_all()?;
+            let resolved = resolve_listed(&entries);
             let mut rows: Vec<Vec<String>> = Vec::new();
-            for (name, json) in &entries {
+            for ((name, _), json) in entries.iter().zip(&resolved) {
                 // FF-9: `_all` stays tolerant — skip a view whose stored JSON
-                // won't parse rather than failing the whole listing.
-                let Ok(def) = SemanticViewDefinition::from_json(name, json) else {
+                // won't parse (or whose `extends` chain won't resolve) rather
+                // than failing the whole listing.
+                let Ok(def) = json
+                    .as_deref()
+                    .map_err(String::clone)
+                    .and_then(|json| SemanticViewDefinition::from_json(name, json))
+                else {
                     continue;
                 };
                 for r in collect_mats(name, &def) {
@@ -131,7 +138,7 @@ pub unsafe extern "C" fn sv_show_semantic_materializations_bind_rust(
                 .map_err(|e| format!("Invalid view name '{view_name}': {e}"))?;
             let present = unsafe { probe_catalog_table_present(borrowed) }?;
             let reader = CatalogReader::new(borrowed, present);
-            let Some(json) = reader.lookup(&view_name)? else {
+            let Some(json) = reader.lookup_resolved(&view_name)? else {
                 return Err(crate::catalog::view_not_found_msg(&view_name));
             };
             // FF-9: named single-view SHOW propagates a parse error rather than
@@ -0,0 +1,220 @@
+//! `validate_semantic_views_json(array_json)` dispatcher.
+//!
+//! The read-only counterpart to a bulk import: each `{name, definition}`
+//! entry of a JSON array runs through the same CREATE-time validation as
+//! `CREATE SEMANTIC VIEW` ([`crate::ddl::define::enrich_definition_for_create`])
+//! and is then expanded item by item, so CI can check a whole catalog file
+//! before deploying it. Nothing is read from or written to the catalog.
+//!
+//! One `(name, status, error)` row is emitted per entry, in input order.
+//! `status` is `ok` or `error`; `error` is empty for valid entries. Only a
+//! top-level value that is not a JSON array fails the whole call.
+
+#![cfg(feature = "extension")]
+
+use serde_json::Value;
+
+use crate::ddl::read_ffi::{read_str_arg, run_dispatcher, serialize_varchar_rows};
+use crate::expand::{expand, DimensionName, MetricName, QueryRequest};
+use crate::model::{AccessModifier, SemanticViewDefinition};
+
+/// One array entry after validation: its `name` as given, and either the
+/// normalized name with the CREATE-enriched definition or the error.
+pub(crate) struct ValidatedEntry {
+    pub(crate) name: String,
+    pub(crate) outcome: Result<(String, SemanticViewDefinition), String>,
+}
+
+/// Parse `array_json` and validate each `{name, definition}` entry in input
+/// order. A name repeated (after normalization) is an error on every entry
+/// after the first valid one. `function` prefixes the whole-call errors.
+pub(crate) fn validate_array(
+    function: &str,
+    array_json: &str,
+) -> Result<Vec<ValidatedEntry>, String> {
+    let value: Value =
+        serde_json::from_str(array_json).map_err(|e| format!("{function}: invalid JSON: {e}"))?;
+    let Value::Array(entries) = value else {
+        return Err(format!(
+            "{function}: expected a JSON array of {{name, definition}} objects"
+        ));
+    };
+
+    let mut seen: Vec<String> = Vec::with_capacity(entries.len());
+    let mut validated = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        let name = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let outcome = validate_entry(i, entry, &name, &seen);
+        if let Ok((ref normalized, _)) = outcome {
+            seen.push(normalized.clone());
+        }
+        validated.push(ValidatedEntry { name, outcome });
+    }
+    Ok(validated)
+}
+
+/// Validate every entry of `array_json`, returning one
+/// `[name, status, error]` row per entry.
+fn validate_entries(array_json: &str) -> Result<Vec<Vec<String>>, String> {
+    Ok(validate_array("validate_semantic_views_json", array_json)?
+        .into_iter()
+        .map(|entry| match entry.outcome {
+            Ok(_) => vec![entry.name, "ok".to_string(), String::new()],
+            Err(e) => vec![entry.name, "error".to_string(), e],
+        })
+        .collect())
+}
+
+/// Validate one array entry. On success returns the normalized view name (so
+/// later entries can be checked for duplicates) and the enriched definition.
+fn validate_entry(
+    index: usize,
+    entry: &Value,
+    name: &str,
+    seen: &[String],
+) -> Result<(String, SemanticViewDefinition), String> {
+    if !entry.is_object() {
+        return Err(format!(
+            "entry {index}: expected a {{name, definition}} object"
+        ));
+    }
+    if name.is_empty() {
+        return Err(format!("entry {index}: missing or empty 'name'"));
+    }
+    let normalized = crate::ident::normalize_view_name(name)?;
+    if seen.contains(&normalized) {
+        return Err(format!(
+            "semantic view '{normalized}' is defined more than once in the array"
+        ));
+    }
+    let Some(definition) = entry.get("definition").filter(|d| d.is_object()) else {
+        return Err(format!(
+            "semantic view '{normalized}': 'definition' must be a JSON object"
+        ));
+    };
+
+    let def = SemanticViewDefinition::from_json(&normalized, &definition.to_string())?;
+    let enriched = crate::ddl::define::enrich_definition_for_create(&normalized, def)
+        .map_err(|e| e.message)?;
+    let def = SemanticViewDefinition::from_json(&normalized, &enriched)?;
+    expand_each_item(&normalized, &def)?;
+    Ok((normalized, def))
+}
+
+/// Expand every dimension and every queryable metric on its own, surfacing
+/// definition errors that only appear at expansion time (unresolvable join
+/// paths, derived-metric cycles, unsupported semi-additive expressions).
+///
+/// Private metrics cannot be requested and window metrics need their
+/// partition dimensions alongside them, so neither is expanded in isolation;
+/// both are still covered by the CREATE-time validation that ran first.
+fn expand_each_item(view_name: &str, def: &SemanticViewDefinition) -> Result<(), String> {
+    let dimension_requests = def.dimensions.iter().map(|d| QueryRequest {
+        dimensions: vec![DimensionName::new(&d.name)],
+        ..Default::default()
+    });
+    let metric_requests = def
+        .metrics
+        .iter()
+        .filter(|m| m.access != AccessModifier::Private && !m.is_window())
+        .map(|m| QueryRequest {
+            metrics: vec![MetricName::new(&m.name)],
+            ..Default::default()
+        });
+    for req in dimension_requests.chain(metric_requests) {
+        expand(view_name, def, &req).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// # Safety
+///
+/// `conn` is a borrowed handle (see `read_ffi` borrow contract); it is not
+/// queried. `json_ptr` must point to `json_len` UTF-8 bytes. The caller
+/// releases the returned buffer via `sv_free_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn sv_validate_semantic_views_json_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    json_ptr: *const u8,
+    json_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_validate_semantic_views_json_bind_rust",
+        |_borrowed| {
+            let json = unsafe { read_str_arg(json_ptr, json_len, "definitions JSON") }?;
+            serialize_varchar_rows(&validate_entries(&json)?)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_entries;
+
+    const ORDERS: &str = r#"{"tables":[{"alias":"o","table":"orders","pk_columns":["id"]}],
+        "dimensions":[{"name":"region","expr":"o.region","source_table":"o"}],
+        "metrics":[{"name":"revenue","expr":"sum(o.amount)","source_table":"o"}]}"#;
+
+    fn statuses(json: &str) -> Vec<(String, String)> {
+        validate_entries(json)
+            .unwrap()
+            .into_iter()
+            .map(|r| (r[0].clone(), r[1].clone()))
+            .collect()
+    }
+
+    #[test]
+    fn mixed_entries_report_per_entry_status() {
+        let json = format!(
+            r#"[{{"name":"good","definition":{ORDERS}}},
+                {{"name":"no_def"}},
+                {{"name":"bad_shape","definition":{{"tables":"nope"}}}},
+                {{"definition":{ORDERS}}},
+                {{"name":"GOOD","definition":{ORDERS}}}]"#
+        );
+        assert_eq!(
+            statuses(&json),
+            vec![
+                ("good".into(), "ok".into()),
+                ("no_def".into(), "error".into()),
+                ("bad_shape".into(), "error".into()),
+                (String::new(), "error".into()),
+                ("GOOD".into(), "error".into()),
+            ]
+        );
+        let rows = validate_entries(&json).unwrap();
+        assert!(rows[0][2].is_empty());
+        assert!(rows[4][2].contains("more than once"), "{}", rows[4][2]);
+    }
+
+    #[test]
+    fn create_time_validation_errors_surface() {
+        // Dimension and metric share a name (SG-13).
+        let json = r#"[{"name":"dup","definition":{"tables":[{"alias":"o","table":"orders","pk_columns":["id"]}],
+            "dimensions":[{"name":"x","expr":"o.a","source_table":"o"}],
+            "metrics":[{"name":"x","expr":"sum(o.b)","source_table":"o"}]}}]"#;
+        let rows = validate_entries(json).unwrap();
+        assert_eq!(rows[0][1], "error");
+        assert!(!rows[0][2].is_empty());
+    }
+
+    #[test]
+    fn non_array_input_fails_whole_call() {
+        assert!(validate_entries(r#"{"name":"x"}"#).is_err());
+        assert!(validate_entries("not json").is_err());
+        assert!(validate_entries("[]").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+//! `semantic_view_doc(name)` scalar function: wraps
+//! [`crate::render_markdown::render_markdown`] so that
+//! `SELECT semantic_view_doc('name')` returns the view's Markdown
+//! documentation as one string.
+//!
+//! The render logic lives in [`crate::render_markdown`] (always compiled,
+//! unit-tested under `cargo test`); this module is only the FFI dispatcher
+//! reached from `sv_register_semantic_view_doc` in `cpp/src/shim.cpp`.
+
+#![cfg(feature = "extension")]
+
+use crate::catalog::CatalogReader;
+use crate::ddl::read_ffi::{
+    probe_catalog_table_present, read_str_arg, run_dispatcher, BorrowedConnection,
+};
+use crate::model::SemanticViewDefinition;
+use crate::render_markdown::render_markdown;
+
+/// # Safety
+///
+/// `conn` is a borrowed handle (do NOT disconnect). `name_ptr` must point
+/// to `name_len` UTF-8 bytes (not NUL-terminated).
+#[no_mangle]
+pub unsafe extern "C" fn sv_semantic_view_doc_exec_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_semantic_view_doc_exec_rust",
+        |borrowed| unsafe { view_doc(borrowed, name_ptr, name_len) },
+    )
+}
+
+/// Body for [`sv_semantic_view_doc_exec_rust`]: resolve the view and render
+/// its Markdown document.
+///
+/// # Safety
+///
+/// `name_ptr` must be null or point to `name_len` readable bytes.
+unsafe fn view_doc(
+    borrowed: &BorrowedConnection,
+    name_ptr: *const u8,
+    name_len: usize,
+) -> Result<Vec<u8>, String> {
+    let raw_name = read_str_arg(name_ptr, name_len, "view name")?;
+    let view_name = crate::ident::normalize_view_name(&raw_name)
+        .map_err(|e| format!("Invalid view name '{raw_name}': {e}"))?;
+    let present = probe_catalog_table_present(borrowed)?;
+    let reader = CatalogReader::new(borrowed, present);
+    let json = reader
+        .lookup_resolved(&view_name)?
+        .ok_or_else(|| crate::catalog::view_not_found_msg(&view_name))?;
+    let def = SemanticViewDefinition::from_json(&view_name, &json)?;
+    Ok(render_markdown(&view_name, &def).into_bytes())
+}
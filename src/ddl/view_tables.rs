@@ -0,0 +1,142 @@
+//! `semantic_view_tables(view)` dispatcher: the physical tables a view reads.
+//!
+//! One row per table entry of the stored definition — the base table (the
+//! first `TABLES` entry, which expansion puts in `FROM`), every other entry
+//! (reached through a relationship join), and every materialization table a
+//! query may be routed to — so a view's dependencies can be joined against
+//! `information_schema.tables` before a table is dropped or renamed.
+//!
+//! Columns: `semantic_view_name, alias, table_name, kind`, where `kind` is
+//! `base`, `joined`, or `materialization` (materializations have no alias and
+//! report their own name there). Rows are sorted base first, then joined,
+//! then materialization, and by `table_name`, `alias` within each kind.
+
+#![cfg(feature = "extension")]
+
+use crate::catalog::CatalogReader;
+use crate::ddl::read_ffi::{
+    probe_catalog_table_present, read_str_arg, run_dispatcher, serialize_varchar_rows,
+};
+use crate::model::SemanticViewDefinition;
+
+/// Build the sorted `[semantic_view_name, alias, table_name, kind]` rows for
+/// one parsed definition.
+fn collect_table_rows(view_name: &str, def: &SemanticViewDefinition) -> Vec<Vec<String>> {
+    // (kind rank, kind, alias, table) so sorting needs no string compare on kind.
+    let mut entries: Vec<(u8, &str, &str, &str)> = def
+        .tables
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let (rank, kind) = if i == 0 { (0, "base") } else { (1, "joined") };
+            (rank, kind, t.alias.as_str(), t.table.as_str())
+        })
+        .collect();
+    entries.extend(
+        def.materializations
+            .iter()
+            .map(|m| (2, "materialization", m.name.as_str(), m.table.as_str())),
+    );
+    entries.sort_by(|a, b| (a.0, a.3, a.2).cmp(&(b.0, b.3, b.2)));
+    entries
+        .into_iter()
+        .map(|(_, kind, alias, table)| {
+            vec![
+                view_name.to_string(),
+                alias.to_string(),
+                table.to_string(),
+                kind.to_string(),
+            ]
+        })
+        .collect()
+}
+
+/// # Safety
+///
+/// `conn` is a borrowed handle (see `read_ffi` borrow contract); `name_ptr`
+/// must point to `name_len` UTF-8 bytes. The caller releases the returned
+/// buffer via `sv_free_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn sv_semantic_view_tables_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_semantic_view_tables_bind_rust",
+        |borrowed| {
+            let view_name = unsafe { read_str_arg(name_ptr, name_len, "view name") }?;
+            let view_name = crate::ident::normalize_view_name(&view_name)
+                .map_err(|e| format!("Invalid view name '{view_name}': {e}"))?;
+            let present = unsafe { probe_catalog_table_present(borrowed) }?;
+            let reader = CatalogReader::new(borrowed, present);
+            let Some(json) = reader.lookup_resolved(&view_name)? else {
+                return Err(crate::catalog::view_not_found_msg(&view_name));
+            };
+            let def = SemanticViewDefinition::from_json(&view_name, &json)?;
+            serialize_varchar_rows(&collect_table_rows(&view_name, &def))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collect_table_rows;
+    use crate::model::{Materialization, SemanticViewDefinition, TableRef};
+
+    fn table(alias: &str, name: &str) -> TableRef {
+        TableRef {
+            alias: alias.to_string(),
+            table: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn base_first_then_joined_then_materializations_sorted() {
+        let def = SemanticViewDefinition {
+            tables: vec![
+                table("o", "orders"),
+                table("p", "products"),
+                table("c", "customers"),
+                table("ship_c", "customers"),
+            ],
+            materializations: vec![Materialization {
+                name: "daily".to_string(),
+                table: "orders_daily".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let rows: Vec<(String, String, String)> = collect_table_rows("sales", &def)
+            .into_iter()
+            .map(|r| {
+                assert_eq!(r[0], "sales");
+                (r[1].clone(), r[2].clone(), r[3].clone())
+            })
+            .collect();
+        let expected = [
+            ("o", "orders", "base"),
+            ("c", "customers", "joined"),
+            ("ship_c", "customers", "joined"),
+            ("p", "products", "joined"),
+            ("daily", "orders_daily", "materialization"),
+        ];
+        assert_eq!(
+            rows,
+            expected
+                .iter()
+                .map(|(a, t, k)| (a.to_string(), t.to_string(), k.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
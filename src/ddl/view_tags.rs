@@ -0,0 +1,100 @@
+//! `semantic_view_tags(view)` dispatcher: a view's tags and labels.
+//!
+//! Columns: `semantic_view_name, kind, key, value`. A tag is one `tag` row
+//! with the tag in `key` and an empty `value`; a label is one `label` row per
+//! key. Tags come first in declared order, then labels sorted by key. A view
+//! with neither returns no rows.
+
+#![cfg(feature = "extension")]
+
+use crate::catalog::CatalogReader;
+use crate::ddl::read_ffi::{
+    probe_catalog_table_present, read_str_arg, run_dispatcher, serialize_varchar_rows,
+};
+use crate::model::SemanticViewDefinition;
+
+/// Build the `[semantic_view_name, kind, key, value]` rows for one parsed
+/// definition.
+fn collect_tag_rows(view_name: &str, def: &SemanticViewDefinition) -> Vec<Vec<String>> {
+    let row = |kind: &str, key: &str, value: &str| {
+        vec![
+            view_name.to_string(),
+            kind.to_string(),
+            key.to_string(),
+            value.to_string(),
+        ]
+    };
+    def.tags
+        .iter()
+        .map(|tag| row("tag", tag, ""))
+        .chain(def.labels.iter().map(|(k, v)| row("label", k, v)))
+        .collect()
+}
+
+/// # Safety
+///
+/// `conn` is a borrowed handle (see `read_ffi` borrow contract); `name_ptr`
+/// must point to `name_len` UTF-8 bytes. The caller releases the returned
+/// buffer via `sv_free_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn sv_semantic_view_tags_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_semantic_view_tags_bind_rust",
+        |borrowed| {
+            let view_name = unsafe { read_str_arg(name_ptr, name_len, "view name") }?;
+            let view_name = crate::ident::normalize_view_name(&view_name)
+                .map_err(|e| format!("Invalid view name '{view_name}': {e}"))?;
+            let present = unsafe { probe_catalog_table_present(borrowed) }?;
+            let reader = CatalogReader::new(borrowed, present);
+            let Some(json) = reader.lookup(&view_name)? else {
+                return Err(crate::catalog::view_not_found_msg(&view_name));
+            };
+            let def = SemanticViewDefinition::from_json(&view_name, &json)?;
+            serialize_varchar_rows(&collect_tag_rows(&view_name, &def))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collect_tag_rows;
+    use crate::model::SemanticViewDefinition;
+
+    #[test]
+    fn tags_in_order_then_labels_by_key() {
+        let def = SemanticViewDefinition {
+            tags: vec!["finance".to_string(), "core".to_string()],
+            labels: [("owner", "data-eng"), ("domain", "sales")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        let rows = collect_tag_rows("sales", &def);
+        let expected = [
+            ["sales", "tag", "finance", ""],
+            ["sales", "tag", "core", ""],
+            ["sales", "label", "domain", "sales"],
+            ["sales", "label", "owner", "data-eng"],
+        ];
+        assert_eq!(rows, expected.map(|r| r.map(str::to_string).to_vec()));
+    }
+
+    #[test]
+    fn untagged_view_has_no_rows() {
+        assert!(collect_tag_rows("sales", &SemanticViewDefinition::default()).is_empty());
+    }
+}
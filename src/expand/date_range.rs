@@ -0,0 +1,200 @@
+//! Relative date-range macros for dimension filters on a view's
+//! `time_dimension`.
+//!
+//! A filter value such as `LAST_30_DAYS` or `THIS_QUARTER` stands for a
+//! half-open range `[start, end)` computed from `current_date` when the query
+//! runs, so a saved request keeps meaning "the last 30 days" instead of the
+//! dates it was written on. Tokens match ASCII case-insensitively. Ranges that
+//! end "now" include today; calendar periods start at `date_trunc` of the
+//! period (ISO weeks start on Monday).
+
+/// A calendar period a `THIS_*` / `LAST_*` macro refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Period {
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Period {
+    fn parse(token: &str) -> Option<Self> {
+        Some(match token {
+            "WEEK" => Self::Week,
+            "MONTH" => Self::Month,
+            "QUARTER" => Self::Quarter,
+            "YEAR" => Self::Year,
+            _ => return None,
+        })
+    }
+
+    fn trunc_unit(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Quarter => "quarter",
+            Self::Year => "year",
+        }
+    }
+
+    fn interval(self) -> &'static str {
+        match self {
+            Self::Week => "INTERVAL 7 DAY",
+            Self::Month => "INTERVAL 1 MONTH",
+            Self::Quarter => "INTERVAL 3 MONTH",
+            Self::Year => "INTERVAL 1 YEAR",
+        }
+    }
+}
+
+/// A recognized date-range macro token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum DateRange {
+    /// `TODAY`
+    Today,
+    /// `YESTERDAY`
+    Yesterday,
+    /// `LAST_<n>_DAYS`: the `n` days ending today, today included.
+    LastDays(u32),
+    /// `THIS_WEEK` / `THIS_MONTH` / `THIS_QUARTER` / `THIS_YEAR`: the current
+    /// period, including the days still to come.
+    This(Period),
+    /// `LAST_WEEK` / `LAST_MONTH` / `LAST_QUARTER` / `LAST_YEAR`: the whole
+    /// period before the current one.
+    Last(Period),
+}
+
+/// Upper bound on `LAST_<n>_DAYS`, so a typo like `LAST_3000000_DAYS` is a
+/// literal rather than an interval `DuckDB` would reject.
+const MAX_DAYS: u32 = 36_600;
+
+impl DateRange {
+    /// The macro `token` names, or `None` for any other value.
+    pub(super) fn parse(token: &str) -> Option<Self> {
+        let upper = token.trim().to_ascii_uppercase();
+        match upper.as_str() {
+            "TODAY" => return Some(Self::Today),
+            "YESTERDAY" => return Some(Self::Yesterday),
+            _ => {}
+        }
+        if let Some(days) = upper
+            .strip_prefix("LAST_")
+            .and_then(|rest| rest.strip_suffix("_DAYS"))
+        {
+            return days
+                .parse()
+                .ok()
+                .filter(|n| (1..=MAX_DAYS).contains(n))
+                .map(Self::LastDays);
+        }
+        if let Some(period) = upper.strip_prefix("THIS_") {
+            return Period::parse(period).map(Self::This);
+        }
+        upper
+            .strip_prefix("LAST_")
+            .and_then(Period::parse)
+            .map(Self::Last)
+    }
+
+    /// SQL expressions for the range's inclusive start and exclusive end.
+    pub(super) fn bounds(self) -> (String, String) {
+        let tomorrow = "current_date + INTERVAL 1 DAY".to_string();
+        match self {
+            Self::Today => ("current_date".to_string(), tomorrow),
+            Self::Yesterday => (
+                "current_date - INTERVAL 1 DAY".to_string(),
+                "current_date".to_string(),
+            ),
+            Self::LastDays(n) => (format!("current_date - INTERVAL {} DAY", n - 1), tomorrow),
+            Self::This(period) => {
+                let start = format!("date_trunc('{}', current_date)", period.trunc_unit());
+                let end = format!("{start} + {}", period.interval());
+                (start, end)
+            }
+            Self::Last(period) => {
+                let end = format!("date_trunc('{}', current_date)", period.trunc_unit());
+                let start = format!("{end} - {}", period.interval());
+                (start, end)
+            }
+        }
+    }
+
+    /// The predicate restricting `expr` to the range.
+    pub(super) fn predicate(self, expr: &str) -> String {
+        let (start, end) = self.bounds();
+        format!("({expr} >= {start} AND {expr} < {end})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateRange, Period};
+
+    #[test]
+    fn tokens_parse_case_insensitively() {
+        assert_eq!(DateRange::parse("today"), Some(DateRange::Today));
+        assert_eq!(
+            DateRange::parse("Last_30_Days"),
+            Some(DateRange::LastDays(30))
+        );
+        assert_eq!(
+            DateRange::parse("THIS_QUARTER"),
+            Some(DateRange::This(Period::Quarter))
+        );
+        assert_eq!(
+            DateRange::parse("last_month"),
+            Some(DateRange::Last(Period::Month))
+        );
+    }
+
+    #[test]
+    fn other_values_are_not_macros() {
+        for value in [
+            "2024-01-01",
+            "LAST_DAYS",
+            "LAST_0_DAYS",
+            "LAST_-3_DAYS",
+            "LAST_99999999_DAYS",
+            "THIS_DECADE",
+            "NEXT_WEEK",
+            "",
+        ] {
+            assert_eq!(DateRange::parse(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn last_30_days_ends_tomorrow_and_includes_today() {
+        assert_eq!(
+            DateRange::LastDays(30).bounds(),
+            (
+                "current_date - INTERVAL 29 DAY".to_string(),
+                "current_date + INTERVAL 1 DAY".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn this_and_last_quarter_share_a_boundary() {
+        let (this_start, this_end) = DateRange::This(Period::Quarter).bounds();
+        let (last_start, last_end) = DateRange::Last(Period::Quarter).bounds();
+        assert_eq!(this_start, "date_trunc('quarter', current_date)");
+        assert_eq!(
+            this_end,
+            "date_trunc('quarter', current_date) + INTERVAL 3 MONTH"
+        );
+        assert_eq!(last_end, this_start);
+        assert_eq!(
+            last_start,
+            "date_trunc('quarter', current_date) - INTERVAL 3 MONTH"
+        );
+    }
+
+    #[test]
+    fn predicate_is_half_open() {
+        assert_eq!(
+            DateRange::Yesterday.predicate("o.ordered_at"),
+            "(o.ordered_at >= current_date - INTERVAL 1 DAY AND o.ordered_at < current_date)"
+        );
+    }
+}
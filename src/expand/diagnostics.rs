@@ -0,0 +1,291 @@
+//! [`expand_with_diagnostics`]: the expanded SQL together with what went into
+//! it, for tooling that shows a query alongside its explanation.
+
+use crate::model::{Cardinality, SemanticViewDefinition};
+
+use super::facts::{inline_derived_metrics, toposort_facts};
+use super::materialization::{find_routing_materialization_name, request_may_route};
+use super::plan::{plan, PlannedJoin};
+use super::sql_gen::expand;
+use super::types::{ExpandError, QueryRequest};
+
+/// The result of [`expand_with_diagnostics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpandOutput {
+    /// The SQL [`expand`] returns for the same request.
+    pub sql: String,
+    /// Resolved dimension names as declared, in output order (view defaults
+    /// applied when the request names nothing).
+    pub dimensions: Vec<String>,
+    /// Resolved metric names as declared, in output order.
+    pub metrics: Vec<String>,
+    /// Resolved fact names as declared, in output order.
+    pub facts: Vec<String>,
+    /// The `LEFT JOIN`s in [`Self::sql`], in emission order. Empty when the
+    /// query reads a materialization instead of the base tables.
+    pub included_joins: Vec<PlannedJoin>,
+    /// Things the SQL does that are legal but easy to misread, one sentence
+    /// each; see [`expand_with_diagnostics`].
+    pub warnings: Vec<String>,
+}
+
+/// [`expand`], plus the items and joins the SQL was built from and any
+/// warnings about it.
+///
+/// Warnings are raised for:
+///
+/// - a selected dimension or metric that [`SemanticViewDefinition::warnings`]
+///   flags (an aggregate dimension, a metric with no aggregate);
+/// - a metrics-only query selecting a metric whose expression, derived
+///   metrics inlined, calls functions but none this crate knows as an
+///   aggregate. [`expand`] only rejects an expression with no call at all,
+///   so a UDAF or macro passes through and fails at query time if it is not one;
+/// - a row-level query (facts or `detail`) that joins a table from the
+///   referenced side of a many-to-one relationship, so each base row can
+///   appear once per matching row of that table. Aggregate queries never
+///   warn here: the same shape is a fan trap, which [`expand`] rejects;
+/// - a query routed to a materialization, which reads its pre-aggregated
+///   table rather than the base tables.
+///
+/// # Errors
+///
+/// As [`expand`].
+pub fn expand_with_diagnostics(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    req: &QueryRequest,
+) -> Result<ExpandOutput, ExpandError> {
+    let sql = expand(view_name, def, req)?;
+    // Everything `plan` rejects, `expand` has rejected already.
+    let p = plan(view_name, def, req)?;
+    let req = req.or_view_defaults(def);
+    let mut out = ExpandOutput {
+        sql,
+        dimensions: p.dimensions.iter().map(|d| d.name.clone()).collect(),
+        metrics: p.metrics.iter().map(|m| m.name.clone()).collect(),
+        facts: p.facts.iter().map(|f| f.name.clone()).collect(),
+        included_joins: Vec::new(),
+        warnings: Vec::new(),
+    };
+    let flagged: Vec<_> = def
+        .warnings()
+        .into_iter()
+        .filter(|w| out.dimensions.contains(&w.item) || out.metrics.contains(&w.item))
+        .collect();
+    out.warnings
+        .extend(flagged.iter().map(|w| w.message.clone()));
+
+    // The same two-part routing decision `expand` makes.
+    let row_level = req.detail || !req.facts.is_empty();
+    let routed = if request_may_route(&req) {
+        find_routing_materialization_name(def, &p.dimensions, &p.metrics)
+    } else {
+        None
+    };
+    if let Some(name) = routed {
+        let table = def
+            .materializations
+            .iter()
+            .find(|m| m.name == name)
+            .map_or("", |m| m.table.as_str());
+        out.warnings.push(format!(
+            "routed to materialization '{name}': the query reads {table} instead of the base tables"
+        ));
+        return Ok(out);
+    }
+
+    if !row_level && p.dimensions.is_empty() {
+        let exprs = toposort_facts(&def.facts)
+            .and_then(|order| inline_derived_metrics(&def.metrics, &def.facts, &order, &def.tables))
+            .map(|r| r.exprs)
+            .unwrap_or_default();
+        for met in &p.metrics {
+            if met.is_window()
+                || !met.non_additive_by.is_empty()
+                || flagged.iter().any(|w| w.item == met.name)
+            {
+                continue;
+            }
+            let expr = exprs
+                .get(&crate::ident::normalize_ident_part(&met.name))
+                .map_or(met.expr.as_str(), String::as_str);
+            if crate::graph::contains_aggregate_function(expr).is_none() {
+                out.warnings.push(format!(
+                    "metric '{}' calls no known aggregate function; without dimensions the \
+                     query is one aggregate row, so DuckDB rejects it unless the expression \
+                     aggregates",
+                    met.name
+                ));
+            }
+        }
+    }
+
+    if row_level {
+        for j in &p.joins {
+            let rel = &def.joins[j.join_index];
+            let many_side = rel.from_alias.eq_ignore_ascii_case(&j.alias);
+            if many_side && rel.cardinality == Cardinality::ManyToOne {
+                out.warnings.push(format!(
+                    "join to '{}' via relationship '{}' fans out: each '{}' row repeats \
+                     once per matching '{}' row",
+                    j.alias,
+                    j.relationship.as_deref().unwrap_or("(unnamed)"),
+                    rel.table,
+                    j.alias
+                ));
+            }
+        }
+    }
+    out.included_joins = p.joins;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand_with_diagnostics;
+    use crate::expand::test_helpers::TestFixtureExt;
+    use crate::expand::QueryRequest;
+    use crate::model::SemanticViewDefinition;
+
+    /// `orders` (`o`, base) -> `customers` (`c`) -> `regions` (`r`);
+    /// `line_items` (`li`) -> `o`.
+    fn def() -> SemanticViewDefinition {
+        SemanticViewDefinition::default()
+            .with_table("o", "orders", &["id"])
+            .with_table("c", "customers", &["id"])
+            .with_table("r", "regions", &["id"])
+            .with_table("li", "line_items", &["id"])
+            .with_dimension("customer_name", "c.name", Some("c"))
+            .with_dimension("region", "r.name", Some("r"))
+            .with_dimension("status", "o.status", Some("o"))
+            .with_dimension("sku", "li.sku", Some("li"))
+            .with_metric("revenue", "sum(o.amount)", Some("o"))
+            .with_pkfk_join("o_to_c", "o", "c", &["customer_id"], &["id"])
+            .with_pkfk_join("c_to_r", "c", "r", &["region_id"], &["id"])
+            .with_pkfk_join("li_to_o", "li", "o", &["order_id"], &["id"])
+    }
+
+    #[test]
+    fn included_joins_are_the_needed_path() {
+        let def = def();
+        let req = QueryRequest::builder()
+            .dimensions(["REGION"])
+            .metrics(["revenue"])
+            .build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert_eq!(out.sql, crate::expand::expand("v", &def, &req).unwrap());
+        assert_eq!(out.dimensions, ["region"]);
+        assert_eq!(out.metrics, ["revenue"]);
+        let aliases: Vec<&str> = out
+            .included_joins
+            .iter()
+            .map(|j| j.alias.as_str())
+            .collect();
+        assert_eq!(aliases, ["c", "r"]);
+        for j in &out.included_joins {
+            assert!(out.sql.contains(&j.on), "{}", out.sql);
+        }
+        assert!(out.warnings.is_empty(), "{:?}", out.warnings);
+
+        // A base-table dimension needs no join at all.
+        let req = QueryRequest::builder()
+            .dimensions(["status"])
+            .metrics(["revenue"])
+            .build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert!(out.included_joins.is_empty());
+        assert!(!out.sql.contains("JOIN"), "{}", out.sql);
+    }
+
+    #[test]
+    fn row_level_fan_out_join_warns() {
+        let def = def();
+        let req = QueryRequest::builder()
+            .dimensions(["status", "sku"])
+            .detail(true)
+            .build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert_eq!(out.included_joins.len(), 1);
+        assert_eq!(
+            out.warnings,
+            [
+                "join to 'li' via relationship 'li_to_o' fans out: each 'o' row repeats \
+              once per matching 'li' row"
+            ]
+        );
+    }
+
+    #[test]
+    fn materialized_query_reports_no_joins() {
+        let def = def().with_materialization("m", "agg_by_region", &["region"], &["revenue"]);
+        let req = QueryRequest::builder()
+            .dimensions(["region"])
+            .metrics(["revenue"])
+            .build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert!(out.sql.contains("agg_by_region"), "{}", out.sql);
+        assert!(out.included_joins.is_empty());
+        assert_eq!(out.warnings.len(), 1);
+        assert!(out.warnings[0].starts_with("routed to materialization 'm'"));
+    }
+
+    #[test]
+    fn aggregate_placement_warnings_for_selected_items_only() {
+        let def = def()
+            .with_dimension("total", "sum(o.amount)", Some("o"))
+            .with_metric("raw_amount", "o.amount", Some("o"));
+        let req = QueryRequest::builder()
+            .dimensions(["total"])
+            .metrics(["revenue"])
+            .build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert_eq!(out.warnings.len(), 1, "{:?}", out.warnings);
+        assert!(out.warnings[0].starts_with("dimension 'total' is an aggregate"));
+
+        let req = QueryRequest::builder()
+            .dimensions(["status"])
+            .metrics(["raw_amount"])
+            .build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert_eq!(out.warnings.len(), 1, "{:?}", out.warnings);
+        assert!(out.warnings[0].starts_with("metric 'raw_amount' has no aggregate"));
+    }
+
+    #[test]
+    fn metrics_only_query_warns_on_unknown_calls() {
+        let def = def()
+            .with_metric("custom", "my_udaf(o.amount)", Some("o"))
+            .with_metric("custom_rounded", "round(custom, 2)", None);
+        let req = QueryRequest::builder().metrics(["custom_rounded"]).build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert_eq!(
+            out.warnings,
+            [
+                "metric 'custom_rounded' calls no known aggregate function; without dimensions \
+              the query is one aggregate row, so DuckDB rejects it unless the expression \
+              aggregates"
+            ]
+        );
+
+        // Grouped, the same metric is evaluated per group: no warning.
+        let req = QueryRequest::builder()
+            .dimensions(["status"])
+            .metrics(["custom_rounded"])
+            .build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert!(out.warnings.is_empty(), "{:?}", out.warnings);
+
+        // A known aggregate behind the derived metric is not flagged.
+        let def = def.with_metric("revenue_rounded", "round(revenue, 2)", None);
+        let req = QueryRequest::builder().metrics(["revenue_rounded"]).build();
+        let out = expand_with_diagnostics("v", &def, &req).unwrap();
+        assert!(out.warnings.is_empty(), "{:?}", out.warnings);
+    }
+
+    #[test]
+    fn errors_are_those_of_expand() {
+        let req = QueryRequest::builder().metrics(["nope"]).build();
+        let err = expand_with_diagnostics("v", &def(), &req).unwrap_err();
+        assert_eq!(err.code(), "UNKNOWN_METRIC");
+    }
+}
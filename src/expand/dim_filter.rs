@@ -0,0 +1,376 @@
+//! Query-time dimension filters ([`DimensionFilter`]) rendered as `WHERE`
+//! predicates.
+//!
+//! Each filter resolves its dimension exactly like a requested one and
+//! compares the dimension as the query would output it — the same
+//! [`SelectItem`] wrapping (`bucket`, `coalesce_to`, `collate`, `output_type`
+//! cast) —
+//! so `region = 'EU'` means "rows whose `region` column reads `EU`". Values
+//! never reach the SQL as raw text: strings go through [`SqlLit::escape`],
+//! numbers and booleans are re-rendered from their parsed JSON form.
+//!
+//! An `Eq` filter on the view's `time_dimension` whose value is a date-range
+//! macro ([`DateRange`]) becomes a range predicate instead; any other value,
+//! on that dimension or another, is compared literally.
+//!
+//! Metric filters ([`MetricFilter`]) share the operators and literal
+//! rendering but compare the metric's aggregate, so they land in `HAVING`.
+
+use serde_json::Value;
+
+use super::date_range::DateRange;
+use super::select_spec::SelectItem;
+use super::sql_gen::resolve_names;
+use super::types::{DimFilterOp, DimensionFilter, ExpandError, MetricFilter};
+use crate::ident::ident_matches;
+use crate::model::{Dimension, Metric, SemanticViewDefinition};
+use crate::sql_lit::SqlLit;
+
+/// A [`DimensionFilter`] whose dimension is resolved and whose values are
+/// rendered SQL literals.
+pub(super) struct ResolvedFilter<'a> {
+    pub(super) dim: &'a Dimension,
+    op: DimFilterOp,
+    literals: Vec<String>,
+    /// Set when the filter is a date-range macro on the time dimension.
+    range: Option<DateRange>,
+}
+
+impl ResolvedFilter<'_> {
+    /// The `WHERE` predicate. `scoped_alias` is the dimension's role-playing
+    /// alias for this query, if any (see `find_using_context`), so the
+    /// predicate reads the same joined copy as a selected dimension would.
+    pub(super) fn predicate(&self, scoped_alias: Option<&str>) -> String {
+        let mut expr = self.dim.expr.clone();
+        if let (Some(scoped), Some(st)) = (scoped_alias, self.dim.source_table.as_deref()) {
+            expr = crate::expr_tokens::rewrite_qualifier(&expr, st, scoped);
+        }
+        let lhs = SelectItem::new(operand(expr), self.dim.output_type.clone(), String::new())
+            .bucketed(self.dim.bucket.as_deref())
+            .coalesced(self.dim.coalesce_to.as_deref())
+            .null_replaced(self.dim.null_value.as_deref())
+            .collated(self.dim.collate.as_deref())
+            .rendered_expr();
+        if let Some(range) = self.range {
+            return range.predicate(&lhs);
+        }
+        comparison(&lhs, self.op, &self.literals)
+    }
+}
+
+/// `expr` parenthesized unless it is a single column reference or a single
+/// function call, so the comparison applies to the whole dimension or metric:
+/// `a OR b` filtered `= TRUE` must read `(a OR b) = TRUE`, not
+/// `a OR (b = TRUE)`.
+fn operand(expr: String) -> String {
+    let trimmed = expr.trim();
+    if crate::expr_tokens::single_call_head(trimmed).is_some() {
+        return expr;
+    }
+    match crate::expr_tokens::scan_references(trimmed).as_slice() {
+        [only] if only.raw.len() == trimmed.len() => expr,
+        _ => format!("({trimmed})"),
+    }
+}
+
+/// `lhs <op> literals`, for literals already checked by [`render_values`].
+pub(super) fn comparison(lhs: &str, op: DimFilterOp, literals: &[String]) -> String {
+    match op {
+        DimFilterOp::Eq => format!("{lhs} = {}", literals[0]),
+        DimFilterOp::NotEq => format!("{lhs} <> {}", literals[0]),
+        DimFilterOp::Gt => format!("{lhs} > {}", literals[0]),
+        DimFilterOp::GtEq => format!("{lhs} >= {}", literals[0]),
+        DimFilterOp::Lt => format!("{lhs} < {}", literals[0]),
+        DimFilterOp::LtEq => format!("{lhs} <= {}", literals[0]),
+        DimFilterOp::In => format!("{lhs} IN ({})", literals.join(", ")),
+        DimFilterOp::NotIn => format!("{lhs} NOT IN ({})", literals.join(", ")),
+    }
+}
+
+/// Check that `values` suit `op` and render them as SQL literals; the
+/// `Err` is the reason, for the caller to wrap in its own error variant.
+pub(super) fn render_values(op: DimFilterOp, values: &[Value]) -> Result<Vec<String>, String> {
+    let arity_ok = if op.takes_one_value() {
+        values.len() == 1
+    } else {
+        !values.is_empty()
+    };
+    if !arity_ok {
+        return Err(format!(
+            "{op:?} takes {}, got {}",
+            if op.takes_one_value() {
+                "exactly one value"
+            } else {
+                "at least one value"
+            },
+            values.len()
+        ));
+    }
+    values
+        .iter()
+        .map(|v| {
+            render_literal(v)
+                .ok_or_else(|| format!("unsupported value {v}; use a string, number, or boolean"))
+        })
+        .collect()
+}
+
+/// Resolve every filter's dimension (unknown names raise `UnknownDimension`),
+/// recognize date-range macros on the view's time dimension, and render the
+/// remaining values.
+///
+/// # Errors
+///
+/// `UnknownDimension` for an unresolvable name; `InvalidDimensionFilter` when
+/// a single-value operator does not have exactly one value, `In`/`NotIn`
+/// have none, or a value is not a string, number, or boolean.
+pub(super) fn resolve_dimension_filters<'a>(
+    view_name: &str,
+    def: &'a SemanticViewDefinition,
+    filters: &[DimensionFilter],
+) -> Result<Vec<ResolvedFilter<'a>>, ExpandError> {
+    filters
+        .iter()
+        .map(|filter| {
+            // One name at a time: filtering the same dimension twice is
+            // legitimate, unlike requesting it twice.
+            let dim = resolve_names::<Dimension, _>(
+                std::slice::from_ref(&filter.dimension),
+                view_name,
+                def,
+            )?[0];
+            let literals = render_values(filter.op, &filter.values).map_err(|reason| {
+                ExpandError::InvalidDimensionFilter {
+                    view_name: view_name.to_string(),
+                    dimension: dim.name.clone(),
+                    reason,
+                }
+            })?;
+            let on_time_dimension = def
+                .time_dimension
+                .as_deref()
+                .is_some_and(|t| ident_matches(&dim.name, t));
+            let range = match (filter.op, filter.values.as_slice()) {
+                (DimFilterOp::Eq, [Value::String(token)]) if on_time_dimension => {
+                    DateRange::parse(token)
+                }
+                _ => None,
+            };
+            Ok(ResolvedFilter {
+                dim,
+                op: filter.op,
+                literals,
+                range,
+            })
+        })
+        .collect()
+}
+
+/// A [`MetricFilter`] whose metric is resolved and whose values are rendered
+/// SQL literals.
+pub(super) struct ResolvedMetricFilter<'a> {
+    pub(super) met: &'a Metric,
+    op: DimFilterOp,
+    literals: Vec<String>,
+}
+
+impl ResolvedMetricFilter<'_> {
+    /// The `HAVING` predicate over `expr`, the metric's fully inlined
+    /// aggregate, wrapped in the metric's `output_type` cast like its column.
+    /// A compound aggregate is parenthesized like a compound dimension.
+    pub(super) fn predicate(&self, expr: &str) -> String {
+        let lhs = SelectItem::new(
+            operand(expr.to_string()),
+            self.met.output_type.clone(),
+            String::new(),
+        )
+        .rendered_expr();
+        comparison(&lhs, self.op, &self.literals)
+    }
+}
+
+/// Resolve every metric filter's metric and render its values.
+///
+/// # Errors
+///
+/// `UnknownMetric` / `PrivateMetric` as for a requested metric;
+/// `InvalidMetricFilter` for values [`resolve_dimension_filters`] would
+/// reject, or a window metric (its value is not an aggregate a `HAVING` can
+/// read).
+pub(super) fn resolve_metric_filters<'a>(
+    view_name: &str,
+    def: &'a SemanticViewDefinition,
+    filters: &[MetricFilter],
+) -> Result<Vec<ResolvedMetricFilter<'a>>, ExpandError> {
+    filters
+        .iter()
+        .map(|filter| {
+            let met =
+                resolve_names::<Metric, _>(std::slice::from_ref(&filter.metric), view_name, def)?
+                    [0];
+            let invalid = |reason: String| ExpandError::InvalidMetricFilter {
+                view_name: view_name.to_string(),
+                metric: met.name.clone(),
+                reason,
+            };
+            if met.is_window() {
+                return Err(invalid("window metrics cannot be filtered".to_string()));
+            }
+            let literals = render_values(filter.op, &filter.values).map_err(invalid)?;
+            Ok(ResolvedMetricFilter {
+                met,
+                op: filter.op,
+                literals,
+            })
+        })
+        .collect()
+}
+
+/// Render a JSON scalar as a SQL literal; `None` for null, arrays, and objects.
+fn render_literal(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(format!("'{}'", SqlLit::escape(s))),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(if *b { "TRUE" } else { "FALSE" }.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{render_literal, resolve_dimension_filters};
+    use crate::expand::test_helpers::TestFixtureExt;
+    use crate::expand::{DimFilterOp, DimensionFilter, DimensionName, ExpandError};
+    use crate::model::SemanticViewDefinition;
+
+    fn def() -> SemanticViewDefinition {
+        SemanticViewDefinition::default()
+            .with_table("o", "orders", &["id"])
+            .with_dimension("region", "o.region", Some("o"))
+    }
+
+    fn filter(op: DimFilterOp, values: Vec<serde_json::Value>) -> DimensionFilter {
+        DimensionFilter {
+            dimension: DimensionName::new("REGION"),
+            op,
+            values,
+        }
+    }
+
+    #[test]
+    fn literals_are_escaped_and_typed() {
+        assert_eq!(render_literal(&json!("O'Brien")).unwrap(), "'O''Brien'");
+        assert_eq!(render_literal(&json!(42)).unwrap(), "42");
+        assert_eq!(render_literal(&json!(1.5)).unwrap(), "1.5");
+        assert_eq!(render_literal(&json!(true)).unwrap(), "TRUE");
+        assert!(render_literal(&json!(null)).is_none());
+        assert!(render_literal(&json!(["a"])).is_none());
+    }
+
+    #[test]
+    fn predicates_per_operator() {
+        let def = def();
+        let cases = [
+            (
+                filter(DimFilterOp::Eq, vec![json!("EU")]),
+                "o.region = 'EU'",
+            ),
+            (
+                filter(DimFilterOp::NotEq, vec![json!("EU")]),
+                "o.region <> 'EU'",
+            ),
+            (filter(DimFilterOp::Gt, vec![json!(3)]), "o.region > 3"),
+            (filter(DimFilterOp::GtEq, vec![json!(3)]), "o.region >= 3"),
+            (filter(DimFilterOp::Lt, vec![json!(2.5)]), "o.region < 2.5"),
+            (
+                filter(DimFilterOp::LtEq, vec![json!("m")]),
+                "o.region <= 'm'",
+            ),
+            (
+                filter(DimFilterOp::In, vec![json!("EU"), json!("US")]),
+                "o.region IN ('EU', 'US')",
+            ),
+            (
+                filter(DimFilterOp::NotIn, vec![json!("x'); DROP TABLE t; --")]),
+                "o.region NOT IN ('x''); DROP TABLE t; --')",
+            ),
+        ];
+        for (f, expected) in cases {
+            let resolved = resolve_dimension_filters("v", &def, &[f]).unwrap();
+            assert_eq!(resolved[0].predicate(None), expected);
+        }
+    }
+
+    #[test]
+    fn wrong_arity_and_unknown_dimension_rejected() {
+        let def = def();
+        let err = resolve_dimension_filters("v", &def, &[filter(DimFilterOp::Eq, vec![])])
+            .err()
+            .unwrap();
+        assert!(
+            matches!(err, ExpandError::InvalidDimensionFilter { .. }),
+            "{err}"
+        );
+        let err = resolve_dimension_filters("v", &def, &[filter(DimFilterOp::In, vec![])])
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("at least one value"), "{err}");
+
+        let unknown = DimensionFilter {
+            dimension: DimensionName::new("country"),
+            op: DimFilterOp::Eq,
+            values: vec![json!("EU")],
+        };
+        let err = resolve_dimension_filters("v", &def, &[unknown])
+            .err()
+            .unwrap();
+        assert!(matches!(err, ExpandError::UnknownDimension { .. }), "{err}");
+    }
+
+    #[test]
+    fn date_macros_apply_to_the_time_dimension_only() {
+        let mut def = def().with_dimension("ordered_on", "o.ordered_at::DATE", Some("o"));
+        def.time_dimension = Some("ordered_on".to_string());
+        let on = |dimension: &str, value: &str| {
+            let f = DimensionFilter {
+                dimension: DimensionName::new(dimension),
+                op: DimFilterOp::Eq,
+                values: vec![json!(value)],
+            };
+            resolve_dimension_filters("v", &def, &[f]).unwrap()[0].predicate(None)
+        };
+        assert_eq!(
+            on("ordered_on", "last_30_days"),
+            "((o.ordered_at::DATE) >= current_date - INTERVAL 29 DAY \
+             AND (o.ordered_at::DATE) < current_date + INTERVAL 1 DAY)"
+        );
+        assert_eq!(
+            on("ordered_on", "THIS_QUARTER"),
+            "((o.ordered_at::DATE) >= date_trunc('quarter', current_date) \
+             AND (o.ordered_at::DATE) < date_trunc('quarter', current_date) + INTERVAL 3 MONTH)"
+        );
+        // Not a macro: the literal path.
+        assert_eq!(
+            on("ordered_on", "2024-01-01"),
+            "(o.ordered_at::DATE) = '2024-01-01'"
+        );
+        // Not the time dimension: the token is just a string.
+        assert_eq!(on("region", "TODAY"), "o.region = 'TODAY'");
+    }
+
+    #[test]
+    fn compound_dimension_is_compared_as_a_whole() {
+        let def = def().with_dimension("is_flagged", "o.is_vip OR o.is_staff", Some("o"));
+        let f = DimensionFilter {
+            dimension: DimensionName::new("is_flagged"),
+            op: DimFilterOp::Eq,
+            values: vec![json!(true)],
+        };
+        assert_eq!(
+            resolve_dimension_filters("v", &def, &[f]).unwrap()[0].predicate(None),
+            "(o.is_vip OR o.is_staff) = TRUE"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::expr_tokens::{inline_references, references_ref};
+use crate::expr_tokens::{count_distinct_arg, distinct_call, inline_references, references_ref};
 use crate::ident::normalize_ident_part;
 use crate::model::{Fact, TableRef};
 use crate::util::is_word_boundary_char;
@@ -385,7 +385,9 @@ fn toposort_derived(
 ///
 /// Processing order:
 /// 1. Base metrics (`source_table.is_some()`): inline facts, apply the SG-8
-///    `COUNT(*)` rewrite (below), store resolved expression
+///    `COUNT(*)` rewrite (below), swap an `approx` metric's `COUNT(DISTINCT x)`
+///    for `approx_count_distinct(x)`, append any `FILTER (WHERE ...)`, store
+///    resolved expression
 /// 2. Derived metrics (`source_table.is_none()`): topologically sort by inter-metric deps,
 ///    then for each derived metric, replace all known metric name references with
 ///    parenthesized resolved expressions
@@ -444,6 +446,31 @@ pub(super) fn inline_derived_metrics(
                 }
             }
         }
+        // Approximate count-distinct: a one-call swap, so FILTER below still
+        // attaches to the (now approximate) aggregate. CREATE guarantees the
+        // expression is a single COUNT(DISTINCT ...).
+        if met.approx {
+            if let Some(arg) = count_distinct_arg(&expr) {
+                expr = format!("approx_count_distinct({arg})");
+            }
+        }
+        // `distinct`: same one-call shape, guaranteed by CREATE.
+        if met.distinct {
+            if let Some(rewritten) = distinct_call(&expr) {
+                expr = rewritten;
+            }
+        }
+        // Metric FILTER: attached after the COUNT(*) rewrite so the predicate
+        // limits the rewritten aggregate too. Facts inline into the predicate
+        // exactly as into the expression.
+        if let Some(ref filter) = met.filter {
+            let filter = if facts.is_empty() {
+                filter.clone()
+            } else {
+                inline_facts(filter, facts, fact_topo_order)
+            };
+            expr = format!("{expr} FILTER (WHERE {filter})");
+        }
         resolved.insert(normalize_ident_part(&met.name), expr);
     }
 
@@ -624,6 +651,9 @@ mod tests {
             access: AccessModifier::Public,
             non_additive_by: vec![],
             window_spec: None,
+            filter: None,
+            approx: false,
+            distinct: false,
         }
     }
 
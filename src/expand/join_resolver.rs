@@ -22,7 +22,7 @@ pub(super) fn scoped_join_alias(table_lower: &str, rel_lower: &str) -> String {
 /// Zips `join.fk_columns` with the referenced table's `pk_columns` to produce
 /// `from_alias.fk = to_alias.pk` pairs, joined by ` AND `.
 /// Uses `join.from_alias` for the FROM side and `join.table` for the TO side.
-pub(super) fn synthesize_on_clause(join: &Join, tables: &[TableRef]) -> String {
+pub(crate) fn synthesize_on_clause(join: &Join, tables: &[TableRef]) -> String {
     synthesize_on_clause_scoped(join, tables, &join.table)
 }
 
@@ -43,10 +43,9 @@ pub(super) fn synthesize_on_clause_scoped(
     // Phase 33: Prefer ref_columns (resolved during inference).
     // Fall back to target PK for backward compat (legacy joins without ref_columns).
     let ref_cols: &[String] = if join.ref_columns.is_empty() {
-        let to_alias_lower = join.table.to_ascii_lowercase();
         tables
             .iter()
-            .find(|t| t.alias.to_ascii_lowercase() == to_alias_lower)
+            .find(|t| t.alias.eq_ignore_ascii_case(&join.table))
             .map_or(&[] as &[String], |t| &t.pk_columns)
     } else {
         &join.ref_columns
@@ -102,7 +101,7 @@ pub(super) fn push_join_clauses(
         let table_ref = def
             .tables
             .iter()
-            .find(|t| t.alias.to_ascii_lowercase() == rj.bare_alias);
+            .find(|t| t.alias.eq_ignore_ascii_case(&rj.bare_alias));
         let physical_table = table_ref.map_or(rj.bare_alias.as_str(), |t| t.table.as_str());
         sql.push_str(prefix);
         sql.push_str(&qualify_and_quote_table_ref(physical_table, def));
@@ -133,29 +132,40 @@ fn build_tree_parents<'a>(
     def: &'a SemanticViewDefinition,
     root: &str,
 ) -> HashMap<String, (String, &'a Join)> {
+    // Lowercase each edge's endpoints once: every dequeued alias scans all
+    // edges, so doing it inside the loop is quadratic in allocations.
+    // Legacy joins (no FK columns) are not part of the PK/FK graph.
+    let edges: Vec<(String, String, &'a Join)> = def
+        .joins
+        .iter()
+        .filter(|j| !j.fk_columns.is_empty())
+        .map(|j| {
+            (
+                j.from_alias.to_ascii_lowercase(),
+                j.table.to_ascii_lowercase(),
+                j,
+            )
+        })
+        .collect();
+
     let mut tree_parent: HashMap<String, (String, &'a Join)> = HashMap::new();
-    let mut visited: HashSet<String> = HashSet::new();
-    visited.insert(root.to_string());
-    let mut queue: VecDeque<String> = VecDeque::new();
-    queue.push_back(root.to_string());
+    let mut visited: HashSet<&str> = HashSet::new();
+    visited.insert(root);
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    queue.push_back(root);
     while let Some(current) = queue.pop_front() {
         // Visit edges in declaration order for determinism.
-        for join in &def.joins {
-            if join.fk_columns.is_empty() {
-                continue; // Legacy join -- not part of the PK/FK graph
-            }
-            let from = join.from_alias.to_ascii_lowercase();
-            let to = join.table.to_ascii_lowercase();
+        for (from, to, join) in &edges {
             let neighbor = if from == current {
-                to
+                to.as_str()
             } else if to == current {
-                from
+                from.as_str()
             } else {
                 continue;
             };
-            if visited.insert(neighbor.clone()) {
-                tree_parent.insert(neighbor.clone(), (current.clone(), join));
-                queue.push_back(neighbor.clone());
+            if visited.insert(neighbor) {
+                tree_parent.insert(neighbor.to_string(), (current.to_string(), *join));
+                queue.push_back(neighbor);
             }
         }
     }
@@ -178,7 +188,7 @@ fn push_bare_join<'a>(
         *join
     } else {
         let Some(join) = def.joins.iter().find(|j| {
-            j.table.to_ascii_lowercase() == alias || j.from_alias.to_ascii_lowercase() == alias
+            j.table.eq_ignore_ascii_case(&alias) || j.from_alias.eq_ignore_ascii_case(&alias)
         }) else {
             return;
         };
@@ -480,6 +490,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_synthesize_on_clause_quotes_reserved_words() {
+        let join = Join {
+            table: "group".to_string(),
+            from_alias: "order".to_string(),
+            fk_columns: vec!["select".to_string()],
+            ref_columns: vec!["we\"ird".to_string()],
+            ..Default::default()
+        };
+        let result = synthesize_on_clause(&join, &[]);
+        assert_eq!(result, r#""order"."select" = "group"."we""ird""#);
+    }
+
     #[test]
     fn test_synthesize_on_clause_empty_fk_columns() {
         let join = Join {
@@ -608,6 +631,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_joins_pkfk_long_chain_emits_root_outward() {
+        // t0 -> t1 -> ... -> t30, declared far end first and with mixed-case
+        // aliases; only the far end is selected.
+        let mut def = SemanticViewDefinition::default().with_table("t0", "t0", &["id"]);
+        for i in 1..=30 {
+            def = def.with_table(&format!("T{i}"), &format!("t{i}"), &["id"]);
+        }
+        for i in (1..=30).rev() {
+            def = def.with_pkfk_join(
+                &format!("r{i}"),
+                &format!("t{}", i - 1),
+                &format!("t{i}"),
+                &["parent_id"],
+                &["id"],
+            );
+        }
+        let def = def.with_dimension("far", "t30.name", Some("t30"));
+        let resolved_dims: Vec<&_> = def.dimensions.iter().collect();
+        let result = resolve_joins_pkfk(&def, &resolved_dims, &[], &[]);
+        let aliases: Vec<&str> = result.iter().map(|rj| rj.emit_alias.as_str()).collect();
+        let expected: Vec<String> = (1..=30).map(|i| format!("t{i}")).collect();
+        assert_eq!(aliases, expected);
+        for (i, rj) in result.iter().enumerate() {
+            assert_eq!(
+                rj.join.name.as_deref(),
+                Some(format!("r{}", i + 1).as_str())
+            );
+        }
+    }
+
     #[test]
     fn test_resolve_joins_pkfk_with_using_relationship() {
         // Build a role-playing scenario: flights -> airports via dep_airport and arr_airport
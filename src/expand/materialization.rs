@@ -8,7 +8,9 @@ use std::collections::HashSet;
 
 use crate::model::{Dimension, Materialization, Metric, SemanticViewDefinition};
 
+use super::output_alias::OutputAliases;
 use super::resolution::{qualify_and_quote_table_ref, quote_stored_ident};
+use super::QueryRequest;
 
 /// Find the materialization whose declared dimension and metric name sets
 /// EXACTLY match the requested ones (case-insensitive), honoring the routing
@@ -39,6 +41,11 @@ fn find_matching_materialization<'a>(
     if def.materializations.is_empty() {
         return None;
     }
+    // Row security filters base-table rows; a pre-aggregated table has
+    // neither those rows nor, in general, the columns the predicate reads.
+    if def.row_security.is_some() {
+        return None;
+    }
     // MAT-04: semi-additive and window-function metrics are never routed.
     if resolved_mets.iter().any(|m| !m.non_additive_by.is_empty()) {
         return None;
@@ -76,6 +83,22 @@ fn find_matching_materialization<'a>(
     })
 }
 
+/// Whether `req` may be answered from a materialization at all, before its
+/// items are matched: an aggregate query (no facts, not `detail`) with no
+/// dimension or metric filters, whose predicates read base-table rows.
+///
+/// The request-side half of the routing decision, shared by [`expand`] and
+/// [`expand_with_diagnostics`] so the reported route is the one taken.
+///
+/// [`expand`]: super::expand
+/// [`expand_with_diagnostics`]: super::expand_with_diagnostics
+pub(crate) fn request_may_route(req: &QueryRequest) -> bool {
+    !req.detail
+        && req.facts.is_empty()
+        && req.dimension_filters.is_empty()
+        && req.metric_filters.is_empty()
+}
+
 /// Attempt to route a query to a materialization table.
 ///
 /// Returns `Some(sql)` selecting from the pre-aggregated table when an
@@ -85,19 +108,18 @@ pub(crate) fn try_route_materialization(
     def: &SemanticViewDefinition,
     resolved_dims: &[&Dimension],
     resolved_mets: &[&Metric],
+    aliases: &OutputAliases,
 ) -> Option<String> {
     find_matching_materialization(def, resolved_dims, resolved_mets)
-        .map(|mat| build_materialized_sql(&mat.table, def, resolved_dims, resolved_mets))
+        .map(|mat| build_materialized_sql(&mat.table, def, resolved_dims, resolved_mets, aliases))
 }
 
 /// Name of the materialization that would be selected for routing, or `None`.
 ///
 /// Used by `explain_semantic_view` to report the routing decision; delegates to
 /// the shared [`find_matching_materialization`] so it cannot drift from the
-/// routing [`try_route_materialization`] actually performs.
-// Used only under the `extension` feature (explain.rs); scope the allow to the
-// default build so genuine dead code is still caught under `extension` (ST-8).
-#[cfg_attr(not(feature = "extension"), allow(dead_code))]
+/// routing [`try_route_materialization`] actually performs; also read by
+/// `expand_with_diagnostics`.
 pub(crate) fn find_routing_materialization_name<'a>(
     def: &'a SemanticViewDefinition,
     resolved_dims: &[&Dimension],
@@ -110,32 +132,29 @@ pub(crate) fn find_routing_materialization_name<'a>(
 ///
 /// The materialization table is expected to have columns named after the
 /// dimension and metric names. The SQL simply selects them by name,
-/// applying `output_type` casts when declared.
+/// applying `output_type` casts when declared and renaming aliased columns.
 fn build_materialized_sql(
     table: &str,
     def: &SemanticViewDefinition,
     dims: &[&Dimension],
     mets: &[&Metric],
+    aliases: &OutputAliases,
 ) -> String {
-    let mut items: Vec<String> = Vec::with_capacity(dims.len() + mets.len());
-
-    for dim in dims {
-        let col = quote_stored_ident(&dim.name);
-        if let Some(ref type_str) = dim.output_type {
-            items.push(format!("    CAST({col} AS {type_str}) AS {col}"));
-        } else {
-            items.push(format!("    {col}"));
-        }
-    }
-
-    for met in mets {
-        let col = quote_stored_ident(&met.name);
-        if let Some(ref type_str) = met.output_type {
-            items.push(format!("    CAST({col} AS {type_str}) AS {col}"));
-        } else {
-            items.push(format!("    {col}"));
-        }
-    }
+    let columns = dims
+        .iter()
+        .map(|d| (&d.name, &d.output_type))
+        .chain(mets.iter().map(|m| (&m.name, &m.output_type)));
+    let items: Vec<String> = columns
+        .map(|(name, output_type)| {
+            let col = quote_stored_ident(name);
+            let alias = aliases.column(name);
+            match output_type {
+                Some(type_str) => format!("    CAST({col} AS {type_str}) AS {alias}"),
+                None if alias == col => format!("    {col}"),
+                None => format!("    {col} AS {alias}"),
+            }
+        })
+        .collect();
 
     let mut sql = String::with_capacity(128);
     if mets.is_empty() {
@@ -157,6 +176,7 @@ fn build_materialized_sql(
 mod tests {
     use super::*;
     use crate::expand::test_helpers::{orders_view, TestFixtureExt};
+    use crate::expand::DimFilterOp;
     use crate::model::{NullsOrder, SortOrder, WindowSpec};
 
     // Helper: create a QueryRequest-like set of resolved dims/mets from a def
@@ -194,7 +214,7 @@ mod tests {
         assert!(def.materializations.is_empty());
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
-        assert!(try_route_materialization(&def, &dims, &mets).is_none());
+        assert!(try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none());
     }
 
     // ================================================
@@ -211,7 +231,7 @@ mod tests {
         );
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue", "order_count"]);
-        let sql = try_route_materialization(&def, &dims, &mets);
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default());
         assert!(sql.is_some(), "should match exact dims+mets");
         let sql = sql.unwrap();
         assert!(
@@ -243,7 +263,7 @@ mod tests {
         );
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue", "order_count"]);
-        let sql = try_route_materialization(&def, &dims, &mets);
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default());
         assert!(sql.is_some(), "case-insensitive matching should work");
     }
 
@@ -262,7 +282,7 @@ mod tests {
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue", "order_count"]);
         assert!(
-            try_route_materialization(&def, &dims, &mets).is_some(),
+            try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_some(),
             "quoted declared materialization names should match unquoted stored names"
         );
     }
@@ -279,7 +299,7 @@ mod tests {
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
         assert!(
-            try_route_materialization(&def, &dims, &mets).is_none(),
+            try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none(),
             "superset dims in mat should not match"
         );
     }
@@ -296,7 +316,7 @@ mod tests {
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
         assert!(
-            try_route_materialization(&def, &dims, &mets).is_none(),
+            try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none(),
             "superset mets in mat should not match"
         );
     }
@@ -313,7 +333,7 @@ mod tests {
         let dims = resolve_dims(&def, &["region", "status"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
         assert!(
-            try_route_materialization(&def, &dims, &mets).is_none(),
+            try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none(),
             "subset dims in mat should not match"
         );
     }
@@ -333,7 +353,7 @@ mod tests {
         // Request different dims+mets than any materialization covers
         let dims = resolve_dims(&def, &["status"]);
         let mets = resolve_mets(&def, &["order_count"]);
-        assert!(try_route_materialization(&def, &dims, &mets).is_none());
+        assert!(try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none());
     }
 
     // ================================================
@@ -356,7 +376,7 @@ mod tests {
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue", "order_count"]);
         assert!(
-            try_route_materialization(&def, &dims, &mets).is_none(),
+            try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none(),
             "semi-additive metrics should exclude routing"
         );
     }
@@ -385,7 +405,7 @@ mod tests {
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue", "order_count"]);
         assert!(
-            try_route_materialization(&def, &dims, &mets).is_none(),
+            try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none(),
             "window metrics should exclude routing"
         );
     }
@@ -405,7 +425,7 @@ mod tests {
         def.dimensions[0].output_type = Some("VARCHAR(50)".to_string());
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
-        let sql = try_route_materialization(&def, &dims, &mets).unwrap();
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).unwrap();
         assert!(
             sql.contains("CAST(\"region\" AS VARCHAR(50)) AS \"region\""),
             "should cast dimension with output_type: {sql}"
@@ -423,7 +443,7 @@ mod tests {
         def.metrics[0].output_type = Some("DOUBLE".to_string());
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
-        let sql = try_route_materialization(&def, &dims, &mets).unwrap();
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).unwrap();
         assert!(
             sql.contains("CAST(\"total_revenue\" AS DOUBLE) AS \"total_revenue\""),
             "should cast metric with output_type: {sql}"
@@ -444,7 +464,7 @@ mod tests {
         );
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
-        let sql = try_route_materialization(&def, &dims, &mets).unwrap();
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).unwrap();
         assert!(
             sql.contains("\"catalog\".\"schema\".\"agg_table\""),
             "multi-part table name should be quoted: {sql}"
@@ -467,7 +487,7 @@ mod tests {
             );
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
-        let sql = try_route_materialization(&def, &dims, &mets).unwrap();
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).unwrap();
         assert!(
             sql.contains("\"first_table\""),
             "first match should win: {sql}"
@@ -493,7 +513,7 @@ mod tests {
         let dims = resolve_dims(&def, &["region"]);
         let mets: Vec<&Metric> = vec![];
         assert!(
-            try_route_materialization(&def, &dims, &mets).is_none(),
+            try_route_materialization(&def, &dims, &mets, &OutputAliases::default()).is_none(),
             "dims-only query should not match mat that has metrics"
         );
     }
@@ -508,7 +528,7 @@ mod tests {
             orders_view().with_materialization("region_list", "region_table", &["region"], &[]);
         let dims = resolve_dims(&def, &["region"]);
         let mets: Vec<&Metric> = vec![];
-        let sql = try_route_materialization(&def, &dims, &mets)
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default())
             .expect("dims-only mat should match dims-only query");
         assert!(
             sql.starts_with("SELECT DISTINCT"),
@@ -527,7 +547,8 @@ mod tests {
         );
         let dims = resolve_dims(&def, &["region"]);
         let mets = resolve_mets(&def, &["total_revenue"]);
-        let sql = try_route_materialization(&def, &dims, &mets).expect("exact match should route");
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default())
+            .expect("exact match should route");
         assert!(
             sql.starts_with("SELECT\n"),
             "routed SQL with metrics must not apply DISTINCT: {sql}"
@@ -548,7 +569,7 @@ mod tests {
         );
         let dims: Vec<&Dimension> = vec![];
         let mets = resolve_mets(&def, &["total_revenue"]);
-        let sql = try_route_materialization(&def, &dims, &mets);
+        let sql = try_route_materialization(&def, &dims, &mets, &OutputAliases::default());
         assert!(
             sql.is_some(),
             "metrics-only mat should match metrics-only query"
@@ -578,7 +599,7 @@ mod tests {
         let req = QueryRequest {
             dimensions: vec![DimensionName::new("region")],
             metrics: vec![MetricName::new("total_revenue")],
-            facts: vec![],
+            ..Default::default()
         };
         let sql = expand("test_view", &def, &req).unwrap();
         assert!(
@@ -591,6 +612,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn only_unfiltered_aggregate_requests_may_route() {
+        let plain = QueryRequest::builder()
+            .dimensions(["region"])
+            .metrics(["total_revenue"]);
+        assert!(request_may_route(&plain.clone().build()));
+        for req in [
+            plain.clone().detail(true).build(),
+            plain.clone().facts(["amount"]).build(),
+            plain
+                .clone()
+                .filter("region", DimFilterOp::Eq, ["EU"])
+                .build(),
+            plain.having("total_revenue", DimFilterOp::Gt, [0]).build(),
+        ] {
+            assert!(!request_may_route(&req), "{req:?}");
+        }
+    }
+
     // ================================================
     // End-to-end via expand() -- no match, standard expansion
     // ================================================
@@ -696,7 +736,7 @@ mod tests {
         let req = QueryRequest {
             dimensions: vec![DimensionName::new("region"), DimensionName::new("status")],
             metrics: vec![MetricName::new("total_revenue")],
-            facts: vec![],
+            ..Default::default()
         };
         let sql = expand("test_view", &def, &req).unwrap();
         assert!(
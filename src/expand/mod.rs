@@ -1,7 +1,14 @@
+mod date_range;
+mod diagnostics;
+mod dim_filter;
 mod facts;
 mod fan_trap;
 mod join_resolver;
 mod materialization;
+mod output_alias;
+mod plan;
+mod qualify;
+mod repro;
 mod resolution;
 mod role_playing;
 mod select_spec;
@@ -21,12 +28,32 @@ mod test_helpers;
 // Behaviour-named expansion test modules, extracted from sql_gen.rs's monolithic
 // phase-named `mod tests` (§6.2 move 6, code-review 2026-07-11).
 #[cfg(test)]
+mod tests_approx_count_distinct;
+#[cfg(test)]
+mod tests_base_sql;
+#[cfg(test)]
+mod tests_bucket;
+#[cfg(test)]
 mod tests_cast;
 #[cfg(test)]
+mod tests_coalesce;
+#[cfg(test)]
+mod tests_collate;
+#[cfg(test)]
+mod tests_compact;
+#[cfg(test)]
 mod tests_count_star_rewrite;
 #[cfg(test)]
+mod tests_defaults;
+#[cfg(test)]
 mod tests_derived_metric;
 #[cfg(test)]
+mod tests_detail_mode;
+#[cfg(test)]
+mod tests_dimension_filter;
+#[cfg(test)]
+mod tests_distinct_metric;
+#[cfg(test)]
 mod tests_expand;
 #[cfg(test)]
 mod tests_expand_basic;
@@ -41,8 +68,16 @@ mod tests_facts_path_role_playing;
 #[cfg(test)]
 mod tests_fan_trap;
 #[cfg(test)]
+mod tests_group_by_mode;
+#[cfg(test)]
+mod tests_having;
+#[cfg(test)]
 mod tests_join_emission_regression;
 #[cfg(test)]
+mod tests_metric_filter;
+#[cfg(test)]
+mod tests_output_aliases;
+#[cfg(test)]
 mod tests_pkfk_expand;
 #[cfg(test)]
 mod tests_private_access;
@@ -51,19 +86,40 @@ mod tests_qualified_name_resolution;
 #[cfg(test)]
 mod tests_qualified_refs;
 #[cfg(test)]
+mod tests_qualify_bare_columns;
+#[cfg(test)]
+mod tests_request_order;
+#[cfg(test)]
 mod tests_role_playing;
+#[cfg(test)]
+mod tests_row_security;
+#[cfg(test)]
+mod tests_sql_header;
+#[cfg(test)]
+mod tests_sql_size_limit;
 
 // Public API (the pre-split expand.rs surface, plus the boxed fan-trap detail
 // structs re-exported for R-9).
+pub use diagnostics::{expand_with_diagnostics, ExpandOutput};
+pub use plan::{plan, ExpandPlan, PlannedJoin};
+pub use repro::minimal_definition;
 pub use resolution::{quote_ident, quote_ident_if_needed, quote_stored_ident, quote_table_ref};
-pub use sql_gen::expand;
+pub use sql_gen::{
+    expand, expand_compact, expand_with_header, expand_with_limit, DEFAULT_MAX_EXPANDED_SQL_BYTES,
+};
 pub use types::{
-    DimensionName, ExpandError, FactName, FanTrapError, MetricFanTrapError, MetricName,
-    QueryRequest,
+    DimFilterOp, DimensionFilter, DimensionName, ExpandError, FactName, FanTrapError, GroupByMode,
+    MetricFanTrapError, MetricFilter, MetricName, QueryRequest, QueryRequestBuilder,
+    MAX_DISPLAYED_AVAILABLE,
 };
 
+// Crate-internal API (render_dot labels relationship edges with the ON clause)
+pub(crate) use join_resolver::synthesize_on_clause;
+
 // Crate-internal API (used by ddl/show_dims_for_metric.rs under extension feature)
 #[cfg(feature = "extension")]
 pub(crate) use facts::collect_derived_metric_source_tables;
 #[cfg(feature = "extension")]
 pub(crate) use materialization::find_routing_materialization_name;
+#[cfg(feature = "extension")]
+pub(crate) use types::display_available;
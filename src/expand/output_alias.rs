@@ -0,0 +1,92 @@
+//! Per-request output column names (`QueryRequest::output_aliases`).
+//!
+//! Only the outermost `AS` changes: CTE columns and the materialization
+//! tables a query may be routed to keep the definition names, so the rest of
+//! the expansion never sees an alias.
+
+use std::collections::HashMap;
+
+use super::resolution::{quote_ident, quote_stored_ident};
+use super::types::ExpandError;
+use crate::ident::normalize_ident_part;
+
+/// The checked aliases of one request, keyed by canonical item name.
+#[derive(Debug, Default)]
+pub(super) struct OutputAliases(HashMap<String, String>);
+
+impl OutputAliases {
+    /// Match `aliases` against the `selected` item names (stored spelling)
+    /// and check that every output column name stays unique.
+    ///
+    /// Output names compare case-insensitively, as `DuckDB` column names do.
+    /// Two definition names that already collide are left to the existing
+    /// checks; only a collision involving an alias is reported here.
+    pub(super) fn resolve<'a>(
+        view_name: &str,
+        aliases: &HashMap<String, String>,
+        selected: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, ExpandError> {
+        let invalid = |name: &str, reason: String| ExpandError::InvalidOutputAlias {
+            view_name: view_name.to_string(),
+            name: name.to_string(),
+            reason,
+        };
+
+        // Sorted, so which of two offending entries is reported is stable.
+        let mut entries: Vec<(&String, &String)> = aliases.iter().collect();
+        entries.sort();
+        let mut pending: HashMap<String, (&str, &str)> = HashMap::with_capacity(entries.len());
+        for (name, alias) in entries {
+            if alias.trim().is_empty() {
+                return Err(invalid(name, "the alias is empty".to_string()));
+            }
+            if pending
+                .insert(normalize_ident_part(name), (name, alias))
+                .is_some()
+            {
+                return Err(invalid(name, "it is aliased more than once".to_string()));
+            }
+        }
+
+        let mut chosen = HashMap::with_capacity(pending.len());
+        // Folded output name -> (item name, whether it is an alias).
+        let mut outputs: HashMap<String, (&str, bool)> = HashMap::new();
+        for name in selected {
+            let key = normalize_ident_part(name);
+            let (output, is_alias) = match pending.remove(&key) {
+                Some((_, alias)) => {
+                    chosen.insert(key, alias.to_string());
+                    (alias.to_lowercase(), true)
+                }
+                None => (key, false),
+            };
+            if let Some((other, other_is_alias)) = outputs.get(&output) {
+                if is_alias || *other_is_alias {
+                    return Err(invalid(
+                        name,
+                        format!("its output column name is also used by '{other}'"),
+                    ));
+                }
+            } else {
+                outputs.insert(output, (name, is_alias));
+            }
+        }
+
+        let mut unmatched: Vec<&str> = pending.into_values().map(|(name, _)| name).collect();
+        unmatched.sort_unstable();
+        if let Some(name) = unmatched.first() {
+            return Err(invalid(
+                name,
+                "it is not a requested dimension, metric, or fact".to_string(),
+            ));
+        }
+        Ok(Self(chosen))
+    }
+
+    /// The quoted output column name for the item stored as `name`.
+    pub(super) fn column(&self, name: &str) -> String {
+        self.0
+            .get(&normalize_ident_part(name))
+            .map_or_else(|| quote_stored_ident(name), |alias| quote_ident(alias))
+    }
+}
@@ -0,0 +1,188 @@
+//! Query plans: which items and joins a request resolves to, without SQL.
+
+use crate::model::{Dimension, Fact, Metric, SemanticViewDefinition};
+
+use super::dim_filter::{resolve_dimension_filters, resolve_metric_filters};
+use super::join_resolver::{resolve_joins_pkfk, synthesize_on_clause, synthesize_on_clause_scoped};
+use super::sql_gen::resolve_names;
+use super::types::{ExpandError, QueryRequest};
+
+/// One `LEFT JOIN` the expanded query emits, in emission order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedJoin {
+    /// Alias after `AS`: the table alias (lowercased), or a role-playing
+    /// scoped alias `{alias}__{relationship}`.
+    pub alias: String,
+    /// Physical table from the `TABLES` entry.
+    pub table: String,
+    /// Index of the relationship in `def.joins`.
+    pub join_index: usize,
+    /// The relationship's name, if it has one.
+    pub relationship: Option<String>,
+    /// The synthesized `ON` condition.
+    pub on: String,
+}
+
+/// The resolved shape of a [`QueryRequest`]: the definitions it selects and
+/// the joins expansion would emit for them.
+#[derive(Debug, Clone)]
+pub struct ExpandPlan<'a> {
+    /// Requested dimensions, in request order.
+    pub dimensions: Vec<&'a Dimension>,
+    /// Requested metrics, in request order.
+    pub metrics: Vec<&'a Metric>,
+    /// Requested facts, in request order.
+    pub facts: Vec<&'a Fact>,
+    /// Joins from the base table, in emission order. Dimensions and metrics
+    /// that are only filtered on count, as they do in [`super::expand`].
+    pub joins: Vec<PlannedJoin>,
+}
+
+/// Resolve `req` against `def` into an [`ExpandPlan`].
+///
+/// Names resolve with the same rules and errors as [`super::expand`], and
+/// joins come from the same resolver, so for a request `expand()` accepts the
+/// plan's joins are the ones its base query emits. Checks that only apply
+/// while generating SQL — fan traps, role-playing ambiguity, `COUNT(*)`
+/// without a key — are not run, and a request routed to a materialization
+/// still reports its base-table joins.
+///
+/// # Errors
+///
+/// Returns `ExpandError` when the request is empty, mixes facts and metrics,
+/// or names an unknown, duplicate, or private item.
+pub fn plan<'a>(
+    view_name: &str,
+    def: &'a SemanticViewDefinition,
+    req: &QueryRequest,
+) -> Result<ExpandPlan<'a>, ExpandError> {
+    let req = &*req.or_view_defaults(def);
+    if !req.facts.is_empty() && !req.metrics.is_empty() {
+        return Err(ExpandError::FactsMetricsMutualExclusion {
+            view_name: view_name.to_string(),
+        });
+    }
+    if req.dimensions.is_empty() && req.metrics.is_empty() && req.facts.is_empty() {
+        return Err(ExpandError::EmptyRequest {
+            view_name: view_name.to_string(),
+        });
+    }
+    let dimensions = resolve_names::<Dimension, _>(&req.dimensions, view_name, def)?;
+    let metrics = resolve_names::<Metric, _>(&req.metrics, view_name, def)?;
+    let facts = resolve_names::<Fact, _>(&req.facts, view_name, def)?;
+    let filters = resolve_dimension_filters(view_name, def, &req.dimension_filters)?;
+
+    let join_dims: Vec<&Dimension> = dimensions
+        .iter()
+        .copied()
+        .chain(filters.iter().map(|f| f.dim))
+        .collect();
+    let join_mets: Vec<&Metric> = metrics
+        .iter()
+        .copied()
+        .chain(
+            resolve_metric_filters(view_name, def, &req.metric_filters)?
+                .iter()
+                .map(|f| f.met),
+        )
+        .collect();
+    let fact_sources: Vec<String> = facts
+        .iter()
+        .filter_map(|f| f.source_table.clone())
+        .collect();
+    let joins = resolve_joins_pkfk(def, &join_dims, &join_mets, &fact_sources)
+        .into_iter()
+        .map(|rj| PlannedJoin {
+            table: def
+                .tables
+                .iter()
+                .find(|t| t.alias.eq_ignore_ascii_case(&rj.bare_alias))
+                .map_or_else(|| rj.bare_alias.clone(), |t| t.table.clone()),
+            join_index: def
+                .joins
+                .iter()
+                .position(|j| std::ptr::eq(j, rj.join))
+                .unwrap_or_default(),
+            relationship: rj.join.name.clone(),
+            on: if rj.scoped {
+                synthesize_on_clause_scoped(rj.join, &def.tables, &rj.emit_alias)
+            } else {
+                synthesize_on_clause(rj.join, &def.tables)
+            },
+            alias: rj.emit_alias,
+        })
+        .collect();
+
+    Ok(ExpandPlan {
+        dimensions,
+        metrics,
+        facts,
+        joins,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::plan;
+    use crate::expand::test_helpers::TestFixtureExt;
+    use crate::expand::{expand, DimFilterOp, ExpandError, QueryRequest};
+    use crate::model::SemanticViewDefinition;
+
+    fn def() -> SemanticViewDefinition {
+        SemanticViewDefinition::default()
+            .with_table("o", "orders", &["id"])
+            .with_table("c", "customers", &["id"])
+            .with_table("r", "regions", &["id"])
+            .with_dimension("customer_name", "c.name", Some("c"))
+            .with_dimension("region", "r.name", Some("r"))
+            .with_metric("revenue", "sum(o.amount)", Some("o"))
+            .with_pkfk_join("o_to_c", "o", "c", &["customer_id"], &["id"])
+            .with_pkfk_join("c_to_r", "c", "r", &["region_id"], &["id"])
+    }
+
+    #[test]
+    fn plan_lists_joins_on_the_path_in_emission_order() {
+        let def = def();
+        let req = QueryRequest::builder()
+            .dimension("REGION")
+            .metric("revenue")
+            .build();
+        let p = plan("v", &def, &req).unwrap();
+        assert_eq!(p.dimensions[0].name, "region");
+        assert_eq!(p.metrics[0].name, "revenue");
+        let aliases: Vec<&str> = p.joins.iter().map(|j| j.alias.as_str()).collect();
+        assert_eq!(aliases, ["c", "r"]);
+        assert_eq!(p.joins[1].table, "regions");
+        assert_eq!(p.joins[1].join_index, 1);
+        assert_eq!(p.joins[1].relationship.as_deref(), Some("c_to_r"));
+        assert_eq!(p.joins[1].on, "\"c\".\"region_id\" = \"r\".\"id\"");
+
+        // The same joins appear in the expanded SQL.
+        let sql = expand("v", &def, &req).unwrap();
+        for j in &p.joins {
+            assert!(sql.contains(&j.on), "{sql}");
+        }
+    }
+
+    #[test]
+    fn filtered_dimension_joins_and_errors_match_expand() {
+        let def = def();
+        let req = QueryRequest::builder()
+            .metric("revenue")
+            .filter("customer_name", DimFilterOp::Eq, ["Ada"])
+            .build();
+        let p = plan("v", &def, &req).unwrap();
+        assert!(p.dimensions.is_empty());
+        assert_eq!(p.joins.len(), 1);
+
+        let req = QueryRequest::builder().dimension("nope").build();
+        assert!(matches!(
+            plan("v", &def, &req),
+            Err(ExpandError::UnknownDimension { .. })
+        ));
+        assert!(matches!(
+            plan("v", &def, &QueryRequest::builder().build()),
+            Err(ExpandError::EmptyRequest { .. })
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+//! Opt-in qualification of bare column references
+//! ([`QueryRequest::qualify_bare_columns`](super::QueryRequest::qualify_bare_columns)).
+//!
+//! A dimension declared as `expr: region` reads fine against a single table,
+//! but once a joined table also has a `region` column `DuckDB` rejects the
+//! reference as ambiguous. With the flag set, an expression that is nothing
+//! but one unqualified column gets its table's alias prefixed — the item's
+//! `source_table`, or the base table when it has none. Anything more complex
+//! is left alone: there is no safe way to tell which parts of `upper(region)`
+//! or `a + b` are columns without a SQL parser.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use super::resolution::quote_ident;
+use crate::expr_tokens::bare_column;
+use crate::ident::normalize_ident_part;
+use crate::model::SemanticViewDefinition;
+
+/// `def` with bare-column dimension and metric expressions qualified, or `def`
+/// itself when it declares no relationships (nothing can be joined in, so
+/// nothing is ambiguous) or no expression needs it.
+///
+/// In a metric expression a bare word naming a metric or fact of the view is
+/// a reference that expansion inlines (a derived metric's `revenue`), not a
+/// column, and is never qualified.
+pub(super) fn qualify_bare_columns(
+    def: &SemanticViewDefinition,
+) -> Cow<'_, SemanticViewDefinition> {
+    let Some(base) = def.tables.first() else {
+        return Cow::Borrowed(def);
+    };
+    if def.joins.is_empty() {
+        return Cow::Borrowed(def);
+    }
+    let inlined_names: HashSet<String> = def
+        .metrics
+        .iter()
+        .map(|m| &m.name)
+        .chain(def.facts.iter().map(|f| &f.name))
+        .map(|n| normalize_ident_part(n))
+        .collect();
+    let qualify = |column: &str, source_table: Option<&String>| {
+        let alias = source_table.unwrap_or(&base.alias);
+        format!("{}.{column}", quote_ident(alias))
+    };
+
+    let mut out: Option<SemanticViewDefinition> = None;
+    for (i, dim) in def.dimensions.iter().enumerate() {
+        if let Some(column) = bare_column(&dim.expr) {
+            out.get_or_insert_with(|| def.clone()).dimensions[i].expr =
+                qualify(column, dim.source_table.as_ref());
+        }
+    }
+    for (i, met) in def.metrics.iter().enumerate() {
+        if let Some(column) =
+            bare_column(&met.expr).filter(|c| !inlined_names.contains(&normalize_ident_part(c)))
+        {
+            out.get_or_insert_with(|| def.clone()).metrics[i].expr =
+                qualify(column, met.source_table.as_ref());
+        }
+    }
+    out.map_or(Cow::Borrowed(def), Cow::Owned)
+}
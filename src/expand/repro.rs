@@ -0,0 +1,365 @@
+//! Minimal reproduction definitions: trim a [`SemanticViewDefinition`] to the
+//! items a single query actually touches, for bug reports and sharing.
+
+use std::collections::HashSet;
+
+use crate::expr_tokens::references_ref;
+use crate::ident::{ident_matches, normalize_ident_part};
+use crate::model::{Dimension, Fact, Metric, SemanticViewDefinition};
+
+use super::dim_filter::{resolve_dimension_filters, resolve_metric_filters};
+use super::facts::collect_transitive_metric_names;
+use super::join_resolver::resolve_joins_pkfk;
+use super::resolution::find_dimension;
+use super::sql_gen::resolve_names;
+use super::types::{ExpandError, QueryRequest};
+
+/// Build the smallest definition that still expands `req` the way `def` does.
+///
+/// Keeps the requested dimensions, metrics, and facts plus everything they
+/// depend on:
+///
+/// - metrics the request filters on (`metric_filters`), and those reached
+///   transitively through derived-metric references and window inner
+///   metrics;
+/// - dimensions the request filters on (`dimension_filters`);
+/// - dimensions named by a kept metric's `NON ADDITIVE BY` or window
+///   `PARTITION BY` / `ORDER BY` clauses (CREATE rejects dangling ones);
+/// - facts referenced, transitively, by kept metric or fact expressions;
+/// - the base table, every table on the join paths `resolve_joins_pkfk`
+///   selects for the kept items, and the relationships between kept tables;
+/// - materializations whose dimensions and metrics were all kept (routing
+///   can change the expanded SQL, so a covering one must survive).
+///
+/// Declaration order is preserved throughout, so the trimmed definition
+/// renders and expands deterministically. Requested names are resolved with
+/// the same rules (and errors) as [`super::expand`], but the query itself is
+/// not expanded — a request that fails later (fan trap, role-playing
+/// ambiguity) still yields a definition that reproduces the failure.
+///
+/// # Errors
+///
+/// Returns `ExpandError` when the request is empty or names an unknown,
+/// duplicate, or private item.
+#[allow(clippy::too_many_lines)]
+pub fn minimal_definition(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    req: &QueryRequest,
+) -> Result<SemanticViewDefinition, ExpandError> {
+    let req = &*req.or_view_defaults(def);
+    if req.dimensions.is_empty() && req.metrics.is_empty() && req.facts.is_empty() {
+        return Err(ExpandError::EmptyRequest {
+            view_name: view_name.to_string(),
+        });
+    }
+    let req_dims = resolve_names::<Dimension, _>(&req.dimensions, view_name, def)?;
+    let req_mets = resolve_names::<Metric, _>(&req.metrics, view_name, def)?;
+    let req_facts = resolve_names::<Fact, _>(&req.facts, view_name, def)?;
+
+    // Metrics: requested + transitive dependencies (canonical keys).
+    let mut metric_keys: HashSet<String> = HashSet::new();
+    for met in &req_mets {
+        metric_keys.extend(collect_transitive_metric_names(met, &def.metrics));
+    }
+    for filter in resolve_metric_filters(view_name, def, &req.metric_filters)? {
+        metric_keys.extend(collect_transitive_metric_names(filter.met, &def.metrics));
+    }
+    let metrics: Vec<&Metric> = def
+        .metrics
+        .iter()
+        .filter(|m| metric_keys.contains(&normalize_ident_part(&m.name)))
+        .collect();
+
+    // Dimensions: requested + filtered on + those referenced by kept
+    // metrics' specs.
+    let mut dim_keys: HashSet<String> = req_dims
+        .iter()
+        .map(|d| normalize_ident_part(&d.name))
+        .collect();
+    for filter in resolve_dimension_filters(view_name, def, &req.dimension_filters)? {
+        dim_keys.insert(normalize_ident_part(&filter.dim.name));
+    }
+    for met in &metrics {
+        let mut refs: Vec<&str> = met
+            .non_additive_by
+            .iter()
+            .map(|na| na.dimension.as_str())
+            .collect();
+        if let Some(ref ws) = met.window_spec {
+            refs.extend(ws.excluding_dims.iter().map(String::as_str));
+            refs.extend(ws.partition_dims.iter().map(String::as_str));
+            refs.extend(ws.order_by.iter().map(|ob| ob.expr.as_str()));
+        }
+        for r in refs {
+            if let Some(d) = find_dimension(def, r) {
+                dim_keys.insert(normalize_ident_part(&d.name));
+            }
+        }
+    }
+    let dimensions: Vec<&Dimension> = def
+        .dimensions
+        .iter()
+        .filter(|d| dim_keys.contains(&normalize_ident_part(&d.name)))
+        .collect();
+
+    // Facts: requested + referenced by kept metrics/facts, to a fixpoint.
+    let mut fact_keys: HashSet<String> = req_facts
+        .iter()
+        .map(|f| normalize_ident_part(&f.name))
+        .collect();
+    loop {
+        let before = fact_keys.len();
+        for fact in &def.facts {
+            let key = normalize_ident_part(&fact.name);
+            if fact_keys.contains(&key) {
+                continue;
+            }
+            let src = fact.source_table.as_deref();
+            let referenced = metrics
+                .iter()
+                .any(|m| references_ref(&m.expr, &fact.name, src))
+                || def.facts.iter().any(|f| {
+                    fact_keys.contains(&normalize_ident_part(&f.name))
+                        && references_ref(&f.expr, &fact.name, src)
+                });
+            if referenced {
+                fact_keys.insert(key);
+            }
+        }
+        if fact_keys.len() == before {
+            break;
+        }
+    }
+    let facts: Vec<&Fact> = def
+        .facts
+        .iter()
+        .filter(|f| fact_keys.contains(&normalize_ident_part(&f.name)))
+        .collect();
+
+    // Tables: base + item sources + join-path intermediaries.
+    let mut table_keys: HashSet<String> = HashSet::new();
+    if let Some(base) = def.tables.first() {
+        table_keys.insert(base.alias.to_ascii_lowercase());
+    }
+    let sources = dimensions
+        .iter()
+        .filter_map(|d| d.source_table.as_deref())
+        .chain(metrics.iter().filter_map(|m| m.source_table.as_deref()))
+        .chain(facts.iter().filter_map(|f| f.source_table.as_deref()));
+    for src in sources {
+        table_keys.insert(src.to_ascii_lowercase());
+    }
+    let fact_sources: Vec<String> = facts
+        .iter()
+        .filter_map(|f| f.source_table.clone())
+        .collect();
+    for rj in resolve_joins_pkfk(def, &dimensions, &metrics, &fact_sources) {
+        table_keys.insert(rj.bare_alias.clone());
+        table_keys.insert(rj.join.from_alias.to_ascii_lowercase());
+    }
+
+    // Relationships: every edge between kept tables, plus any relationship a
+    // kept metric names in USING (its target is then kept too).
+    let using: Vec<&str> = metrics
+        .iter()
+        .flat_map(|m| m.using_relationships.iter().map(String::as_str))
+        .collect();
+    for join in &def.joins {
+        let named = join
+            .name
+            .as_deref()
+            .is_some_and(|n| using.iter().any(|u| ident_matches(n, u)));
+        if named {
+            table_keys.insert(join.table.to_ascii_lowercase());
+            table_keys.insert(join.from_alias.to_ascii_lowercase());
+        }
+    }
+    let joins = def
+        .joins
+        .iter()
+        .filter(|j| {
+            table_keys.contains(&j.table.to_ascii_lowercase())
+                && table_keys.contains(&j.from_alias.to_ascii_lowercase())
+        })
+        .cloned()
+        .collect();
+
+    let materializations = def
+        .materializations
+        .iter()
+        .filter(|mat| {
+            mat.dimensions
+                .iter()
+                .all(|n| dimensions.iter().any(|d| ident_matches(&d.name, n)))
+                && mat
+                    .metrics
+                    .iter()
+                    .all(|n| metrics.iter().any(|m| ident_matches(&m.name, n)))
+        })
+        .cloned()
+        .collect();
+
+    Ok(SemanticViewDefinition {
+        tables: def
+            .tables
+            .iter()
+            .filter(|t| table_keys.contains(&t.alias.to_ascii_lowercase()))
+            .cloned()
+            .collect(),
+        default_dimensions: def
+            .default_dimensions
+            .iter()
+            .filter(|n| dimensions.iter().any(|d| ident_matches(&d.name, n)))
+            .cloned()
+            .collect(),
+        default_metrics: def
+            .default_metrics
+            .iter()
+            .filter(|n| metrics.iter().any(|m| ident_matches(&m.name, n)))
+            .cloned()
+            .collect(),
+        time_dimension: def
+            .time_dimension
+            .clone()
+            .filter(|n| dimensions.iter().any(|d| ident_matches(&d.name, n))),
+        dimensions: dimensions.into_iter().cloned().collect(),
+        metrics: metrics.into_iter().cloned().collect(),
+        joins,
+        facts: facts.into_iter().cloned().collect(),
+        materializations,
+        ..def.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::minimal_definition;
+    use crate::expand::test_helpers::{orders_view, TestFixtureExt};
+    use crate::expand::{expand, DimensionName, ExpandError, FactName, MetricName, QueryRequest};
+
+    /// orders -> customers -> regions, plus an unrelated orders -> products
+    /// branch, a derived metric, and a fact feeding one base metric.
+    fn star() -> crate::model::SemanticViewDefinition {
+        orders_view()
+            .clear_dimensions()
+            .clear_metrics()
+            .with_table("c", "customers", &["id"])
+            .with_table("r", "regions", &["id"])
+            .with_table("p", "products", &["id"])
+            .with_pkfk_join("orders_customer", "orders", "c", &["customer_id"], &["id"])
+            .with_pkfk_join("customer_region", "c", "r", &["region_id"], &["id"])
+            .with_pkfk_join("orders_product", "orders", "p", &["product_id"], &["id"])
+            .with_dimension("region_name", "r.name", Some("r"))
+            .with_dimension("category", "p.category", Some("p"))
+            .with_dimension("status", "orders.status", Some("orders"))
+            .with_fact("net", "orders.amount - orders.discount", "orders")
+            .with_metric("revenue", "SUM(net)", Some("orders"))
+            .with_metric("order_count", "COUNT(orders.id)", Some("orders"))
+            .with_metric("avg_order", "revenue / order_count", None)
+            .with_metric("product_count", "COUNT(p.id)", Some("p"))
+            .with_materialization("by_category", "agg_cat", &["category"], &["product_count"])
+    }
+
+    #[test]
+    fn strips_unused_items_and_joins() {
+        let def = star();
+        let req = QueryRequest {
+            dimensions: vec![DimensionName::new("region_name")],
+            metrics: vec![MetricName::new("avg_order")],
+            ..Default::default()
+        };
+        let min = minimal_definition("v", &def, &req).unwrap();
+
+        assert_eq!(
+            min.dimensions
+                .iter()
+                .map(|d| d.name.as_str())
+                .collect::<Vec<_>>(),
+            ["region_name"]
+        );
+        // Derived metric keeps both operands, in declaration order.
+        assert_eq!(
+            min.metrics
+                .iter()
+                .map(|m| m.name.as_str())
+                .collect::<Vec<_>>(),
+            ["revenue", "order_count", "avg_order"]
+        );
+        // The fact inlined into `revenue` survives.
+        assert_eq!(
+            min.facts
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>(),
+            ["net"]
+        );
+        // Path orders -> c -> r kept; the products branch is dropped.
+        assert_eq!(
+            min.tables
+                .iter()
+                .map(|t| t.alias.as_str())
+                .collect::<Vec<_>>(),
+            ["orders", "c", "r"]
+        );
+        assert_eq!(
+            min.joins
+                .iter()
+                .filter_map(|j| j.name.as_deref())
+                .collect::<Vec<_>>(),
+            ["orders_customer", "customer_region"]
+        );
+        assert!(min.materializations.is_empty());
+
+        // The trimmed definition expands to exactly the same SQL.
+        assert_eq!(
+            expand("v", &min, &req).unwrap(),
+            expand("v", &def, &req).unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_covering_materialization() {
+        let def = star();
+        let req = QueryRequest {
+            dimensions: vec![DimensionName::new("category")],
+            metrics: vec![MetricName::new("product_count")],
+            ..Default::default()
+        };
+        let min = minimal_definition("v", &def, &req).unwrap();
+        assert_eq!(min.materializations.len(), 1);
+        assert_eq!(
+            expand("v", &min, &req).unwrap(),
+            expand("v", &def, &req).unwrap()
+        );
+    }
+
+    #[test]
+    fn fact_query_keeps_fact_source_path() {
+        let def = star().with_fact("cat_upper", "upper(p.category)", "p");
+        let req = QueryRequest {
+            facts: vec![FactName::new("cat_upper")],
+            ..Default::default()
+        };
+        let min = minimal_definition("v", &def, &req).unwrap();
+        assert!(min.metrics.is_empty() && min.dimensions.is_empty());
+        assert_eq!(min.facts.len(), 1);
+        assert_eq!(
+            min.tables
+                .iter()
+                .map(|t| t.alias.as_str())
+                .collect::<Vec<_>>(),
+            ["orders", "p"]
+        );
+    }
+
+    #[test]
+    fn unknown_name_uses_expand_error() {
+        let req = QueryRequest {
+            dimensions: vec![DimensionName::new("nope")],
+            ..Default::default()
+        };
+        let err = minimal_definition("v", &star(), &req).unwrap_err();
+        assert!(matches!(err, ExpandError::UnknownDimension { .. }), "{err}");
+    }
+}
@@ -281,6 +281,8 @@ pub(super) fn find_metric<'a>(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::{
         dim_ref_key, find_dimension, find_metric, qualify_and_quote_table_ref, quote_ident,
         quote_table_ref,
@@ -299,6 +301,14 @@ mod tests {
             joins: vec![],
             facts: vec![],
             materializations: vec![],
+            base_sql: None,
+            extends: None,
+            default_dimensions: vec![],
+            default_metrics: vec![],
+            time_dimension: None,
+            row_security: None,
+            tags: vec![],
+            labels: BTreeMap::new(),
             created_on: None,
             database_name: db.map(str::to_string),
             schema_name: schema.map(str::to_string),
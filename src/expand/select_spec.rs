@@ -17,10 +17,12 @@
 //! [`push_group_by_ordinals`] (ordinal GROUP BY) emit helpers, each previously
 //! copied across the base / CTE strategies.
 
-use crate::model::SemanticViewDefinition;
+use crate::model::{BucketBin, SemanticViewDefinition};
+use crate::sql_lit::SqlLit;
 
 use super::join_resolver::{push_join_clauses, ResolvedJoin};
 use super::resolution::{qualify_and_quote_table_ref, quote_ident};
+use super::types::GroupByMode;
 
 /// One `SELECT`-list item: an expression, an optional `output_type` CAST wrap,
 /// and an output alias.
@@ -33,6 +35,10 @@ use super::resolution::{qualify_and_quote_table_ref, quote_ident};
 pub(super) struct SelectItem {
     expr: String,
     cast: Option<String>,
+    bins: Vec<(String, SqlLit)>,
+    coalesce_to: Option<SqlLit>,
+    null_value: Option<SqlLit>,
+    collate: Option<String>,
     alias: String,
 }
 
@@ -40,25 +46,122 @@ impl SelectItem {
     /// Build a select item. `cast` is the dimension/metric/fact `output_type`
     /// (rendered as `CAST(expr AS <cast>)` when `Some`).
     pub(super) fn new(expr: String, cast: Option<String>, alias: String) -> Self {
-        Self { expr, cast, alias }
+        Self {
+            expr,
+            cast,
+            bins: Vec::new(),
+            coalesce_to: None,
+            null_value: None,
+            collate: None,
+            alias,
+        }
+    }
+
+    /// Replace the expression with `CASE WHEN expr <when> THEN '<label>' ...
+    /// END` — the dimension `bucket` setting. This is the innermost wrap, so
+    /// `coalesce_to` / `null_value` see the labels (and can name the rows no
+    /// bin matched). Labels are escaped as SQL string literals.
+    pub(super) fn bucketed(mut self, bins: Option<&[BucketBin]>) -> Self {
+        self.bins = bins
+            .unwrap_or_default()
+            .iter()
+            .map(|bin| (bin.when.trim().to_string(), SqlLit::escape(&bin.label)))
+            .collect();
+        self
+    }
+
+    /// Wrap the expression as `COALESCE(NULLIF(expr, ''), '<replacement>')`
+    /// — the dimension `coalesce_to` setting — so NULLs and empty strings
+    /// land in one group. The wrap sits inside any CAST, and the replacement
+    /// is escaped as a SQL string literal.
+    pub(super) fn coalesced(mut self, replacement: Option<&str>) -> Self {
+        self.coalesce_to = replacement.map(SqlLit::escape);
+        self
+    }
+
+    /// Wrap the expression as `COALESCE(expr, '<replacement>')` — the
+    /// dimension `null_value` setting — so NULLs group under the replacement
+    /// while empty strings stay as they are. Like [`Self::coalesced`], which
+    /// CREATE never allows alongside it, the wrap sits inside any CAST.
+    pub(super) fn null_replaced(mut self, replacement: Option<&str>) -> Self {
+        self.null_value = replacement.map(SqlLit::escape);
+        self
+    }
+
+    /// Append `COLLATE "<collation>"` after the (optionally cast) expression —
+    /// the dimension `collate` setting. Each dotted part is quoted separately,
+    /// so `nocase.noaccent` renders as `"nocase"."noaccent"`. Because the
+    /// collation is part of [`Self::rendered_expr`], the ordinal `GROUP BY`
+    /// and any repeated `PARTITION BY` group under the same collation.
+    pub(super) fn collated(mut self, collation: Option<&str>) -> Self {
+        self.collate =
+            collation.map(|c| c.split('.').map(quote_ident).collect::<Vec<_>>().join("."));
+        self
     }
 
-    /// Write `CAST(expr AS <cast>)` (or bare `expr`) into `out`. The single
-    /// source of the CAST-wrap rendering, shared by [`Self::rendered_expr`] and
-    /// [`Self::render`] so they cannot diverge (the E-1 invariant lives here);
-    /// writing into the caller's buffer keeps `render` to one allocation and
-    /// avoids cloning `expr` in the common no-cast case.
+    /// Write `CAST(expr AS <cast>)` (or bare `expr`) into `out`, followed by
+    /// any `COLLATE` suffix. The single source of the CAST-wrap rendering,
+    /// shared by [`Self::rendered_expr`] and [`Self::render`] so they cannot
+    /// diverge (the E-1 invariant lives here); writing into the caller's
+    /// buffer keeps `render` to one allocation and avoids cloning `expr` in the
+    /// common no-cast case.
     fn write_expr(&self, out: &mut String) {
         match &self.cast {
             Some(ty) => {
                 out.push_str("CAST(");
-                out.push_str(&self.expr);
+                self.write_coalesced(out);
                 out.push_str(" AS ");
                 out.push_str(ty);
                 out.push(')');
             }
-            None => out.push_str(&self.expr),
+            None => self.write_coalesced(out),
+        }
+        if let Some(ref collation) = self.collate {
+            out.push_str(" COLLATE ");
+            out.push_str(collation);
+        }
+    }
+
+    /// Write `expr`, or its `COALESCE(NULLIF(expr, ''), '<replacement>')`
+    /// wrap when [`Self::coalesced`] set one, or its `COALESCE(expr,
+    /// '<replacement>')` wrap when [`Self::null_replaced`] did.
+    fn write_coalesced(&self, out: &mut String) {
+        match (&self.coalesce_to, &self.null_value) {
+            (Some(lit), _) => {
+                out.push_str("COALESCE(NULLIF(");
+                self.write_bucketed(out);
+                out.push_str(", ''), '");
+                out.push_str(&lit.to_string());
+                out.push_str("')");
+            }
+            (None, Some(lit)) => {
+                out.push_str("COALESCE(");
+                self.write_bucketed(out);
+                out.push_str(", '");
+                out.push_str(&lit.to_string());
+                out.push_str("')");
+            }
+            (None, None) => self.write_bucketed(out),
+        }
+    }
+
+    /// Write `expr`, or the `CASE` over it when [`Self::bucketed`] set bins.
+    fn write_bucketed(&self, out: &mut String) {
+        if self.bins.is_empty() {
+            out.push_str(&self.expr);
+            return;
         }
+        out.push_str("CASE");
+        for (when, label) in &self.bins {
+            out.push_str(" WHEN ");
+            out.push_str(&self.expr);
+            out.push(' ');
+            out.push_str(when);
+            out.push_str(" THEN '");
+            out.push_str(&label.to_string());
+            out.push('\'');
+        }
+        out.push_str(" END");
     }
 
     /// The rendered expression with the optional CAST wrap applied, WITHOUT the
@@ -90,18 +193,51 @@ impl SelectItem {
 ///
 /// `lead` is the whitespace before `FROM` — `"\n"` at the top level, `"\n    "`
 /// inside a CTE. The base table is qualified + quoted via
-/// [`qualify_and_quote_table_ref`]; the first declared table's alias is
-/// appended as `AS <quote_ident>` when present. Shared by the base, facts,
+/// [`qualify_and_quote_table_ref`], or replaced by the parenthesised
+/// `base_sql` subquery when the view declares one; the first declared table's
+/// alias is appended as `AS <quote_ident>` when present. Shared by the base, facts,
 /// semi-additive, and window emitters (§6.2). The materialization renderer
 /// intentionally does not use this — it selects from the pre-aggregated table
 /// with no alias.
+///
+/// A view's `row_security` predicate is applied here, as
+/// `FROM (SELECT * FROM <base> AS <alias> WHERE <predicate>) AS <alias>`: every
+/// emitter reads the base table through this one function, so no query shape
+/// can leave the predicate out, and the predicate sees the same alias it would
+/// in a top-level `WHERE`. (Materialization routing is disabled for such views
+/// instead; see `find_matching_materialization`.)
 pub(super) fn push_from_base(sql: &mut String, def: &SemanticViewDefinition, lead: &str) {
     sql.push_str(lead);
     sql.push_str("FROM ");
-    sql.push_str(&qualify_and_quote_table_ref(def.base_table(), def));
-    if let Some(base_ref) = def.tables.first() {
+    let alias = def.tables.first().map(|t| quote_ident(&t.alias));
+    if let Some(predicate) = &def.row_security {
+        sql.push_str("(SELECT * FROM ");
+        push_base_relation(sql, def, alias.as_deref());
+        sql.push_str(" WHERE ");
+        sql.push_str(predicate.trim());
+        sql.push(')');
+        if let Some(alias) = &alias {
+            sql.push_str(" AS ");
+            sql.push_str(alias);
+        }
+    } else {
+        push_base_relation(sql, def, alias.as_deref());
+    }
+}
+
+/// The base relation itself — the qualified table or the parenthesised
+/// `base_sql` — followed by `AS <alias>` when there is one.
+fn push_base_relation(sql: &mut String, def: &SemanticViewDefinition, alias: Option<&str>) {
+    if let Some(base_sql) = &def.base_sql {
+        sql.push('(');
+        sql.push_str(base_sql.trim());
+        sql.push(')');
+    } else {
+        sql.push_str(&qualify_and_quote_table_ref(def.base_table(), def));
+    }
+    if let Some(alias) = alias {
         sql.push_str(" AS ");
-        sql.push_str(&quote_ident(&base_ref.alias));
+        sql.push_str(alias);
     }
 }
 
@@ -137,6 +273,50 @@ pub(super) enum GroupBy {
     /// Ordinals — never expressions — are the E-1 alias-shadowing defense; see
     /// [`push_group_by_ordinals`].
     Ordinals(usize),
+    /// `GROUP BY ALL`, requested through [`GroupByMode::All`].
+    /// `DuckDB` groups by the non-aggregate select items — the dimensions —
+    /// resolving them as expressions, so the alias-shadowing risk is the
+    /// caller's to accept.
+    All,
+    /// `GROUP BY` over each dimension's rendered expression, requested
+    /// through [`GroupByMode::Expressions`]. Repeating
+    /// [`SelectItem::rendered_expr`] — never the output alias — keeps the
+    /// grouping identical to the select item, cast and collation included.
+    Expressions(Vec<String>),
+}
+
+impl GroupBy {
+    /// The grouping for the leading dimension items `dims` under `mode`;
+    /// `None` when there are no dimensions to group by.
+    pub(super) fn dimensions(mode: GroupByMode, dims: &[SelectItem]) -> Self {
+        match (dims.len(), mode) {
+            (0, _) => Self::None,
+            (n, GroupByMode::Ordinals) => Self::Ordinals(n),
+            (_, GroupByMode::All) => Self::All,
+            (_, GroupByMode::Expressions) => {
+                Self::Expressions(dims.iter().map(SelectItem::rendered_expr).collect())
+            }
+        }
+    }
+
+    /// Append the clause, laid out as [`push_group_by_ordinals`] describes.
+    pub(super) fn push(&self, sql: &mut String, lead: &str, item_indent: &str) {
+        match self {
+            Self::None => {}
+            Self::Ordinals(n) => push_group_by_ordinals(sql, *n, lead, item_indent),
+            Self::All => {
+                sql.push_str(lead);
+                sql.push_str("GROUP BY ALL");
+            }
+            Self::Expressions(exprs) => {
+                sql.push_str(lead);
+                sql.push_str("GROUP BY\n");
+                let items: Vec<String> =
+                    exprs.iter().map(|e| format!("{item_indent}{e}")).collect();
+                sql.push_str(&items.join(",\n"));
+            }
+        }
+    }
 }
 
 /// The `FROM` source of a top-level [`SelectSpec`].
@@ -156,7 +336,7 @@ pub(super) enum FromSource<'a> {
 }
 
 /// A whole top-level `SELECT` statement: `SELECT[ DISTINCT]` + select list +
-/// `FROM` (+ `LEFT JOIN`s) + optional ordinal `GROUP BY`.
+/// `FROM` (+ `LEFT JOIN`s) + optional `WHERE` + optional ordinal `GROUP BY`.
 ///
 /// The culmination of §6.2 move 3 (code-review 2026-07-11): the four
 /// hand-rolled top-level emitters — base ([`super::sql_gen::expand`]), facts
@@ -181,8 +361,15 @@ pub(super) struct SelectSpec<'a> {
     pub(super) items: Vec<SelectItem>,
     /// The `FROM` source (+ joins, for the base-table case).
     pub(super) from: FromSource<'a>,
+    /// `WHERE` predicates, joined with `AND` (the query's dimension filters). Empty for
+    /// no `WHERE`. With more than one, each is parenthesized before joining.
+    pub(super) filters: Vec<String>,
     /// The `GROUP BY`, if any.
     pub(super) group_by: GroupBy,
+    /// `HAVING` predicates, joined with `AND` (the query's metric filters).
+    /// With more than one, each is parenthesized before joining.
+    /// Only meaningful with a [`GroupBy::Ordinals`] or [`GroupBy::All`].
+    pub(super) having: Vec<String>,
 }
 
 impl SelectSpec<'_> {
@@ -212,14 +399,31 @@ impl SelectSpec<'_> {
                 sql.push_str(name);
             }
         }
-        match self.group_by {
-            GroupBy::None => {}
-            GroupBy::Ordinals(n) => push_group_by_ordinals(&mut sql, n, "\n", "    "),
-        }
+        push_conjunction(&mut sql, "\nWHERE ", &self.filters);
+        self.group_by.push(&mut sql, "\n", "    ");
+        push_conjunction(&mut sql, "\nHAVING ", &self.having);
         sql
     }
 }
 
+/// Append `keyword` and `predicates` joined with `AND`, nothing when there are
+/// none. With more than one, each is parenthesized so an `OR` inside one
+/// cannot absorb its neighbours.
+fn push_conjunction(sql: &mut String, keyword: &str, predicates: &[String]) {
+    match predicates {
+        [] => {}
+        [only] => {
+            sql.push_str(keyword);
+            sql.push_str(only);
+        }
+        _ => {
+            let wrapped: Vec<String> = predicates.iter().map(|p| format!("({p})")).collect();
+            sql.push_str(keyword);
+            sql.push_str(&wrapped.join("\n  AND "));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{push_group_by_ordinals, FromSource, GroupBy, SelectItem, SelectSpec};
@@ -317,7 +521,9 @@ mod tests {
                 def: &def,
                 joins: Vec::new(),
             },
+            filters: vec![],
             group_by: GroupBy::Ordinals(1),
+            having: vec![],
         };
         assert_eq!(
             spec.render(),
@@ -341,7 +547,9 @@ mod tests {
                 def: &def,
                 joins: Vec::new(),
             },
+            filters: vec![],
             group_by: GroupBy::None,
+            having: vec![],
         };
         assert_eq!(
             spec.render(),
@@ -364,7 +572,9 @@ mod tests {
                 ),
             ],
             from: FromSource::Named("__sv_snapshot".to_string()),
+            filters: vec![],
             group_by: GroupBy::Ordinals(1),
+            having: vec![],
         };
         assert_eq!(
             spec.render(),
@@ -373,6 +583,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_where_before_group_by_and_having_after() {
+        let def = minimal_def("orders", "region", "region", "cnt", "count(*)");
+        let spec = SelectSpec {
+            distinct: false,
+            items: vec![
+                SelectItem::new("region".to_string(), None, "\"region\"".to_string()),
+                SelectItem::new("count(*)".to_string(), None, "\"cnt\"".to_string()),
+            ],
+            from: FromSource::BaseTable {
+                def: &def,
+                joins: Vec::new(),
+            },
+            filters: vec!["region = 'EU'".to_string()],
+            group_by: GroupBy::Ordinals(1),
+            having: vec!["count(*) > 10".to_string(), "count(*) < 99".to_string()],
+        };
+        assert_eq!(
+            spec.render(),
+            "SELECT\n    region AS \"region\",\n    count(*) AS \"cnt\"\n\
+             FROM \"orders\" AS \"orders\"\nWHERE region = 'EU'\nGROUP BY\n    1\n\
+             HAVING (count(*) > 10)\n  AND (count(*) < 99)"
+        );
+    }
+
     #[test]
     fn render_named_source_no_group_by() {
         // The window OUTER shape: SELECT over a bare CTE name, no GROUP BY.
@@ -384,7 +619,9 @@ mod tests {
                 "\"m\"".to_string(),
             )],
             from: FromSource::Named("__sv_agg".to_string()),
+            filters: vec![],
             group_by: GroupBy::None,
+            having: vec![],
         };
         assert_eq!(
             spec.render(),
@@ -42,9 +42,10 @@ use std::collections::{HashMap, HashSet};
 use crate::model::{Metric, NonAdditiveDim, NullsOrder, SemanticViewDefinition, SortOrder};
 
 use super::join_resolver::{push_join_clauses, resolve_joins_pkfk};
+use super::output_alias::OutputAliases;
 use super::resolution::{quote_ident, quote_stored_ident};
 use super::select_spec::{push_from_base, FromSource, GroupBy, SelectItem, SelectSpec};
-use super::types::{ExpandError, ResolvedDim};
+use super::types::{ExpandError, GroupByMode, ResolvedDim};
 
 /// Resolve a NON ADDITIVE BY dim reference — bare (`report_date`), dotted
 /// (`o.report_date`), or either with quoted parts (`o."order date"`) — to the
@@ -139,6 +140,8 @@ pub(super) fn expand_semi_additive(
     resolved_dims: &[ResolvedDim],
     resolved_mets: &[&Metric],
     resolved_exprs: &HashMap<String, String>,
+    group_by: GroupByMode,
+    aliases: &OutputAliases,
 ) -> Result<String, ExpandError> {
     let mut sql = String::with_capacity(512);
 
@@ -233,7 +236,7 @@ pub(super) fn expand_semi_additive(
         outer_items.push(SelectItem::new(
             quote_stored_ident(&rd.dim.name),
             None,
-            quote_stored_ident(&rd.dim.name),
+            aliases.column(&rd.dim.name),
         ));
     }
 
@@ -245,23 +248,22 @@ pub(super) fn expand_semi_additive(
             &decomposed[met_idx].0,
             is_active_semi(met),
             &na_groups,
+            aliases.column(&met.name),
         ));
     }
 
-    // Dimensions present ⇒ ordinal GROUP BY over them; a metrics-only snapshot
+    // Dimensions present ⇒ GROUP BY over them; a metrics-only snapshot
     // query is a global aggregate with no GROUP BY.
-    let group_by = if resolved_dims.is_empty() {
-        GroupBy::None
-    } else {
-        GroupBy::Ordinals(resolved_dims.len())
-    };
+    let group_by = GroupBy::dimensions(group_by, &outer_items[..resolved_dims.len()]);
 
     sql.push_str(
         &SelectSpec {
             distinct: false,
             items: outer_items,
             from: FromSource::Named("__sv_snapshot".to_string()),
+            filters: vec![],
             group_by,
+            having: vec![],
         }
         .render(),
     );
@@ -352,7 +354,11 @@ fn push_cte_dimension_columns(
             base_expr,
             dim.output_type.clone(),
             quote_stored_ident(&dim.name),
-        );
+        )
+        .bucketed(dim.bucket.as_deref())
+        .coalesced(dim.coalesce_to.as_deref())
+        .null_replaced(dim.null_value.as_deref())
+        .collated(dim.collate.as_deref());
         cte_select_items.push(format!("        {}", item.render()));
         // The window PARTITION/ORDER clauses must repeat this EXPRESSION, never
         // the select alias (E-1) -- see the doc comment.
@@ -524,12 +530,14 @@ fn na_order_item(
 /// `FUNC(CASE WHEN "<rn_col>" = 1 THEN "__sv_semi_<idx>" END)` -- where every
 /// row tied at rank 1 contributes (RANK semantics, SG-4). A regular or
 /// effectively-regular metric aggregates over all rows: `FUNC("__sv_reg_<idx>")`.
+/// `alias` is the quoted output column name.
 fn outer_metric_column(
     met_idx: usize,
     met: &Metric,
     agg_func: &str,
     is_active_semi: bool,
     na_groups: &[NaGroup],
+    alias: String,
 ) -> SelectItem {
     let inner = if is_active_semi {
         let rn_col = get_rn_column_for_metric(met_idx, na_groups);
@@ -537,11 +545,7 @@ fn outer_metric_column(
     } else {
         format!("{agg_func}(\"__sv_reg_{met_idx}\")")
     };
-    SelectItem::new(
-        inner,
-        met.output_type.clone(),
-        quote_stored_ident(&met.name),
-    )
+    SelectItem::new(inner, met.output_type.clone(), alias)
 }
 
 /// A group of metrics sharing the same NON ADDITIVE BY dimension set.
@@ -829,9 +833,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -886,9 +890,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("region")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -924,9 +928,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("region")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -961,12 +965,12 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![
                 DimensionName::new("customer_id"),
                 DimensionName::new("report_date"),
             ],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -999,9 +1003,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1036,9 +1040,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("amount"), MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1070,9 +1074,9 @@ mod tests {
     fn test_no_semi_additive_no_cte() {
         let def = orders_view();
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("region")],
             metrics: vec![MetricName::new("total_revenue")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1194,9 +1198,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("discounted"), MetricName::new("balance")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -1233,9 +1237,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("row_count"), MetricName::new("balance")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -1275,12 +1279,12 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![
                 MetricName::new("uniq_customers"),
                 MetricName::new("balance"),
             ],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -1311,9 +1315,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("safe_total"), MetricName::new("balance")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -1348,9 +1352,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("profit"), MetricName::new("balance")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -1391,9 +1395,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -1432,9 +1436,9 @@ mod tests {
             .with_pkfk_join("acct_date", "a", "d", &["date_id"], &["id"]);
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1478,9 +1482,9 @@ mod tests {
             .with_pkfk_join("acct_date", "a", "d", &["date_id"], &["id"]);
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1526,13 +1530,13 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![
                 DimensionName::new("customer_id"),
                 // The dotted NA dim IS queried (by its bare name).
                 DimensionName::new("report_date"),
             ],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1606,9 +1610,8 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
-            dimensions: vec![],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1657,9 +1660,9 @@ mod tests {
             .with_pkfk_join("cust_acct", "a", "c", &["customer_id"], &["id"]);
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("acct_name")],
             metrics: vec![MetricName::new("total_balance")],
+            ..Default::default()
         };
 
         // Effectively-regular semi-additive metrics get the standard check.
@@ -1690,9 +1693,9 @@ mod tests {
             .with_pkfk_join("acct_cust", "a", "c", &["customer_id"], &["id"]);
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_name")],
             metrics: vec![MetricName::new("total_balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1723,9 +1726,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1758,9 +1761,9 @@ mod tests {
         );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("customer_id")],
             metrics: vec![MetricName::new("balance")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -1808,11 +1811,10 @@ mod tests {
     }
 
     fn carrier_only_req() -> QueryRequest {
-        QueryRequest {
-            facts: vec![],
-            dimensions: vec![DimensionName::new("carrier")],
-            metrics: vec![MetricName::new("latest_bal")],
-        }
+        QueryRequest::builder()
+            .dimension("carrier")
+            .metric("latest_bal")
+            .build()
     }
 
     /// The snapshot ORDER BY must reference the metric's SCOPED airport
@@ -1903,12 +1905,12 @@ mod tests {
                 &[("city", SortOrder::Asc, NullsOrder::Last)],
             );
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("carrier")],
             metrics: vec![
                 MetricName::new("latest_bal"),
                 MetricName::new("latest_bal_dep"),
             ],
+            ..Default::default()
         };
         let sql = expand("rp_view", &def, &req).expect("expand");
         assert!(
@@ -1966,11 +1968,10 @@ mod tests {
         }
 
         fn snapshot_req() -> QueryRequest {
-            QueryRequest {
-                facts: vec![],
-                dimensions: vec![DimensionName::new("customer_id")],
-                metrics: vec![MetricName::new("balance")],
-            }
+            QueryRequest::builder()
+                .dimension("customer_id")
+                .metric("balance")
+                .build()
         }
 
         /// SG-4 data-level: alice has TWO rows tied at the latest date
@@ -2248,12 +2249,12 @@ mod tests {
             );
 
             let req = QueryRequest {
-                facts: vec![],
                 dimensions: vec![DimensionName::new("customer_id")],
                 metrics: vec![
                     MetricName::new("latest_bal"),
                     MetricName::new("earliest_bal"),
                 ],
+                ..Default::default()
             };
 
             let sql = expand("test_view", &def, &req).expect("expand");
@@ -1,15 +1,17 @@
 use crate::model::{AccessModifier, Dimension, Fact, Metric, SemanticViewDefinition};
 use crate::util::suggest_closest;
 
+use super::dim_filter::{resolve_dimension_filters, resolve_metric_filters};
 use super::facts::{
     collect_transitive_metric_names, inline_derived_metrics, inline_facts, toposort_facts,
 };
 use super::fan_trap::{check_fan_traps, validate_fact_table_path};
 use super::join_resolver::resolve_joins_pkfk;
-use super::resolution::{find_dimension, find_metric, quote_stored_ident};
+use super::output_alias::OutputAliases;
+use super::resolution::{find_dimension, find_metric};
 use super::role_playing::{check_fact_role_playing_path, find_using_context};
 use super::select_spec::{FromSource, GroupBy, SelectItem, SelectSpec};
-use super::types::{ExpandError, QueryRequest, ResolvedDim};
+use super::types::{CiName, ExpandError, GroupByMode, QueryRequest, ResolvedDim};
 
 /// An entity kind resolvable by name against a [`SemanticViewDefinition`]
 /// (dimensions, metrics, facts). Encapsulates lookup, the PRIVATE-access
@@ -20,7 +22,7 @@ use super::types::{ExpandError, QueryRequest, ResolvedDim};
 /// unrepresentable: the old positional API let the dimension call sites pass
 /// `DuplicateDimension` in the private-error slot (harmless only because
 /// dimensions are never private), a mistake the compiler could not catch.
-trait Resolvable: Sized {
+pub(super) trait Resolvable: Sized {
     /// Find this entity by (possibly qualified) name in the definition.
     fn find<'a>(def: &'a SemanticViewDefinition, name: &str) -> Option<&'a Self>;
     /// Is this resolved entity PRIVATE — barred from direct querying?
@@ -148,7 +150,7 @@ impl Resolvable for Metric {
 /// request string (SG-14): `region` and `o.region` resolve to the same
 /// dimension and are rejected as duplicates instead of emitting the same
 /// column twice.
-fn resolve_names<'a, T: Resolvable, N: AsRef<str>>(
+pub(super) fn resolve_names<'a, T: Resolvable, N: AsRef<str>>(
     names: &[N],
     view_name: &str,
     def: &'a SemanticViewDefinition,
@@ -188,7 +190,8 @@ fn resolve_names<'a, T: Resolvable, N: AsRef<str>>(
 /// just like metric expansion inlines facts into aggregate expressions.
 ///
 /// Dimensions, when present, add columns to SELECT but do NOT trigger GROUP BY
-/// (unlike metric queries where dims + metrics => GROUP BY).
+/// (unlike metric queries where dims + metrics => GROUP BY). Detail-mode
+/// requests also land here, possibly with no facts at all.
 #[allow(clippy::too_many_lines)]
 fn expand_facts(
     view_name: &str,
@@ -198,15 +201,31 @@ fn expand_facts(
     // 1. Validate + resolve requested facts.
     let resolved_facts = resolve_names::<Fact, _>(&req.facts, view_name, def)?;
 
-    // 2. Resolve requested dimensions (same logic as expand()).
+    // 2. Resolve requested dimensions (same logic as expand()), and the
+    //    dimensions the request filters on: those need their tables on the
+    //    same path and joined, though they are not selected.
     let resolved_dims = resolve_names::<Dimension, _>(&req.dimensions, view_name, def)?;
+    let filters = resolve_dimension_filters(view_name, def, &req.dimension_filters)?;
+    let join_dims: Vec<&Dimension> = resolved_dims
+        .iter()
+        .copied()
+        .chain(filters.iter().map(|f| f.dim))
+        .collect();
+    let aliases = OutputAliases::resolve(
+        view_name,
+        &req.output_aliases,
+        resolved_dims
+            .iter()
+            .map(|d| d.name.as_str())
+            .chain(resolved_facts.iter().map(|f| f.name.as_str())),
+    )?;
 
     // 3. Validate table path constraint (FACT-04).
     let fact_tables: Vec<String> = resolved_facts
         .iter()
         .filter_map(|f| f.source_table.clone())
         .collect();
-    let dim_tables: Vec<String> = resolved_dims
+    let dim_tables: Vec<String> = join_dims
         .iter()
         .filter_map(|d| d.source_table.clone())
         .collect();
@@ -219,7 +238,7 @@ fn expand_facts(
     // same error the metrics path raises when no co-queried metric supplies
     // USING. Previously the facts path skipped this check and silently bound
     // the dimension to an arbitrary relationship edge.
-    for dim in &resolved_dims {
+    for dim in &join_dims {
         let _ = find_using_context(view_name, def, dim, &[])?;
     }
 
@@ -242,11 +261,17 @@ fn expand_facts(
 
     // Dimensions first
     for dim in &resolved_dims {
-        items.push(SelectItem::new(
-            dim.expr.clone(),
-            dim.output_type.clone(),
-            quote_stored_ident(&dim.name),
-        ));
+        items.push(
+            SelectItem::new(
+                dim.expr.clone(),
+                dim.output_type.clone(),
+                aliases.column(&dim.name),
+            )
+            .bucketed(dim.bucket.as_deref())
+            .coalesced(dim.coalesce_to.as_deref())
+            .null_replaced(dim.null_value.as_deref())
+            .collated(dim.collate.as_deref()),
+        );
     }
 
     // Then facts (inlined expressions, no aggregation)
@@ -255,7 +280,7 @@ fn expand_facts(
         items.push(SelectItem::new(
             resolved_expr,
             fact.output_type.clone(),
-            quote_stored_ident(&fact.name),
+            aliases.column(&fact.name),
         ));
     }
 
@@ -267,37 +292,163 @@ fn expand_facts(
         .iter()
         .filter_map(|f| f.source_table.clone())
         .collect();
-    let joins = resolve_joins_pkfk(def, &resolved_dims, &[], &fact_sources);
+    let joins = resolve_joins_pkfk(def, &join_dims, &[], &fact_sources);
 
     // 7. A fact query is an unaggregated top-level SELECT over the base table
-    //    (+ joins): no DISTINCT, no GROUP BY.
+    //    (+ joins): no DISTINCT, no GROUP BY. No dimension here is role-played
+    //    (step 3b), so filter predicates need no scoped alias.
     Ok(SelectSpec {
         distinct: false,
         items,
         from: FromSource::BaseTable { def, joins },
+        filters: filters.iter().map(|f| f.predicate(None)).collect(),
         group_by: GroupBy::None,
+        having: vec![],
     }
     .render())
 }
 
+/// Default cap on the length of the SQL [`expand`] returns: 16 MiB.
+///
+/// Ordinary queries expand to a few kilobytes; only a pathological request
+/// (e.g. `*` over a view with thousands of large dimension expressions)
+/// comes near this, and such SQL would otherwise go on to stress `DuckDB`'s
+/// parser. Use [`expand_with_limit`] to apply a different cap.
+pub const DEFAULT_MAX_EXPANDED_SQL_BYTES: usize = 16 * 1024 * 1024;
+
 /// Expand a semantic view definition into a SQL query string.
 ///
 /// Takes a view name (for error messages), its definition, and a query request
 /// specifying which dimensions and metrics to include. Returns the generated SQL
 /// or an `ExpandError` if the request is invalid.
 ///
+/// A request naming no dimension, metric, or fact selects the view's
+/// `default_dimensions` and `default_metrics` instead.
+///
+/// Output columns follow the request: `req.dimensions` in the order given,
+/// then `req.metrics` in the order given, whatever the declaration order or
+/// the tables the items come from. The ordinal `GROUP BY` (`1, 2, ...`)
+/// therefore names the dimensions in request order, as does the
+/// expression list under `GroupByMode::Expressions`. Callers may rely on
+/// this order.
+///
 /// # Errors
 ///
 /// Returns `ExpandError` if:
-/// - Neither dimensions nor metrics are requested (`EmptyRequest`)
+/// - Neither dimensions nor metrics are requested and the view declares no
+///   defaults (`EmptyRequest`)
+/// - Metrics are requested with `detail: true` (`DetailModeMetrics`)
+/// - `GROUP BY ALL` is requested without both dimensions and metrics
+///   (`GroupByAllWithoutAggregation`)
 /// - A requested dimension or metric name is not found (`UnknownDimension`, `UnknownMetric`)
 /// - A dimension or metric name is duplicated (`DuplicateDimension`, `DuplicateMetric`)
-#[allow(clippy::too_many_lines)]
+/// - An output alias names nothing requested, is blank, or collides with
+///   another output column (`InvalidOutputAlias`)
+/// - The generated SQL exceeds [`DEFAULT_MAX_EXPANDED_SQL_BYTES`] (`SqlTooLarge`)
 pub fn expand(
     view_name: &str,
     def: &SemanticViewDefinition,
     req: &QueryRequest,
 ) -> Result<String, ExpandError> {
+    expand_with_limit(view_name, def, req, DEFAULT_MAX_EXPANDED_SQL_BYTES)
+}
+
+/// [`expand`] with an explicit cap on the generated SQL length, in bytes.
+///
+/// # Errors
+///
+/// As [`expand`], with `SqlTooLarge` raised when the SQL is longer than
+/// `max_sql_bytes`.
+pub fn expand_with_limit(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    req: &QueryRequest,
+    max_sql_bytes: usize,
+) -> Result<String, ExpandError> {
+    let sql = expand_unbounded(view_name, def, req)?;
+    if sql.len() > max_sql_bytes {
+        return Err(ExpandError::SqlTooLarge {
+            view_name: view_name.to_string(),
+            len: sql.len(),
+            max_len: max_sql_bytes,
+        });
+    }
+    Ok(sql)
+}
+
+/// [`expand`], with a leading `--` comment naming the view and the items the
+/// request selects:
+///
+/// ```text
+/// -- semantic_view: orders; dimensions: region; metrics: total_revenue
+/// SELECT
+/// ...
+/// ```
+///
+/// Meant for telling generated queries apart in `DuckDB`'s query log. Empty
+/// item lists are left out, and an empty request reports the view defaults it
+/// expands to. Line breaks in names are replaced with spaces so the comment
+/// cannot end early.
+///
+/// # Errors
+///
+/// As [`expand`].
+pub fn expand_with_header(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    req: &QueryRequest,
+) -> Result<String, ExpandError> {
+    let sql = expand(view_name, def, req)?;
+    let req = req.or_view_defaults(def);
+    let one_line = |s: &str| s.replace(['\r', '\n'], " ");
+    let list = |names: Vec<&str>| one_line(&names.join(", "));
+    let mut parts = vec![format!("semantic_view: {}", one_line(view_name))];
+    let sections: [(&str, Vec<&str>); 3] = [
+        (
+            "dimensions",
+            req.dimensions.iter().map(CiName::as_str).collect(),
+        ),
+        ("metrics", req.metrics.iter().map(CiName::as_str).collect()),
+        ("facts", req.facts.iter().map(CiName::as_str).collect()),
+    ];
+    for (label, names) in sections {
+        if !names.is_empty() {
+            parts.push(format!("{label}: {}", list(names)));
+        }
+    }
+    let header = parts.join("; ");
+    Ok(format!("-- {header}\n{sql}"))
+}
+
+/// [`expand`], on as few lines as possible: the same query with each run of
+/// whitespace outside literals and quoted identifiers collapsed to one space.
+///
+/// For logs and error payloads, where the indented form is mostly noise.
+///
+/// # Errors
+///
+/// As [`expand`].
+pub fn expand_compact(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    req: &QueryRequest,
+) -> Result<String, ExpandError> {
+    expand(view_name, def, req).map(|sql| crate::expr_tokens::collapse_whitespace(&sql))
+}
+
+#[allow(clippy::too_many_lines)]
+fn expand_unbounded(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    req: &QueryRequest,
+) -> Result<String, ExpandError> {
+    let req = &*req.or_view_defaults(def);
+    let def = &*if req.qualify_bare_columns {
+        super::qualify::qualify_bare_columns(def)
+    } else {
+        std::borrow::Cow::Borrowed(def)
+    };
+
     // 0. Facts and metrics are mutually exclusive.
     if !req.facts.is_empty() && !req.metrics.is_empty() {
         return Err(ExpandError::FactsMetricsMutualExclusion {
@@ -305,6 +456,14 @@ pub fn expand(
         });
     }
 
+    // 0b. Detail mode is row-level, so it has nothing to aggregate with.
+    if req.detail && !req.metrics.is_empty() {
+        return Err(ExpandError::DetailModeMetrics {
+            view_name: view_name.to_string(),
+            metrics: req.metrics.iter().map(|m| m.as_str().to_string()).collect(),
+        });
+    }
+
     // 1. Validate: at least one dimension, metric, or fact is required.
     if req.dimensions.is_empty() && req.metrics.is_empty() && req.facts.is_empty() {
         return Err(ExpandError::EmptyRequest {
@@ -312,8 +471,31 @@ pub fn expand(
         });
     }
 
-    // Dispatch to fact expansion path when facts are requested.
-    if !req.facts.is_empty() {
+    // 1b. `GROUP BY ALL` only means something for a grouped aggregate.
+    if req.group_by == GroupByMode::All && (req.dimensions.is_empty() || req.metrics.is_empty()) {
+        return Err(ExpandError::GroupByAllWithoutAggregation {
+            view_name: view_name.to_string(),
+        });
+    }
+
+    // 1c. Metric filters are HAVING predicates, so they need a GROUP BY:
+    //     facts, detail, dimensions-only, and global-aggregate queries have none.
+    if let Some(first) = req.metric_filters.first() {
+        if req.dimensions.is_empty() || req.metrics.is_empty() {
+            return Err(ExpandError::InvalidMetricFilter {
+                view_name: view_name.to_string(),
+                metric: first.metric.as_str().to_string(),
+                reason: "metric filters apply after aggregation and need a GROUP BY; \
+                         request at least one dimension and one metric"
+                    .to_string(),
+            });
+        }
+    }
+
+    // Dispatch to the row-level path when facts are requested or detail mode
+    // is on. With no facts it selects the dimensions alone, without the
+    // DISTINCT a dimensions-only aggregate query gets.
+    if req.detail || !req.facts.is_empty() {
         return expand_facts(view_name, def, req);
     }
 
@@ -325,14 +507,46 @@ pub fn expand(
     // Derived metrics that reference private bases still work because
     // inline_derived_metrics resolves expressions, not access modifiers.
     let resolved_mets = resolve_names::<Metric, _>(&req.metrics, view_name, def)?;
+    let aliases = OutputAliases::resolve(
+        view_name,
+        &req.output_aliases,
+        resolved_dims
+            .iter()
+            .map(|d| d.name.as_str())
+            .chain(resolved_mets.iter().map(|m| m.name.as_str())),
+    )?;
+
+    // Dimension filters: resolved up front so unknown names and bad values
+    // fail before any SQL is built. Their dimensions take part in fan-trap
+    // checks, role-playing resolution, and join selection like selected ones.
+    let filters = resolve_dimension_filters(view_name, def, &req.dimension_filters)?;
+    let join_dims: Vec<&Dimension> = resolved_dims
+        .iter()
+        .copied()
+        .chain(filters.iter().map(|f| f.dim))
+        .collect();
+    // Metric filters likewise: a filtered metric need not be selected, but its
+    // aggregate is computed over the same joins.
+    let metric_filters = resolve_metric_filters(view_name, def, &req.metric_filters)?;
+    let join_mets: Vec<&Metric> = resolved_mets
+        .iter()
+        .copied()
+        .chain(metric_filters.iter().map(|f| f.met))
+        .collect();
 
     // Phase 55: Materialization routing.
     // Attempt to route to a pre-aggregated table if an exact match exists.
     // Returns None if no match, or if any metric is semi-additive / window.
-    if let Some(routed_sql) =
-        super::materialization::try_route_materialization(def, &resolved_dims, &resolved_mets)
-    {
-        return Ok(routed_sql);
+    // A filtered request is never routed: the predicate reads base-table rows.
+    if super::materialization::request_may_route(req) {
+        if let Some(routed_sql) = super::materialization::try_route_materialization(
+            def,
+            &resolved_dims,
+            &resolved_mets,
+            &aliases,
+        ) {
+            return Ok(routed_sql);
+        }
     }
 
     // 4. Pre-compute all metric expressions: inline facts into base metrics,
@@ -353,7 +567,7 @@ pub fn expand(
     // with no PRIMARY KEY declared. Emitting it as-is would count
     // NULL-extended LEFT JOIN rows (one per childless base row).
     if !resolved.count_star_no_pk.is_empty() {
-        for met in &resolved_mets {
+        for met in &join_mets {
             for name in collect_transitive_metric_names(met, &def.metrics) {
                 if let Some(table_alias) = resolved.count_star_no_pk.get(&name) {
                     let metric_name = def
@@ -373,7 +587,7 @@ pub fn expand(
     let resolved_exprs = resolved.exprs;
 
     // Phase 31: Check for fan traps before generating SQL.
-    check_fan_traps(view_name, def, &resolved_dims, &resolved_mets)?;
+    check_fan_traps(view_name, def, &join_dims, &join_mets)?;
 
     // Phase 32: pair each resolved dimension with its role-playing scoped alias
     // (e.g. "a__dep_airport"). R-8 (code-review 2026-07-11): zipped into
@@ -381,9 +595,14 @@ pub fn expand(
     // position-indexed side array (`dim_scoped_aliases[i]`).
     let mut resolved: Vec<ResolvedDim> = Vec::with_capacity(resolved_dims.len());
     for &dim in &resolved_dims {
-        let scoped_alias = find_using_context(view_name, def, dim, &resolved_mets)?;
+        let scoped_alias = find_using_context(view_name, def, dim, &join_mets)?;
         resolved.push(ResolvedDim { dim, scoped_alias });
     }
+    let mut predicates: Vec<String> = Vec::with_capacity(filters.len());
+    for filter in &filters {
+        let scoped_alias = find_using_context(view_name, def, filter.dim, &join_mets)?;
+        predicates.push(filter.predicate(scoped_alias.as_deref()));
+    }
 
     // Phase 47: Check if any resolved metric ACTUALLY needs semi-additive expansion.
     // A semi-additive metric only needs CTE treatment when at least one of its
@@ -396,10 +615,34 @@ pub fn expand(
         .iter()
         .map(|d| crate::ident::normalize_ident_part(&d.name))
         .collect();
-    let has_active_semi_additive = resolved_mets
+    let has_active_semi_additive = join_mets
         .iter()
         .any(|m| super::semi_additive::is_active_semi_additive(def, m, &queried_dim_keys));
 
+    // The CTE strategies build their own inner SELECT and take neither a
+    // WHERE nor a HAVING; reject rather than silently ignore the filters.
+    let cte_strategy = if has_active_semi_additive {
+        Some("semi-additive")
+    } else if resolved_mets.iter().any(|m| m.is_window()) {
+        Some("window")
+    } else {
+        None
+    };
+    if let (Some(strategy), Some(first)) = (cte_strategy, filters.first()) {
+        return Err(ExpandError::InvalidDimensionFilter {
+            view_name: view_name.to_string(),
+            dimension: first.dim.name.clone(),
+            reason: format!("dimension filters are not supported with {strategy} metrics"),
+        });
+    }
+    if let (Some(strategy), Some(first)) = (cte_strategy, metric_filters.first()) {
+        return Err(ExpandError::InvalidMetricFilter {
+            view_name: view_name.to_string(),
+            metric: first.met.name.clone(),
+            reason: format!("metric filters are not supported with {strategy} metrics"),
+        });
+    }
+
     if has_active_semi_additive {
         return super::semi_additive::expand_semi_additive(
             view_name,
@@ -407,6 +650,8 @@ pub fn expand(
             &resolved,
             &resolved_mets,
             &resolved_exprs,
+            req.group_by,
+            &aliases,
         );
     }
 
@@ -437,9 +682,32 @@ pub fn expand(
             &resolved,
             &resolved_mets,
             &resolved_exprs,
+            req.group_by,
+            &aliases,
         );
     }
 
+    // 4b. A metrics-only query is one global aggregate row, so a metric
+    //     with no aggregate would be a bare column DuckDB rejects with a
+    //     GROUP BY binder error. Only an expression with no function call
+    //     at all (a column, a literal, arithmetic over them) is provably
+    //     not an aggregate; a call this crate does not know (a UDAF, a
+    //     macro) is left to DuckDB and flagged by `expand_with_diagnostics`.
+    //     Window and semi-additive metrics returned above.
+    if resolved_dims.is_empty() {
+        for met in &resolved_mets {
+            let expr = resolved_exprs
+                .get(&crate::ident::normalize_ident_part(&met.name))
+                .map_or(met.expr.as_str(), String::as_str);
+            if crate::expr_tokens::scan_function_heads(expr).is_empty() {
+                return Err(ExpandError::NonAggregateInGlobalMode {
+                    view_name: view_name.to_string(),
+                    name: met.name.clone(),
+                });
+            }
+        }
+    }
+
     // 5. Build the top-level SELECT.
     //    Dimensions-only (no metrics): SELECT DISTINCT, no GROUP BY.
     //    Metrics-only (no dimensions): SELECT (global aggregate), no GROUP BY.
@@ -458,11 +726,17 @@ pub fn expand(
                 base_expr = crate::expr_tokens::rewrite_qualifier(&base_expr, st, scoped);
             }
         }
-        items.push(SelectItem::new(
-            base_expr,
-            dim.output_type.clone(),
-            quote_stored_ident(&dim.name),
-        ));
+        items.push(
+            SelectItem::new(
+                base_expr,
+                dim.output_type.clone(),
+                aliases.column(&dim.name),
+            )
+            .bucketed(dim.bucket.as_deref())
+            .coalesced(dim.coalesce_to.as_deref())
+            .null_replaced(dim.null_value.as_deref())
+            .collated(dim.collate.as_deref()),
+        );
     }
     for met in &resolved_mets {
         // Look up the pre-computed resolved expression (handles both base +
@@ -475,29 +749,44 @@ pub fn expand(
         items.push(SelectItem::new(
             resolved_expr,
             met.output_type.clone(),
-            quote_stored_ident(&met.name),
+            aliases.column(&met.name),
         ));
     }
 
     // 6. Join resolution via PK/FK graph.
     //    The resolver returns structured edges in emission order; role-playing
     //    scoped joins (e.g. "a__dep_airport") follow the bare joins.
-    let joins = resolve_joins_pkfk(def, &resolved_dims, &resolved_mets, &[]);
+    let joins = resolve_joins_pkfk(def, &join_dims, &join_mets, &[]);
 
     // 7. GROUP BY (only when both dimensions and metrics are present).
     //    Ordinal positions avoid ambiguity when an expression matches its alias
-    //    (e.g. `status AS "status"`) — see push_group_by_ordinals (E-1).
-    let group_by = if !resolved_dims.is_empty() && !resolved_mets.is_empty() {
-        GroupBy::Ordinals(resolved_dims.len())
-    } else {
+    //    (e.g. `status AS "status"`) — see push_group_by_ordinals (E-1) —
+    //    unless the request asked for `GROUP BY ALL`.
+    let group_by = if resolved_mets.is_empty() {
         GroupBy::None
+    } else {
+        GroupBy::dimensions(req.group_by, &items[..resolved_dims.len()])
     };
 
+    // 8. HAVING over each filtered metric's inlined aggregate. Step 1c
+    //    guarantees the GROUP BY above is present.
+    let having = metric_filters
+        .iter()
+        .map(|f| {
+            let expr = resolved_exprs
+                .get(&crate::ident::normalize_ident_part(&f.met.name))
+                .map_or(f.met.expr.as_str(), String::as_str);
+            f.predicate(expr)
+        })
+        .collect();
+
     Ok(SelectSpec {
         distinct,
         items,
         from: FromSource::BaseTable { def, joins },
+        filters: predicates,
         group_by,
+        having,
     }
     .render())
 }
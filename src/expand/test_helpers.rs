@@ -3,6 +3,10 @@
 //! Provides builder functions for common SemanticViewDefinition fixtures,
 //! following the pattern established in `graph/test_helpers.rs`.
 
+use std::collections::BTreeMap;
+
+use super::QueryRequest;
+
 use crate::model::{
     AccessModifier, Dimension, Fact, Join, Materialization, Metric, NonAdditiveDim, NullsOrder,
     SemanticViewDefinition, SortOrder, TableRef, WindowSpec,
@@ -29,6 +33,10 @@ pub(super) fn orders_view() -> SemanticViewDefinition {
                 expr: "region".to_string(),
                 source_table: None,
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -37,6 +45,10 @@ pub(super) fn orders_view() -> SemanticViewDefinition {
                 expr: "status".to_string(),
                 source_table: None,
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -53,6 +65,9 @@ pub(super) fn orders_view() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
             Metric {
                 name: "order_count".to_string(),
@@ -65,11 +80,22 @@ pub(super) fn orders_view() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
         ],
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -103,6 +129,10 @@ pub(super) fn minimal_def(
             expr: dim_expr.to_string(),
             source_table: None,
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         }],
@@ -117,10 +147,21 @@ pub(super) fn minimal_def(
             access: AccessModifier::Public,
             non_additive_by: vec![],
             window_spec: None,
+            filter: None,
+            approx: false,
+            distinct: false,
         }],
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -128,6 +169,42 @@ pub(super) fn minimal_def(
     }
 }
 
+/// Two-table view for query-shape tests: `line_items` (alias `li`, the base
+/// table) joined many-to-one to `orders` (alias `o`).
+///
+/// - dimensions: region = `o.region`, sku = `li.sku`
+/// - facts: `net_price` = `li.price * (1 - li.discount)`
+/// - metrics: revenue = `sum(li.price)`, `line_count` = `count(li.id)`,
+///   `avg_price` = `revenue / line_count` (derived)
+pub(super) fn line_items_orders_view() -> SemanticViewDefinition {
+    SemanticViewDefinition::default()
+        .with_table("li", "line_items", &["id"])
+        .with_table("o", "orders", &["id"])
+        .with_dimension("region", "o.region", Some("o"))
+        .with_dimension("sku", "li.sku", Some("li"))
+        .with_fact("net_price", "li.price * (1 - li.discount)", "li")
+        .with_metric("revenue", "sum(li.price)", Some("li"))
+        .with_metric("line_count", "count(li.id)", Some("li"))
+        .with_metric("avg_price", "revenue / line_count", None)
+        .with_pkfk_join("li_to_o", "li", "o", &["order_id"], &["id"])
+}
+
+/// Single-table `orders` view (alias `o`, primary key `id`) with one
+/// dimension, region = `o.region`. Tests add the facts and metrics they need.
+pub(super) fn region_orders_view() -> SemanticViewDefinition {
+    SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_dimension("region", "o.region", Some("o"))
+}
+
+/// A request for `metrics` grouped by `region`.
+pub(super) fn region_request(metrics: &[&str]) -> QueryRequest {
+    QueryRequest::builder()
+        .dimension("region")
+        .metrics(metrics.iter().copied())
+        .build()
+}
+
 /// Extension trait for test fixture mutations.
 ///
 /// Allows builder-style chaining: `orders_view().with_dimension(...).with_join(...)`
@@ -170,6 +247,10 @@ impl TestFixtureExt for SemanticViewDefinition {
             expr: expr.to_string(),
             source_table: source_table.map(|s| s.to_string()),
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         });
@@ -188,6 +269,9 @@ impl TestFixtureExt for SemanticViewDefinition {
             access: AccessModifier::Public,
             non_additive_by: vec![],
             window_spec: None,
+            filter: None,
+            approx: false,
+            distinct: false,
         });
         self
     }
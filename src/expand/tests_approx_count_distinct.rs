@@ -0,0 +1,59 @@
+//! Metric `approx`: `COUNT(DISTINCT x)` rendered as `approx_count_distinct(x)`,
+//! exact counts otherwise.
+
+use super::*;
+use crate::expand::test_helpers::{region_orders_view, region_request, TestFixtureExt};
+use crate::model::SemanticViewDefinition;
+
+fn def(approx: bool) -> SemanticViewDefinition {
+    let mut def = region_orders_view()
+        .with_fact("buyer", "o.customer_id", "o")
+        .with_metric("customers", "COUNT(DISTINCT buyer)", Some("o"))
+        .with_metric("revenue", "sum(o.amount)", Some("o"))
+        .with_metric("revenue_per_customer", "revenue / customers", None);
+    def.metrics[0].approx = approx;
+    def
+}
+
+#[test]
+fn exact_by_default() {
+    let sql = expand("v", &def(false), &region_request(&["customers"])).unwrap();
+    assert!(
+        sql.contains(r#"COUNT(DISTINCT (o.customer_id)) AS "customers""#),
+        "{sql}"
+    );
+    assert!(!sql.contains("approx_count_distinct"), "{sql}");
+}
+
+#[test]
+fn approx_switches_the_function() {
+    let sql = expand("v", &def(true), &region_request(&["customers", "revenue"])).unwrap();
+    assert!(
+        sql.contains(r#"approx_count_distinct((o.customer_id)) AS "customers""#),
+        "{sql}"
+    );
+    assert!(!sql.contains("COUNT(DISTINCT"), "{sql}");
+    assert!(sql.contains(r#"sum(o.amount) AS "revenue""#), "{sql}");
+}
+
+#[test]
+fn derived_metrics_see_the_approximate_count() {
+    let sql = expand("v", &def(true), &region_request(&["revenue_per_customer"])).unwrap();
+    assert!(
+        sql.contains("(sum(o.amount)) / (approx_count_distinct((o.customer_id)))"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn filter_attaches_to_the_approximate_count() {
+    let mut def = def(true);
+    def.metrics[0].filter = Some("o.status = 'completed'".to_string());
+    let sql = expand("v", &def, &region_request(&["customers"])).unwrap();
+    assert!(
+        sql.contains(
+            "approx_count_distinct((o.customer_id)) FILTER (WHERE o.status = 'completed')"
+        ),
+        "{sql}"
+    );
+}
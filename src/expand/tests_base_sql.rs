@@ -0,0 +1,50 @@
+//! `base_sql` expansion: the base table is replaced by a parenthesised
+//! subquery under the base alias, and joins still hang off that alias.
+
+use super::*;
+use crate::expand::test_helpers::TestFixtureExt;
+use crate::model::SemanticViewDefinition;
+
+fn def() -> SemanticViewDefinition {
+    let mut def = SemanticViewDefinition::default()
+        .with_table("o", "", &["id"])
+        .with_table("c", "customers", &["id"])
+        .with_pkfk_join("order_customer", "o", "c", &["customer_id"], &["id"])
+        .with_dimension("region", "c.region", Some("c"))
+        .with_dimension("status", "o.status", Some("o"))
+        .with_metric("revenue", "sum(o.amount)", Some("o"));
+    def.base_sql = Some(
+        "SELECT * FROM orders WHERE status <> 'test'\n UNION ALL SELECT * FROM archived_orders"
+            .to_string(),
+    );
+    def
+}
+
+fn req(dimensions: &[&str]) -> QueryRequest {
+    QueryRequest::builder()
+        .dimensions(dimensions.iter().copied())
+        .metric("revenue")
+        .build()
+}
+
+#[test]
+fn subquery_replaces_base_table_under_base_alias() {
+    let sql = expand("v", &def(), &req(&["status"])).unwrap();
+    assert!(
+        sql.contains(
+            "FROM (SELECT * FROM orders WHERE status <> 'test'\n UNION ALL SELECT * FROM archived_orders) AS \"o\""
+        ),
+        "{sql}"
+    );
+    assert!(!sql.contains("LEFT JOIN"), "{sql}");
+}
+
+#[test]
+fn joins_apply_on_top_of_the_subquery() {
+    let sql = expand("v", &def(), &req(&["region"])).unwrap();
+    let from = sql.find("FROM (SELECT").expect("subquery FROM");
+    let join = sql
+        .find("LEFT JOIN \"customers\" AS \"c\" ON")
+        .expect("join to customers");
+    assert!(from < join, "{sql}");
+}
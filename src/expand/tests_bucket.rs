@@ -0,0 +1,95 @@
+//! Dimension `bucket`: the `CASE WHEN` emission, shared by the select item and
+//! every `GROUP BY` spelling, and its interaction with the other dimension
+//! wraps and with dimension filters.
+
+use super::*;
+use crate::expand::test_helpers::TestFixtureExt;
+use crate::model::{BucketBin, SemanticViewDefinition};
+
+fn bins(spec: &[(&str, &str)]) -> Vec<BucketBin> {
+    spec.iter()
+        .map(|(when, label)| BucketBin {
+            when: (*when).to_string(),
+            label: (*label).to_string(),
+        })
+        .collect()
+}
+
+fn bucketed_view(spec: &[(&str, &str)]) -> SemanticViewDefinition {
+    let mut def = SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_dimension("size", "o.amount", Some("o"))
+        .with_metric("order_count", "count(o.id)", Some("o"));
+    def.dimensions[0].bucket = Some(bins(spec));
+    def
+}
+
+fn size_by_count(group_by: GroupByMode) -> QueryRequest {
+    QueryRequest::builder()
+        .dimensions(["size"])
+        .metrics(["order_count"])
+        .group_by(group_by)
+        .build()
+}
+
+#[test]
+fn two_bin_bucket() {
+    let def = bucketed_view(&[("< 100", "small"), (">= 100", "large")]);
+    let sql = expand("v", &def, &size_by_count(GroupByMode::Ordinals)).unwrap();
+    assert!(
+        sql.contains(
+            "    CASE WHEN o.amount < 100 THEN 'small' WHEN o.amount >= 100 THEN 'large' END \
+             AS \"size\""
+        ),
+        "{sql}"
+    );
+    assert!(sql.contains("GROUP BY\n    1"), "{sql}");
+}
+
+#[test]
+fn three_bin_bucket_groups_by_the_same_case() {
+    let def = bucketed_view(&[
+        ("< 100", "low"),
+        ("BETWEEN 100 AND 999", "medium"),
+        (">= 1000", "high"),
+    ]);
+    let case = "CASE WHEN o.amount < 100 THEN 'low' \
+                WHEN o.amount BETWEEN 100 AND 999 THEN 'medium' \
+                WHEN o.amount >= 1000 THEN 'high' END";
+    let sql = expand("v", &def, &size_by_count(GroupByMode::Expressions)).unwrap();
+    assert!(sql.contains(&format!("    {case} AS \"size\"")), "{sql}");
+    assert!(sql.contains(&format!("GROUP BY\n    {case}")), "{sql}");
+    assert_eq!(sql.matches("CASE").count(), 2, "{sql}");
+}
+
+#[test]
+fn labels_are_escaped_and_wraps_nest_outside_the_case() {
+    let mut def = bucketed_view(&[("< 0", "don't know")]);
+    def.dimensions[0].coalesce_to = Some("other".to_string());
+    def.dimensions[0].output_type = Some("VARCHAR".to_string());
+    let sql = expand("v", &def, &size_by_count(GroupByMode::Ordinals)).unwrap();
+    assert!(
+        sql.contains(
+            "CAST(COALESCE(NULLIF(CASE WHEN o.amount < 0 THEN 'don''t know' END, ''), 'other') \
+             AS VARCHAR) AS \"size\""
+        ),
+        "{sql}"
+    );
+}
+
+#[test]
+fn dimension_filter_compares_the_label() {
+    let def = bucketed_view(&[("< 100", "small"), (">= 100", "large")]);
+    let req = QueryRequest::builder()
+        .metrics(["order_count"])
+        .filter("size", DimFilterOp::Eq, ["large"])
+        .build();
+    let sql = expand("v", &def, &req).unwrap();
+    assert!(
+        sql.contains(
+            "WHERE CASE WHEN o.amount < 100 THEN 'small' WHEN o.amount >= 100 THEN 'large' END \
+             = 'large'"
+        ),
+        "{sql}"
+    );
+}
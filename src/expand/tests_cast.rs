@@ -15,9 +15,8 @@ fn output_type_on_metric_emits_cast() {
         .with_metric("revenue", "sum(amount)", None);
     def.metrics[0].output_type = Some("BIGINT".to_string());
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(
@@ -36,9 +35,8 @@ fn output_type_on_dimension_emits_cast() {
         ..Default::default()
     });
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region_id")],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(
@@ -53,9 +51,8 @@ fn no_output_type_no_cast() {
         .with_table("orders", "orders", &[])
         .with_metric("revenue", "sum(amount)", None);
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(
@@ -0,0 +1,102 @@
+//! Dimension `coalesce_to`: the `COALESCE(NULLIF(expr, ''), '<value>')` wrap,
+//! its literal escaping, and its ordering relative to casts and collations;
+//! and its NULL-only sibling `null_value`, `COALESCE(expr, '<value>')`.
+
+use super::*;
+use crate::expand::test_helpers::orders_view;
+
+fn coalesced_region(replacement: &str) -> crate::model::SemanticViewDefinition {
+    let mut def = orders_view();
+    def.dimensions[0].coalesce_to = Some(replacement.to_string());
+    def
+}
+
+#[test]
+fn coalesce_wraps_select_item_grouped_by_ordinal() {
+    let def = coalesced_region("Unknown");
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("region")],
+        metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
+    };
+    let sql = expand("orders", &def, &req).unwrap();
+    // The ordinal GROUP BY points at select item 1, so the group key is the
+    // wrapped expression itself.
+    assert!(
+        sql.contains("    COALESCE(NULLIF(region, ''), 'Unknown') AS \"region\""),
+        "{sql}"
+    );
+    assert!(sql.contains("GROUP BY\n    1"), "{sql}");
+    assert_eq!(sql.matches("COALESCE").count(), 1, "{sql}");
+}
+
+#[test]
+fn coalesce_replacement_is_escaped_as_literal() {
+    let def = coalesced_region("n/a'); DROP TABLE orders; --");
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("region")],
+        metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
+    };
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(
+        sql.contains("COALESCE(NULLIF(region, ''), 'n/a''); DROP TABLE orders; --')"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn coalesce_sits_inside_cast_and_before_collate() {
+    let mut def = coalesced_region("Unknown");
+    def.dimensions[0].output_type = Some("VARCHAR".to_string());
+    def.dimensions[0].collate = Some("nocase".to_string());
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("region")],
+        ..Default::default()
+    };
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(
+        sql.contains(
+            "CAST(COALESCE(NULLIF(region, ''), 'Unknown') AS VARCHAR) COLLATE \"nocase\" AS \"region\""
+        ),
+        "{sql}"
+    );
+}
+
+fn null_valued_region(replacement: &str) -> crate::model::SemanticViewDefinition {
+    let mut def = orders_view();
+    def.dimensions[0].null_value = Some(replacement.to_string());
+    def
+}
+
+#[test]
+fn null_value_wraps_select_item_grouped_by_ordinal() {
+    let def = null_valued_region("N/A");
+    let req = QueryRequest::builder()
+        .dimensions(["region", "status"])
+        .metric("total_revenue")
+        .build();
+    let sql = expand("orders", &def, &req).unwrap();
+    // SELECT and GROUP BY cannot drift apart: the GROUP BY names select
+    // items by ordinal, so the group key is the wrapped expression.
+    assert!(
+        sql.contains("    COALESCE(region, 'N/A') AS \"region\""),
+        "{sql}"
+    );
+    assert!(sql.contains("GROUP BY\n    1,\n    2"), "{sql}");
+    assert!(!sql.contains("NULLIF"), "{sql}");
+    // Dimensions without `null_value` are not wrapped.
+    assert!(sql.contains("    status AS \"status\""), "{sql}");
+}
+
+#[test]
+fn null_value_is_escaped_and_sits_inside_cast() {
+    let mut def = null_valued_region("it's unknown");
+    def.dimensions[0].output_type = Some("VARCHAR".to_string());
+    let req = QueryRequest::builder().dimension("region").build();
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(
+        sql.contains("CAST(COALESCE(region, 'it''s unknown') AS VARCHAR) AS \"region\""),
+        "{sql}"
+    );
+}
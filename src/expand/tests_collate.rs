@@ -0,0 +1,89 @@
+//! Dimension `collate`: `COLLATE` emission and its interaction with the
+//! ordinal `GROUP BY`, `output_type` casts, and window-metric CTEs.
+
+use super::*;
+use crate::expand::test_helpers::{orders_view, TestFixtureExt};
+use crate::model::{NullsOrder, SortOrder, WindowOrderBy, WindowSpec};
+
+fn collated_region(collation: &str) -> crate::model::SemanticViewDefinition {
+    let mut def = orders_view();
+    def.dimensions[0].collate = Some(collation.to_string());
+    def
+}
+
+#[test]
+fn collate_applies_to_select_item_grouped_by_ordinal() {
+    let def = collated_region("NOCASE");
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("region")],
+        metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
+    };
+    let sql = expand("orders", &def, &req).unwrap();
+    // GROUP BY is ordinal, so grouping uses the collated select item itself:
+    // the COLLATE must sit on select item 1 and nowhere else.
+    assert!(
+        sql.contains("    region COLLATE \"NOCASE\" AS \"region\""),
+        "{sql}"
+    );
+    assert!(sql.contains("GROUP BY\n    1"), "{sql}");
+    assert_eq!(sql.matches("COLLATE").count(), 1, "{sql}");
+}
+
+#[test]
+fn collate_follows_output_type_cast_and_quotes_each_part() {
+    let mut def = collated_region("nocase.noaccent");
+    def.dimensions[0].output_type = Some("VARCHAR".to_string());
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("region")],
+        ..Default::default()
+    };
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(
+        sql.contains("CAST(region AS VARCHAR) COLLATE \"nocase\".\"noaccent\" AS \"region\""),
+        "{sql}"
+    );
+}
+
+#[test]
+fn collate_carries_into_window_cte() {
+    let def = collated_region("nocase")
+        .with_metric("running_revenue", "SUM(total_revenue)", None)
+        .with_window_spec(
+            "running_revenue",
+            WindowSpec {
+                window_function: "SUM".to_string(),
+                inner_metric: "total_revenue".to_string(),
+                extra_args: vec![],
+                excluding_dims: vec![],
+                partition_dims: vec![],
+                order_by: vec![WindowOrderBy {
+                    expr: "region".to_string(),
+                    order: SortOrder::Asc,
+                    nulls: NullsOrder::Last,
+                }],
+                frame_clause: None,
+            },
+        );
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("region")],
+        metrics: vec![MetricName::new("running_revenue")],
+        ..Default::default()
+    };
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(
+        sql.contains("region COLLATE \"nocase\" AS \"region\""),
+        "{sql}"
+    );
+}
+
+#[test]
+fn no_collate_no_suffix() {
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("region")],
+        metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
+    };
+    let sql = expand("orders", &orders_view(), &req).unwrap();
+    assert!(!sql.contains("COLLATE"), "{sql}");
+}
@@ -0,0 +1,43 @@
+//! `expand_compact`: the `expand()` query with whitespace collapsed.
+
+use super::*;
+use crate::expand::test_helpers::{orders_view, TestFixtureExt};
+
+/// Whitespace-separated tokens; literals here hold no quotes that could hide
+/// a separator, so this is the token stream both forms must share.
+fn tokens(sql: &str) -> Vec<&str> {
+    sql.split_whitespace().collect()
+}
+
+#[test]
+fn compact_is_pretty_on_one_line() {
+    let def = orders_view().with_dimension(
+        "region_label",
+        "coalesce(region, 'no\n  region')",
+        Some("orders"),
+    );
+    let req = QueryRequest::builder()
+        .dimensions(["region", "region_label"])
+        .metric("total_revenue")
+        .build();
+    let pretty = expand("orders", &def, &req).unwrap();
+    let compact = expand_compact("orders", &def, &req).unwrap();
+
+    assert!(pretty.lines().count() > 1);
+    assert_eq!(compact.lines().count(), 2, "only the literal breaks a line");
+    assert!(compact.contains("'no\n  region'"), "{compact}");
+    assert!(!compact.contains("  SELECT") && !compact.contains("\n    "));
+    assert_eq!(tokens(&compact), tokens(&pretty));
+}
+
+#[test]
+fn compact_without_literals_is_a_single_line() {
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .metric("total_revenue")
+        .build();
+    let compact = expand_compact("orders", &orders_view(), &req).unwrap();
+    assert!(!compact.contains('\n'), "{compact}");
+    assert!(compact.starts_with("SELECT "), "{compact}");
+    assert!(compact.ends_with("GROUP BY 1"), "{compact}");
+}
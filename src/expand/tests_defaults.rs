@@ -0,0 +1,84 @@
+//! View `default_dimensions` / `default_metrics`: selected when a request
+//! names nothing, ignored otherwise.
+
+use super::*;
+use crate::expand::test_helpers::TestFixtureExt;
+use crate::model::SemanticViewDefinition;
+
+fn def() -> SemanticViewDefinition {
+    let mut def = SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_dimension("region", "o.region", Some("o"))
+        .with_dimension("status", "o.status", Some("o"))
+        .with_metric("revenue", "sum(o.amount)", Some("o"))
+        .with_metric("order_count", "count(*)", Some("o"));
+    def.default_dimensions = vec!["region".to_string()];
+    def.default_metrics = vec!["revenue".to_string()];
+    def
+}
+
+fn empty() -> QueryRequest {
+    QueryRequest::builder().build()
+}
+
+#[test]
+fn empty_request_selects_the_defaults() {
+    let defaulted = expand("v", &def(), &empty()).unwrap();
+    let explicit = expand(
+        "v",
+        &def(),
+        &QueryRequest::builder()
+            .dimension("region")
+            .metric("revenue")
+            .build(),
+    )
+    .unwrap();
+    assert_eq!(defaulted, explicit);
+}
+
+#[test]
+fn any_requested_name_replaces_the_defaults() {
+    let sql = expand(
+        "v",
+        &def(),
+        &QueryRequest::builder().metric("order_count").build(),
+    )
+    .unwrap();
+    assert!(sql.contains(r#"AS "order_count""#), "{sql}");
+    assert!(!sql.contains(r#"AS "region""#), "{sql}");
+    assert!(!sql.contains(r#"AS "revenue""#), "{sql}");
+}
+
+#[test]
+fn metrics_only_defaults_give_a_global_aggregate() {
+    let mut def = def();
+    def.default_dimensions.clear();
+    let sql = expand("v", &def, &empty()).unwrap();
+    assert!(sql.contains(r#"AS "revenue""#), "{sql}");
+    assert!(!sql.contains("GROUP BY"), "{sql}");
+}
+
+#[test]
+fn without_defaults_an_empty_request_is_still_an_error() {
+    let mut def = def();
+    def.default_dimensions.clear();
+    def.default_metrics.clear();
+    let err = expand("v", &def, &empty()).unwrap_err();
+    assert!(matches!(err, ExpandError::EmptyRequest { .. }), "{err}");
+}
+
+#[test]
+fn plan_and_repro_use_the_defaults_too() {
+    let def = def();
+    let plan = plan::plan("v", &def, &empty()).unwrap();
+    assert_eq!(plan.dimensions.len(), 1);
+    assert_eq!(plan.metrics.len(), 1);
+    let trimmed = repro::minimal_definition("v", &def, &empty()).unwrap();
+    assert_eq!(trimmed.metrics.len(), 1);
+    assert_eq!(trimmed.default_metrics, ["revenue"]);
+
+    // Defaults naming trimmed-away items are dropped with them.
+    let req = QueryRequest::builder().metric("order_count").build();
+    let trimmed = repro::minimal_definition("v", &def, &req).unwrap();
+    assert!(trimmed.default_metrics.is_empty() && trimmed.default_dimensions.is_empty());
+}
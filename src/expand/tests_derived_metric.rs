@@ -4,6 +4,8 @@
 //! code-review 2026-07-11) — behaviour-named files replace the phase-named
 //! archaeology. `use super::*` resolves against `crate::expand`'s re-exports.
 
+use std::collections::BTreeMap;
+
 use super::*;
 use crate::expand::facts::{inline_derived_metrics, toposort_facts};
 use crate::expand::test_helpers::{minimal_def, TestFixtureExt};
@@ -192,9 +194,9 @@ fn expand_derived_metric_correct_sql() {
     let mut def = def;
     def.metrics[0].source_table = Some("o".to_string());
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("profit")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -257,15 +259,23 @@ fn expand_derived_only_no_base_metrics_requested() {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("profit")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -335,15 +345,23 @@ fn resolve_joins_includes_transitive_deps_from_derived() {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("avg_order_value")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -387,15 +405,22 @@ fn expand_derived_metric_with_facts_chain() {
             access: AccessModifier::Public,
         }],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("profit")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -0,0 +1,74 @@
+//! Detail-mode expansion (`QueryRequest::detail`): raw joined rows with no
+//! `DISTINCT`, `GROUP BY`, or aggregation.
+
+use super::*;
+use crate::expand::test_helpers::line_items_orders_view;
+
+fn detail(dims: &[&str], metrics: &[&str], facts: &[&str]) -> QueryRequest {
+    QueryRequest::builder()
+        .dimensions(dims.iter().copied())
+        .metrics(metrics.iter().copied())
+        .facts(facts.iter().copied())
+        .detail(true)
+        .build()
+}
+
+#[test]
+fn dimensions_only_detail_has_no_distinct_or_group_by() {
+    let sql = expand(
+        "v",
+        &line_items_orders_view(),
+        &detail(&["region", "sku"], &[], &[]),
+    )
+    .unwrap();
+    assert!(!sql.contains("DISTINCT"), "{sql}");
+    assert!(!sql.contains("GROUP BY"), "{sql}");
+    assert!(sql.contains("o.region"), "{sql}");
+    assert!(sql.contains("li.sku"), "{sql}");
+    assert!(sql.contains("LEFT JOIN"), "{sql}");
+}
+
+#[test]
+fn same_request_without_detail_is_distinct() {
+    let mut req = detail(&["region", "sku"], &[], &[]);
+    req.detail = false;
+    let sql = expand("v", &line_items_orders_view(), &req).unwrap();
+    assert!(sql.contains("SELECT DISTINCT"), "{sql}");
+}
+
+#[test]
+fn detail_with_facts_matches_fact_query() {
+    let with_detail = expand(
+        "v",
+        &line_items_orders_view(),
+        &detail(&["region"], &[], &["net_price"]),
+    )
+    .unwrap();
+    let mut req = detail(&["region"], &[], &["net_price"]);
+    req.detail = false;
+    assert_eq!(
+        with_detail,
+        expand("v", &line_items_orders_view(), &req).unwrap()
+    );
+}
+
+#[test]
+fn detail_rejects_metrics() {
+    let err = expand(
+        "v",
+        &line_items_orders_view(),
+        &detail(&["region"], &["revenue"], &[]),
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, ExpandError::DetailModeMetrics { ref metrics, .. } if metrics == &["revenue"]),
+        "{err}"
+    );
+    assert!(err.to_string().contains("cannot include metrics"), "{err}");
+}
+
+#[test]
+fn detail_with_nothing_requested_is_empty_request() {
+    let err = expand("v", &line_items_orders_view(), &detail(&[], &[], &[])).unwrap_err();
+    assert!(matches!(err, ExpandError::EmptyRequest { .. }), "{err}");
+}
@@ -0,0 +1,122 @@
+//! Query-time dimension filters (`QueryRequest::dimension_filters`) emitted
+//! as a `WHERE` on the base-table query.
+
+use serde_json::json;
+
+use super::*;
+use crate::expand::test_helpers::{line_items_orders_view, TestFixtureExt};
+use crate::model::{NullsOrder, SortOrder};
+
+fn req(dims: &[&str], metrics: &[&str], filters: Vec<DimensionFilter>) -> QueryRequest {
+    let mut req = QueryRequest::builder()
+        .dimensions(dims.iter().copied())
+        .metrics(metrics.iter().copied())
+        .build();
+    req.dimension_filters = filters;
+    req
+}
+
+fn region_in(values: &[&str]) -> DimensionFilter {
+    DimensionFilter {
+        dimension: DimensionName::new("region"),
+        op: DimFilterOp::In,
+        values: values.iter().map(|v| json!(v)).collect(),
+    }
+}
+
+#[test]
+fn where_precedes_group_by() {
+    let sql = expand(
+        "v",
+        &line_items_orders_view(),
+        &req(&["sku"], &["revenue"], vec![region_in(&["EU", "US"])]),
+    )
+    .unwrap();
+    let where_at = sql.find("WHERE o.region IN ('EU', 'US')").expect(&sql);
+    let group_at = sql.find("GROUP BY").expect(&sql);
+    assert!(where_at < group_at, "{sql}");
+}
+
+#[test]
+fn filtered_dimension_is_joined_without_being_selected() {
+    let sql = expand(
+        "v",
+        &line_items_orders_view(),
+        &req(&[], &["revenue"], vec![region_in(&["EU"])]),
+    )
+    .unwrap();
+    assert!(sql.contains("LEFT JOIN"), "{sql}");
+    assert!(sql.contains("WHERE o.region IN ('EU')"), "{sql}");
+    assert!(!sql.contains(r#"AS "region""#), "{sql}");
+}
+
+#[test]
+fn multiple_filters_are_anded_and_values_escaped() {
+    let filters = vec![
+        DimensionFilter {
+            dimension: DimensionName::new("region"),
+            op: DimFilterOp::NotEq,
+            values: vec![json!("O'Hare")],
+        },
+        DimensionFilter {
+            dimension: DimensionName::new("region"),
+            op: DimFilterOp::NotIn,
+            values: vec![json!("x"), json!("y")],
+        },
+    ];
+    let sql = expand(
+        "v",
+        &line_items_orders_view(),
+        &req(&["sku"], &["revenue"], filters),
+    )
+    .unwrap();
+    assert!(
+        sql.contains("WHERE (o.region <> 'O''Hare')\n  AND (o.region NOT IN ('x', 'y'))"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn filters_apply_in_detail_mode() {
+    let mut r = req(&["sku"], &[], vec![region_in(&["EU"])]);
+    r.detail = true;
+    let sql = expand("v", &line_items_orders_view(), &r).unwrap();
+    assert!(!sql.contains("DISTINCT"), "{sql}");
+    assert!(sql.contains("WHERE o.region IN ('EU')"), "{sql}");
+}
+
+#[test]
+fn filtered_request_is_not_routed_to_materialization() {
+    let def =
+        line_items_orders_view().with_materialization("m", "agg_by_sku", &["sku"], &["revenue"]);
+    let unfiltered = expand("v", &def, &req(&["sku"], &["revenue"], vec![])).unwrap();
+    assert!(unfiltered.contains("agg_by_sku"), "{unfiltered}");
+    let filtered = expand(
+        "v",
+        &def,
+        &req(&["sku"], &["revenue"], vec![region_in(&["EU"])]),
+    )
+    .unwrap();
+    assert!(!filtered.contains("agg_by_sku"), "{filtered}");
+}
+
+#[test]
+fn semi_additive_query_rejects_filters() {
+    let def = line_items_orders_view()
+        .with_dimension("snapshot_date", "li.snapshot_date", Some("li"))
+        .with_non_additive_by(
+            "revenue",
+            &[("snapshot_date", SortOrder::Desc, NullsOrder::Last)],
+        );
+    let err = expand(
+        "v",
+        &def,
+        &req(&["sku"], &["revenue"], vec![region_in(&["EU"])]),
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, ExpandError::InvalidDimensionFilter { .. }),
+        "{err}"
+    );
+    assert!(err.to_string().contains("semi-additive"), "{err}");
+}
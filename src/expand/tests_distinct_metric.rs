@@ -0,0 +1,60 @@
+//! Metric `distinct`: a single `COUNT`/`SUM`/... call rendered with
+//! `DISTINCT` inserted, everywhere the metric's expression is used.
+
+use super::*;
+use crate::expand::test_helpers::{region_orders_view, region_request, TestFixtureExt};
+use crate::model::SemanticViewDefinition;
+
+fn def() -> SemanticViewDefinition {
+    let mut def = region_orders_view()
+        .with_fact("buyer", "o.customer_id", "o")
+        .with_metric("buyers", "count(buyer)", Some("o"))
+        .with_metric("distinct_amounts", "sum(o.amount)", Some("o"))
+        .with_metric("amount_per_buyer", "distinct_amounts / buyers", None);
+    def.metrics[0].distinct = true;
+    def.metrics[1].distinct = true;
+    def
+}
+
+#[test]
+fn count_distinct() {
+    let sql = expand("v", &def(), &region_request(&["buyers"])).unwrap();
+    assert!(
+        sql.contains(r#"count(DISTINCT (o.customer_id)) AS "buyers""#),
+        "{sql}"
+    );
+}
+
+#[test]
+fn sum_distinct() {
+    let sql = expand("v", &def(), &region_request(&["distinct_amounts"])).unwrap();
+    assert!(
+        sql.contains(r#"sum(DISTINCT o.amount) AS "distinct_amounts""#),
+        "{sql}"
+    );
+}
+
+#[test]
+fn off_by_default() {
+    let mut def = def();
+    def.metrics[1].distinct = false;
+    let sql = expand("v", &def, &region_request(&["distinct_amounts"])).unwrap();
+    assert!(
+        sql.contains(r#"sum(o.amount) AS "distinct_amounts""#),
+        "{sql}"
+    );
+}
+
+#[test]
+fn derived_metrics_and_filters_see_the_distinct_call() {
+    let mut def = def();
+    def.metrics[0].filter = Some("o.status = 'completed'".to_string());
+    let sql = expand("v", &def, &region_request(&["amount_per_buyer"])).unwrap();
+    assert!(
+        sql.contains(
+            "(sum(DISTINCT o.amount)) / (count(DISTINCT (o.customer_id)) \
+             FILTER (WHERE o.status = 'completed'))"
+        ),
+        "{sql}"
+    );
+}
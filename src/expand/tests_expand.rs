@@ -12,9 +12,9 @@ use crate::model::SemanticViewDefinition;
 fn test_basic_single_dimension_single_metric() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     let expected = "\
@@ -31,12 +31,12 @@ GROUP BY
 fn test_multiple_dimensions_multiple_metrics() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region"), DimensionName::new("status")],
         metrics: vec![
             MetricName::new("total_revenue"),
             MetricName::new("order_count"),
         ],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(sql.starts_with("SELECT\n"), "Should start with SELECT");
@@ -69,9 +69,8 @@ fn test_multiple_dimensions_multiple_metrics() {
 fn test_global_aggregate_no_dimensions() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(sql.starts_with("SELECT\n"), "Should start with SELECT");
@@ -86,13 +85,66 @@ fn test_global_aggregate_no_dimensions() {
     assert!(!sql.contains("GROUP BY"), "No GROUP BY when no dimensions");
 }
 
+#[test]
+fn test_global_aggregate_rejects_non_aggregate_metric() {
+    let def = SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_dimension("region", "o.region", Some("o"))
+        .with_metric("revenue", "sum(o.amount)", Some("o"))
+        .with_metric("unit_price", "o.price", Some("o"));
+
+    let req = QueryRequest::builder()
+        .metrics(["revenue", "unit_price"])
+        .build();
+    let err = expand("orders", &def, &req).unwrap_err();
+    assert_eq!(err.code(), "NON_AGGREGATE_IN_GLOBAL_MODE");
+    assert!(
+        err.to_string()
+            .contains("metric 'unit_price' has no aggregate"),
+        "{err}"
+    );
+
+    // With dimensions the query is grouped, so the check does not apply.
+    let req = QueryRequest::builder()
+        .dimensions(["region"])
+        .metrics(["unit_price"])
+        .build();
+    assert!(expand("orders", &def, &req).is_ok());
+
+    let req = QueryRequest::builder().metrics(["revenue"]).build();
+    assert!(expand("orders", &def, &req).is_ok());
+}
+
+#[test]
+fn test_global_aggregate_passes_unknown_function_calls_through() {
+    // `mean` is an aggregate alias and `my_udaf` could be a UDAF or macro;
+    // neither is provably a bare column, so DuckDB gets to decide.
+    let def = SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_metric("avg_amount", "mean(o.amount)", Some("o"))
+        .with_metric("custom", "my_udaf(o.amount)", Some("o"))
+        .with_metric("scaled", "o.amount * 2", Some("o"));
+
+    let req = QueryRequest::builder()
+        .metrics(["avg_amount", "custom"])
+        .build();
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(sql.contains("mean(o.amount)"), "{sql}");
+    assert!(sql.contains("my_udaf(o.amount)"), "{sql}");
+
+    // Arithmetic over columns calls nothing, so it cannot aggregate.
+    let req = QueryRequest::builder().metrics(["scaled"]).build();
+    let err = expand("orders", &def, &req).unwrap_err();
+    assert_eq!(err.code(), "NON_AGGREGATE_IN_GLOBAL_MODE");
+}
+
 #[test]
 fn test_identifier_quoting() {
     let def = minimal_def("select", "col", "col", "cnt", "count(*)");
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("col")],
         metrics: vec![MetricName::new("cnt")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     // Base table "select" must be quoted
@@ -109,9 +161,9 @@ fn test_dimension_expression_not_quoted() {
         "sum(amount)",
     );
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("month")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     // Expression appears verbatim in SELECT; GROUP BY uses ordinal position
@@ -122,11 +174,7 @@ fn test_dimension_expression_not_quoted() {
 #[test]
 fn test_empty_request_error() {
     let def = orders_view();
-    let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
-        metrics: vec![],
-    };
+    let req = QueryRequest::default();
     let result = expand("orders", &def, &req);
     assert!(result.is_err());
     match result.unwrap_err() {
@@ -141,9 +189,8 @@ fn test_empty_request_error() {
 fn test_dimensions_only_generates_distinct() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region"), DimensionName::new("status")],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(
@@ -168,16 +215,38 @@ fn test_dimensions_only_generates_distinct() {
     );
 }
 
+#[test]
+fn test_dimensions_only_distinct_across_join_with_filter() {
+    let def = SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_table("c", "customers", &["id"])
+        .with_dimension("status", "o.status", Some("o"))
+        .with_dimension("segment", "c.segment", Some("c"))
+        .with_metric("revenue", "sum(o.amount)", Some("o"))
+        .with_pkfk_join("o_to_c", "o", "c", &["customer_id"], &["id"]);
+    let req = QueryRequest::builder()
+        .dimensions(["segment", "status"])
+        .filter("status", DimFilterOp::NotEq, ["cancelled"])
+        .build();
+    let sql = expand("v", &def, &req).unwrap();
+    assert_eq!(
+        sql,
+        "SELECT DISTINCT\n    c.segment AS \"segment\",\n    o.status AS \"status\"\n\
+         FROM \"orders\" AS \"o\"\n\
+         LEFT JOIN \"customers\" AS \"c\" ON \"o\".\"customer_id\" = \"c\".\"id\"\n\
+         WHERE o.status <> 'cancelled'"
+    );
+}
+
 #[test]
 fn test_metrics_only_still_works() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![
             MetricName::new("total_revenue"),
             MetricName::new("order_count"),
         ],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(sql.starts_with("SELECT\n"), "Should start with SELECT");
@@ -205,9 +274,9 @@ fn test_case_insensitive_dimension_lookup() {
     let def = minimal_def("orders", "Region", "region", "total_revenue", "sum(amount)");
     // Request uses lowercase "region" but definition has "Region"
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     // Should succeed and use the definition's expression
@@ -219,9 +288,9 @@ fn test_case_insensitive_dimension_lookup() {
 fn test_unknown_dimension_error() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("reigon")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let result = expand("orders", &def, &req);
     assert!(result.is_err());
@@ -245,9 +314,8 @@ fn test_unknown_dimension_error() {
 fn test_unknown_metric_error() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("totl_revenue")],
+        ..Default::default()
     };
     let result = expand("orders", &def, &req);
     assert!(result.is_err());
@@ -271,9 +339,9 @@ fn test_unknown_metric_error() {
 fn test_unknown_dimension_no_suggestion() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("xyzzy")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let result = expand("orders", &def, &req);
     assert!(result.is_err());
@@ -289,9 +357,9 @@ fn test_unknown_dimension_no_suggestion() {
 fn test_duplicate_dimension_error() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region"), DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let result = expand("orders", &def, &req);
     assert!(result.is_err());
@@ -308,12 +376,11 @@ fn test_duplicate_dimension_error() {
 fn test_duplicate_metric_error() {
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![
             MetricName::new("total_revenue"),
             MetricName::new("total_revenue"),
         ],
+        ..Default::default()
     };
     let result = expand("orders", &def, &req);
     assert!(result.is_err());
@@ -333,9 +400,8 @@ fn test_case_insensitive_metric_lookup() {
         .with_metric("Total_Revenue", "sum(amount)", None);
     // Request uses lowercase "total_revenue" but definition has "Total_Revenue"
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     // Should succeed and use the definition's name casing in the alias
@@ -415,9 +481,9 @@ fn test_join_excluded_when_not_needed() {
         .with_pkfk_join("cust", "orders", "customers", &["customer_id"], &["id"]);
     // Request only "region" which comes from base table
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(
@@ -430,9 +496,9 @@ fn test_join_excluded_when_not_needed() {
 fn test_no_joins_declared_no_error() {
     let def = minimal_def("orders", "region", "region", "total_revenue", "sum(amount)");
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(
@@ -451,9 +517,9 @@ fn test_dot_qualified_base_table() {
         "count(*)",
     );
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("status")],
         metrics: vec![MetricName::new("order_count")],
+        ..Default::default()
     };
     let sql = expand("jaffle_orders", &def, &req).unwrap();
     // Must produce "jaffle"."raw_orders" not "jaffle.raw_orders"
@@ -473,9 +539,9 @@ fn test_base_table_qualified_with_catalog_schema() {
     def.database_name = Some("memory".to_string());
     def.schema_name = Some("main".to_string());
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders_view", &def, &req).unwrap();
     assert!(
@@ -495,12 +561,12 @@ fn test_join_table_qualified_with_catalog_schema() {
     def.database_name = Some("memory".to_string());
     def.schema_name = Some("main".to_string());
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![
             DimensionName::new("region"),
             DimensionName::new("customer_name"),
         ],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders_view", &def, &req).unwrap();
     assert!(
@@ -520,9 +586,9 @@ fn test_base_table_qualified_schema_only() {
     let mut def = minimal_def("orders", "region", "region", "total_revenue", "sum(amount)");
     def.schema_name = Some("analytics".to_string());
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders_view", &def, &req).unwrap();
     assert!(
@@ -537,9 +603,9 @@ fn test_base_table_qualified_schema_only() {
 fn test_base_table_unqualified_when_no_catalog_schema() {
     let def = minimal_def("orders", "region", "region", "total_revenue", "sum(amount)");
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders_view", &def, &req).unwrap();
     // Should NOT have any dot-qualification beyond what's in the table name itself
@@ -569,9 +635,9 @@ fn test_already_qualified_table_not_double_qualified() {
     def.database_name = Some("memory".to_string());
     def.schema_name = Some("main".to_string());
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders_view", &def, &req).unwrap();
     assert!(
@@ -4,6 +4,8 @@
 //! code-review 2026-07-11) — behaviour-named files replace the phase-named
 //! archaeology. `use super::*` resolves against `crate::expand`'s re-exports.
 
+use std::collections::BTreeMap;
+
 use super::*;
 use crate::model::TableRef;
 
@@ -56,6 +58,14 @@ fn def_with_join_columns() -> crate::model::SemanticViewDefinition {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -68,9 +78,8 @@ fn def_with_join_columns() -> crate::model::SemanticViewDefinition {
 fn table_qualified_dimension_lookup_with_matching_source_table() {
     let def = def_with_join_columns();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("o.region")],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("sales_view", &def, &req).unwrap();
     assert!(
@@ -87,9 +96,8 @@ fn table_qualified_dimension_lookup_with_matching_source_table() {
 fn bare_dimension_name_still_resolves() {
     let def = def_with_join_columns();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("sales_view", &def, &req);
     assert!(
@@ -103,9 +111,8 @@ fn bare_dimension_name_still_resolves() {
 fn table_qualified_unknown_dimension_returns_error() {
     let def = def_with_join_columns();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("o.nosuch")],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("sales_view", &def, &req);
     match result {
@@ -120,9 +127,8 @@ fn table_qualified_unknown_dimension_returns_error() {
 fn table_qualified_metric_lookup_with_matching_source_table() {
     let def = def_with_join_columns();
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("o.revenue")],
+        ..Default::default()
     };
     let sql = expand("sales_view", &def, &req).unwrap();
     assert!(
@@ -163,9 +169,9 @@ fn quoted_stored_names_emit_single_quoted_output_aliases() {
         ..Default::default()
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("order date")],
         metrics: vec![MetricName::new("total sales")],
+        ..Default::default()
     };
     let sql = expand("sales_view", &def, &req).expect("quoted-name query should expand");
     // One canonical pair of quotes per alias — the output columns are named
@@ -223,9 +223,9 @@ fn expand_with_facts_inlines_into_metric() {
     )
     .with_fact("net_price", "price * (1 - discount)", "line_items");
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_net")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -240,9 +240,8 @@ fn expand_without_facts_unchanged() {
         .with_table("orders", "orders", &[])
         .with_metric("total", "SUM(amount)", None);
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("total")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -259,9 +258,8 @@ fn expand_multi_level_facts() {
         .with_fact("net_price", "extended_price * (1 - discount)", "line_items")
         .with_fact("tax_amount", "net_price * tax_rate", "line_items");
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("total_tax")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
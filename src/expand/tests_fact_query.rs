@@ -26,7 +26,7 @@ fn test_fact_query_basic() {
     let req = QueryRequest {
         facts: vec![FactName::new("net_price")],
         dimensions: vec![DimensionName::new("region")],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("test_view", &def, &req).unwrap();
     assert!(
@@ -47,8 +47,7 @@ fn test_fact_query_no_dimensions() {
     let def = multi_table_def();
     let req = QueryRequest {
         facts: vec![FactName::new("net_price")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("test_view", &def, &req).unwrap();
     assert!(
@@ -76,8 +75,7 @@ fn test_fact_query_inline_facts() {
         .with_pkfk_join("li_to_o", "li", "o", &["order_id"], &["id"]);
     let req = QueryRequest {
         facts: vec![FactName::new("line_total")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("test_view", &def, &req).unwrap();
     // line_total's expression should have net_price inlined (parenthesized)
@@ -92,8 +90,7 @@ fn test_fact_query_unknown_fact() {
     let def = multi_table_def();
     let req = QueryRequest {
         facts: vec![FactName::new("nonexistent")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("test_view", &def, &req);
     assert!(result.is_err());
@@ -109,8 +106,7 @@ fn test_fact_query_duplicate_fact() {
     let def = multi_table_def();
     let req = QueryRequest {
         facts: vec![FactName::new("net_price"), FactName::new("net_price")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("test_view", &def, &req);
     assert!(result.is_err());
@@ -126,8 +122,7 @@ fn test_fact_query_private_fact() {
     let def = multi_table_def().with_private_fact("raw_price", "li.price", "li");
     let req = QueryRequest {
         facts: vec![FactName::new("raw_price")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("test_view", &def, &req);
     assert!(result.is_err());
@@ -153,7 +148,7 @@ fn test_fact_path_violation() {
     let req = QueryRequest {
         facts: vec![FactName::new("net_price")],
         dimensions: vec![DimensionName::new("pay_status")],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("test_view", &def, &req);
     assert!(result.is_err());
@@ -179,7 +174,7 @@ fn test_fact_path_valid_linear() {
     let req = QueryRequest {
         facts: vec![FactName::new("detail_val")],
         dimensions: vec![DimensionName::new("region")],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("test_view", &def, &req);
     assert!(result.is_ok(), "Linear path should be valid: {result:?}");
@@ -191,8 +186,7 @@ fn test_fact_query_with_output_type() {
     def.facts[0].output_type = Some("DECIMAL(10,2)".to_string());
     let req = QueryRequest {
         facts: vec![FactName::new("net_price")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("test_view", &def, &req).unwrap();
     assert!(
@@ -204,3 +198,27 @@ fn test_fact_query_with_output_type() {
         "Must include output type: {sql}"
     );
 }
+
+#[test]
+fn test_fact_query_row_level_window_function() {
+    // A row-level window function (ranking line items) is a fact, not a
+    // metric: the facts path emits it per row with no GROUP BY to fight.
+    let def = multi_table_def().with_fact(
+        "price_rank",
+        "row_number() OVER (PARTITION BY li.order_id ORDER BY li.price DESC)",
+        "li",
+    );
+    let req = QueryRequest {
+        facts: vec![FactName::new("price_rank")],
+        dimensions: vec![DimensionName::new("region")],
+        ..Default::default()
+    };
+    let sql = expand("test_view", &def, &req).unwrap();
+    assert!(!sql.contains("GROUP BY"), "{sql}");
+    assert!(
+        sql.contains(
+            "row_number() OVER (PARTITION BY li.order_id ORDER BY li.price DESC) AS \"price_rank\""
+        ),
+        "{sql}"
+    );
+}
@@ -12,8 +12,8 @@ fn test_facts_metrics_mutual_exclusion() {
     let def = orders_view().with_fact("line_total", "quantity * price", "orders");
     let req = QueryRequest {
         facts: vec![FactName::new("line_total")],
-        dimensions: vec![],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let result = expand("test_view", &def, &req);
     assert!(result.is_err());
@@ -34,8 +34,7 @@ fn test_empty_request_with_facts_is_not_empty() {
     let def = orders_view().with_fact("line_total", "quantity * price", "orders");
     let req = QueryRequest {
         facts: vec![FactName::new("line_total")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("test_view", &def, &req);
     // The expand should NOT return EmptyRequest. It may return another error
@@ -33,7 +33,7 @@ fn test_facts_path_role_playing_dimension_raises_ambiguous_path() {
     let req = QueryRequest {
         facts: vec![FactName::new("order_note")],
         dimensions: vec![DimensionName::new("city")],
-        metrics: vec![],
+        ..Default::default()
     };
     let err = expand("orders", &def, &req).unwrap_err();
     match err {
@@ -71,8 +71,7 @@ fn fact_on_role_playing_table_errors_ambiguous() {
         .with_pkfk_join("arr_airport", "orders", "a", &["arr_code"], &["code"]);
     let req = QueryRequest {
         facts: vec![FactName::new("airport_city")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let err = expand("orders", &def, &req).unwrap_err();
     match err {
@@ -113,8 +112,7 @@ fn fact_on_descendant_of_role_playing_table_errors_ambiguous() {
         .with_pkfk_join("airport_region", "a", "r", &["region_id"], &["region_id"]);
     let req = QueryRequest {
         facts: vec![FactName::new("region_name")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let err = expand("orders", &def, &req).unwrap_err();
     match err {
@@ -138,7 +136,7 @@ fn test_facts_path_single_relationship_dimension_ok() {
     let req = QueryRequest {
         facts: vec![FactName::new("order_note")],
         dimensions: vec![DimensionName::new("city")],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(
@@ -169,7 +167,7 @@ fn test_facts_path_convergent_parent_dimension_not_ambiguous() {
     let req = QueryRequest {
         facts: vec![FactName::new("net_price")],
         dimensions: vec![DimensionName::new("region")],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).expect("convergent parent must not raise AmbiguousPath");
     assert!(sql.contains("net_price"), "fact survives: {sql}");
@@ -190,9 +188,9 @@ fn test_metrics_path_convergent_parent_dimension_not_ambiguous() {
         .with_pkfk_join("li_to_o", "li", "orders", &["order_id"], &["id"])
         .with_pkfk_join("pay_to_o", "pay", "orders", &["order_id"], &["id"]);
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).expect("convergent parent must not raise AmbiguousPath");
     assert!(sql.contains("SUM"), "metric survives: {sql}");
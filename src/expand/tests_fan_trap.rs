@@ -4,6 +4,8 @@
 //! code-review 2026-07-11) — behaviour-named files replace the phase-named
 //! archaeology. `use super::*` resolves against `crate::expand`'s re-exports.
 
+use std::collections::BTreeMap;
+
 use super::*;
 use crate::expand::test_helpers::minimal_def;
 use crate::model::{Cardinality, Dimension, Join, Metric, SemanticViewDefinition, TableRef};
@@ -86,6 +88,14 @@ fn fan_trap_three_table_def() -> SemanticViewDefinition {
         ],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -97,9 +107,9 @@ fn fan_trap_three_table_def() -> SemanticViewDefinition {
 fn fan_trap_one_to_many_blocked() {
     let def = fan_trap_three_table_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("status")],
         metrics: vec![MetricName::new("order_count")],
+        ..Default::default()
     };
     let result = expand("sales", &def, &req);
     assert!(result.is_err(), "Fan trap must block the query");
@@ -117,9 +127,9 @@ fn fan_trap_one_to_many_blocked() {
 fn fan_trap_many_to_one_safe() {
     let def = fan_trap_three_table_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("revenue")],
+        ..Default::default()
     };
     let result = expand("sales", &def, &req);
     assert!(
@@ -169,15 +179,23 @@ fn fan_trap_one_to_one_safe() {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("detail")],
         metrics: vec![MetricName::new("cnt")],
+        ..Default::default()
     };
     let result = expand("test", &def, &req);
     assert!(
@@ -191,9 +209,9 @@ fn fan_trap_one_to_one_safe() {
 fn fan_trap_same_table_safe() {
     let def = fan_trap_three_table_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("status")],
         metrics: vec![MetricName::new("revenue")],
+        ..Default::default()
     };
     let result = expand("sales", &def, &req);
     assert!(
@@ -207,9 +225,9 @@ fn fan_trap_same_table_safe() {
 fn fan_trap_no_joins_safe() {
     let def = minimal_def("orders", "region", "region", "cnt", "COUNT(*)");
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("cnt")],
+        ..Default::default()
     };
     let result = expand("test", &def, &req);
     assert!(result.is_ok(), "No joins must be safe: {:?}", result.err());
@@ -225,9 +243,9 @@ fn fan_trap_transitive_chain() {
         ..Default::default()
     });
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("status")],
         metrics: vec![MetricName::new("customer_count")],
+        ..Default::default()
     };
     let result = expand("sales", &def, &req);
     assert!(
@@ -252,9 +270,9 @@ fn fan_trap_derived_metric_blocked() {
         ..Default::default()
     });
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("status")],
         metrics: vec![MetricName::new("avg_order")],
+        ..Default::default()
     };
     let result = expand("sales", &def, &req);
     assert!(result.is_err(), "Derived metric fan trap must be detected");
@@ -355,7 +373,7 @@ fn cyclic_relationships_do_not_hang_expand() {
     let req = QueryRequest {
         dimensions: vec!["d".into()],
         metrics: vec!["m".into()],
-        facts: vec![],
+        ..Default::default()
     };
     let _ = expand("v", &def, &req);
 }
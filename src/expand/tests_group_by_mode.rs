@@ -0,0 +1,152 @@
+//! `QueryRequest::group_by`: ordinal `GROUP BY` by default, `GROUP BY ALL` or
+//! the dimension expressions on request, and only for queries that group.
+
+use super::*;
+use crate::expand::test_helpers::{orders_view, TestFixtureExt};
+use crate::model::{NullsOrder, SortOrder, WindowSpec};
+
+fn req(dims: &[&str], metrics: &[&str], mode: GroupByMode) -> QueryRequest {
+    QueryRequest::builder()
+        .dimensions(dims.iter().copied())
+        .metrics(metrics.iter().copied())
+        .group_by(mode)
+        .build()
+}
+
+#[test]
+fn ordinals_by_default() {
+    let sql = expand(
+        "orders",
+        &orders_view(),
+        &QueryRequest::builder()
+            .dimensions(["region", "status"])
+            .metric("total_revenue")
+            .build(),
+    )
+    .unwrap();
+    assert!(sql.ends_with("GROUP BY\n    1,\n    2"), "{sql}");
+}
+
+#[test]
+fn group_by_all_replaces_the_ordinals() {
+    let sql = expand(
+        "orders",
+        &orders_view(),
+        &req(&["region", "status"], &["total_revenue"], GroupByMode::All),
+    )
+    .unwrap();
+    assert!(sql.ends_with("\nGROUP BY ALL"), "{sql}");
+    assert_eq!(sql.matches("GROUP BY").count(), 1, "{sql}");
+}
+
+#[test]
+fn expressions_repeat_each_dimension_as_selected() {
+    let mut def = orders_view().with_dimension("upper_region", "upper(region)", None);
+    def.dimensions
+        .iter_mut()
+        .find(|d| d.name == "status")
+        .unwrap()
+        .null_value = Some("unknown".to_string());
+    let sql = expand(
+        "orders",
+        &def,
+        &req(
+            &["region", "status", "upper_region"],
+            &["total_revenue"],
+            GroupByMode::Expressions,
+        ),
+    )
+    .unwrap();
+    assert!(
+        sql.ends_with("GROUP BY\n    region,\n    COALESCE(status, 'unknown'),\n    upper(region)"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn expressions_mode_leaves_ungrouped_queries_alone() {
+    for (dims, metrics) in [
+        (&["region"][..], &[][..]),
+        (&[][..], &["total_revenue"][..]),
+    ] {
+        let sql = expand(
+            "orders",
+            &orders_view(),
+            &req(dims, metrics, GroupByMode::Expressions),
+        )
+        .unwrap();
+        assert!(!sql.contains("GROUP BY"), "{sql}");
+    }
+}
+
+#[test]
+fn group_by_all_needs_dimensions_and_metrics() {
+    for (dims, metrics) in [
+        (&["region"][..], &[][..]),
+        (&[][..], &["total_revenue"][..]),
+    ] {
+        let err = expand(
+            "orders",
+            &orders_view(),
+            &req(dims, metrics, GroupByMode::All),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ExpandError::GroupByAllWithoutAggregation { .. }),
+            "{err}"
+        );
+        assert_eq!(err.code(), "GROUP_BY_ALL_WITHOUT_AGGREGATION");
+        assert!(err.to_string().contains("GROUP BY ALL"), "{err}");
+    }
+}
+
+#[test]
+fn cte_strategies_honour_the_mode() {
+    let semi = orders_view()
+        .with_dimension("snapshot_date", "snapshot_date", None)
+        .with_non_additive_by(
+            "total_revenue",
+            &[("snapshot_date", SortOrder::Desc, NullsOrder::Last)],
+        );
+    let sql = expand(
+        "orders",
+        &semi,
+        &req(&["region"], &["total_revenue"], GroupByMode::All),
+    )
+    .unwrap();
+    assert!(sql.ends_with("\nGROUP BY ALL"), "{sql}");
+
+    let window = orders_view()
+        .with_metric("running_revenue", "", None)
+        .with_window_spec(
+            "running_revenue",
+            WindowSpec {
+                window_function: "SUM".to_string(),
+                inner_metric: "total_revenue".to_string(),
+                ..Default::default()
+            },
+        );
+    let sql = expand(
+        "orders",
+        &window,
+        &req(&["region"], &["running_revenue"], GroupByMode::All),
+    )
+    .unwrap();
+    assert!(sql.contains("\n    GROUP BY ALL\n)"), "{sql}");
+
+    let sql = expand(
+        "orders",
+        &semi,
+        &req(&["region"], &["total_revenue"], GroupByMode::Expressions),
+    )
+    .unwrap();
+    // The outer query reads the snapshot CTE, so its keys are CTE columns.
+    assert!(sql.ends_with("GROUP BY\n    \"region\""), "{sql}");
+    let sql = expand(
+        "orders",
+        &window,
+        &req(&["region"], &["running_revenue"], GroupByMode::Expressions),
+    )
+    .unwrap();
+    assert!(sql.contains("\n    GROUP BY\n        region\n)"), "{sql}");
+}
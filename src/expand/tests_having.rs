@@ -0,0 +1,125 @@
+//! Query-time metric filters (`QueryRequest::metric_filters`) emitted as a
+//! `HAVING` after the `GROUP BY`, next to the dimension filters' `WHERE`.
+
+use super::*;
+use crate::expand::test_helpers::{line_items_orders_view, TestFixtureExt};
+
+#[test]
+fn pre_and_post_filters_in_one_query() {
+    let req = QueryRequest::builder()
+        .dimensions(["region"])
+        .metrics(["revenue"])
+        .filter("region", DimFilterOp::NotEq, ["EU"])
+        .having("revenue", DimFilterOp::Gt, [1000])
+        .build();
+    let sql = expand("v", &line_items_orders_view(), &req).unwrap();
+    let where_at = sql.find("WHERE o.region <> 'EU'").expect(&sql);
+    let group_at = sql.find("GROUP BY").expect(&sql);
+    let having_at = sql.find("HAVING sum(li.price) > 1000").expect(&sql);
+    assert!(where_at < group_at && group_at < having_at, "{sql}");
+}
+
+#[test]
+fn filtered_metric_need_not_be_selected() {
+    let req = QueryRequest::builder()
+        .dimensions(["sku"])
+        .metrics(["revenue"])
+        .having("line_count", DimFilterOp::GtEq, [2])
+        .having("line_count", DimFilterOp::Lt, [10])
+        .build();
+    let sql = expand("v", &line_items_orders_view(), &req).unwrap();
+    assert!(
+        sql.ends_with("HAVING (count(li.id) >= 2)\n  AND (count(li.id) < 10)"),
+        "{sql}"
+    );
+    assert!(!sql.contains(r#"AS "line_count""#), "{sql}");
+}
+
+#[test]
+fn derived_metric_filter_uses_inlined_expression() {
+    let req = QueryRequest::builder()
+        .dimensions(["sku"])
+        .metrics(["revenue"])
+        .having("avg_price", DimFilterOp::LtEq, [2.5])
+        .build();
+    let sql = expand("v", &line_items_orders_view(), &req).unwrap();
+    assert!(
+        sql.contains("HAVING ((sum(li.price)) / (count(li.id))) <= 2.5"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn compound_metric_filters_are_compared_as_a_whole() {
+    let def = line_items_orders_view().with_metric(
+        "any_flag",
+        "bool_or(li.price > 100) OR bool_and(li.price > 0)",
+        Some("li"),
+    );
+    let req = QueryRequest::builder()
+        .dimensions(["sku"])
+        .metrics(["revenue"])
+        .having("any_flag", DimFilterOp::Eq, [true])
+        .having("revenue", DimFilterOp::Gt, [0])
+        .build();
+    let sql = expand("v", &def, &req).unwrap();
+    assert!(
+        sql.ends_with(
+            "HAVING ((bool_or(li.price > 100) OR bool_and(li.price > 0)) = TRUE)\n  \
+             AND (sum(li.price) > 0)"
+        ),
+        "{sql}"
+    );
+}
+
+#[test]
+fn post_filter_without_group_by_is_rejected() {
+    let cases = [
+        // Global aggregate: metrics, no dimensions.
+        QueryRequest::builder().metrics(["revenue"]),
+        // Dimensions-only DISTINCT.
+        QueryRequest::builder().dimensions(["sku"]),
+    ];
+    for builder in cases {
+        let req = builder.having("revenue", DimFilterOp::Gt, [0]).build();
+        let err = expand("v", &line_items_orders_view(), &req).unwrap_err();
+        assert_eq!(err.code(), "INVALID_METRIC_FILTER", "{err}");
+        assert!(err.to_string().contains("need a GROUP BY"), "{err}");
+    }
+}
+
+#[test]
+fn bad_metric_filters_are_rejected() {
+    let unknown = QueryRequest::builder()
+        .dimensions(["sku"])
+        .metrics(["revenue"])
+        .having("revnue", DimFilterOp::Gt, [0])
+        .build();
+    let err = expand("v", &line_items_orders_view(), &unknown).unwrap_err();
+    assert!(matches!(err, ExpandError::UnknownMetric { .. }), "{err}");
+
+    let two_values = QueryRequest::builder()
+        .dimensions(["sku"])
+        .metrics(["revenue"])
+        .having("revenue", DimFilterOp::Gt, [1, 2])
+        .build();
+    let err = expand("v", &line_items_orders_view(), &two_values).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("invalid filter on metric 'revenue': Gt takes exactly one value"),
+        "{err}"
+    );
+}
+
+#[test]
+fn filtered_request_is_not_routed_to_materialization() {
+    let def =
+        line_items_orders_view().with_materialization("m", "agg_by_sku", &["sku"], &["revenue"]);
+    let req = QueryRequest::builder()
+        .dimensions(["sku"])
+        .metrics(["revenue"])
+        .having("revenue", DimFilterOp::Gt, [0])
+        .build();
+    let sql = expand("v", &def, &req).unwrap();
+    assert!(!sql.contains("agg_by_sku"), "{sql}");
+}
@@ -43,9 +43,9 @@ LEFT JOIN \"customers\" AS \"c\" ON \"o\".\"customer_id\" = \"c\".\"id\"
 GROUP BY
     1";
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("total_qty")],
+        ..Default::default()
     };
     let sql_a = expand("test", &li_o_c_def(true), &req).unwrap();
     let sql_b = expand("test", &li_o_c_def(false), &req).unwrap();
@@ -68,9 +68,9 @@ fn sg2_two_parent_child_picks_connecting_edge() {
         .with_pkfk_join("li_to_p", "li", "p", &["product_id"], &["id"])
         .with_pkfk_join("li_to_o", "li", "o", &["order_id"], &["id"]);
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("qty")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     let expected = "\
@@ -107,9 +107,9 @@ fn ld_li_o_def() -> SemanticViewDefinition {
 #[test]
 fn sg10_fk_side_chain_includes_intermediate_join() {
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("detail_qty")],
+        ..Default::default()
     };
     let sql = expand("test", &ld_li_o_def(), &req).unwrap();
     let expected = "\
@@ -137,8 +137,7 @@ GROUP BY
 fn sg10_fact_source_chain_includes_intermediate_join() {
     let req = QueryRequest {
         facts: vec![FactName::new("detail_amount")],
-        dimensions: vec![],
-        metrics: vec![],
+        ..Default::default()
     };
     let sql = expand("test", &ld_li_o_def(), &req).unwrap();
     let expected = "\
@@ -163,9 +162,9 @@ fn sg12_bare_alias_containing_double_underscore_joins_normally() {
         .with_metric("cnt", "count(*)", Some("o"))
         .with_pkfk_join("o_to_dim", "o", "my__dim", &["dim_id"], &["id"]);
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("dim_name")],
         metrics: vec![MetricName::new("cnt")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     let expected = "\
@@ -205,9 +204,9 @@ fn sg12_role_playing_scoped_alias_format_preserved() {
             &["airport_code"],
         );
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("city")],
         metrics: vec![MetricName::new("departure_count")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -0,0 +1,54 @@
+//! Metric `filter` expansion: `<expr> FILTER (WHERE <filter>)` on the
+//! filtered metric only, carried into derived metrics that reference it.
+
+use super::*;
+use crate::expand::test_helpers::{region_orders_view, region_request, TestFixtureExt};
+use crate::model::SemanticViewDefinition;
+
+fn def() -> SemanticViewDefinition {
+    let mut def = region_orders_view()
+        .with_fact("net", "o.amount - o.discount", "o")
+        .with_metric("revenue", "sum(o.amount)", Some("o"))
+        .with_metric("completed_revenue", "sum(o.amount)", Some("o"))
+        .with_metric("completed_net", "sum(net)", Some("o"))
+        .with_metric("completed_share", "completed_revenue / revenue", None);
+    def.metrics[1].filter = Some("o.status = 'completed'".to_string());
+    def.metrics[2].filter = Some("o.status = 'completed'".to_string());
+    def
+}
+
+#[test]
+fn filter_clause_appended_to_filtered_metric_only() {
+    let sql = expand(
+        "v",
+        &def(),
+        &region_request(&["revenue", "completed_revenue"]),
+    )
+    .unwrap();
+    assert!(
+        sql.contains(
+            r#"sum(o.amount) FILTER (WHERE o.status = 'completed') AS "completed_revenue""#
+        ),
+        "{sql}"
+    );
+    assert!(sql.contains(r#"sum(o.amount) AS "revenue""#), "{sql}");
+    assert_eq!(sql.matches("FILTER").count(), 1, "{sql}");
+}
+
+#[test]
+fn facts_inline_into_filtered_metric() {
+    let sql = expand("v", &def(), &region_request(&["completed_net"])).unwrap();
+    assert!(
+        sql.contains("sum((o.amount - o.discount)) FILTER (WHERE o.status = 'completed')"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn derived_metric_inherits_filtered_operand() {
+    let sql = expand("v", &def(), &region_request(&["completed_share"])).unwrap();
+    assert!(
+        sql.contains("(sum(o.amount) FILTER (WHERE o.status = 'completed')) / (sum(o.amount))"),
+        "{sql}"
+    );
+}
@@ -0,0 +1,147 @@
+//! `QueryRequest::output_aliases`: per-request output column names, applied
+//! to the outermost `AS` only and unique across the SELECT.
+
+use super::*;
+use crate::expand::test_helpers::{orders_view, TestFixtureExt};
+use crate::model::{NullsOrder, SortOrder, WindowSpec};
+
+fn expand_err(req: &QueryRequest) -> ExpandError {
+    expand("orders", &orders_view(), req).unwrap_err()
+}
+
+fn assert_invalid(err: &ExpandError, name: &str, reason: &str) {
+    assert!(
+        matches!(err, ExpandError::InvalidOutputAlias { name: n, .. } if n == name),
+        "{err}"
+    );
+    assert_eq!(err.code(), "INVALID_OUTPUT_ALIAS");
+    assert!(err.to_string().contains(reason), "{err}");
+}
+
+#[test]
+fn dimension_and_metric_aliases_override_the_as_clause() {
+    let req = QueryRequest::builder()
+        .dimensions(["region", "status"])
+        .metric("total_revenue")
+        .alias("region", "Region Name")
+        .alias("TOTAL_REVENUE", "Revenue \"USD\"")
+        .build();
+    let sql = expand("orders", &orders_view(), &req).unwrap();
+    assert!(sql.contains("    region AS \"Region Name\",\n"), "{sql}");
+    assert!(sql.contains("    status AS \"status\",\n"), "{sql}");
+    assert!(
+        sql.contains("sum(amount) AS \"Revenue \"\"USD\"\"\"\n"),
+        "{sql}"
+    );
+    assert!(sql.ends_with("GROUP BY\n    1,\n    2"), "{sql}");
+}
+
+#[test]
+fn fact_query_columns_take_aliases() {
+    let def = orders_view().with_fact("net", "amount - discount", "orders");
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .fact("net")
+        .alias("net", "Net Amount")
+        .build();
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(sql.contains("AS \"Net Amount\""), "{sql}");
+    assert!(sql.contains("region AS \"region\""), "{sql}");
+}
+
+#[test]
+fn cte_strategies_alias_only_the_outer_select() {
+    let semi = orders_view()
+        .with_dimension("snapshot_date", "snapshot_date", None)
+        .with_non_additive_by(
+            "total_revenue",
+            &[("snapshot_date", SortOrder::Desc, NullsOrder::Last)],
+        );
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .metric("total_revenue")
+        .alias("region", "Region")
+        .alias("total_revenue", "Revenue")
+        .build();
+    let sql = expand("orders", &semi, &req).unwrap();
+    assert!(sql.contains("region AS \"region\","), "{sql}");
+    assert!(sql.contains("\"region\" AS \"Region\""), "{sql}");
+    assert!(sql.contains("END) AS \"Revenue\""), "{sql}");
+
+    let window = orders_view()
+        .with_metric("running_revenue", "", None)
+        .with_window_spec(
+            "running_revenue",
+            WindowSpec {
+                window_function: "SUM".to_string(),
+                inner_metric: "total_revenue".to_string(),
+                ..Default::default()
+            },
+        );
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .metric("running_revenue")
+        .alias("region", "Region")
+        .alias("running_revenue", "Running")
+        .build();
+    let sql = expand("orders", &window, &req).unwrap();
+    assert!(sql.contains("\"region\" AS \"Region\""), "{sql}");
+    assert!(sql.contains(") AS \"Running\""), "{sql}");
+}
+
+#[test]
+fn routed_materialization_renames_its_columns() {
+    let def = orders_view().with_materialization(
+        "by_region",
+        "orders_by_region",
+        &["region"],
+        &["total_revenue"],
+    );
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .metric("total_revenue")
+        .alias("region", "Region")
+        .build();
+    let sql = expand("orders", &def, &req).unwrap();
+    assert!(sql.contains("\"region\" AS \"Region\""), "{sql}");
+    assert!(sql.contains("    \"total_revenue\"\nFROM"), "{sql}");
+}
+
+#[test]
+fn alias_colliding_with_another_column_is_rejected() {
+    let req = QueryRequest::builder()
+        .dimensions(["region", "status"])
+        .alias("region", "STATUS")
+        .build();
+    assert_invalid(&expand_err(&req), "status", "also used by 'region'");
+
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .metric("total_revenue")
+        .alias("region", "x")
+        .alias("total_revenue", "X")
+        .build();
+    assert_invalid(&expand_err(&req), "total_revenue", "also used by 'region'");
+}
+
+#[test]
+fn unusable_aliases_are_rejected() {
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .alias("status", "Status")
+        .build();
+    assert_invalid(&expand_err(&req), "status", "not a requested");
+
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .alias("region", "  ")
+        .build();
+    assert_invalid(&expand_err(&req), "region", "empty");
+
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .alias("region", "a")
+        .alias("REGION", "b")
+        .build();
+    assert_invalid(&expand_err(&req), "region", "more than once");
+}
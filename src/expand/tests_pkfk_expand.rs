@@ -4,6 +4,8 @@
 //! code-review 2026-07-11) — behaviour-named files replace the phase-named
 //! archaeology. `use super::*` resolves against `crate::expand`'s re-exports.
 
+use std::collections::BTreeMap;
+
 use super::*;
 use crate::model::{Dimension, Join, Metric, SemanticViewDefinition, TableRef};
 
@@ -53,6 +55,14 @@ fn pkfk_two_table_def() -> SemanticViewDefinition {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -121,6 +131,14 @@ fn pkfk_three_table_def() -> SemanticViewDefinition {
         ],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -133,9 +151,9 @@ fn pkfk_three_table_def() -> SemanticViewDefinition {
 fn test_pkfk_on_clause_simple() {
     let def = pkfk_two_table_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("total_amount")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -182,6 +200,14 @@ fn test_pkfk_on_clause_composite() {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -189,9 +215,9 @@ fn test_pkfk_on_clause_composite() {
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("detail")],
         metrics: vec![MetricName::new("cnt")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -209,9 +235,9 @@ fn test_pkfk_on_clause_composite() {
 fn test_pkfk_left_join_emitted() {
     let def = pkfk_two_table_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("total_amount")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -234,9 +260,9 @@ fn test_pkfk_left_join_emitted() {
 fn test_pkfk_transitive_join_inclusion() {
     let def = pkfk_three_table_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("total_qty")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -253,9 +279,9 @@ fn test_pkfk_transitive_join_inclusion() {
 fn test_pkfk_pruning() {
     let def = pkfk_three_table_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("product")],
         metrics: vec![MetricName::new("total_qty")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -269,9 +295,9 @@ fn test_pkfk_topological_order() {
     let mut def = pkfk_three_table_def();
     def.joins.reverse();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("total_qty")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     let o_pos = sql
@@ -285,3 +311,59 @@ fn test_pkfk_topological_order() {
         "orders (closer to root) must appear before customers (further from root) in topo order: {sql}"
     );
 }
+
+#[test]
+fn test_pkfk_self_join_via_second_alias() {
+    // employees (e) -> employees (m): a self-join is two TABLES aliases over
+    // one physical table; `source_table` and the join key on the alias.
+    let def = SemanticViewDefinition {
+        tables: vec![
+            TableRef {
+                alias: "e".to_string(),
+                table: "employees".to_string(),
+                pk_columns: vec!["id".to_string()],
+                ..Default::default()
+            },
+            TableRef {
+                alias: "m".to_string(),
+                table: "employees".to_string(),
+                pk_columns: vec!["id".to_string()],
+                ..Default::default()
+            },
+        ],
+        dimensions: vec![Dimension {
+            name: "manager_name".to_string(),
+            expr: "m.name".to_string(),
+            source_table: Some("m".to_string()),
+            ..Default::default()
+        }],
+        metrics: vec![Metric {
+            name: "headcount".to_string(),
+            expr: "count(e.id)".to_string(),
+            source_table: Some("e".to_string()),
+            ..Default::default()
+        }],
+        joins: vec![Join {
+            table: "m".to_string(),
+            from_alias: "e".to_string(),
+            fk_columns: vec!["manager_id".to_string()],
+            ref_columns: vec!["id".to_string()],
+            name: Some("reports_to".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    crate::graph::validate_graph(&def).unwrap();
+    let req = QueryRequest {
+        dimensions: vec![DimensionName::new("manager_name")],
+        metrics: vec![MetricName::new("headcount")],
+        ..Default::default()
+    };
+    let sql = expand("test", &def, &req).unwrap();
+    assert!(sql.contains("FROM \"employees\" AS \"e\""), "{sql}");
+    assert!(
+        sql.contains("LEFT JOIN \"employees\" AS \"m\" ON \"e\".\"manager_id\" = \"m\".\"id\""),
+        "{sql}"
+    );
+    assert!(sql.contains("m.name AS \"manager_name\""), "{sql}");
+}
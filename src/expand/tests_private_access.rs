@@ -4,6 +4,8 @@
 //! code-review 2026-07-11) — behaviour-named files replace the phase-named
 //! archaeology. `use super::*` resolves against `crate::expand`'s re-exports.
 
+use std::collections::BTreeMap;
+
 use super::*;
 use crate::model::{AccessModifier, Dimension, Metric, SemanticViewDefinition};
 
@@ -31,6 +33,14 @@ fn make_def_with_private_metric() -> SemanticViewDefinition {
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -68,6 +78,14 @@ fn make_def_with_private_and_derived() -> SemanticViewDefinition {
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -79,9 +97,9 @@ fn make_def_with_private_and_derived() -> SemanticViewDefinition {
 fn private_metric_rejected() {
     let def = make_def_with_private_metric();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("secret_cost")],
+        ..Default::default()
     };
     match expand("test_view", &def, &req) {
         Err(ExpandError::PrivateMetric { name, .. }) => {
@@ -95,9 +113,8 @@ fn private_metric_rejected() {
 fn private_metric_error_message_contains_private() {
     let def = make_def_with_private_metric();
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("secret_cost")],
+        ..Default::default()
     };
     let err = expand("test_view", &def, &req).unwrap_err();
     let msg = err.to_string();
@@ -115,9 +132,9 @@ fn private_metric_error_message_contains_private() {
 fn public_metric_still_works() {
     let def = make_def_with_private_metric();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("test_view", &def, &req).unwrap();
     assert!(
@@ -130,9 +147,9 @@ fn public_metric_still_works() {
 fn derived_metric_referencing_private_base_works() {
     let def = make_def_with_private_and_derived();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("profit")],
+        ..Default::default()
     };
     let sql = expand("test_view", &def, &req).unwrap();
     assert!(sql.contains("profit"), "SQL should contain profit metric");
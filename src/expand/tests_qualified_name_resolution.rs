@@ -12,9 +12,9 @@ fn test_qualified_dimension_wrong_table_errors() {
     // SG-14: no fallback to "any dimension with that bare name".
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("x.region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let err = expand("orders", &def, &req).unwrap_err();
     match err {
@@ -32,9 +32,8 @@ fn test_qualified_metric_wrong_table_errors() {
             .clear_metrics()
             .with_metric("total_revenue", "sum(amount)", Some("orders"));
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![MetricName::new("x.total_revenue")],
+        ..Default::default()
     };
     let err = expand("orders", &def, &req).unwrap_err();
     match err {
@@ -51,9 +50,9 @@ fn test_base_alias_qualification_matches_unqualified_declaration() {
     // item; qualifying the request with the base alias must resolve.
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("orders.region")],
         metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
     };
     let sql = expand("orders", &def, &req).unwrap();
     assert!(sql.contains("region AS \"region\""), "SQL: {sql}");
@@ -65,12 +64,11 @@ fn test_bare_and_qualified_same_dimension_rejected_as_duplicate() {
     // `region` and `orders.region` cannot emit the column twice.
     let def = orders_view();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![
             DimensionName::new("region"),
             DimensionName::new("orders.region"),
         ],
-        metrics: vec![],
+        ..Default::default()
     };
     let err = expand("orders", &def, &req).unwrap_err();
     match err {
@@ -88,12 +86,11 @@ fn test_bare_and_qualified_same_metric_rejected_as_duplicate() {
             .clear_metrics()
             .with_metric("total_revenue", "sum(amount)", Some("orders"));
     let req = QueryRequest {
-        facts: vec![],
-        dimensions: vec![],
         metrics: vec![
             MetricName::new("total_revenue"),
             MetricName::new("orders.total_revenue"),
         ],
+        ..Default::default()
     };
     let err = expand("orders", &def, &req).unwrap_err();
     match err {
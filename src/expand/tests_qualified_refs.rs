@@ -4,6 +4,8 @@
 //! code-review 2026-07-11) — behaviour-named files replace the phase-named
 //! archaeology. `use super::*` resolves against `crate::expand`'s re-exports.
 
+use std::collections::BTreeMap;
+
 use super::*;
 use crate::model::{Dimension, Join, Metric, SemanticViewDefinition, TableRef};
 
@@ -44,6 +46,14 @@ fn qualified_ref_def() -> SemanticViewDefinition {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -56,9 +66,9 @@ fn qualified_ref_def() -> SemanticViewDefinition {
 fn test_expand_qualified_column_refs_verbatim() {
     let def = qualified_ref_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("total_amount")],
+        ..Default::default()
     };
     let sql = expand("p27_test", &def, &req).unwrap();
 
@@ -119,6 +129,14 @@ fn test_expand_multiple_qualified_refs_different_tables() {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -126,12 +144,12 @@ fn test_expand_multiple_qualified_refs_different_tables() {
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![
             DimensionName::new("customer_name"),
             DimensionName::new("order_region"),
         ],
         metrics: vec![MetricName::new("total_amount")],
+        ..Default::default()
     };
     let sql = expand("p27_test", &def, &req).unwrap();
 
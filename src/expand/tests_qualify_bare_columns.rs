@@ -0,0 +1,70 @@
+//! `QueryRequest::qualify_bare_columns`: bare column expressions get their
+//! table's alias once the view has joins, everything else is left as written.
+
+use super::*;
+use crate::expand::test_helpers::TestFixtureExt;
+use crate::model::SemanticViewDefinition;
+
+/// Orders joined to customers; both tables have a `region` column.
+fn def() -> SemanticViewDefinition {
+    SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_table("c", "customers", &["id"])
+        .with_pkfk_join("customer", "o", "c", &["customer_id"], &["id"])
+        .with_dimension("region", "region", None)
+        .with_dimension("customer_name", "name", Some("c"))
+        .with_dimension("upper_region", "upper(region)", Some("o"))
+        .with_metric("revenue", "sum(amount)", Some("o"))
+        .with_metric("revenue_again", "revenue", None)
+}
+
+fn request(qualify: bool) -> QueryRequest {
+    QueryRequest::builder()
+        .dimensions(["region", "customer_name", "upper_region"])
+        .metrics(["revenue", "revenue_again"])
+        .qualify_bare_columns(qualify)
+        .build()
+}
+
+#[test]
+fn off_by_default() {
+    let sql = expand("v", &def(), &request(false)).unwrap();
+    assert!(sql.contains(r#"    region AS "region""#), "{sql}");
+    assert!(sql.contains(r#"    name AS "customer_name""#), "{sql}");
+}
+
+#[test]
+fn bare_columns_get_their_table_alias() {
+    let sql = expand("v", &def(), &request(true)).unwrap();
+    // No source_table: the base table's alias.
+    assert!(sql.contains(r#""o".region AS "region""#), "{sql}");
+    assert!(sql.contains(r#""c".name AS "customer_name""#), "{sql}");
+}
+
+#[test]
+fn other_expressions_are_left_alone() {
+    let sql = expand("v", &def(), &request(true)).unwrap();
+    assert!(sql.contains(r#"upper(region) AS "upper_region""#), "{sql}");
+    assert!(sql.contains(r#"sum(amount) AS "revenue""#), "{sql}");
+    // `revenue` names a metric, so it is inlined rather than qualified.
+    assert!(sql.contains(r#"(sum(amount)) AS "revenue_again""#), "{sql}");
+}
+
+#[test]
+fn single_table_view_is_unchanged() {
+    let single = SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_dimension("region", "region", None)
+        .with_metric("revenue", "sum(amount)", Some("o"));
+    let req = |qualify| {
+        QueryRequest::builder()
+            .dimension("region")
+            .metric("revenue")
+            .qualify_bare_columns(qualify)
+            .build()
+    };
+    assert_eq!(
+        expand("v", &single, &req(true)).unwrap(),
+        expand("v", &single, &req(false)).unwrap()
+    );
+}
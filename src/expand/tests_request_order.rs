@@ -0,0 +1,124 @@
+//! Output column order: dimensions in request order, then metrics in request
+//! order, regardless of declaration order, source table, or join shape.
+
+use super::*;
+use crate::model::{Dimension, Join, Metric, SemanticViewDefinition, TableRef};
+
+/// orders (o) -> customers (c), with a dimension and a metric on each table.
+fn two_table_def() -> SemanticViewDefinition {
+    let table = |alias: &str, table: &str| TableRef {
+        alias: alias.to_string(),
+        table: table.to_string(),
+        pk_columns: vec!["id".to_string()],
+        ..Default::default()
+    };
+    let dim = |name: &str, expr: &str, src: &str| Dimension {
+        name: name.to_string(),
+        expr: expr.to_string(),
+        source_table: Some(src.to_string()),
+        ..Default::default()
+    };
+    let metric = |name: &str, expr: &str, src: &str| Metric {
+        name: name.to_string(),
+        expr: expr.to_string(),
+        source_table: Some(src.to_string()),
+        ..Default::default()
+    };
+    SemanticViewDefinition {
+        tables: vec![table("o", "orders"), table("c", "customers")],
+        dimensions: vec![
+            dim("region", "o.region", "o"),
+            dim("status", "o.status", "o"),
+            dim("segment", "c.segment", "c"),
+        ],
+        metrics: vec![
+            metric("revenue", "sum(o.amount)", "o"),
+            metric("order_count", "count(o.id)", "o"),
+            Metric {
+                name: "avg_order".to_string(),
+                expr: "revenue / order_count".to_string(),
+                ..Default::default()
+            },
+        ],
+        joins: vec![Join {
+            table: "c".to_string(),
+            from_alias: "o".to_string(),
+            fk_columns: vec!["customer_id".to_string()],
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn run(dims: &[&str], metrics: &[&str]) -> String {
+    let req = QueryRequest::builder()
+        .dimensions(dims.iter().copied())
+        .metrics(metrics.iter().copied())
+        .build();
+    expand("sales", &two_table_def(), &req).unwrap()
+}
+
+/// Output column names of the top-level SELECT, in order.
+fn select_aliases(sql: &str) -> Vec<String> {
+    let body = &sql[sql.find("SELECT\n").unwrap() + 7..sql.find("\nFROM ").unwrap()];
+    body.lines()
+        .map(|line| {
+            let alias = &line[line.rfind(" AS \"").unwrap() + 5..];
+            alias
+                .trim_end_matches(',')
+                .trim_end_matches('"')
+                .to_string()
+        })
+        .collect()
+}
+
+/// The positions listed in an ordinal `GROUP BY`.
+fn group_by_ordinals(sql: &str) -> Vec<usize> {
+    let tail = &sql[sql.rfind("GROUP BY\n").unwrap() + 9..];
+    tail.split(',').map(|o| o.trim().parse().unwrap()).collect()
+}
+
+#[test]
+fn dimensions_then_metrics_in_request_order_across_joins() {
+    let dims = ["segment", "status", "region"];
+    let metrics = ["avg_order", "order_count", "revenue"];
+    let sql = run(&dims, &metrics);
+    let aliases = select_aliases(&sql);
+    assert_eq!(aliases, [&dims[..], &metrics[..]].concat(), "{sql}");
+
+    // Each ordinal names the dimension at that request position.
+    let ordinals = group_by_ordinals(&sql);
+    assert_eq!(ordinals, [1, 2, 3], "{sql}");
+    let grouped: Vec<&str> = ordinals.iter().map(|&o| aliases[o - 1].as_str()).collect();
+    assert_eq!(grouped, dims, "{sql}");
+}
+
+#[test]
+fn request_order_wins_over_declaration_order() {
+    // Both requests name the same items; only the order differs.
+    for (dims, metrics) in [
+        (["region", "segment"], ["revenue", "order_count"]),
+        (["segment", "region"], ["order_count", "revenue"]),
+    ] {
+        let sql = run(&dims, &metrics);
+        assert_eq!(
+            select_aliases(&sql),
+            [&dims[..], &metrics[..]].concat(),
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn group_by_expressions_follow_request_order() {
+    let req = QueryRequest::builder()
+        .dimensions(["status", "segment"])
+        .metric("revenue")
+        .group_by(GroupByMode::Expressions)
+        .build();
+    let sql = expand("sales", &two_table_def(), &req).unwrap();
+    assert!(
+        sql.ends_with("GROUP BY\n    o.status,\n    c.segment"),
+        "{sql}"
+    );
+}
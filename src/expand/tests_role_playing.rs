@@ -4,6 +4,8 @@
 //! code-review 2026-07-11) — behaviour-named files replace the phase-named
 //! archaeology. `use super::*` resolves against `crate::expand`'s re-exports.
 
+use std::collections::BTreeMap;
+
 use super::*;
 use crate::expand::test_helpers::TestFixtureExt;
 use crate::model::{Cardinality, Dimension, Join, Metric, SemanticViewDefinition, TableRef};
@@ -87,6 +89,14 @@ fn flights_airports_def() -> SemanticViewDefinition {
         ],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -133,9 +143,9 @@ fn descendant_of_role_playing_table_errors_ambiguous() {
     // as a dimension directly on `a` does.
     let def = flights_airports_regions_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region_name")],
         metrics: vec![MetricName::new("arrival_count")],
+        ..Default::default()
     };
     let err = expand("test_flights", &def, &req).unwrap_err();
     match err {
@@ -173,9 +183,9 @@ fn descendant_through_single_relationship_still_resolves() {
     def.metrics
         .retain(|m| m.name != "arrival_count" && m.name != "total_flights");
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region_name")],
         metrics: vec![MetricName::new("departure_count")],
+        ..Default::default()
     };
     let sql = expand("test_flights", &def, &req).expect("single-path descendant must resolve");
     assert!(sql.contains("region_name"), "SQL: {sql}");
@@ -185,9 +195,9 @@ fn descendant_through_single_relationship_still_resolves() {
 fn using_metric_generates_scoped_join_alias() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("city")],
         metrics: vec![MetricName::new("departure_count")],
+        ..Default::default()
     };
     let sql = expand("test_flights", &def, &req).unwrap();
     assert!(
@@ -204,12 +214,12 @@ fn using_metric_generates_scoped_join_alias() {
 fn two_using_metrics_generate_two_scoped_joins() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("carrier")],
         metrics: vec![
             MetricName::new("departure_count"),
             MetricName::new("arrival_count"),
         ],
+        ..Default::default()
     };
     let sql = expand("test_flights", &def, &req).unwrap();
     assert!(
@@ -226,9 +236,9 @@ fn two_using_metrics_generate_two_scoped_joins() {
 fn dimension_rewritten_to_scoped_alias() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("city")],
         metrics: vec![MetricName::new("departure_count")],
+        ..Default::default()
     };
     let sql = expand("test_flights", &def, &req).unwrap();
     assert!(
@@ -241,9 +251,8 @@ fn dimension_rewritten_to_scoped_alias() {
 fn ambiguous_dimension_without_using_produces_error() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("city")],
-        metrics: vec![],
+        ..Default::default()
     };
     let result = expand("test_flights", &def, &req);
     assert!(result.is_err(), "Ambiguous dimension must produce error");
@@ -296,9 +305,9 @@ fn non_ambiguous_single_relationship_works_without_using() {
         ..Default::default()
     });
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("revenue")],
+        ..Default::default()
     };
     let result = expand("test", &def, &req);
     assert!(
@@ -312,9 +321,9 @@ fn non_ambiguous_single_relationship_works_without_using() {
 fn base_table_dimension_works_unchanged() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("carrier")],
         metrics: vec![MetricName::new("departure_count")],
+        ..Default::default()
     };
     let sql = expand("test_flights", &def, &req).unwrap();
     assert!(
@@ -363,15 +372,23 @@ fn fan_trap_detection_works_with_using_paths() {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("carrier")],
         metrics: vec![MetricName::new("airport_count")],
+        ..Default::default()
     };
     let result = expand("test", &def, &req);
     assert!(result.is_err(), "Fan trap must still be detected");
@@ -385,9 +402,9 @@ fn fan_trap_detection_works_with_using_paths() {
 fn derived_metric_with_two_using_resolves_both_joins() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("carrier")],
         metrics: vec![MetricName::new("total_flights")],
+        ..Default::default()
     };
     let sql = expand("test_flights", &def, &req).unwrap();
     assert!(
@@ -424,15 +441,23 @@ fn metric_using_from_base_table_no_unnecessary_join() {
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("region")],
         metrics: vec![MetricName::new("cnt")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -479,15 +504,23 @@ fn backward_compat_no_using_expands_as_before() {
         }],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
         comment: None,
     };
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("customer_name")],
         metrics: vec![MetricName::new("revenue")],
+        ..Default::default()
     };
     let sql = expand("test", &def, &req).unwrap();
     assert!(
@@ -504,9 +537,9 @@ fn backward_compat_no_using_expands_as_before() {
 fn ambiguous_dimension_with_derived_metric_using_both_paths() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("city")],
         metrics: vec![MetricName::new("total_flights")],
+        ..Default::default()
     };
     let result = expand("test_flights", &def, &req);
     assert!(
@@ -523,9 +556,9 @@ fn ambiguous_dimension_with_derived_metric_using_both_paths() {
 fn scoped_join_on_clause_uses_correct_fk_pk() {
     let def = flights_airports_def();
     let req = QueryRequest {
-        facts: vec![],
         dimensions: vec![DimensionName::new("city")],
         metrics: vec![MetricName::new("departure_count")],
+        ..Default::default()
     };
     let sql = expand("test_flights", &def, &req).unwrap();
     assert!(
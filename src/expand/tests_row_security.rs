@@ -0,0 +1,106 @@
+//! `row_security`: the view's predicate filters the base table in every
+//! expansion, whatever the request asks for.
+
+use super::*;
+use crate::expand::test_helpers::TestFixtureExt;
+use crate::model::SemanticViewDefinition;
+
+const PREDICATE: &str = "o.tenant_id = current_setting('app.tenant')";
+
+/// orders (o, base) -> customers (c), restricted to the current tenant.
+fn def() -> SemanticViewDefinition {
+    let mut def = SemanticViewDefinition::default()
+        .with_table("o", "orders", &["id"])
+        .with_table("c", "customers", &["id"])
+        .with_pkfk_join("customer", "o", "c", &["customer_id"], &["id"])
+        .with_dimension("region", "c.region", Some("c"))
+        .with_dimension("status", "o.status", Some("o"))
+        .with_metric("revenue", "sum(o.amount)", Some("o"))
+        .with_fact("amount", "o.amount", "o");
+    def.row_security = Some(PREDICATE.to_string());
+    def
+}
+
+fn assert_secured(sql: &str) {
+    assert!(
+        sql.contains(&format!(
+            r#"FROM (SELECT * FROM "orders" AS "o" WHERE {PREDICATE}) AS "o""#
+        )),
+        "{sql}"
+    );
+}
+
+#[test]
+fn applied_with_no_user_filters() {
+    for req in [
+        QueryRequest::builder().dimension("status").build(),
+        QueryRequest::builder().metric("revenue").build(),
+        QueryRequest::builder()
+            .dimension("region")
+            .metric("revenue")
+            .build(),
+        QueryRequest::builder().fact("amount").build(),
+    ] {
+        assert_secured(&expand("v", &def(), &req).unwrap());
+    }
+}
+
+#[test]
+fn applied_alongside_dimension_filters() {
+    let req = QueryRequest::builder()
+        .dimension("status")
+        .metric("revenue")
+        .filter("region", DimFilterOp::Eq, ["EU"])
+        .build();
+    let sql = expand("v", &def(), &req).unwrap();
+    assert_secured(&sql);
+    assert!(sql.contains("WHERE c.region = 'EU'"), "{sql}");
+}
+
+#[test]
+fn applied_to_base_sql_views() {
+    let mut def = def();
+    def.base_sql = Some("SELECT * FROM orders WHERE NOT deleted".to_string());
+    let sql = expand(
+        "v",
+        &def,
+        &QueryRequest::builder().metric("revenue").build(),
+    )
+    .unwrap();
+    assert!(
+        sql.contains(&format!(
+            r#"FROM (SELECT * FROM (SELECT * FROM orders WHERE NOT deleted) AS "o" WHERE {PREDICATE}) AS "o""#
+        )),
+        "{sql}"
+    );
+}
+
+#[test]
+fn disables_materialization_routing() {
+    let def = def().with_materialization("m", "revenue_by_status", &["status"], &["revenue"]);
+    let req = QueryRequest::builder()
+        .dimension("status")
+        .metric("revenue")
+        .build();
+    let sql = expand("v", &def, &req).unwrap();
+    assert!(!sql.contains("revenue_by_status"), "{sql}");
+    assert_secured(&sql);
+}
+
+#[test]
+fn child_views_keep_the_parent_predicate() {
+    let parent = def();
+    let mut child = SemanticViewDefinition {
+        row_security: Some("o.status <> 'void'".to_string()),
+        ..SemanticViewDefinition::default()
+    };
+    child.inherit_from(&parent);
+    assert_eq!(
+        child.row_security.as_deref(),
+        Some("(o.tenant_id = current_setting('app.tenant')) AND (o.status <> 'void')")
+    );
+
+    let mut bare = SemanticViewDefinition::default();
+    bare.inherit_from(&parent);
+    assert_eq!(bare.row_security.as_deref(), Some(PREDICATE));
+}
@@ -0,0 +1,38 @@
+//! `expand_with_header`: a leading comment naming the view and requested items.
+
+use super::*;
+use crate::expand::test_helpers::orders_view;
+
+#[test]
+fn header_names_view_and_items() {
+    let req = QueryRequest::builder()
+        .dimension("region")
+        .metric("total_revenue")
+        .build();
+    let sql = expand_with_header("orders", &orders_view(), &req).unwrap();
+    let (header, body) = sql.split_once('\n').unwrap();
+    assert_eq!(
+        header,
+        "-- semantic_view: orders; dimensions: region; metrics: total_revenue"
+    );
+    assert_eq!(body, expand("orders", &orders_view(), &req).unwrap());
+}
+
+#[test]
+fn header_omits_empty_sections() {
+    let req = QueryRequest::builder().metric("total_revenue").build();
+    let sql = expand_with_header("orders", &orders_view(), &req).unwrap();
+    assert!(
+        sql.starts_with("-- semantic_view: orders; metrics: total_revenue\nSELECT"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn header_stays_on_one_line() {
+    let req = QueryRequest::builder().dimension("region").build();
+    let sql = expand_with_header("multi\nline", &orders_view(), &req).unwrap();
+    let (header, body) = sql.split_once('\n').unwrap();
+    assert_eq!(header, "-- semantic_view: multi line; dimensions: region");
+    assert!(body.starts_with("SELECT"), "{body}");
+}
@@ -0,0 +1,60 @@
+//! `SqlTooLarge`: the cap on expanded SQL length.
+
+use super::*;
+use crate::expand::test_helpers::{orders_view, TestFixtureExt};
+
+/// `orders_view()` plus `n` dimensions whose expressions are each `width`
+/// bytes of `coalesce(...)` padding.
+fn wide_view(n: usize, width: usize) -> (crate::model::SemanticViewDefinition, QueryRequest) {
+    let mut def = orders_view().clear_dimensions();
+    let mut dims = Vec::with_capacity(n);
+    for i in 0..n {
+        let name = format!("d{i}");
+        let pad = "x".repeat(width);
+        def = def.with_dimension(&name, &format!("coalesce(region, '{pad}')"), None);
+        dims.push(DimensionName::new(&name));
+    }
+    let req = QueryRequest {
+        dimensions: dims,
+        metrics: vec![MetricName::new("total_revenue")],
+        ..Default::default()
+    };
+    (def, req)
+}
+
+#[test]
+fn synthetic_huge_selection_hits_default_cap() {
+    // 2_000 dimensions x ~9 KiB expression ≈ 18 MiB of SELECT list.
+    let (def, req) = wide_view(2_000, 9 * 1024);
+    let err = expand("orders", &def, &req).unwrap_err();
+    match err {
+        ExpandError::SqlTooLarge { len, max_len, .. } => {
+            assert_eq!(max_len, DEFAULT_MAX_EXPANDED_SQL_BYTES);
+            assert!(len > max_len, "{len} <= {max_len}");
+        }
+        other => panic!("expected SqlTooLarge, got {other}"),
+    }
+}
+
+#[test]
+fn explicit_limit_is_applied_and_reported() {
+    let (def, req) = wide_view(3, 16);
+    let sql = expand("orders", &def, &req).unwrap();
+
+    assert_eq!(
+        expand_with_limit("orders", &def, &req, sql.len()).unwrap(),
+        sql,
+        "SQL exactly at the cap is allowed"
+    );
+    let err = expand_with_limit("orders", &def, &req, sql.len() - 1).unwrap_err();
+    assert!(matches!(err, ExpandError::SqlTooLarge { .. }), "{err}");
+    let msg = err.to_string();
+    assert!(
+        msg.contains(&format!("expanded SQL is {} bytes", sql.len())),
+        "{msg}"
+    );
+    assert!(
+        msg.contains(&format!("{}-byte limit", sql.len() - 1)),
+        "{msg}"
+    );
+}
@@ -1,6 +1,12 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt;
 use std::marker::PhantomData;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::model::SemanticViewDefinition;
+
 /// A query-request name (dimension or metric) with case- **and quote**-
 /// insensitive equality and hashing.
 ///
@@ -109,6 +115,20 @@ impl<K> AsRef<str> for CiName<K> {
     }
 }
 
+/// Serialized as the name exactly as written, so a request round-trips with
+/// its original spelling and quoting.
+impl<K> Serialize for CiName<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de, K> Deserialize<'de> for CiName<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
 impl<K> From<String> for CiName<K> {
     fn from(s: String) -> Self {
         Self::new(s)
@@ -139,19 +159,448 @@ pub type MetricName = CiName<MetricKind>;
 /// A fact name with case- and quote-insensitive equality and hashing (see [`CiName`]).
 pub type FactName = CiName<FactKind>;
 
+/// Comparison operator of a [`DimensionFilter`] or [`MetricFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DimFilterOp {
+    /// `dim = value` (exactly one value).
+    Eq,
+    /// `dim <> value` (exactly one value). Rows where the dimension is NULL
+    /// never match, as in SQL.
+    NotEq,
+    /// `dim IN (values...)` (at least one value).
+    In,
+    /// `dim NOT IN (values...)` (at least one value).
+    NotIn,
+    /// `dim > value` (exactly one value).
+    Gt,
+    /// `dim >= value` (exactly one value).
+    GtEq,
+    /// `dim < value` (exactly one value).
+    Lt,
+    /// `dim <= value` (exactly one value).
+    LtEq,
+}
+
+impl DimFilterOp {
+    /// Whether the operator compares against exactly one value (`In` and
+    /// `NotIn` take a non-empty list).
+    #[must_use]
+    pub fn takes_one_value(self) -> bool {
+        !matches!(self, Self::In | Self::NotIn)
+    }
+}
+
+/// A structured query-time predicate on a dimension's value — the safe
+/// alternative to splicing a raw `WHERE` fragment.
+///
+/// The dimension resolves like a requested one (it need not be selected), and
+/// is compared as the query outputs it: after its `bucket`, `coalesce_to`,
+/// `collate`, and `output_type` cast. Values are JSON scalars: strings are emitted as
+/// escaped string literals, numbers and booleans as SQL literals; `null`,
+/// arrays, and objects are rejected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DimensionFilter {
+    pub dimension: DimensionName,
+    pub op: DimFilterOp,
+    pub values: Vec<serde_json::Value>,
+}
+
+/// A structured predicate on a metric's aggregated value, applied after
+/// aggregation as a `HAVING` condition — where a [`DimensionFilter`] is the
+/// pre-aggregation `WHERE` counterpart.
+///
+/// The metric resolves like a requested one and need not be selected. Only
+/// grouped queries (at least one dimension and one metric) take metric
+/// filters, and window or semi-additive metrics cannot be filtered. Values
+/// follow the [`DimensionFilter`] rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricFilter {
+    pub metric: MetricName,
+    pub op: DimFilterOp,
+    pub values: Vec<serde_json::Value>,
+}
+
+/// How a grouped query spells its `GROUP BY`.
+///
+/// Only queries that aggregate by dimensions group at all: the base path
+/// with both dimensions and metrics, and the semi-additive and window
+/// strategies' aggregation steps. Every mode groups by the same keys; they
+/// differ only in the SQL text. Ordinals are the default for compatibility —
+/// every release before this option emitted them — and because they cannot
+/// be captured by a physical column that shares a dimension's output name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GroupByMode {
+    /// `GROUP BY 1, 2, …` over the leading dimension select items.
+    #[default]
+    Ordinals,
+    /// `GROUP BY ALL`: `DuckDB` groups by every non-aggregate select item,
+    /// which are exactly the dimensions. Only valid for requests with both
+    /// dimensions and metrics.
+    All,
+    /// `GROUP BY` repeating each dimension's select expression (after any
+    /// `bucket`, `coalesce_to` / `null_value`, cast, and collation), for readers and
+    /// tools that do not resolve ordinals.
+    Expressions,
+}
+
+impl GroupByMode {
+    /// Returns `true` for the default (`Ordinals`), which a serialized
+    /// [`QueryRequest`] omits.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        matches!(self, Self::Ordinals)
+    }
+}
+
 /// A request to expand a semantic view into SQL.
 ///
 /// Contains the names of dimensions and metrics to include in the query.
-/// At least one dimension, metric, or fact must be specified. Supported modes:
+/// At least one dimension, metric, or fact must be specified, unless the view
+/// declares `default_dimensions` / `default_metrics`, which an empty request
+/// selects instead (see [`QueryRequest::or_view_defaults`]). Supported modes:
 /// - Dimensions only: `SELECT DISTINCT` (no aggregation)
 /// - Metrics only: global aggregate (no `GROUP BY`)
 /// - Both: grouped aggregation with `GROUP BY`
 /// - Facts mode: row-level query (facts cannot be combined with metrics)
-#[derive(Debug, Clone)]
+/// - Detail mode (`detail: true`): row-level query over the requested
+///   dimensions (and facts) with neither `DISTINCT` nor `GROUP BY`, one output
+///   row per joined row. Metrics are rejected in detail mode.
+///
+/// Requests (de)serialize with serde as a JSON object keyed by field name.
+/// Every field may be omitted and takes its default; defaulted fields are
+/// left out when serializing, and enum values use their Rust variant names
+/// (`"group_by": "All"`, `"op": "NotIn"`).
+///
+/// ```
+/// # use semantic_views::expand::{GroupByMode, QueryRequest};
+/// let req: QueryRequest =
+///     serde_json::from_str(r#"{"dimensions": ["region"], "group_by": "All"}"#).unwrap();
+/// assert_eq!(req.group_by, GroupByMode::All);
+/// assert!(req.metrics.is_empty());
+/// assert_eq!(
+///     serde_json::to_string(&req).unwrap(),
+///     r#"{"dimensions":["region"],"group_by":"All"}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct QueryRequest {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub dimensions: Vec<DimensionName>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub metrics: Vec<MetricName>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub facts: Vec<FactName>,
+    /// Return the raw joined rows instead of de-duplicating dimension-only
+    /// requests with `SELECT DISTINCT`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub detail: bool,
+    /// Row predicates combined with `AND` into the query's `WHERE`,
+    /// applied before aggregation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dimension_filters: Vec<DimensionFilter>,
+    /// Group predicates combined with `AND` into the query's `HAVING`,
+    /// applied after aggregation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metric_filters: Vec<MetricFilter>,
+    /// Prefix dimension and metric expressions that are a single unqualified
+    /// column (`region`) with their table's alias, so a joined table with a
+    /// column of the same name cannot make them ambiguous. Applies to views
+    /// with relationships; any other expression is emitted as written.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub qualify_bare_columns: bool,
+    /// How to spell the `GROUP BY` of a grouped query.
+    #[serde(default, skip_serializing_if = "GroupByMode::is_default")]
+    pub group_by: GroupByMode,
+    /// Output column names to use instead of definition names, keyed by the
+    /// requested dimension, metric, or fact name (matched like any other
+    /// request name). Every output name in the SELECT must stay unique.
+    /// Serialized sorted by key so equal requests produce equal JSON.
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
+    pub output_aliases: HashMap<String, String>,
+}
+
+fn serialize_sorted<S: Serializer>(
+    map: &HashMap<String, String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    map.iter()
+        .collect::<std::collections::BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
+impl QueryRequest {
+    /// Start building a request fluently; see [`QueryRequestBuilder`].
+    ///
+    /// ```
+    /// # use semantic_views::expand::{DimensionName, MetricName, QueryRequest};
+    /// let req = QueryRequest::builder()
+    ///     .dimension("region")
+    ///     .metric("total_revenue")
+    ///     .build();
+    /// assert_eq!(req.dimensions, vec![DimensionName::new("region")]);
+    /// assert_eq!(req.metrics, vec![MetricName::new("total_revenue")]);
+    /// assert!(req.facts.is_empty() && !req.detail);
+    /// ```
+    pub fn builder() -> QueryRequestBuilder {
+        QueryRequestBuilder::default()
+    }
+
+    /// True when no dimension, metric, or fact is requested.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.dimensions.is_empty() && self.metrics.is_empty() && self.facts.is_empty()
+    }
+
+    /// The request to run against `def`: this one, or — when it names
+    /// nothing — the view's `default_dimensions` and `default_metrics`, with
+    /// the other settings kept. Still empty when the view has no defaults.
+    #[must_use]
+    pub fn or_view_defaults(&self, def: &SemanticViewDefinition) -> Cow<'_, Self> {
+        if !self.is_empty() {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(Self {
+            dimensions: def
+                .default_dimensions
+                .iter()
+                .map(DimensionName::new)
+                .collect(),
+            metrics: def.default_metrics.iter().map(MetricName::new).collect(),
+            ..self.clone()
+        })
+    }
+}
+
+/// Fluent builder for [`QueryRequest`], from [`QueryRequest::builder`].
+///
+/// A name that is already present — under the same case- and
+/// quote-insensitive rule `expand()` matches with — is skipped, so assembling
+/// a request from several sources cannot trip the `Duplicate*` errors. Other
+/// validation (unknown names, an empty request) is left to `expand()`.
+///
+/// ```
+/// # use semantic_views::expand::{DimFilterOp, DimensionName, QueryRequest};
+/// let req = QueryRequest::builder()
+///     .dimensions(["region", "REGION", "status"])
+///     .metrics(["revenue", "order_count"])
+///     .filter("status", DimFilterOp::In, ["shipped", "delivered"])
+///     .build();
+/// assert_eq!(
+///     req.dimensions,
+///     vec![DimensionName::new("region"), DimensionName::new("status")]
+/// );
+/// assert_eq!(req.metrics.len(), 2);
+/// assert_eq!(req.dimension_filters[0].values.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct QueryRequestBuilder {
+    dimensions: Vec<DimensionName>,
+    metrics: Vec<MetricName>,
+    facts: Vec<FactName>,
+    detail: bool,
+    dimension_filters: Vec<DimensionFilter>,
+    metric_filters: Vec<MetricFilter>,
+    qualify_bare_columns: bool,
+    group_by: GroupByMode,
+    output_aliases: HashMap<String, String>,
+}
+
+/// Append `name` unless an equal name is already present.
+fn push_unique<K>(names: &mut Vec<CiName<K>>, name: impl Into<String>) {
+    let name = CiName::new(name);
+    if !names.contains(&name) {
+        names.push(name);
+    }
+}
+
+impl QueryRequestBuilder {
+    /// Add a dimension to select.
+    pub fn dimension(mut self, name: impl Into<String>) -> Self {
+        push_unique(&mut self.dimensions, name);
+        self
+    }
+
+    /// Add several dimensions to select, in order.
+    pub fn dimensions<I>(self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        names.into_iter().fold(self, Self::dimension)
+    }
+
+    /// Add a metric to aggregate.
+    pub fn metric(mut self, name: impl Into<String>) -> Self {
+        push_unique(&mut self.metrics, name);
+        self
+    }
+
+    /// Add several metrics to aggregate, in order.
+    pub fn metrics<I>(self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        names.into_iter().fold(self, Self::metric)
+    }
+
+    /// Add a fact to select (row-level query).
+    pub fn fact(mut self, name: impl Into<String>) -> Self {
+        push_unique(&mut self.facts, name);
+        self
+    }
+
+    /// Add several facts to select, in order.
+    pub fn facts<I>(self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        names.into_iter().fold(self, Self::fact)
+    }
+
+    /// Set [`QueryRequest::detail`].
+    pub fn detail(mut self, detail: bool) -> Self {
+        self.detail = detail;
+        self
+    }
+
+    /// Set [`QueryRequest::qualify_bare_columns`].
+    pub fn qualify_bare_columns(mut self, qualify: bool) -> Self {
+        self.qualify_bare_columns = qualify;
+        self
+    }
+
+    /// Set [`QueryRequest::group_by`].
+    pub fn group_by(mut self, mode: GroupByMode) -> Self {
+        self.group_by = mode;
+        self
+    }
+
+    /// Name the output column of the requested item `name` `alias`; see
+    /// [`QueryRequest::output_aliases`].
+    pub fn alias(mut self, name: impl Into<String>, alias: impl Into<String>) -> Self {
+        self.output_aliases.insert(name.into(), alias.into());
+        self
+    }
+
+    /// Add a [`DimensionFilter`]. Values convert through
+    /// `serde_json::Value`, so strings, numbers, and booleans can be passed
+    /// directly.
+    pub fn filter<I>(mut self, dimension: impl Into<String>, op: DimFilterOp, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<serde_json::Value>,
+    {
+        self.dimension_filters.push(DimensionFilter {
+            dimension: DimensionName::new(dimension),
+            op,
+            values: values.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Add a [`MetricFilter`] (a `HAVING` condition). Values convert like
+    /// [`Self::filter`]'s.
+    pub fn having<I>(mut self, metric: impl Into<String>, op: DimFilterOp, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<serde_json::Value>,
+    {
+        self.metric_filters.push(MetricFilter {
+            metric: MetricName::new(metric),
+            op,
+            values: values.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Finish the request.
+    #[must_use]
+    pub fn build(self) -> QueryRequest {
+        QueryRequest {
+            dimensions: self.dimensions,
+            metrics: self.metrics,
+            facts: self.facts,
+            detail: self.detail,
+            dimension_filters: self.dimension_filters,
+            metric_filters: self.metric_filters,
+            qualify_bare_columns: self.qualify_bare_columns,
+            group_by: self.group_by,
+            output_aliases: self.output_aliases,
+        }
+    }
+}
+
+/// Parse the compact request syntax used at a CLI or REPL prompt:
+/// `;`-separated sections of `key=name,name,...`, where the key is `dims`
+/// (or `dimensions`), `metrics`, or `facts`.
+///
+/// Whitespace around keys and names is ignored and empty sections (a
+/// trailing `;`, or `metrics=`) contribute nothing. Names go through
+/// [`QueryRequestBuilder`], so repeats are dropped. Every other field keeps
+/// its default. A section without `=`, an unknown or repeated key, or an
+/// empty name inside a list is an error.
+///
+/// ```
+/// # use semantic_views::expand::{DimensionName, MetricName, QueryRequest};
+/// let req: QueryRequest = "dims=region, month; metrics=revenue".parse().unwrap();
+/// assert_eq!(
+///     req.dimensions,
+///     vec![DimensionName::new("region"), DimensionName::new("month")]
+/// );
+/// assert_eq!(req.metrics, vec![MetricName::new("revenue")]);
+/// ```
+impl std::str::FromStr for QueryRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut builder = Self::builder();
+        let mut seen: Vec<&str> = Vec::new();
+        for section in s.split(';').map(str::trim).filter(|sec| !sec.is_empty()) {
+            let Some((key, list)) = section.split_once('=') else {
+                return Err(format!(
+                    "invalid request section '{section}': expected key=name,name,..."
+                ));
+            };
+            let key = key.trim();
+            let kind = match key.to_ascii_lowercase().as_str() {
+                "dims" | "dimensions" => "dimensions",
+                "metrics" => "metrics",
+                "facts" => "facts",
+                _ => {
+                    return Err(format!(
+                        "unknown request section '{key}': expected dims, metrics, or facts"
+                    ))
+                }
+            };
+            if seen.contains(&kind) {
+                return Err(format!("request section '{kind}' is given more than once"));
+            }
+            seen.push(kind);
+
+            let list = list.trim();
+            if list.is_empty() {
+                continue;
+            }
+            let names: Vec<&str> = list.split(',').map(str::trim).collect();
+            if names.iter().any(|n| n.is_empty()) {
+                return Err(format!("empty name in request section '{key}': '{list}'"));
+            }
+            builder = match kind {
+                "dimensions" => builder.dimensions(names),
+                "metrics" => builder.metrics(names),
+                _ => builder.facts(names),
+            };
+        }
+        Ok(builder.build())
+    }
 }
 
 /// A resolved dimension paired with its role-playing scoped alias, if any.
@@ -175,6 +624,203 @@ pub(crate) struct ResolvedDim<'a> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn request_dsl_parses_sections_and_trims_whitespace() {
+        let req: QueryRequest = "  dims = region ,month ;metrics=revenue,  count ; facts=amount;"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            req.dimensions,
+            vec![DimensionName::new("region"), DimensionName::new("month")]
+        );
+        assert_eq!(
+            req.metrics,
+            vec![MetricName::new("revenue"), MetricName::new("count")]
+        );
+        assert_eq!(req.facts, vec![FactName::new("amount")]);
+        assert!(!req.detail && req.dimension_filters.is_empty());
+
+        let req: QueryRequest = "DIMENSIONS=region,Region".parse().unwrap();
+        assert_eq!(req.dimensions, vec![DimensionName::new("region")]);
+    }
+
+    #[test]
+    fn request_dsl_skips_empty_sections() {
+        let req: QueryRequest = ";; metrics= ;dims=region;".parse().unwrap();
+        assert_eq!(req.dimensions, vec![DimensionName::new("region")]);
+        assert!(req.metrics.is_empty());
+        assert!("".parse::<QueryRequest>().unwrap().is_empty());
+        assert!(" ; ".parse::<QueryRequest>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn request_dsl_rejects_malformed_input() {
+        let err = |s: &str| s.parse::<QueryRequest>().unwrap_err();
+        assert_eq!(
+            err("dims region"),
+            "invalid request section 'dims region': expected key=name,name,..."
+        );
+        assert_eq!(
+            err("dims=a;measures=b"),
+            "unknown request section 'measures': expected dims, metrics, or facts"
+        );
+        assert_eq!(
+            err("dims=a;dimensions=b"),
+            "request section 'dimensions' is given more than once"
+        );
+        assert_eq!(
+            err("metrics=a,,b"),
+            "empty name in request section 'metrics': 'a,,b'"
+        );
+        assert!(err("metrics=a,").starts_with("empty name"));
+    }
+
+    #[test]
+    fn error_codes_are_stable() {
+        let view_name = || "sales".to_string();
+        let cases = [
+            (
+                ExpandError::EmptyRequest {
+                    view_name: view_name(),
+                },
+                "EMPTY_REQUEST",
+            ),
+            (
+                ExpandError::UnknownDimension {
+                    view_name: view_name(),
+                    name: "regoin".to_string(),
+                    available: vec!["region".to_string()],
+                    suggestion: Some("region".to_string()),
+                },
+                "UNKNOWN_DIMENSION",
+            ),
+            (
+                ExpandError::DuplicateMetric {
+                    view_name: view_name(),
+                    name: "revenue".to_string(),
+                },
+                "DUPLICATE_METRIC",
+            ),
+            (
+                ExpandError::FactsMetricsMutualExclusion {
+                    view_name: view_name(),
+                },
+                "FACTS_METRICS_EXCLUSIVE",
+            ),
+            (
+                ExpandError::SqlTooLarge {
+                    view_name: view_name(),
+                    len: 2,
+                    max_len: 1,
+                },
+                "SQL_TOO_LARGE",
+            ),
+            (
+                ExpandError::InvalidMetricFilter {
+                    view_name: view_name(),
+                    metric: "revenue".to_string(),
+                    reason: "needs a GROUP BY".to_string(),
+                },
+                "INVALID_METRIC_FILTER",
+            ),
+            (
+                ExpandError::NonAggregateInGlobalMode {
+                    view_name: view_name(),
+                    name: "unit_price".to_string(),
+                },
+                "NON_AGGREGATE_IN_GLOBAL_MODE",
+            ),
+        ];
+        for (err, code) in cases {
+            assert_eq!(err.code(), code, "{err}");
+            assert_eq!(err.view_name(), "sales");
+        }
+    }
+
+    #[test]
+    fn long_available_list_is_capped_in_display_only() {
+        let available: Vec<String> = (0..500).map(|i| format!("dim_{i:03}")).collect();
+        let err = ExpandError::UnknownDimension {
+            view_name: "wide".to_string(),
+            name: "dim_x".to_string(),
+            available: available.clone(),
+            suggestion: None,
+        };
+        let msg = err.to_string();
+        assert!(msg.contains("Available: [dim_000, dim_001,"), "{msg}");
+        assert!(msg.contains("dim_009, ... and 490 more]"), "{msg}");
+        assert!(!msg.contains("dim_010"), "{msg}");
+        assert_eq!(err.available(), available.as_slice());
+        assert_eq!(err.suggestion(), None);
+
+        let short = display_available(&available[..MAX_DISPLAYED_AVAILABLE]);
+        assert!(!short.contains("more"), "{short}");
+    }
+
+    #[test]
+    fn error_json_carries_code_message_and_context() {
+        let err = ExpandError::UnknownMetric {
+            view_name: "sales".to_string(),
+            name: "revnue".to_string(),
+            available: vec!["revenue".to_string()],
+            suggestion: Some("revenue".to_string()),
+        };
+        let json = err.to_json();
+        assert_eq!(json["code"], "UNKNOWN_METRIC");
+        assert_eq!(json["message"], err.to_string());
+        assert_eq!(
+            json["context"],
+            serde_json::json!({"view_name": "sales", "name": "revnue"})
+        );
+    }
+
+    #[test]
+    fn query_request_json_round_trip() {
+        let req = QueryRequest::builder()
+            .dimensions(["region", "\"Status\""])
+            .metric("revenue")
+            .filter("status", DimFilterOp::NotIn, ["void", "test"])
+            .group_by(GroupByMode::Expressions)
+            .qualify_bare_columns(true)
+            .alias("revenue", "Revenue")
+            .alias("region", "Region")
+            .build();
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "dimensions": ["region", "\"Status\""],
+                "metrics": ["revenue"],
+                "dimension_filters": [
+                    {"dimension": "status", "op": "NotIn", "values": ["void", "test"]}
+                ],
+                "qualify_bare_columns": true,
+                "group_by": "Expressions",
+                "output_aliases": {"region": "Region", "revenue": "Revenue"}
+            })
+        );
+        let back: QueryRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(back.dimensions, req.dimensions);
+        assert_eq!(back.metrics, req.metrics);
+        assert_eq!(back.dimension_filters[0].op, DimFilterOp::NotIn);
+        assert_eq!(back.group_by, GroupByMode::Expressions);
+        assert!(back.qualify_bare_columns && !back.detail);
+        assert_eq!(back.output_aliases, req.output_aliases);
+    }
+
+    #[test]
+    fn query_request_json_defaults_and_unknown_fields() {
+        let req: QueryRequest = serde_json::from_str("{}").unwrap();
+        assert!(req.is_empty());
+        assert_eq!(req.group_by, GroupByMode::Ordinals);
+        assert_eq!(serde_json::to_string(&req).unwrap(), "{}");
+
+        let err = serde_json::from_str::<QueryRequest>(r#"{"dimension": ["region"]}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unknown field `dimension`"), "{err}");
+    }
+
     #[test]
     fn dimension_name_case_insensitive_eq() {
         assert_eq!(DimensionName::new("Foo"), DimensionName::new("foo"));
@@ -403,6 +1049,35 @@ pub enum ExpandError {
     PrivateFact { view_name: String, name: String },
     /// Facts and metrics cannot be combined in the same query.
     FactsMetricsMutualExclusion { view_name: String },
+    /// A dimension filter is malformed (wrong number or type of values) or
+    /// cannot be applied to this query shape.
+    InvalidDimensionFilter {
+        view_name: String,
+        dimension: String,
+        reason: String,
+    },
+    /// A metric filter is malformed, or the query has no `GROUP BY` for its
+    /// `HAVING` to apply to.
+    InvalidMetricFilter {
+        view_name: String,
+        metric: String,
+        reason: String,
+    },
+    /// `GROUP BY ALL` was requested for a query without both dimensions and
+    /// metrics, which has nothing to group.
+    GroupByAllWithoutAggregation { view_name: String },
+    /// An entry of `QueryRequest::output_aliases` cannot be applied: it names
+    /// nothing requested, is empty, or repeats another output column name.
+    InvalidOutputAlias {
+        view_name: String,
+        name: String,
+        reason: String,
+    },
+    /// Metrics were requested in detail (row-level) mode.
+    DetailModeMetrics {
+        view_name: String,
+        metrics: Vec<String>,
+    },
     /// A requested fact name does not exist in the view definition.
     UnknownFact {
         view_name: String,
@@ -475,6 +1150,35 @@ pub enum ExpandError {
         metric_name: String,
         table_alias: String,
     },
+    /// A metrics-only (global aggregate) query selected a metric whose
+    /// expression calls no function at all, so it would be a bare column
+    /// outside any `GROUP BY`.
+    NonAggregateInGlobalMode { view_name: String, name: String },
+    /// The expanded SQL is longer than the configured cap (see
+    /// `DEFAULT_MAX_EXPANDED_SQL_BYTES` / `expand_with_limit`).
+    SqlTooLarge {
+        view_name: String,
+        len: usize,
+        max_len: usize,
+    },
+}
+
+/// How many names an error message lists from an `available` list before
+/// summarizing the rest as "... and N more". The full list stays on the error
+/// value ([`ExpandError::available`]).
+pub const MAX_DISPLAYED_AVAILABLE: usize = 10;
+
+/// Comma-separated `names`, capped at [`MAX_DISPLAYED_AVAILABLE`] so a view
+/// with hundreds of items does not produce a hundreds-of-names message.
+pub(crate) fn display_available(names: &[String]) -> String {
+    if names.len() <= MAX_DISPLAYED_AVAILABLE {
+        return names.join(", ");
+    }
+    format!(
+        "{}, ... and {} more",
+        names[..MAX_DISPLAYED_AVAILABLE].join(", "),
+        names.len() - MAX_DISPLAYED_AVAILABLE
+    )
 }
 
 impl fmt::Display for ExpandError {
@@ -506,7 +1210,7 @@ impl fmt::Display for ExpandError {
                 write!(
                     f,
                     "semantic view '{view_name}': unknown dimension '{name}'. Available: [{}]",
-                    available.join(", ")
+                    display_available(available)
                 )?;
                 if let Some(s) = suggestion {
                     write!(f, ". Did you mean '{s}'?")?;
@@ -522,7 +1226,7 @@ impl fmt::Display for ExpandError {
                 write!(
                     f,
                     "semantic view '{view_name}': unknown metric '{name}'. Available: [{}]",
-                    available.join(", ")
+                    display_available(available)
                 )?;
                 if let Some(s) = suggestion {
                     write!(f, ". Did you mean '{s}'?")?;
@@ -674,6 +1378,51 @@ impl fmt::Display for ExpandError {
                      Use facts := [...] OR metrics := [...], not both."
                 )
             }
+            Self::InvalidDimensionFilter {
+                view_name,
+                dimension,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': invalid filter on dimension '{dimension}': {reason}"
+                )
+            }
+            Self::InvalidMetricFilter {
+                view_name,
+                metric,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': invalid filter on metric '{metric}': {reason}"
+                )
+            }
+            Self::GroupByAllWithoutAggregation { view_name } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': GROUP BY ALL needs both dimensions and metrics; \
+                     only a grouped aggregate query has a GROUP BY."
+                )
+            }
+            Self::InvalidOutputAlias {
+                view_name,
+                name,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': invalid output alias for '{name}': {reason}"
+                )
+            }
+            Self::DetailModeMetrics { view_name, metrics } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': detail mode returns unaggregated rows and \
+                     cannot include metrics (requested: {}). Request dimensions or facts only.",
+                    metrics.join(", ")
+                )
+            }
             Self::UnknownFact {
                 view_name,
                 name,
@@ -683,7 +1432,7 @@ impl fmt::Display for ExpandError {
                 write!(
                     f,
                     "semantic view '{view_name}': unknown fact '{name}'. Available: [{}]",
-                    available.join(", ")
+                    display_available(available)
                 )?;
                 if let Some(s) = suggestion {
                     write!(f, ". Did you mean '{s}'?")?;
@@ -805,8 +1554,193 @@ impl fmt::Display for ExpandError {
                      explicit column: COUNT({table_alias}.<column>)."
                 )
             }
+            Self::NonAggregateInGlobalMode { view_name, name } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': metric '{name}' has no aggregate function, so \
+                     it cannot be queried without dimensions (the query is a single global \
+                     aggregate row). Add dimensions to the request, or wrap the metric's \
+                     expression in an aggregate such as SUM(...)."
+                )
+            }
+            Self::SqlTooLarge {
+                view_name,
+                len,
+                max_len,
+            } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': expanded SQL is {len} bytes, which exceeds the \
+                     {max_len}-byte limit. Request fewer dimensions, metrics, or facts."
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for ExpandError {}
+
+impl ExpandError {
+    /// Stable machine-readable identifier for this error kind, e.g.
+    /// `"UNKNOWN_DIMENSION"`. Codes are part of the public API: they do not
+    /// change when a message is reworded, and a new variant gets a new code.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::EmptyRequest { .. } => "EMPTY_REQUEST",
+            Self::UnknownDimension { .. } => "UNKNOWN_DIMENSION",
+            Self::UnknownMetric { .. } => "UNKNOWN_METRIC",
+            Self::UnknownFact { .. } => "UNKNOWN_FACT",
+            Self::DuplicateDimension { .. } => "DUPLICATE_DIMENSION",
+            Self::DuplicateMetric { .. } => "DUPLICATE_METRIC",
+            Self::DuplicateFact { .. } => "DUPLICATE_FACT",
+            Self::FanTrap { .. } => "FAN_TRAP",
+            Self::MetricFanTrap { .. } => "METRIC_FAN_TRAP",
+            Self::RootGrainFanTrap { .. } => "ROOT_GRAIN_FAN_TRAP",
+            Self::UncheckableDefinition { .. } => "UNCHECKABLE_DEFINITION",
+            Self::AmbiguousPath { .. } => "AMBIGUOUS_PATH",
+            Self::AmbiguousDescendantPath { .. } => "AMBIGUOUS_DESCENDANT_PATH",
+            Self::AmbiguousFactPath { .. } => "AMBIGUOUS_FACT_PATH",
+            Self::PrivateMetric { .. } => "PRIVATE_METRIC",
+            Self::PrivateFact { .. } => "PRIVATE_FACT",
+            Self::FactsMetricsMutualExclusion { .. } => "FACTS_METRICS_EXCLUSIVE",
+            Self::InvalidDimensionFilter { .. } => "INVALID_DIMENSION_FILTER",
+            Self::InvalidMetricFilter { .. } => "INVALID_METRIC_FILTER",
+            Self::GroupByAllWithoutAggregation { .. } => "GROUP_BY_ALL_WITHOUT_AGGREGATION",
+            Self::InvalidOutputAlias { .. } => "INVALID_OUTPUT_ALIAS",
+            Self::DetailModeMetrics { .. } => "DETAIL_MODE_METRICS",
+            Self::FactPathViolation { .. } => "FACT_PATH_VIOLATION",
+            Self::WindowAggregateMixing { .. } => "WINDOW_AGGREGATE_MIXING",
+            Self::WindowMetricRequiredDimension { .. } => "WINDOW_REQUIRED_DIMENSION",
+            Self::CatalogPoisoned { .. } => "CATALOG_POISONED",
+            Self::CycleDetected { .. } => "CYCLE_DETECTED",
+            Self::MaxDepthExceeded { .. } => "MAX_DEPTH_EXCEEDED",
+            Self::SemiAdditiveCoQueryUnsupported { .. } => "SEMI_ADDITIVE_CO_QUERY_UNSUPPORTED",
+            Self::SemiAdditiveUnsupportedExpression { .. } => {
+                "SEMI_ADDITIVE_UNSUPPORTED_EXPRESSION"
+            }
+            Self::CountStarRequiresPrimaryKey { .. } => "COUNT_STAR_REQUIRES_PRIMARY_KEY",
+            Self::NonAggregateInGlobalMode { .. } => "NON_AGGREGATE_IN_GLOBAL_MODE",
+            Self::SqlTooLarge { .. } => "SQL_TOO_LARGE",
+        }
+    }
+
+    /// Every name the view offers for an unknown dimension, metric, or fact
+    /// (`Display` shows at most [`MAX_DISPLAYED_AVAILABLE`] of them); empty
+    /// for other errors.
+    #[must_use]
+    pub fn available(&self) -> &[String] {
+        match self {
+            Self::UnknownDimension { available, .. }
+            | Self::UnknownMetric { available, .. }
+            | Self::UnknownFact { available, .. } => available,
+            _ => &[],
+        }
+    }
+
+    /// The closest available name to an unknown one, if any is close enough.
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::UnknownDimension { suggestion, .. }
+            | Self::UnknownMetric { suggestion, .. }
+            | Self::UnknownFact { suggestion, .. } => suggestion.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The semantic view the failing request targeted.
+    #[must_use]
+    pub fn view_name(&self) -> &str {
+        match self {
+            Self::FanTrap { detail } => &detail.view_name,
+            Self::MetricFanTrap { detail } => &detail.view_name,
+            Self::EmptyRequest { view_name }
+            | Self::UnknownDimension { view_name, .. }
+            | Self::UnknownMetric { view_name, .. }
+            | Self::UnknownFact { view_name, .. }
+            | Self::DuplicateDimension { view_name, .. }
+            | Self::DuplicateMetric { view_name, .. }
+            | Self::DuplicateFact { view_name, .. }
+            | Self::RootGrainFanTrap { view_name, .. }
+            | Self::UncheckableDefinition { view_name, .. }
+            | Self::AmbiguousPath { view_name, .. }
+            | Self::AmbiguousDescendantPath { view_name, .. }
+            | Self::AmbiguousFactPath { view_name, .. }
+            | Self::PrivateMetric { view_name, .. }
+            | Self::PrivateFact { view_name, .. }
+            | Self::FactsMetricsMutualExclusion { view_name }
+            | Self::InvalidDimensionFilter { view_name, .. }
+            | Self::InvalidMetricFilter { view_name, .. }
+            | Self::GroupByAllWithoutAggregation { view_name }
+            | Self::InvalidOutputAlias { view_name, .. }
+            | Self::DetailModeMetrics { view_name, .. }
+            | Self::FactPathViolation { view_name, .. }
+            | Self::WindowAggregateMixing { view_name, .. }
+            | Self::WindowMetricRequiredDimension { view_name, .. }
+            | Self::CatalogPoisoned { view_name }
+            | Self::CycleDetected { view_name, .. }
+            | Self::MaxDepthExceeded { view_name, .. }
+            | Self::SemiAdditiveCoQueryUnsupported { view_name, .. }
+            | Self::SemiAdditiveUnsupportedExpression { view_name, .. }
+            | Self::CountStarRequiresPrimaryKey { view_name, .. }
+            | Self::NonAggregateInGlobalMode { view_name, .. }
+            | Self::SqlTooLarge { view_name, .. } => view_name,
+        }
+    }
+
+    /// The view name plus the item this error is about, when there is one
+    /// (`name` for unknown/duplicate/private items, `metric` / `dimension` /
+    /// `fact` otherwise), as a JSON object.
+    #[must_use]
+    pub fn context(&self) -> serde_json::Value {
+        let mut context = serde_json::Map::new();
+        context.insert("view_name".to_string(), self.view_name().into());
+        let item: Option<(&str, &str)> = match self {
+            Self::UnknownDimension { name, .. }
+            | Self::UnknownMetric { name, .. }
+            | Self::UnknownFact { name, .. }
+            | Self::DuplicateDimension { name, .. }
+            | Self::DuplicateMetric { name, .. }
+            | Self::DuplicateFact { name, .. }
+            | Self::PrivateMetric { name, .. }
+            | Self::PrivateFact { name, .. } => Some(("name", name)),
+            Self::FanTrap { detail } => Some(("metric", &detail.metric_name)),
+            Self::MetricFanTrap { detail } => Some(("metric", &detail.metric_name)),
+            Self::RootGrainFanTrap { metric_name, .. }
+            | Self::WindowMetricRequiredDimension { metric_name, .. }
+            | Self::SemiAdditiveCoQueryUnsupported { metric_name, .. }
+            | Self::SemiAdditiveUnsupportedExpression { metric_name, .. }
+            | Self::CountStarRequiresPrimaryKey { metric_name, .. }
+            | Self::NonAggregateInGlobalMode {
+                name: metric_name, ..
+            }
+            | Self::InvalidMetricFilter {
+                metric: metric_name,
+                ..
+            } => Some(("metric", metric_name)),
+            Self::AmbiguousPath { dimension_name, .. }
+            | Self::AmbiguousDescendantPath { dimension_name, .. } => {
+                Some(("dimension", dimension_name))
+            }
+            Self::InvalidDimensionFilter { dimension, .. } => Some(("dimension", dimension)),
+            Self::AmbiguousFactPath { fact_name, .. } => Some(("fact", fact_name)),
+            _ => None,
+        };
+        if let Some((key, value)) = item {
+            context.insert(key.to_string(), value.into());
+        }
+        serde_json::Value::Object(context)
+    }
+
+    /// `{"code": ..., "message": ..., "context": {...}}` for callers that
+    /// surface structured errors; `message` is the `Display` text.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "code": self.code(),
+            "message": self.to_string(),
+            "context": self.context(),
+        })
+    }
+}
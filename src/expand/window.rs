@@ -13,11 +13,14 @@ use std::collections::{HashMap, HashSet};
 use crate::model::{Metric, NullsOrder, SemanticViewDefinition, SortOrder};
 
 use super::join_resolver::{push_join_clauses, resolve_joins_pkfk};
+use super::output_alias::OutputAliases;
 use super::resolution::{quote_ident, quote_stored_ident};
-use super::select_spec::{
-    push_from_base, push_group_by_ordinals, FromSource, GroupBy, SelectItem, SelectSpec,
-};
-use super::types::{ExpandError, ResolvedDim};
+use super::select_spec::{push_from_base, FromSource, GroupBy, SelectItem, SelectSpec};
+use super::types::{ExpandError, GroupByMode, ResolvedDim};
+
+/// Window function name expanded to `inner / NULLIF(SUM(inner) OVER (...), 0)`
+/// rather than emitted as a call (`DuckDB` has no such function).
+const RATIO_TO_REPORT: &str = "RATIO_TO_REPORT";
 
 /// Resolve a window dimension reference (bare, dotted, or quoted) to the
 /// `__sv_agg` CTE-alias column it must emit in the OVER clause — i.e.
@@ -58,6 +61,8 @@ pub(super) fn expand_window_metrics(
     resolved_dims: &[ResolvedDim],
     resolved_mets: &[&Metric],
     resolved_exprs: &HashMap<String, String>,
+    group_by: GroupByMode,
+    aliases: &OutputAliases,
 ) -> Result<String, ExpandError> {
     // 1. Validate required dimensions for each window metric.
     //
@@ -159,6 +164,7 @@ pub(super) fn expand_window_metrics(
     sql.push_str("WITH __sv_agg AS (\n    SELECT\n");
 
     let mut cte_select_items: Vec<String> = Vec::new();
+    let mut dim_items: Vec<SelectItem> = Vec::with_capacity(resolved_dims.len());
 
     // Dimension columns in CTE
     for rd in resolved_dims {
@@ -173,8 +179,13 @@ pub(super) fn expand_window_metrics(
             base_expr,
             dim.output_type.clone(),
             quote_stored_ident(&dim.name),
-        );
+        )
+        .bucketed(dim.bucket.as_deref())
+        .coalesced(dim.coalesce_to.as_deref())
+        .null_replaced(dim.null_value.as_deref())
+        .collated(dim.collate.as_deref());
         cte_select_items.push(format!("        {}", item.render()));
+        dim_items.push(item);
     }
 
     // Inner metric aggregated columns in CTE
@@ -196,9 +207,7 @@ pub(super) fn expand_window_metrics(
     push_join_clauses(&mut sql, &resolved_joins, def, "\n    LEFT JOIN ");
 
     // CTE GROUP BY (all dimension columns)
-    if !resolved_dims.is_empty() {
-        push_group_by_ordinals(&mut sql, resolved_dims.len(), "\n    ", "        ");
-    }
+    GroupBy::dimensions(group_by, &dim_items).push(&mut sql, "\n    ", "        ");
 
     sql.push_str("\n)\n");
 
@@ -211,7 +220,7 @@ pub(super) fn expand_window_metrics(
         outer_items.push(SelectItem::new(
             quote_stored_ident(&rd.dim.name),
             None,
-            quote_stored_ident(&rd.dim.name),
+            aliases.column(&rd.dim.name),
         ));
     }
 
@@ -233,32 +242,39 @@ pub(super) fn expand_window_metrics(
         }
         let func_call = format!("{}({})", ws.window_function, func_args.join(", "));
 
+        let is_ratio = ws.window_function.eq_ignore_ascii_case(RATIO_TO_REPORT);
+
         // Compute PARTITION BY columns
-        let partition_cols: Vec<String> = if ws.partition_dims.is_empty() {
-            // PARTITION BY EXCLUDING: all queried dims minus excluding_dims.
-            // Key the excluded set through the shared resolver so a quoted/dotted
-            // EXCLUDING reference still matches its declared dimension.
-            let excluding_set: HashSet<String> = ws
-                .excluding_dims
-                .iter()
-                .map(|d| super::resolution::dim_ref_key(def, d))
-                .collect();
-            resolved_dims
-                .iter()
-                .filter(|rd| {
-                    !excluding_set.contains(&crate::ident::normalize_ident_part(&rd.dim.name))
-                })
-                .map(|rd| quote_stored_ident(&rd.dim.name))
-                .collect()
-        } else {
-            // Explicit PARTITION BY: emit each listed dim's resolved CTE alias so
-            // a quoted/dotted reference binds to the CTE column, not a
-            // doubled-quote/dotted non-column.
-            ws.partition_dims
-                .iter()
-                .map(|d| window_dim_column(def, d))
-                .collect()
-        };
+        let partition_cols: Vec<String> =
+            if is_ratio && ws.partition_dims.is_empty() && ws.excluding_dims.is_empty() {
+                // RATIO_TO_REPORT ... OVER (): the grand total, not the
+                // all-queried-dims partition (which would make every share 1).
+                Vec::new()
+            } else if ws.partition_dims.is_empty() {
+                // PARTITION BY EXCLUDING: all queried dims minus excluding_dims.
+                // Key the excluded set through the shared resolver so a quoted/dotted
+                // EXCLUDING reference still matches its declared dimension.
+                let excluding_set: HashSet<String> = ws
+                    .excluding_dims
+                    .iter()
+                    .map(|d| super::resolution::dim_ref_key(def, d))
+                    .collect();
+                resolved_dims
+                    .iter()
+                    .filter(|rd| {
+                        !excluding_set.contains(&crate::ident::normalize_ident_part(&rd.dim.name))
+                    })
+                    .map(|rd| quote_stored_ident(&rd.dim.name))
+                    .collect()
+            } else {
+                // Explicit PARTITION BY: emit each listed dim's resolved CTE alias so
+                // a quoted/dotted reference binds to the CTE column, not a
+                // doubled-quote/dotted non-column.
+                ws.partition_dims
+                    .iter()
+                    .map(|d| window_dim_column(def, d))
+                    .collect()
+            };
 
         // Build OVER clause
         let mut over_parts: Vec<String> = Vec::new();
@@ -288,12 +304,21 @@ pub(super) fn expand_window_metrics(
         }
 
         let over_clause = over_parts.join(" ");
-        let window_expr = format!("{func_call} OVER ({over_clause})");
+        // RATIO_TO_REPORT is Snowflake's, not DuckDB's: the share of the
+        // inner metric in its partition's total, so `OVER ()` is
+        // percent-of-grand-total over the aggregated rows. NULLIF keeps an
+        // all-zero partition NULL instead of a division error.
+        let window_expr = if is_ratio {
+            let inner = quote_ident(&inner_key);
+            format!("{inner} / NULLIF(SUM({inner}) OVER ({over_clause}), 0)")
+        } else {
+            format!("{func_call} OVER ({over_clause})")
+        };
 
         outer_items.push(SelectItem::new(
             window_expr,
             met.output_type.clone(),
-            quote_stored_ident(&met.name),
+            aliases.column(&met.name),
         ));
     }
 
@@ -303,7 +328,9 @@ pub(super) fn expand_window_metrics(
             distinct: false,
             items: outer_items,
             from: FromSource::Named("__sv_agg".to_string()),
+            filters: vec![],
             group_by: GroupBy::None,
+            having: vec![],
         }
         .render(),
     );
@@ -381,9 +408,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req)
@@ -429,13 +456,13 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![
                 DimensionName::new("store"),
                 DimensionName::new("date"),
                 DimensionName::new("year"),
             ],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -460,6 +487,70 @@ mod tests {
         );
     }
 
+    /// A cumulative (running-total) metric is a window metric, not a separate
+    /// metric kind: `SUM(total_qty) OVER (PARTITION BY EXCLUDING date ORDER BY
+    /// date)`. Pin the shape — the inner metric is aggregated per queried-dim
+    /// grain in the CTE, and the outer query applies the windowed SUM with no
+    /// GROUP BY of its own — so GROUP BY semantics stay consistent however the
+    /// running total is partitioned.
+    #[test]
+    fn test_window_cumulative_sum_over_ordered_dim() {
+        let def = minimal_def("sales", "store", "store", "total_qty", "SUM(s.quantity)")
+            .with_dimension("date", "date", None)
+            .with_metric("cumulative_qty", "SUM(total_qty)", None)
+            .with_window_spec(
+                "cumulative_qty",
+                WindowSpec {
+                    window_function: "SUM".to_string(),
+                    inner_metric: "total_qty".to_string(),
+                    extra_args: vec![],
+                    excluding_dims: vec!["date".to_string()],
+                    partition_dims: vec![],
+                    order_by: vec![WindowOrderBy {
+                        expr: "date".to_string(),
+                        order: SortOrder::Asc,
+                        nulls: NullsOrder::Last,
+                    }],
+                    frame_clause: None,
+                },
+            );
+
+        // Ordering dimension alone: one running total over the whole series.
+        let req = QueryRequest {
+            dimensions: vec![DimensionName::new("date")],
+            metrics: vec![MetricName::new("cumulative_qty")],
+            ..Default::default()
+        };
+        let sql = expand("test_view", &def, &req).unwrap();
+        assert!(
+            sql.contains("SUM(s.quantity) AS \"total_qty\""),
+            "CTE should aggregate the inner metric: {sql}"
+        );
+        assert!(
+            sql.contains("SUM(\"total_qty\") OVER (ORDER BY \"date\" ASC NULLS LAST)"),
+            "outer query should emit the cumulative window: {sql}"
+        );
+        let outer = &sql[sql.find("FROM __sv_agg").expect("outer FROM")..];
+        assert!(
+            !outer.contains("GROUP BY"),
+            "outer window query must not re-aggregate: {sql}"
+        );
+
+        // With another dimension, the running total restarts per store.
+        let req = QueryRequest {
+            dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
+            metrics: vec![MetricName::new("cumulative_qty")],
+            ..Default::default()
+        };
+        let sql = expand("test_view", &def, &req).unwrap();
+        assert!(
+            sql.contains(
+                "SUM(\"total_qty\") OVER (PARTITION BY \"store\" ORDER BY \"date\" ASC NULLS LAST)"
+            ),
+            "cumulative window should partition by the other queried dims: {sql}"
+        );
+    }
+
     /// A window metric whose inner-metric reference is written QUOTED and
     /// mixed-case (`"Total_Qty"`) against an unquoted base metric `total_qty`.
     /// The CTE aggregate column and the outer window reference must both resolve
@@ -489,9 +580,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -536,13 +627,13 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![
                 DimensionName::new("store"),
                 DimensionName::new("date"),
                 DimensionName::new("year"),
             ],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -583,9 +674,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -618,9 +709,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -671,13 +762,13 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![
                 DimensionName::new("store"),
                 DimensionName::new("date"),
                 DimensionName::new("year"),
             ],
             metrics: vec![MetricName::new("total_qty"), MetricName::new("avg_7")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -718,9 +809,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
             metrics: vec![MetricName::new("total_qty"), MetricName::new("avg_price")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -765,9 +856,9 @@ mod tests {
 
         // Query with only 'store' -- missing 'date' which is required by EXCLUDING and ORDER BY
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -823,9 +914,9 @@ mod tests {
             .with_pkfk_join("cust_acct", "a", "c", &["customer_id"], &["id"]);
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("acct_name"), DimensionName::new("date")],
             metrics: vec![MetricName::new("total_balance")],
+            ..Default::default()
         };
 
         // Window metrics are checked like any other aggregate: fan-out error.
@@ -861,9 +952,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).unwrap();
@@ -903,9 +994,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("date")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let result = expand("test_view", &def, &req);
@@ -948,9 +1039,9 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         // Must not error the required-dimension check (dotted `s.date` resolves).
@@ -996,12 +1087,12 @@ mod tests {
             );
 
         let req = QueryRequest {
-            facts: vec![],
             dimensions: vec![
                 DimensionName::new("store"),
                 DimensionName::new("order date"),
             ],
             metrics: vec![MetricName::new("total_qty")],
+            ..Default::default()
         };
 
         let sql = expand("test_view", &def, &req).expect("dotted-quoted ORDER BY must resolve");
@@ -1027,4 +1118,52 @@ mod tests {
             "dotted-quoted ORDER BY text must not leak as a non-column: {sql}"
         );
     }
+
+    /// Percent-of-total is `RATIO_TO_REPORT(metric) OVER (...)`: the inner
+    /// metric is aggregated per queried-dim grain in the CTE, then divided by
+    /// its windowed SUM over those aggregated rows — the grand total for
+    /// `OVER ()`, a per-partition total with PARTITION BY [EXCLUDING].
+    #[test]
+    fn test_window_ratio_to_report_over_two_dims() {
+        let ratio = |excluding: &[&str]| WindowSpec {
+            window_function: "RATIO_TO_REPORT".to_string(),
+            inner_metric: "total_qty".to_string(),
+            excluding_dims: excluding.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        };
+        let def = minimal_def("sales", "store", "store", "total_qty", "SUM(s.quantity)")
+            .with_dimension("date", "date", None)
+            .with_metric("qty_share", "RATIO_TO_REPORT(total_qty)", None)
+            .with_metric("qty_share_of_day", "RATIO_TO_REPORT(total_qty)", None)
+            .with_window_spec("qty_share", ratio(&[]))
+            .with_window_spec("qty_share_of_day", ratio(&["store"]));
+
+        let req = QueryRequest {
+            dimensions: vec![DimensionName::new("store"), DimensionName::new("date")],
+            metrics: vec![
+                MetricName::new("qty_share"),
+                MetricName::new("qty_share_of_day"),
+            ],
+            ..Default::default()
+        };
+        let sql = expand("test_view", &def, &req).unwrap();
+        assert!(
+            sql.contains("SUM(s.quantity) AS \"total_qty\""),
+            "CTE should aggregate the inner metric: {sql}"
+        );
+        assert!(
+            sql.contains("\"total_qty\" / NULLIF(SUM(\"total_qty\") OVER (), 0) AS \"qty_share\""),
+            "grand-total share: {sql}"
+        );
+        assert!(
+            sql.contains(
+                "\"total_qty\" / NULLIF(SUM(\"total_qty\") OVER (PARTITION BY \"date\"), 0) \
+                 AS \"qty_share_of_day\""
+            ),
+            "share within each date: {sql}"
+        );
+        assert!(!sql.contains("RATIO_TO_REPORT"), "{sql}");
+        let outer = &sql[sql.find("FROM __sv_agg").expect("outer FROM")..];
+        assert!(!outer.contains("GROUP BY"), "{sql}");
+    }
 }
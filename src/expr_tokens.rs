@@ -420,6 +420,270 @@ pub(crate) fn rewrite_qualifier(expr: &str, alias: &str, replacement: &str) -> S
     out
 }
 
+/// The first lexical delimiter problem in `expr`, if any: an unbalanced or
+/// mismatched `(`/`)` or `[`/`]`, or an unterminated `'…'` string, `"…"`
+/// identifier, `$tag$…$tag$` string, or `/* … */` comment.
+///
+/// This is a balance check over the same lexical structure [`scan_references`]
+/// walks (with `--` / `/* */` comments skipped too, since not every entry point
+/// blanks them), not a SQL parser: it catches the truncated or mistyped
+/// expression that would otherwise only fail at query time, e.g.
+/// `sum(amount`.
+pub(crate) fn delimiter_problem(expr: &str) -> Option<String> {
+    let bytes = expr.as_bytes();
+    let len = bytes.len();
+    let mut open: Vec<u8> = Vec::new();
+    let mut i = 0;
+    while i < len {
+        match bytes[i] {
+            q @ (b'\'' | b'"') => {
+                let mut j = i + 1;
+                loop {
+                    if j >= len {
+                        return Some(if q == b'\'' {
+                            "unterminated string literal".to_string()
+                        } else {
+                            "unterminated quoted identifier".to_string()
+                        });
+                    }
+                    if bytes[j] == q {
+                        if j + 1 < len && bytes[j + 1] == q {
+                            j += 2; // doubled quote escape
+                            continue;
+                        }
+                        break;
+                    }
+                    j += 1;
+                }
+                i = j + 1;
+            }
+            b'$' => match crate::util::read_dollar_tag_len(bytes, i) {
+                Some(tag_len) => {
+                    let tag = &bytes[i..i + tag_len];
+                    let body = i + tag_len;
+                    let close = (body..=len.saturating_sub(tag_len))
+                        .find(|&j| &bytes[j..j + tag_len] == tag);
+                    match close {
+                        Some(j) => i = j + tag_len,
+                        None => return Some("unterminated dollar-quoted string".to_string()),
+                    }
+                }
+                None => i += 1,
+            },
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < len && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => match expr[i + 2..].find("*/") {
+                Some(k) => i = i + 2 + k + 2,
+                None => return Some("unterminated /* comment".to_string()),
+            },
+            b @ (b'(' | b'[') => {
+                open.push(b);
+                i += 1;
+            }
+            b @ (b')' | b']') => {
+                let want = if b == b')' { b'(' } else { b'[' };
+                if open.pop() != Some(want) {
+                    return Some(format!("unexpected '{}'", b as char));
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    open.last().map(|&b| format!("unclosed '{}'", b as char))
+}
+
+/// The first statement-breaking token in `expr` outside string literals,
+/// quoted identifiers, and dollar-quoted strings: `;`, `--`, or `/*`.
+///
+/// Expressions are spliced into generated SQL verbatim, so an expression
+/// like `amount); DROP TABLE orders; --` could end the generated statement
+/// or comment out the rest of it. None of the three has a use inside a
+/// single scalar or aggregate expression, so their presence is rejected
+/// outright. Not airtight, but it stops the obvious forms.
+pub(crate) fn statement_break(expr: &str) -> Option<&'static str> {
+    let bytes = expr.as_bytes();
+    let len = bytes.len();
+    let mut i = 0;
+    while i < len {
+        match bytes[i] {
+            b'\'' => i = skip_single_quoted(bytes, i),
+            b'"' => i = skip_quoted_part(bytes, i),
+            b'$' => i = try_skip_dollar_quoted(bytes, i).unwrap_or(i + 1),
+            b';' => return Some(";"),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => return Some("--"),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => return Some("/*"),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// The function name when `expr` is exactly one function call — an
+/// unqualified name, optional whitespace, and a parenthesized argument list
+/// whose closing `)` ends the expression (`SUM(o.amount)`,
+/// `count(DISTINCT o.id)`). `None` for anything else, including
+/// `SUM(a) + SUM(b)` and `SUM(a) FILTER (WHERE ...)`.
+///
+/// Parentheses inside string literals, quoted identifiers, and dollar-quoted
+/// strings do not count toward the match.
+pub(crate) fn single_call_head(expr: &str) -> Option<&str> {
+    let expr = expr.trim();
+    let bytes = expr.as_bytes();
+    let head_len = bytes
+        .iter()
+        .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_'))
+        .unwrap_or(bytes.len());
+    if head_len == 0 || bytes[0].is_ascii_digit() {
+        return None;
+    }
+    let mut i = head_len;
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    if bytes.get(i) != Some(&b'(') {
+        return None;
+    }
+    let mut depth = 0usize;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' => i = skip_single_quoted(bytes, i),
+            b'"' => i = skip_quoted_part(bytes, i),
+            b'$' => i = try_skip_dollar_quoted(bytes, i).unwrap_or(i + 1),
+            b'(' => {
+                depth += 1;
+                i += 1;
+            }
+            b')' => {
+                depth = depth.checked_sub(1)?;
+                i += 1;
+                if depth == 0 {
+                    return (i == bytes.len()).then_some(&expr[..head_len]);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// The argument of `expr` when it is exactly one `COUNT(DISTINCT <arg>)` call
+/// (any case, any spacing), trimmed; `None` for anything else, including a
+/// plain `COUNT(x)` and `COUNT(DISTINCT x) + 1`.
+pub(crate) fn count_distinct_arg(expr: &str) -> Option<&str> {
+    let expr = expr.trim();
+    let head = single_call_head(expr)?;
+    if !head.eq_ignore_ascii_case("count") {
+        return None;
+    }
+    let open = expr.find('(')?;
+    let args = expr[open + 1..expr.len() - 1].trim_start();
+    let keyword = args.get(..8)?;
+    let rest = &args[8..];
+    let arg = rest.trim();
+    (keyword.eq_ignore_ascii_case("distinct")
+        && rest.starts_with(|c: char| c.is_ascii_whitespace())
+        && !arg.is_empty())
+    .then_some(arg)
+}
+
+/// Aggregates whose result changes when duplicate inputs are dropped, so a
+/// metric's `distinct` flag may add `DISTINCT` to them. `min`/`max` are left
+/// out: `DISTINCT` never changes their result.
+pub(crate) const DISTINCT_AGGREGATES: &[&str] =
+    &["count", "sum", "avg", "string_agg", "list", "array_agg"];
+
+/// `expr` with `DISTINCT` inserted into its argument list, when `expr` is
+/// exactly one call to a [`DISTINCT_AGGREGATES`] function whose arguments
+/// are neither `*` nor already `DISTINCT`; `None` for anything else.
+pub(crate) fn distinct_call(expr: &str) -> Option<String> {
+    let expr = expr.trim();
+    let head = single_call_head(expr)?;
+    if !DISTINCT_AGGREGATES
+        .iter()
+        .any(|agg| head.eq_ignore_ascii_case(agg))
+    {
+        return None;
+    }
+    let open = expr.find('(')?;
+    let args = expr[open + 1..expr.len() - 1].trim();
+    let already = args
+        .get(..8)
+        .is_some_and(|kw| kw.eq_ignore_ascii_case("distinct"))
+        && args[8..].starts_with(|c: char| c.is_ascii_whitespace());
+    if args.is_empty() || args == "*" || already {
+        return None;
+    }
+    Some(format!("{head}(DISTINCT {args})"))
+}
+
+/// Bare words `DuckDB` reads as values rather than column references.
+const VALUE_KEYWORDS: &[&str] = &[
+    "null",
+    "true",
+    "false",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "localtime",
+    "localtimestamp",
+];
+
+/// The column name when `expr` is nothing but one unqualified column
+/// reference (`region`, `"Region"`), `None` for anything else: a qualified
+/// chain, a call, a literal, a number, or a value keyword such as
+/// `current_date`.
+pub(crate) fn bare_column(expr: &str) -> Option<&str> {
+    let expr = expr.trim();
+    let [only] = scan_references(expr)[..] else {
+        return None;
+    };
+    let is_column = only.raw.len() == expr.len()
+        && only.is_bare()
+        && !expr.as_bytes()[0].is_ascii_digit()
+        && (expr.starts_with('"') || !VALUE_KEYWORDS.contains(&only.key().as_str()));
+    is_column.then_some(expr)
+}
+
+/// `sql` with every run of whitespace outside string literals, quoted
+/// identifiers, and dollar-quoted strings replaced by one space, and leading
+/// and trailing whitespace removed. Literal content is copied verbatim, so
+/// the statement means the same thing; it is only shorter.
+///
+/// Like the rest of this module it assumes comments are already gone: a `--`
+/// comment would swallow everything after the line break this removes.
+pub(crate) fn collapse_whitespace(sql: &str) -> String {
+    let sql = sql.trim();
+    let bytes = sql.as_bytes();
+    let mut out = String::with_capacity(sql.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = match bytes[i] {
+            b'\'' => skip_single_quoted(bytes, i),
+            b'"' => skip_quoted_part(bytes, i),
+            b'$' => try_skip_dollar_quoted(bytes, i).unwrap_or(i + 1),
+            b if b.is_ascii_whitespace() => {
+                out.push_str(&sql[copied..i]);
+                out.push(' ');
+                let mut j = i + 1;
+                while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+                    j += 1;
+                }
+                copied = j;
+                j
+            }
+            _ => i + 1,
+        };
+        i = end;
+    }
+    out.push_str(&sql[copied..]);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -429,6 +693,166 @@ mod tests {
         scan_references(expr).iter().map(IdentRef::key).collect()
     }
 
+    #[test]
+    fn delimiter_problem_flags_unbalanced_and_unterminated() {
+        assert_eq!(
+            delimiter_problem("sum(amount").as_deref(),
+            Some("unclosed '('")
+        );
+        assert_eq!(
+            delimiter_problem("sum(amount))").as_deref(),
+            Some("unexpected ')'")
+        );
+        assert_eq!(
+            delimiter_problem("arr[1)").as_deref(),
+            Some("unexpected ')'")
+        );
+        assert_eq!(
+            delimiter_problem("x = 'abc").as_deref(),
+            Some("unterminated string literal")
+        );
+        assert_eq!(
+            delimiter_problem("\"col").as_deref(),
+            Some("unterminated quoted identifier")
+        );
+        assert_eq!(
+            delimiter_problem("$q$abc").as_deref(),
+            Some("unterminated dollar-quoted string")
+        );
+        assert_eq!(
+            delimiter_problem("a /* b").as_deref(),
+            Some("unterminated /* comment")
+        );
+    }
+
+    #[test]
+    fn statement_break_finds_unquoted_separators_and_comments() {
+        assert_eq!(statement_break("amount); DROP TABLE orders; --"), Some(";"));
+        assert_eq!(statement_break("amount -- rest"), Some("--"));
+        assert_eq!(statement_break("amount /* x */"), Some("/*"));
+        for ok in [
+            "o.note = 'a;b'",
+            "o.note = '-- not a comment'",
+            "\"odd;name\" + 1",
+            "$t$ /* ; $t$",
+            "a - -b",
+            "a / *b",
+        ] {
+            assert_eq!(statement_break(ok), None, "{ok}");
+        }
+    }
+
+    #[test]
+    fn single_call_head_matches_whole_expression_calls_only() {
+        assert_eq!(single_call_head("SUM(o.amount)"), Some("SUM"));
+        assert_eq!(single_call_head(" count (DISTINCT o.id) "), Some("count"));
+        assert_eq!(single_call_head("max(coalesce(a, ')'))"), Some("max"));
+        for not_single in [
+            "SUM(a) + SUM(b)",
+            "SUM(a) FILTER (WHERE a > 0)",
+            "o.amount",
+            "(SUM(a))",
+            "1(a)",
+            "SUM(a",
+            "",
+        ] {
+            assert_eq!(single_call_head(not_single), None, "{not_single}");
+        }
+    }
+
+    #[test]
+    fn count_distinct_arg_matches_single_count_distinct_calls() {
+        assert_eq!(count_distinct_arg("COUNT(DISTINCT o.id)"), Some("o.id"));
+        assert_eq!(
+            count_distinct_arg(" count ( distinct\n lower(c.email) ) "),
+            Some("lower(c.email)")
+        );
+        for other in [
+            "COUNT(o.id)",
+            "COUNT(DISTINCTo.id)",
+            "COUNT(DISTINCT )",
+            "COUNT(DISTINCT o.id) + 1",
+            "SUM(DISTINCT o.amount)",
+            "approx_count_distinct(o.id)",
+        ] {
+            assert_eq!(count_distinct_arg(other), None, "{other}");
+        }
+    }
+
+    #[test]
+    fn distinct_call_adds_distinct_to_supported_aggregates() {
+        assert_eq!(
+            distinct_call("count(o.customer_id)").as_deref(),
+            Some("count(DISTINCT o.customer_id)")
+        );
+        assert_eq!(
+            distinct_call(" SUM( o.amount ) ").as_deref(),
+            Some("SUM(DISTINCT o.amount)")
+        );
+        assert_eq!(
+            distinct_call("string_agg(o.tag, ',')").as_deref(),
+            Some("string_agg(DISTINCT o.tag, ',')")
+        );
+        for other in [
+            "COUNT(*)",
+            "COUNT(DISTINCT o.id)",
+            "max(o.amount)",
+            "sum(o.a) + sum(o.b)",
+            "o.amount",
+            "sum()",
+        ] {
+            assert_eq!(distinct_call(other), None, "{other}");
+        }
+    }
+
+    #[test]
+    fn collapse_whitespace_keeps_literals_verbatim() {
+        assert_eq!(
+            collapse_whitespace("\n  SELECT\n    a,\t'x  \n y' AS \"b  c\"\nFROM  t \n"),
+            "SELECT a, 'x  \n y' AS \"b  c\" FROM t"
+        );
+        assert_eq!(
+            collapse_whitespace("SELECT $q$ a\n b $q$"),
+            "SELECT $q$ a\n b $q$"
+        );
+    }
+
+    #[test]
+    fn bare_column_accepts_lone_unqualified_references_only() {
+        assert_eq!(bare_column(" region "), Some("region"));
+        assert_eq!(bare_column("\"Order Date\""), Some("\"Order Date\""));
+        assert_eq!(bare_column("\"null\""), Some("\"null\""));
+        for not_bare in [
+            "o.region",
+            "upper(region)",
+            "region || 'x'",
+            "'region'",
+            "42",
+            "NULL",
+            "current_date",
+            "",
+        ] {
+            assert_eq!(bare_column(not_bare), None, "{not_bare}");
+        }
+    }
+
+    #[test]
+    fn delimiter_problem_ignores_delimiters_in_literals_and_comments() {
+        for ok in [
+            "sum(o.amount)",
+            "coalesce(x, ')')",
+            "\"weird (name\" + 1",
+            "'it''s (fine'",
+            "$t$ ( $t$",
+            "a -- trailing ( comment",
+            "a /* ( */ + b",
+            "list_value(1, 2)[1]",
+            "",
+        ] {
+            assert_eq!(delimiter_problem(ok), None, "{ok}");
+        }
+    }
+
     #[test]
     fn scans_bare_and_qualified_chains() {
         assert_eq!(keys("revenue - cost"), vec!["revenue", "cost"]);
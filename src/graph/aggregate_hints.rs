@@ -0,0 +1,123 @@
+//! Aggregate-placement hints for [`SemanticViewDefinition::warnings`].
+//!
+//! A dimension is grouped by, so `SUM(o.amount)` there makes the expanded
+//! `GROUP BY` fail; a base metric is aggregated, so `o.amount` there fails
+//! the other way. Both are flagged as warnings rather than errors because
+//! the checks are lexical: a dimension that is a whole aggregate call is
+//! flagged, but one that merely contains an aggregate (a scalar subquery,
+//! `SUM(x) OVER (...)`) is not, and a base metric built on a function this
+//! crate does not know as an aggregate is flagged although it may be fine.
+
+use crate::expr_tokens::single_call_head;
+use crate::model::SemanticViewDefinition;
+
+use super::derived_metrics::{contains_aggregate_function, is_aggregate_function};
+use super::validate::ValidationWarning;
+
+/// Dimensions whose whole expression is one aggregate call, then base
+/// metrics with no aggregate call anywhere, each in declaration order.
+pub(super) fn aggregate_placement_warnings(def: &SemanticViewDefinition) -> Vec<ValidationWarning> {
+    let dims = def.dimensions.iter().filter_map(|dim| {
+        let head = single_call_head(&dim.expr)?.to_ascii_lowercase();
+        is_aggregate_function(&head).then(|| ValidationWarning {
+            item: dim.name.clone(),
+            message: format!(
+                "dimension '{}' is an aggregate ({head}); dimensions are grouped by, so \
+                 queries using it will fail. Declare it as a metric instead.",
+                dim.name
+            ),
+        })
+    });
+    let mets = def
+        .metrics
+        .iter()
+        .filter(|m| m.source_table.is_some() && !m.is_window())
+        .filter(|m| contains_aggregate_function(&m.expr).is_none())
+        .map(|met| ValidationWarning {
+            item: met.name.clone(),
+            message: format!(
+                "metric '{}' has no aggregate function; a metric is evaluated per group, \
+                 so wrap the expression in one (e.g. SUM) or declare it as a dimension.",
+                met.name
+            ),
+        });
+    dims.chain(mets).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::aggregate_placement_warnings;
+    use crate::model::{Dimension, Metric, SemanticViewDefinition, WindowSpec};
+
+    fn dim(name: &str, expr: &str) -> Dimension {
+        Dimension {
+            name: name.to_string(),
+            expr: expr.to_string(),
+            source_table: Some("o".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn met(name: &str, expr: &str, source_table: Option<&str>) -> Metric {
+        Metric {
+            name: name.to_string(),
+            expr: expr.to_string(),
+            source_table: source_table.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn flagged(def: &SemanticViewDefinition) -> Vec<String> {
+        aggregate_placement_warnings(def)
+            .into_iter()
+            .map(|w| w.item)
+            .collect()
+    }
+
+    #[test]
+    fn aggregate_dimension_is_flagged() {
+        let def = SemanticViewDefinition {
+            dimensions: vec![
+                dim("total", "SUM(o.amount)"),
+                dim("region", "upper(o.region)"),
+                dim("running", "sum(o.amount) OVER (ORDER BY o.day)"),
+                dim("label", "'sum(' || o.name"),
+            ],
+            ..Default::default()
+        };
+        let warnings = aggregate_placement_warnings(&def);
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert_eq!(warnings[0].item, "total");
+        assert!(
+            warnings[0]
+                .message
+                .starts_with("dimension 'total' is an aggregate (sum)"),
+            "{}",
+            warnings[0].message
+        );
+    }
+
+    #[test]
+    fn metric_without_aggregate_is_flagged() {
+        let def = SemanticViewDefinition {
+            metrics: vec![
+                met("amount", "o.amount", Some("o")),
+                met("revenue", "sum(o.amount)", Some("o")),
+                met("margin", "revenue - cost", None),
+                met("rate", "count(o.id) * 1.0 / 7", Some("o")),
+                Metric {
+                    window_spec: Some(WindowSpec::default()),
+                    ..met("lagged", "lag(revenue)", Some("o"))
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(flagged(&def), ["amount"]);
+        let warnings = aggregate_placement_warnings(&def);
+        assert!(
+            warnings[0].message.contains("has no aggregate function"),
+            "{}",
+            warnings[0].message
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! Metric `approx` validation.
+//!
+//! Expansion swaps an `approx` metric's `COUNT(DISTINCT x)` for
+//! `approx_count_distinct(x)` (see `inline_derived_metrics`). The swap is only
+//! defined for an expression that is exactly that one call; on anything else
+//! the flag would be silently ignored, so CREATE rejects it instead.
+
+use crate::errors::ParseError;
+use crate::expr_tokens::count_distinct_arg;
+use crate::model::SemanticViewDefinition;
+
+/// Validate that every metric with `approx` set is a non-window base metric
+/// whose expression is a single `COUNT(DISTINCT ...)` call.
+pub fn validate_approx_metrics(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    for met in def.metrics.iter().filter(|m| m.approx) {
+        let reason = if met.source_table.is_none() {
+            Some("derived metrics cannot be approximate; mark the base metrics they reference")
+        } else if met.is_window() {
+            Some("window metrics cannot be approximate; mark the inner metric instead")
+        } else if count_distinct_arg(&met.expr).is_none() {
+            Some("its expression must be a single COUNT(DISTINCT ...) call")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(ParseError::positionless(format!(
+                "invalid approx on metric '{}': {reason}",
+                met.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_approx_metrics;
+    use crate::model::{Metric, SemanticViewDefinition};
+
+    fn def_with(expr: &str, source_table: Option<&str>) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            metrics: vec![Metric {
+                name: "customers".to_string(),
+                expr: expr.to_string(),
+                source_table: source_table.map(str::to_string),
+                approx: true,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn single_count_distinct_accepted() {
+        assert!(
+            validate_approx_metrics(&def_with("COUNT(DISTINCT o.customer_id)", Some("o"))).is_ok()
+        );
+    }
+
+    #[test]
+    fn other_expressions_rejected() {
+        for (expr, source_table, expected) in [
+            (
+                "COUNT(o.customer_id)",
+                Some("o"),
+                "single COUNT(DISTINCT ...) call",
+            ),
+            (
+                "COUNT(DISTINCT o.a) + 1",
+                Some("o"),
+                "single COUNT(DISTINCT ...) call",
+            ),
+            (
+                "customers_exact",
+                None,
+                "derived metrics cannot be approximate",
+            ),
+        ] {
+            let err = validate_approx_metrics(&def_with(expr, source_table))
+                .unwrap_err()
+                .message;
+            assert!(
+                err.contains("invalid approx on metric 'customers'"),
+                "{err}"
+            );
+            assert!(err.contains(expected), "{err}");
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! `base_sql` validation.
+//!
+//! A view reads its base rows either from the first declared table or from a
+//! `base_sql` query, never both: the base `TableRef` keeps its alias and keys
+//! but leaves `table` empty. The query is spliced into the generated SQL as
+//! `FROM (<base_sql>) AS <alias>`, so it gets the same lexical checks as an
+//! expression — an unquoted `;` or comment would end or truncate the statement
+//! around it.
+
+use crate::errors::ParseError;
+use crate::expr_tokens::{delimiter_problem, statement_break};
+use crate::model::SemanticViewDefinition;
+
+/// Validate that exactly one of the base table and `base_sql` is set, that
+/// every other table names a physical table, and that `base_sql` is a single
+/// well-formed query.
+pub fn validate_base_sql(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    let err = |msg: String| Err(ParseError::positionless(msg));
+    for (i, t) in def.tables.iter().enumerate() {
+        let is_base = i == 0;
+        let has_table = !t.table.trim().is_empty();
+        match (is_base && def.base_sql.is_some(), has_table) {
+            (true, true) => {
+                return err(format!(
+                    "table '{}' sets both a table ('{}') and base_sql; \
+                     remove one so the view has a single base source",
+                    t.alias, t.table
+                ));
+            }
+            (false, false) if is_base => {
+                return err(format!(
+                    "table '{}' needs a table name, or base_sql for the view's base rows",
+                    t.alias
+                ));
+            }
+            (false, false) => {
+                return err(format!(
+                    "table '{}' needs a table name; only the base table can be \
+                     replaced by base_sql",
+                    t.alias
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let Some(base_sql) = def.base_sql.as_deref() else {
+        return Ok(());
+    };
+    if def.tables.is_empty() {
+        return err(
+            "base_sql needs a base entry in tables to supply its alias and keys".to_string(),
+        );
+    }
+    if base_sql.trim().is_empty() {
+        return err("base_sql must not be empty".to_string());
+    }
+    if let Some(token) = statement_break(base_sql) {
+        return err(format!(
+            "base_sql contains '{token}' outside a string literal: {base_sql}. \
+             It must be a single query without statement separators or comments"
+        ));
+    }
+    if let Some(problem) = delimiter_problem(base_sql) {
+        return err(format!("base_sql is malformed ({problem}): {base_sql}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_base_sql;
+    use crate::model::{SemanticViewDefinition, TableRef};
+
+    fn def(base_table: &str, base_sql: Option<&str>) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            tables: vec![
+                TableRef {
+                    alias: "o".to_string(),
+                    table: base_table.to_string(),
+                    pk_columns: vec!["id".to_string()],
+                    ..Default::default()
+                },
+                TableRef {
+                    alias: "c".to_string(),
+                    table: "customers".to_string(),
+                    ..Default::default()
+                },
+            ],
+            base_sql: base_sql.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn table_or_query_accepted() {
+        assert!(validate_base_sql(&def("orders", None)).is_ok());
+        let sql = "SELECT * FROM orders WHERE status <> 'test;data'";
+        assert!(validate_base_sql(&def("", Some(sql))).is_ok());
+    }
+
+    #[test]
+    fn both_or_neither_rejected() {
+        let err = validate_base_sql(&def("orders", Some("SELECT 1")))
+            .unwrap_err()
+            .message;
+        assert!(
+            err.contains("both a table ('orders') and base_sql"),
+            "{err}"
+        );
+
+        let err = validate_base_sql(&def("", None)).unwrap_err().message;
+        assert!(
+            err.contains("table 'o' needs a table name, or base_sql"),
+            "{err}"
+        );
+
+        let mut joined_missing = def("", Some("SELECT 1"));
+        joined_missing.tables[1].table.clear();
+        let err = validate_base_sql(&joined_missing).unwrap_err().message;
+        assert!(err.contains("only the base table"), "{err}");
+    }
+
+    #[test]
+    fn malformed_query_rejected() {
+        let err = validate_base_sql(&def("", Some("SELECT 1; DROP TABLE orders")))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("contains ';'"), "{err}");
+
+        let err = validate_base_sql(&def("", Some("SELECT (1")))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("base_sql is malformed"), "{err}");
+
+        let err = validate_base_sql(&def("", Some("  "))).unwrap_err().message;
+        assert!(err.contains("must not be empty"), "{err}");
+    }
+}
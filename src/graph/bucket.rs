@@ -0,0 +1,81 @@
+//! Dimension `bucket` validation.
+//!
+//! Expansion renders a bucket dimension as `CASE WHEN expr <when> THEN
+//! '<label>' ... END` (see `SelectItem::bucketed`). An empty bin list would
+//! render `CASE END`, and a blank `when` would compare nothing, so both are
+//! rejected when the view is created. The `when` text itself is checked with
+//! the other expressions in `validate_expression_syntax`.
+
+use crate::errors::ParseError;
+use crate::model::SemanticViewDefinition;
+
+/// Validate that every dimension `bucket` has at least one bin and that no
+/// bin's `when` is blank.
+pub fn validate_buckets(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    for dim in &def.dimensions {
+        let Some(ref bins) = dim.bucket else {
+            continue;
+        };
+        if bins.is_empty() {
+            return Err(ParseError::positionless(format!(
+                "bucket dimension '{}' has no bins; declare at least one {{when, label}} \
+                 or remove bucket",
+                dim.name
+            )));
+        }
+        if let Some(i) = bins.iter().position(|bin| bin.when.trim().is_empty()) {
+            return Err(ParseError::positionless(format!(
+                "bucket dimension '{}': bin {} has an empty when",
+                dim.name,
+                i + 1
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_buckets;
+    use crate::model::{BucketBin, Dimension, SemanticViewDefinition};
+
+    fn def_with(bins: Option<Vec<BucketBin>>) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            dimensions: vec![Dimension {
+                name: "size".to_string(),
+                expr: "o.amount".to_string(),
+                bucket: bins,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn bin(when: &str, label: &str) -> BucketBin {
+        BucketBin {
+            when: when.to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn bins_accepted_and_plain_dimension_ignored() {
+        assert!(validate_buckets(&def_with(None)).is_ok());
+        assert!(validate_buckets(&def_with(Some(vec![bin("< 100", "small")]))).is_ok());
+    }
+
+    #[test]
+    fn empty_bins_rejected() {
+        let err = validate_buckets(&def_with(Some(vec![])))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("bucket dimension 'size' has no bins"), "{err}");
+    }
+
+    #[test]
+    fn blank_when_rejected() {
+        let bins = vec![bin("< 100", "small"), bin("  ", "large")];
+        let err = validate_buckets(&def_with(Some(bins))).unwrap_err().message;
+        assert!(err.contains("bin 2 has an empty when"), "{err}");
+    }
+}
@@ -0,0 +1,74 @@
+//! Dimension `collate` validation.
+//!
+//! Expansion emits a dimension's collation as `expr COLLATE "<part>"[."<part>"]`
+//! (see `SelectItem::collated`). The quoting keeps the name inert, but a
+//! collation that is not a plain identifier can never resolve in `DuckDB`, so it
+//! is rejected when the view is created rather than on every query.
+
+use crate::errors::ParseError;
+use crate::model::SemanticViewDefinition;
+
+/// Validate that every dimension `collate` value is a dotted identifier:
+/// one or more `.`-separated parts, each an ASCII letter or `_` followed by
+/// ASCII alphanumerics or `_` (`nocase`, `nocase.noaccent`, `de_de`).
+pub fn validate_collations(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    for dim in &def.dimensions {
+        let Some(ref collation) = dim.collate else {
+            continue;
+        };
+        if !collation.split('.').all(is_collation_part) {
+            return Err(ParseError::positionless(format!(
+                "invalid collation '{collation}' on dimension '{}': a collation must be an \
+                 identifier such as 'nocase' or 'nocase.noaccent'",
+                dim.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn is_collation_part(part: &str) -> bool {
+    let mut bytes = part.bytes();
+    bytes
+        .next()
+        .is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_collations;
+    use crate::model::{Dimension, SemanticViewDefinition};
+
+    fn def_with_collation(collation: &str) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            dimensions: vec![Dimension {
+                name: "region".to_string(),
+                expr: "region".to_string(),
+                collate: Some(collation.to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn identifier_collations_accepted() {
+        for ok in ["nocase", "NOCASE", "nocase.noaccent", "de_de", "_x1"] {
+            assert!(validate_collations(&def_with_collation(ok)).is_ok(), "{ok}");
+        }
+    }
+
+    #[test]
+    fn non_identifier_collations_rejected() {
+        for bad in ["", "no case", "1abc", "nocase.", "\"nocase\"", "x) --"] {
+            let err = validate_collations(&def_with_collation(bad)).unwrap_err();
+            assert!(
+                err.message.contains("invalid collation"),
+                "{bad}: {}",
+                err.message
+            );
+            assert!(err.message.contains("'region'"), "{}", err.message);
+        }
+    }
+}
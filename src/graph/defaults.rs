@@ -0,0 +1,114 @@
+//! `default_dimensions` / `default_metrics` / `time_dimension` validation.
+//!
+//! An empty query selects the view's defaults (`QueryRequest::or_view_defaults`),
+//! so a misspelt default would only surface as an unknown-name error on the
+//! first such query; a misspelt time dimension would silently turn every
+//! date-range macro into a literal comparison. Names are matched the way
+//! `expand()` matches them.
+
+use crate::errors::ParseError;
+use crate::expand::{DimensionName, MetricName};
+use crate::model::SemanticViewDefinition;
+
+/// Validate that every default dimension and metric, and the time dimension,
+/// names one the view declares.
+pub fn validate_defaults(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    for name in &def.default_dimensions {
+        let wanted = DimensionName::new(name);
+        if !def
+            .dimensions
+            .iter()
+            .any(|d| DimensionName::new(&d.name) == wanted)
+        {
+            return Err(ParseError::positionless(format!(
+                "default dimension '{name}' is not a dimension of this view"
+            )));
+        }
+    }
+    for name in &def.default_metrics {
+        let wanted = MetricName::new(name);
+        if !def
+            .metrics
+            .iter()
+            .any(|m| MetricName::new(&m.name) == wanted)
+        {
+            return Err(ParseError::positionless(format!(
+                "default metric '{name}' is not a metric of this view"
+            )));
+        }
+    }
+    if let Some(name) = &def.time_dimension {
+        let wanted = DimensionName::new(name);
+        if !def
+            .dimensions
+            .iter()
+            .any(|d| DimensionName::new(&d.name) == wanted)
+        {
+            return Err(ParseError::positionless(format!(
+                "time dimension '{name}' is not a dimension of this view"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_defaults;
+    use crate::model::{Dimension, Metric, SemanticViewDefinition};
+
+    fn def(default_dimensions: &[&str], default_metrics: &[&str]) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            dimensions: vec![Dimension {
+                name: "region".to_string(),
+                expr: "o.region".to_string(),
+                ..Default::default()
+            }],
+            metrics: vec![Metric {
+                name: "revenue".to_string(),
+                expr: "sum(o.amount)".to_string(),
+                ..Default::default()
+            }],
+            default_dimensions: default_dimensions
+                .iter()
+                .map(|s| (*s).to_string())
+                .collect(),
+            default_metrics: default_metrics.iter().map(|s| (*s).to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn declared_names_accepted_case_insensitively() {
+        assert!(validate_defaults(&def(&[], &[])).is_ok());
+        assert!(validate_defaults(&def(&["REGION"], &["Revenue"])).is_ok());
+    }
+
+    #[test]
+    fn unknown_names_rejected() {
+        let err = validate_defaults(&def(&["country"], &[]))
+            .unwrap_err()
+            .message;
+        assert_eq!(
+            err,
+            "default dimension 'country' is not a dimension of this view"
+        );
+        let err = validate_defaults(&def(&[], &["region"]))
+            .unwrap_err()
+            .message;
+        assert_eq!(err, "default metric 'region' is not a metric of this view");
+    }
+
+    #[test]
+    fn time_dimension_must_be_declared() {
+        let mut with_time = def(&[], &[]);
+        with_time.time_dimension = Some("Region".to_string());
+        assert!(validate_defaults(&with_time).is_ok());
+        with_time.time_dimension = Some("order_date".to_string());
+        let err = validate_defaults(&with_time).unwrap_err().message;
+        assert_eq!(
+            err,
+            "time dimension 'order_date' is not a dimension of this view"
+        );
+    }
+}
@@ -52,8 +52,53 @@ const AGGREGATE_FUNCTIONS: &[&str] = &[
     "bit_xor",
     "bool_and",
     "bool_or",
+    "count_if",
+    "countif",
+    "first",
+    "last",
+    "arbitrary",
+    "arg_max",
+    "arg_min",
+    "arg_max_null",
+    "arg_min_null",
+    "argmax",
+    "argmin",
+    "max_by",
+    "min_by",
+    "list",
+    "product",
+    "fsum",
+    "favg",
+    "mean",
+    "sumkahan",
+    "kahan_sum",
+    "geomean",
+    "geometric_mean",
+    "weighted_avg",
+    "quantile",
+    "quantile_cont",
+    "quantile_disc",
+    "approx_quantile",
+    "reservoir_quantile",
+    "mad",
+    "entropy",
+    "kurtosis",
+    "kurtosis_pop",
+    "skewness",
+    "histogram",
+    "bitstring_agg",
+    "sem",
+    "approx_top_k",
+    "json_group_array",
+    "json_group_object",
+    "json_group_structure",
 ];
 
+/// Is `name` (already lowercased, unqualified) a known aggregate function?
+pub(crate) fn is_aggregate_function(name: &str) -> bool {
+    AGGREGATE_FUNCTIONS.contains(&name)
+}
+
 /// Check if an expression contains an aggregate function call.
 ///
 /// Uses the shared reference tokenizer ([`crate::expr_tokens::scan_function_heads`])
@@ -0,0 +1,96 @@
+//! Metric `distinct` validation.
+//!
+//! Expansion turns a `distinct` metric's `SUM(x)` into `SUM(DISTINCT x)` (see
+//! `inline_derived_metrics`). Like `approx`, the rewrite is only defined for
+//! one whole aggregate call, and only for aggregates where dropping duplicate
+//! inputs can change the result, so CREATE rejects the flag anywhere else
+//! rather than ignore it.
+
+use crate::errors::ParseError;
+use crate::expr_tokens::distinct_call;
+use crate::model::SemanticViewDefinition;
+
+/// Validate that every metric with `distinct` set is a non-window,
+/// non-approximate base metric whose expression is a single call that can
+/// take `DISTINCT`.
+pub fn validate_distinct_metrics(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    for met in def.metrics.iter().filter(|m| m.distinct) {
+        let reason = if met.source_table.is_none() {
+            Some("derived metrics cannot be distinct; mark the base metrics they reference")
+        } else if met.is_window() {
+            Some("window metrics cannot be distinct; mark the inner metric instead")
+        } else if met.approx {
+            Some("approx already counts distinct values")
+        } else if distinct_call(&met.expr).is_none() {
+            Some(
+                "its expression must be a single COUNT, SUM, AVG, STRING_AGG, LIST, or \
+                 ARRAY_AGG call, not over * and not already DISTINCT",
+            )
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(ParseError::positionless(format!(
+                "invalid distinct on metric '{}': {reason}",
+                met.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_distinct_metrics;
+    use crate::model::{Metric, SemanticViewDefinition};
+
+    fn def_with(expr: &str, source_table: Option<&str>) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            metrics: vec![Metric {
+                name: "buyers".to_string(),
+                expr: expr.to_string(),
+                source_table: source_table.map(str::to_string),
+                distinct: true,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn supported_single_calls_accepted() {
+        for expr in ["COUNT(o.customer_id)", "sum(o.amount)", "avg(o.amount)"] {
+            assert!(
+                validate_distinct_metrics(&def_with(expr, Some("o"))).is_ok(),
+                "{expr}"
+            );
+        }
+    }
+
+    #[test]
+    fn other_metrics_rejected() {
+        let mut approx = def_with("COUNT(o.customer_id)", Some("o"));
+        approx.metrics[0].approx = true;
+        for (def, expected) in [
+            (def_with("COUNT(*)", Some("o")), "not over *"),
+            (def_with("max(o.amount)", Some("o")), "single COUNT, SUM"),
+            (
+                def_with("COUNT(DISTINCT o.customer_id)", Some("o")),
+                "not already DISTINCT",
+            ),
+            (
+                def_with("sum(o.a) - sum(o.b)", Some("o")),
+                "single COUNT, SUM",
+            ),
+            (
+                def_with("revenue", None),
+                "derived metrics cannot be distinct",
+            ),
+            (approx, "approx already counts distinct values"),
+        ] {
+            let err = validate_distinct_metrics(&def).unwrap_err().message;
+            assert!(err.contains("invalid distinct on metric 'buyers'"), "{err}");
+            assert!(err.contains(expected), "{err}");
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Define-time lexical checks of dimension, metric, and fact expressions, and
+//! of the view's `row_security` predicate.
+//!
+//! Expressions are spliced into generated SQL verbatim. Three failure modes
+//! are caught here, when the view is created, instead of on the first query:
+//!
+//! - an empty dimension, metric, or fact expression, which would render as
+//!   `AS "name"` with nothing before it;
+//! - an unquoted `;`, `--`, or `/*` ([`crate::expr_tokens::statement_break`]),
+//!   which could end the generated statement or comment out the rest of it
+//!   (`amount); DROP TABLE orders; --`);
+//! - a truncated `sum(o.amount` or an unterminated string, via
+//!   [`crate::expr_tokens::delimiter_problem`].
+//!
+//! CREATE runs without a database connection (the DDL is rewritten before
+//! execution), so neither is a full parse.
+
+use crate::errors::ParseError;
+use crate::expr_tokens::{delimiter_problem, statement_break};
+use crate::model::SemanticViewDefinition;
+
+/// Validate that every dimension, metric, and fact expression — including a
+/// dimension's bucket conditions, a metric's `filter`, and the parts of a
+/// window metric's spec that are emitted verbatim — is free of unquoted statement separators and comments, and is
+/// lexically well-formed (balanced `()`/`[]`, terminated quotes and comments).
+/// The same holds for the view's `row_security` predicate. Dimension, metric,
+/// and fact expressions must also be non-empty.
+pub fn validate_expression_syntax(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    let main_exprs = def
+        .dimensions
+        .iter()
+        .map(|d| ("dimension", &d.name, &d.expr))
+        .chain(def.metrics.iter().map(|m| ("metric", &m.name, &m.expr)))
+        .chain(def.facts.iter().map(|f| ("fact", &f.name, &f.expr)));
+    for (kind, name, expr) in main_exprs {
+        if expr.trim().is_empty() {
+            return Err(ParseError::positionless(format!(
+                "{kind} '{name}' has an empty expression"
+            )));
+        }
+    }
+
+    let mut items: Vec<(&str, &str, &str)> = Vec::new();
+    for d in &def.dimensions {
+        items.push(("dimension", &d.name, &d.expr));
+        for bin in d.bucket.iter().flatten() {
+            items.push(("dimension", &d.name, &bin.when));
+        }
+    }
+    for m in &def.metrics {
+        items.push(("metric", &m.name, &m.expr));
+        if let Some(ref filter) = m.filter {
+            items.push(("metric", &m.name, filter));
+        }
+        if let Some(ref spec) = m.window_spec {
+            let spec_parts = spec
+                .extra_args
+                .iter()
+                .map(String::as_str)
+                .chain(spec.order_by.iter().map(|o| o.expr.as_str()))
+                .chain(spec.frame_clause.as_deref());
+            items.extend(spec_parts.map(|part| ("metric", m.name.as_str(), part)));
+        }
+    }
+    for f in &def.facts {
+        items.push(("fact", &f.name, &f.expr));
+    }
+    if let Some(ref predicate) = def.row_security {
+        items.push(("filter", "row_security", predicate));
+    }
+
+    for (kind, name, expr) in items {
+        if let Some(token) = statement_break(expr) {
+            return Err(ParseError::positionless(format!(
+                "{kind} '{name}' contains '{token}' outside a string literal: {expr}. \
+                 Expressions must be a single SQL expression without statement \
+                 separators or comments"
+            )));
+        }
+        if let Some(problem) = delimiter_problem(expr) {
+            return Err(ParseError::positionless(format!(
+                "{kind} '{name}' has a malformed expression ({problem}): {expr}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_expression_syntax;
+    use crate::model::{Dimension, Fact, Metric, SemanticViewDefinition, WindowSpec};
+
+    fn def(dim: &str, metric: &str, fact: &str) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            dimensions: vec![Dimension {
+                name: "region".to_string(),
+                expr: dim.to_string(),
+                ..Default::default()
+            }],
+            metrics: vec![Metric {
+                name: "revenue".to_string(),
+                expr: metric.to_string(),
+                ..Default::default()
+            }],
+            facts: vec![Fact {
+                name: "net".to_string(),
+                expr: fact.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn well_formed_expressions_accepted() {
+        let d = def("upper(o.region)", "sum(o.amount)", "o.amount - o.tax");
+        assert!(validate_expression_syntax(&d).is_ok());
+    }
+
+    #[test]
+    fn malformed_expression_names_the_item() {
+        let err = validate_expression_syntax(&def("o.region", "sum(o.amount", "o.x"))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("metric 'revenue'"), "{err}");
+        assert!(err.contains("unclosed '('"), "{err}");
+
+        let err = validate_expression_syntax(&def("o.region = 'EU", "sum(o.a)", "o.x"))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("dimension 'region'"), "{err}");
+
+        let err = validate_expression_syntax(&def("o.region", "sum(o.a)", "(o.x))"))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("fact 'net'"), "{err}");
+    }
+
+    #[test]
+    fn empty_expressions_rejected() {
+        let err = validate_expression_syntax(&def("", "sum(o.a)", "o.x"))
+            .unwrap_err()
+            .message;
+        assert_eq!(err, "dimension 'region' has an empty expression");
+
+        let err = validate_expression_syntax(&def("o.region", "  ", "o.x"))
+            .unwrap_err()
+            .message;
+        assert_eq!(err, "metric 'revenue' has an empty expression");
+
+        let err = validate_expression_syntax(&def("o.region", "sum(o.a)", ""))
+            .unwrap_err()
+            .message;
+        assert_eq!(err, "fact 'net' has an empty expression");
+    }
+
+    #[test]
+    fn statement_separators_and_comments_rejected() {
+        let err = validate_expression_syntax(&def(
+            "o.region",
+            "sum(o.amount); DROP TABLE orders; --",
+            "o.x",
+        ))
+        .unwrap_err()
+        .message;
+        assert!(err.contains("metric 'revenue' contains ';'"), "{err}");
+
+        let err = validate_expression_syntax(&def("o.region -- hidden", "sum(o.a)", "o.x"))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("dimension 'region' contains '--'"), "{err}");
+
+        // Quoted semicolons and comment markers are data, not syntax.
+        let d = def("o.note = 'a; b -- c /* d'", "sum(o.a)", "o.x");
+        assert!(validate_expression_syntax(&d).is_ok());
+    }
+
+    #[test]
+    fn window_frame_clause_checked() {
+        let mut d = def("o.region", "sum(o.a)", "o.x");
+        d.metrics[0].window_spec = Some(WindowSpec {
+            window_function: "SUM".to_string(),
+            inner_metric: "revenue".to_string(),
+            frame_clause: Some("ROWS UNBOUNDED PRECEDING); SELECT 1; --".to_string()),
+            ..Default::default()
+        });
+        let err = validate_expression_syntax(&d).unwrap_err().message;
+        assert!(err.contains("metric 'revenue' contains ';'"), "{err}");
+    }
+    #[test]
+    fn row_security_checked() {
+        let mut d = def("o.region", "sum(o.a)", "o.x");
+        d.row_security = Some("o.tenant_id = current_setting('app.tenant')".to_string());
+        assert!(validate_expression_syntax(&d).is_ok());
+        d.row_security = Some("o.tenant_id = 1; --".to_string());
+        let err = validate_expression_syntax(&d).unwrap_err().message;
+        assert!(err.contains("filter 'row_security' contains ';'"), "{err}");
+    }
+}
@@ -0,0 +1,83 @@
+//! Metric `filter` validation.
+//!
+//! Expansion attaches a metric's filter as `<expr> FILTER (WHERE <filter>)`
+//! (see `inline_derived_metrics`). `FILTER` binds to a single aggregate call,
+//! so the clause is only meaningful when the whole expression is one: on
+//! `SUM(a) + SUM(b)` it would silently filter `SUM(b)` alone, and a derived or
+//! window metric has no aggregate of its own to filter.
+
+use crate::errors::ParseError;
+use crate::expr_tokens::single_call_head;
+use crate::model::SemanticViewDefinition;
+
+/// Validate that every metric with a `filter` is a non-window base metric
+/// whose expression is a single function call (`SUM(o.amount)`).
+pub fn validate_metric_filters(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    for met in def.metrics.iter().filter(|m| m.filter.is_some()) {
+        let reason = if met.source_table.is_none() {
+            Some("derived metrics cannot take a filter; filter the base metrics they reference")
+        } else if met.is_window() {
+            Some("window metrics cannot take a filter; filter the inner metric instead")
+        } else if single_call_head(&met.expr).is_none() {
+            Some("its expression must be a single aggregate call such as SUM(o.amount)")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(ParseError::positionless(format!(
+                "invalid filter on metric '{}': {reason}",
+                met.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_metric_filters;
+    use crate::model::{Metric, SemanticViewDefinition, WindowSpec};
+
+    fn def_with(metric: Metric) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            metrics: vec![metric],
+            ..Default::default()
+        }
+    }
+
+    fn filtered(expr: &str, source_table: Option<&str>) -> Metric {
+        Metric {
+            name: "completed_revenue".to_string(),
+            expr: expr.to_string(),
+            source_table: source_table.map(str::to_string),
+            filter: Some("o.status = 'completed'".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn single_aggregate_base_metric_accepted() {
+        let def = def_with(filtered("SUM(o.amount)", Some("o")));
+        assert!(validate_metric_filters(&def).is_ok());
+    }
+
+    #[test]
+    fn compound_derived_and_window_metrics_rejected() {
+        let err = validate_metric_filters(&def_with(filtered("SUM(o.a) + SUM(o.b)", Some("o"))))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("single aggregate call"), "{err}");
+
+        let err = validate_metric_filters(&def_with(filtered("revenue - cost", None)))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("derived metrics"), "{err}");
+
+        let mut window = filtered("AVG(revenue)", Some("o"));
+        window.window_spec = Some(WindowSpec::default());
+        let err = validate_metric_filters(&def_with(window))
+            .unwrap_err()
+            .message;
+        assert!(err.contains("window metrics"), "{err}");
+    }
+}
@@ -1,22 +1,45 @@
 //! Relationship graph validation and topological sort for semantic view definitions.
 
+mod aggregate_hints;
+mod approx;
+mod base_sql;
+mod bucket;
 mod cardinality;
+mod collation;
+mod defaults;
 mod derived_metrics;
+mod distinct;
+mod expr_syntax;
 mod facts;
 mod join_tree;
+mod metric_filter;
 mod names;
+mod null_value;
 mod relationship;
 mod toposort;
 mod using;
+mod validate;
 
 #[cfg(test)]
 mod test_helpers;
 
 // Public API (matches prior graph.rs surface exactly)
+pub use approx::validate_approx_metrics;
+pub use base_sql::validate_base_sql;
+pub use bucket::validate_buckets;
 pub(crate) use cardinality::infer_cardinality;
+pub use collation::validate_collations;
+pub use defaults::validate_defaults;
 pub use derived_metrics::{contains_aggregate_function, validate_derived_metrics};
+pub use distinct::validate_distinct_metrics;
+pub use expr_syntax::validate_expression_syntax;
 pub use facts::{find_fact_references, validate_facts};
 pub(crate) use join_tree::JoinTree;
-pub use names::validate_name_uniqueness;
+pub use metric_filter::validate_metric_filters;
+pub use names::{
+    set_strict_names, strict_names, validate_name_characters, validate_name_uniqueness,
+};
+pub use null_value::validate_null_values;
 pub use relationship::{validate_graph, RelationshipGraph};
 pub use using::validate_using_relationships;
+pub use validate::{ValidationCheck, ValidationError, ValidationWarning};
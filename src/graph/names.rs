@@ -18,11 +18,21 @@
 //! quoting — `region`, `REGION`, `"Region"` — all share key `region` and
 //! collide.
 //!
+//! An empty (or all-whitespace) name is rejected here too: it can never be
+//! requested and renders as a `""` output column.
+//!
+//! [`validate_name_characters`] adds an opt-in strict mode
+//! (`SET semantic_views_strict_names = true`) limiting view, dimension,
+//! metric, and fact names to letters, digits, `_`, space, and `-`, so control
+//! characters and other surprises never reach generated SQL or error text.
+//! It is off by default because any quoted identifier is a valid name.
+//!
 //! This is define-time-only validation: read paths (`SHOW`, `DESCRIBE`,
 //! expansion) intentionally keep first-match behavior so legacy catalog rows
 //! that predate this check still load and query.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::errors::ParseError;
 use crate::model::SemanticViewDefinition;
@@ -32,16 +42,34 @@ use crate::model::SemanticViewDefinition;
 /// (case-insensitive, quoted or not — see the module docs and
 /// [`crate::ident::normalize_ident_part`]).
 ///
-/// Returns `Err` naming the colliding item and the kinds involved.
+/// Returns `Err` naming the colliding item and the kinds involved, or the
+/// position of an item whose name is empty.
 pub fn validate_name_uniqueness(def: &SemanticViewDefinition) -> Result<(), ParseError> {
     let mut seen: HashMap<String, (&str, &str)> = HashMap::new();
     let items = def
         .dimensions
         .iter()
-        .map(|d| ("dimension", d.name.as_str()))
-        .chain(def.metrics.iter().map(|m| ("metric", m.name.as_str())))
-        .chain(def.facts.iter().map(|f| ("fact", f.name.as_str())));
-    for (kind, name) in items {
+        .enumerate()
+        .map(|(i, d)| ("dimension", i, d.name.as_str()))
+        .chain(
+            def.metrics
+                .iter()
+                .enumerate()
+                .map(|(i, m)| ("metric", i, m.name.as_str())),
+        )
+        .chain(
+            def.facts
+                .iter()
+                .enumerate()
+                .map(|(i, f)| ("fact", i, f.name.as_str())),
+        );
+    for (kind, index, name) in items {
+        if name.trim().is_empty() {
+            return Err(ParseError::positionless(format!(
+                "{kind} {} has an empty name; every {kind} needs a name",
+                index + 1
+            )));
+        }
         let key = crate::ident::normalize_ident_part(name);
         if let Some((first_kind, first_name)) = seen.get(key.as_str()) {
             return Err(ParseError::positionless(format!(
@@ -55,9 +83,66 @@ pub fn validate_name_uniqueness(def: &SemanticViewDefinition) -> Result<(), Pars
     Ok(())
 }
 
+/// Process-wide strict-name setting, written by the GLOBAL-only
+/// `semantic_views_strict_names` extension option.
+static STRICT_NAMES: AtomicBool = AtomicBool::new(false);
+
+/// Whether CREATE currently enforces the strict name character class.
+#[must_use]
+pub fn strict_names() -> bool {
+    STRICT_NAMES.load(Ordering::Relaxed)
+}
+
+/// Turn the strict name character class on or off for later CREATEs.
+pub fn set_strict_names(strict: bool) {
+    STRICT_NAMES.store(strict, Ordering::Relaxed);
+}
+
+/// Is `c` allowed in a name under the strict character class?
+fn is_strict_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | ' ' | '-')
+}
+
+/// When `strict`, check that the view name and every dimension, metric, and
+/// fact name use only letters, digits, `_`, space, and `-`. Does nothing
+/// otherwise.
+///
+/// Returns `Err` naming the item and the first disallowed character, both
+/// escaped so a newline or other control character shows up as `\n`.
+pub fn validate_name_characters(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    strict: bool,
+) -> Result<(), ParseError> {
+    if !strict {
+        return Ok(());
+    }
+    let items = std::iter::once(("semantic view", view_name))
+        .chain(
+            def.dimensions
+                .iter()
+                .map(|d| ("dimension", d.name.as_str())),
+        )
+        .chain(def.metrics.iter().map(|m| ("metric", m.name.as_str())))
+        .chain(def.facts.iter().map(|f| ("fact", f.name.as_str())));
+    for (kind, name) in items {
+        if let Some(c) = name.chars().find(|&c| !is_strict_name_char(c)) {
+            return Err(ParseError::positionless(format!(
+                "{kind} '{}' contains the character '{}' (U+{:04X}); with \
+                 semantic_views_strict_names enabled, names may only contain \
+                 letters, digits, '_', ' ', and '-'",
+                name.escape_debug(),
+                c.escape_debug(),
+                u32::from(c)
+            )));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::validate_name_uniqueness;
+    use super::{validate_name_characters, validate_name_uniqueness};
     use crate::model::{Dimension, Fact, Metric, SemanticViewDefinition};
 
     fn def_with(dims: &[&str], metrics: &[&str], facts: &[&str]) -> SemanticViewDefinition {
@@ -129,6 +214,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn control_character_name_depends_on_mode() {
+        let def = def_with(&["region\nEU"], &["revenue"], &[]);
+        assert!(validate_name_characters("sales", &def, false).is_ok());
+        let err = validate_name_characters("sales", &def, true)
+            .unwrap_err()
+            .message;
+        assert!(
+            err.starts_with("dimension 'region\\nEU' contains the character '\\n' (U+000A)"),
+            "{err}"
+        );
+
+        let def = def_with(&["region"], &[], &[]);
+        assert!(validate_name_characters("sales\u{7}", &def, false).is_ok());
+        let err = validate_name_characters("sales\u{7}", &def, true)
+            .unwrap_err()
+            .message;
+        assert!(err.starts_with("semantic view 'sales\\u{7}'"), "{err}");
+    }
+
+    #[test]
+    fn strict_mode_accepts_the_sane_character_class() {
+        let def = def_with(&["order date", "ship-mode", "région_2"], &["Revenue"], &[]);
+        assert!(validate_name_characters("sales view", &def, true).is_ok());
+        let def = def_with(&["a.b"], &[], &[]);
+        assert!(validate_name_characters("sales", &def, true).is_err());
+    }
+
+    #[test]
+    fn empty_names_rejected_by_position() {
+        let def = def_with(&["region", ""], &[], &[]);
+        let err = validate_name_uniqueness(&def).unwrap_err().message;
+        assert_eq!(
+            err,
+            "dimension 2 has an empty name; every dimension needs a name"
+        );
+
+        let def = def_with(&["region"], &["  "], &[]);
+        let err = validate_name_uniqueness(&def).unwrap_err().message;
+        assert!(err.starts_with("metric 1 has an empty name"), "{err}");
+
+        let def = def_with(&[], &[], &[""]);
+        let err = validate_name_uniqueness(&def).unwrap_err().message;
+        assert!(err.starts_with("fact 1 has an empty name"), "{err}");
+    }
+
     #[test]
     fn duplicate_dimension_names_rejected() {
         let def = def_with(&["region", "Region"], &[], &[]);
@@ -0,0 +1,59 @@
+//! Dimension `null_value` validation.
+//!
+//! `coalesce_to` and `null_value` both wrap a dimension in `COALESCE`, with
+//! different treatment of empty strings. Only one wrap is emitted, so a
+//! dimension declaring both would silently lose one of them.
+
+use crate::errors::ParseError;
+use crate::model::SemanticViewDefinition;
+
+/// Validate that no dimension sets both `coalesce_to` and `null_value`.
+pub fn validate_null_values(def: &SemanticViewDefinition) -> Result<(), ParseError> {
+    for dim in &def.dimensions {
+        if dim.coalesce_to.is_some() && dim.null_value.is_some() {
+            return Err(ParseError::positionless(format!(
+                "dimension '{}' sets both coalesce_to and null_value; \
+                 use coalesce_to to replace NULLs and empty strings, \
+                 null_value to replace NULLs only",
+                dim.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_null_values;
+    use crate::model::{Dimension, SemanticViewDefinition};
+
+    fn def_with(coalesce_to: Option<&str>, null_value: Option<&str>) -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            dimensions: vec![Dimension {
+                name: "region".to_string(),
+                expr: "o.region".to_string(),
+                coalesce_to: coalesce_to.map(str::to_string),
+                null_value: null_value.map(str::to_string),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn either_setting_alone_accepted() {
+        assert!(validate_null_values(&def_with(Some("Unknown"), None)).is_ok());
+        assert!(validate_null_values(&def_with(None, Some("N/A"))).is_ok());
+    }
+
+    #[test]
+    fn both_settings_rejected() {
+        let err = validate_null_values(&def_with(Some("Unknown"), Some("N/A")))
+            .unwrap_err()
+            .message;
+        assert!(
+            err.contains("dimension 'region' sets both coalesce_to and null_value"),
+            "{err}"
+        );
+    }
+}
@@ -64,7 +64,8 @@ impl RelationshipGraph {
             // Self-reference check
             if from == to {
                 return Err(format!(
-                    "table '{}' cannot reference itself",
+                    "table '{}' cannot reference itself. To self-join, declare \
+                     the table a second time under another alias and reference that alias",
                     join.from_alias
                 ));
             }
@@ -250,6 +251,34 @@ fn validate_fk_references(def: &SemanticViewDefinition) -> Result<(), String> {
     Ok(())
 }
 
+/// Check that both ends of every relationship name a declared table alias.
+///
+/// The ON clause is synthesized from `from_alias` and `table`, so an
+/// undeclared alias on either side would only fail once `DuckDB` binds the
+/// expanded query. The DDL parser only produces declared aliases; JSON and
+/// YAML definitions can carry anything.
+fn check_relationship_tables_declared(def: &SemanticViewDefinition) -> Result<(), String> {
+    let declared: Vec<String> = def
+        .tables
+        .iter()
+        .map(|t| t.alias.to_ascii_lowercase())
+        .collect();
+    for join in def.joins.iter().filter(|j| !j.fk_columns.is_empty()) {
+        for alias in [&join.from_alias, &join.table] {
+            if declared.contains(&alias.to_ascii_lowercase()) {
+                continue;
+            }
+            let rel_name = join.name.as_deref().unwrap_or("?");
+            let mut msg = format!("relationship '{rel_name}' references unknown table '{alias}'");
+            if let Some(s) = suggest_closest(&alias.to_ascii_lowercase(), &declared) {
+                let _ = write!(msg, "; did you mean '{s}'?");
+            }
+            return Err(msg);
+        }
+    }
+    Ok(())
+}
+
 /// Check that all dim/metric `source_table` aliases are declared in the graph.
 fn check_source_tables_reachable(
     def: &SemanticViewDefinition,
@@ -285,9 +314,30 @@ fn check_source_tables_reachable(
     Ok(())
 }
 
+/// Reject two `TABLES` entries with the same alias (ASCII case-insensitive,
+/// like every other alias comparison in the graph). Expansion emits each
+/// alias once per join and the graph keys nodes by alias, so a repeated alias
+/// either collapses two tables into one node or emits a duplicate `AS` that
+/// `DuckDB` rejects at query time. This includes a table declared twice
+/// without aliases, since the alias defaults to the table name.
+fn check_unique_table_aliases(def: &SemanticViewDefinition) -> Result<(), String> {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for t in &def.tables {
+        if let Some(first) = seen.insert(t.alias.to_ascii_lowercase(), &t.table) {
+            return Err(format!(
+                "duplicate table alias '{}': tables '{first}' and '{}' are both declared \
+                 with it. Give each table a distinct alias.",
+                t.alias, t.table
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Validate the relationship graph of a semantic view definition.
 ///
 /// Runs all define-time checks:
+/// 0. Table alias uniqueness (also for definitions without relationships)
 /// 1. Self-reference detection (`from_alias` == `to_alias`)
 /// 2. Cycle detection (Kahn's algorithm)
 /// 3. Diamond detection (multiple parents)
@@ -301,6 +351,8 @@ fn check_source_tables_reachable(
 /// is empty, returns `Ok` with a default empty graph. This preserves backward
 /// compatibility with Phase 10/11 definitions.
 pub fn validate_graph(def: &SemanticViewDefinition) -> Result<RelationshipGraph, ParseError> {
+    check_unique_table_aliases(def).map_err(ParseError::positionless)?;
+
     // Legacy skip: no Phase 24 joins -> skip graph validation entirely.
     let has_pkfk_joins = def.joins.iter().any(|j| !j.fk_columns.is_empty());
     if !has_pkfk_joins || def.tables.is_empty() {
@@ -312,6 +364,7 @@ pub fn validate_graph(def: &SemanticViewDefinition) -> Result<RelationshipGraph,
         });
     }
 
+    check_relationship_tables_declared(def).map_err(ParseError::positionless)?;
     let graph = RelationshipGraph::from_definition(def).map_err(ParseError::positionless)?;
 
     // 1. Cycle detection (Kahn's algorithm).
@@ -336,6 +389,8 @@ pub fn validate_graph(def: &SemanticViewDefinition) -> Result<RelationshipGraph,
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use crate::graph::validate_graph;
     use crate::model::{Join, SemanticViewDefinition, TableRef};
 
@@ -360,6 +415,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn self_join_through_second_alias_accepted() {
+        let def = make_def(
+            vec![
+                ("e", "employees", vec!["id"]),
+                ("m", "employees", vec!["id"]),
+            ],
+            vec![("e", "m", vec!["manager_id"])],
+            vec![],
+            vec![],
+        );
+        assert!(validate_graph(&def).is_ok());
+    }
+
+    #[test]
+    fn duplicate_table_alias_rejected_case_insensitively() {
+        let def = make_def(
+            vec![("o", "orders", vec!["id"]), ("O", "customers", vec!["id"])],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let err = validate_graph(&def).unwrap_err().message;
+        assert!(
+            err.contains("duplicate table alias 'O'")
+                && err.contains("'orders'")
+                && err.contains("'customers'"),
+            "expected duplicate-alias error, got: {err}"
+        );
+    }
+
     // -----------------------------------------------------------------------
     // Cycle detection
     // -----------------------------------------------------------------------
@@ -462,6 +548,45 @@ mod tests {
         );
     }
 
+    // -----------------------------------------------------------------------
+    // Relationship endpoints
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn relationship_between_declared_tables_is_accepted() {
+        let def = make_def(
+            vec![("o", "orders", vec!["id"]), ("c", "customers", vec!["id"])],
+            vec![("O", "c", vec!["customer_id"])],
+            vec![],
+            vec![],
+        );
+        assert!(validate_graph(&def).is_ok());
+    }
+
+    #[test]
+    fn relationship_to_undeclared_table_is_rejected() {
+        let def = make_def(
+            vec![("o", "orders", vec!["id"]), ("c", "customers", vec!["id"])],
+            vec![("o", "cust", vec!["customer_id"])],
+            vec![],
+            vec![],
+        );
+        let err = validate_graph(&def).unwrap_err().message;
+        assert!(
+            err.contains("references unknown table 'cust'"),
+            "expected unknown relationship table error, got: {err}"
+        );
+
+        let def = make_def(
+            vec![("o", "orders", vec!["id"]), ("c", "customers", vec!["id"])],
+            vec![("x", "c", vec!["customer_id"])],
+            vec![],
+            vec![],
+        );
+        let err = validate_graph(&def).unwrap_err().message;
+        assert!(err.contains("unknown table 'x'"), "got: {err}");
+    }
+
     // -----------------------------------------------------------------------
     // Unreachable source_table
     // -----------------------------------------------------------------------
@@ -571,6 +696,14 @@ mod tests {
             metrics: vec![],
             facts: vec![],
             materializations: vec![],
+            base_sql: None,
+            extends: None,
+            default_dimensions: vec![],
+            default_metrics: vec![],
+            time_dimension: None,
+            row_security: None,
+            tags: vec![],
+            labels: BTreeMap::new(),
 
             created_on: None,
             database_name: None,
@@ -767,6 +900,8 @@ mod tests {
     // -----------------------------------------------------------------------
 
     mod phase33_fk_reference_tests {
+        use std::collections::BTreeMap;
+
         use super::super::validate_fk_references;
         use crate::model::{Join, SemanticViewDefinition, TableRef};
 
@@ -779,6 +914,14 @@ mod tests {
                 metrics: vec![],
                 facts: vec![],
                 materializations: vec![],
+                base_sql: None,
+                extends: None,
+                default_dimensions: vec![],
+                default_metrics: vec![],
+                time_dimension: None,
+                row_security: None,
+                tags: vec![],
+                labels: BTreeMap::new(),
 
                 created_on: None,
                 database_name: None,
@@ -1,5 +1,7 @@
 //! Shared test helpers for graph submodule tests.
 
+use std::collections::BTreeMap;
+
 use crate::model::{
     AccessModifier, Dimension, Fact, Join, Metric, SemanticViewDefinition, TableRef,
 };
@@ -39,6 +41,10 @@ pub(super) fn make_def(
                 expr: name.to_string(),
                 source_table: source.map(|s| s.to_string()),
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             })
@@ -56,10 +62,21 @@ pub(super) fn make_def(
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             })
             .collect(),
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -101,6 +118,14 @@ pub(super) fn make_def_with_facts(
         metrics: vec![],
         joins: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -127,6 +152,9 @@ pub(super) fn make_def_with_derived_metrics(
             access: AccessModifier::Public,
             non_additive_by: vec![],
             window_spec: None,
+            filter: None,
+            approx: false,
+            distinct: false,
         });
     }
     for (name, expr) in derived_metrics {
@@ -141,6 +169,9 @@ pub(super) fn make_def_with_derived_metrics(
             access: AccessModifier::Public,
             non_additive_by: vec![],
             window_spec: None,
+            filter: None,
+            approx: false,
+            distinct: false,
         });
     }
     SemanticViewDefinition {
@@ -157,6 +188,14 @@ pub(super) fn make_def_with_derived_metrics(
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -207,10 +246,21 @@ pub(super) fn make_def_with_named_joins(
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             })
             .collect(),
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
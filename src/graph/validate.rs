@@ -0,0 +1,232 @@
+//! All-problems-at-once definition validation ([`SemanticViewDefinition::validate`]).
+//!
+//! CREATE runs the same checks but stops at the first failure, which suits a
+//! DDL error. A library caller building or importing a definition wants every
+//! problem in one go, so `validate` runs each check independently and collects
+//! one [`ValidationError`] per failing check.
+//!
+//! [`SemanticViewDefinition::warnings`] is the advisory counterpart: lexical
+//! heuristics that CREATE does not enforce.
+
+use std::fmt;
+
+use crate::errors::ParseError;
+use crate::model::SemanticViewDefinition;
+
+/// Which CREATE-time check a [`ValidationError`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationCheck {
+    /// Dimension, metric, and fact names share one namespace.
+    NameUniqueness,
+    /// Expressions are single, lexically well-formed SQL expressions.
+    ExpressionSyntax,
+    /// Exactly one base source (table or `base_sql`), and `base_sql` syntax.
+    BaseSql,
+    /// Table aliases, relationship graph shape, FK targets, and `source_table`
+    /// reachability.
+    Relationships,
+    /// Fact source tables and fact-to-fact references.
+    Facts,
+    /// Derived-metric references and cycles.
+    DerivedMetrics,
+    /// Metric `USING` relationship names.
+    UsingRelationships,
+    /// Dimension `collate` names.
+    Collations,
+    /// Dimension `coalesce_to` / `null_value` exclusivity.
+    NullValues,
+    /// Dimension `bucket` bins.
+    Buckets,
+    /// Metric `filter` placement.
+    MetricFilters,
+    /// Metric `approx` placement.
+    ApproxMetrics,
+    /// Metric `distinct` placement.
+    DistinctMetrics,
+    /// `default_dimensions` / `default_metrics` / `time_dimension` name
+    /// declared items.
+    Defaults,
+}
+
+/// One failed check from [`SemanticViewDefinition::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub check: ValidationCheck,
+    /// The message CREATE would report for this check.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// One finding from [`SemanticViewDefinition::warnings`]: an item that is
+/// accepted but probably not what was meant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationWarning {
+    /// The dimension or metric the warning is about, as declared.
+    pub item: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// One of the CREATE-time validators `validate()` runs.
+type Check = fn(&SemanticViewDefinition) -> Result<(), ParseError>;
+
+impl SemanticViewDefinition {
+    /// Run every CREATE-time definition check and return all failures.
+    ///
+    /// Checks run independently, so a duplicate name, a dangling
+    /// `source_table`, and a malformed expression are reported together.
+    /// Each check still reports only its first problem; fix it and re-run to
+    /// see the next one of the same kind. Cardinality inference and the
+    /// missing-PRIMARY-KEY check that CREATE also performs mutate or depend on
+    /// the DDL path and are not repeated here.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let checks: [(ValidationCheck, Check); 14] = [
+            (
+                ValidationCheck::NameUniqueness,
+                super::validate_name_uniqueness,
+            ),
+            (
+                ValidationCheck::ExpressionSyntax,
+                super::validate_expression_syntax,
+            ),
+            (ValidationCheck::BaseSql, super::validate_base_sql),
+            (ValidationCheck::Relationships, |def| {
+                super::validate_graph(def).map(|_| ())
+            }),
+            (ValidationCheck::Facts, super::validate_facts),
+            (
+                ValidationCheck::DerivedMetrics,
+                super::validate_derived_metrics,
+            ),
+            (
+                ValidationCheck::UsingRelationships,
+                super::validate_using_relationships,
+            ),
+            (ValidationCheck::Collations, super::validate_collations),
+            (ValidationCheck::NullValues, super::validate_null_values),
+            (ValidationCheck::Buckets, super::validate_buckets),
+            (
+                ValidationCheck::MetricFilters,
+                super::validate_metric_filters,
+            ),
+            (
+                ValidationCheck::ApproxMetrics,
+                super::validate_approx_metrics,
+            ),
+            (
+                ValidationCheck::DistinctMetrics,
+                super::validate_distinct_metrics,
+            ),
+            (ValidationCheck::Defaults, super::validate_defaults),
+        ];
+        let errors: Vec<ValidationError> = checks
+            .into_iter()
+            .filter_map(|(check, run)| {
+                run(self).err().map(|e| ValidationError {
+                    check,
+                    message: e.message,
+                })
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Heuristic findings that do not stop CREATE: a dimension whose
+    /// expression is an aggregate call (`SUM(o.amount)`), and a base metric
+    /// whose expression has no aggregate at all (`o.amount`). Either fails
+    /// only once queried, with a `GROUP BY` error from `DuckDB`.
+    /// `expand_with_diagnostics` reports the ones a query touches.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        super::aggregate_hints::aggregate_placement_warnings(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidationCheck;
+    use crate::model::{Dimension, Metric, SemanticViewDefinition, TableRef};
+
+    fn table(alias: &str) -> TableRef {
+        TableRef {
+            alias: alias.to_string(),
+            table: "orders".to_string(),
+            pk_columns: vec!["id".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn valid_definition_passes() {
+        let def = SemanticViewDefinition {
+            tables: vec![table("o")],
+            dimensions: vec![Dimension {
+                name: "region".to_string(),
+                expr: "o.region".to_string(),
+                source_table: Some("o".to_string()),
+                ..Default::default()
+            }],
+            metrics: vec![Metric {
+                name: "revenue".to_string(),
+                expr: "sum(o.amount)".to_string(),
+                source_table: Some("o".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(def.validate(), Ok(()));
+    }
+
+    #[test]
+    fn independent_problems_are_reported_together() {
+        let def = SemanticViewDefinition {
+            // Duplicate alias (Relationships).
+            tables: vec![table("o"), table("O")],
+            dimensions: vec![Dimension {
+                name: "revenue".to_string(),
+                // Unclosed paren (ExpressionSyntax).
+                expr: "upper(o.region".to_string(),
+                ..Default::default()
+            }],
+            metrics: vec![Metric {
+                // Collides with the dimension (NameUniqueness).
+                name: "revenue".to_string(),
+                expr: "sum(o.amount) * 2".to_string(),
+                source_table: Some("o".to_string()),
+                // Filter on more than a single call (MetricFilters).
+                filter: Some("o.status = 'x'".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let errors = def.validate().unwrap_err();
+        let checks: Vec<ValidationCheck> = errors.iter().map(|e| e.check).collect();
+        assert_eq!(
+            checks,
+            vec![
+                ValidationCheck::NameUniqueness,
+                ValidationCheck::ExpressionSyntax,
+                ValidationCheck::Relationships,
+                ValidationCheck::MetricFilters,
+            ],
+            "{errors:?}"
+        );
+        assert!(errors[2].to_string().contains("duplicate table alias"));
+    }
+}
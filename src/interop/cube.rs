@@ -0,0 +1,684 @@
+//! Import of Cube data models (`cubes:` YAML, or the same structure as JSON /
+//! camelCase keys from a JavaScript model) into semantic view definitions.
+//!
+//! Each cube becomes one view named after it, aliased by its own name:
+//!
+//! - `sql_table` is the base table; `primary_key: true` dimensions form its
+//!   PRIMARY KEY. A `sql: SELECT * FROM <table>` cube is treated as
+//!   `sql_table: <table>`.
+//! - `joins` with a `many_to_one` / `belongs_to` or `one_to_one` / `has_one`
+//!   relationship and an equi-join `sql` (`{CUBE}.a = {other}.b [AND ...]`)
+//!   add the joined cube's table and a relationship named after it. Key
+//!   declarations are added so that define-time cardinality inference
+//!   arrives at the Cube relationship: the referenced columns are the joined
+//!   table's key, and for one-to-one the joining columns are UNIQUE too. The
+//!   joined cube's dimensions become `<cube>__<dimension>`.
+//! - dimensions map one-to-one; `{CUBE}` / `${CUBE}` and `{other_cube}`
+//!   references resolve to table aliases, and a bare column is qualified.
+//! - `sum` / `avg` / `min` / `max` / `count` / `count_distinct` /
+//!   `count_distinct_approx` measures become aggregate metrics, with
+//!   `filters` as the metric's `FILTER (WHERE ...)` predicate; `number`
+//!   measures over other measures (`{revenue} / {count}`) become derived
+//!   metrics. `public: false` measures are PRIVATE.
+//!
+//! Segments, pre-aggregations, `extends`, one-to-many joins, rolling-window
+//! and running-total measures, and `case` / `sub_query` / `geo` dimensions
+//! are reported in [`Import::warnings`].
+
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+use super::Import;
+use crate::model::{
+    AccessModifier, Cardinality, Dimension, Join, Metric, SemanticViewDefinition, TableRef,
+};
+
+#[derive(Deserialize, Default)]
+struct CubeSchema {
+    #[serde(default)]
+    cubes: Vec<Cube>,
+}
+
+#[derive(Deserialize)]
+struct Cube {
+    name: String,
+    #[serde(default, alias = "sqlTable")]
+    sql_table: Option<String>,
+    #[serde(default)]
+    sql: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    extends: Option<IgnoredAny>,
+    #[serde(default)]
+    joins: Vec<CubeJoin>,
+    #[serde(default)]
+    dimensions: Vec<CubeDimension>,
+    #[serde(default)]
+    measures: Vec<CubeMeasure>,
+    #[serde(default)]
+    segments: Option<IgnoredAny>,
+    #[serde(default, alias = "preAggregations")]
+    pre_aggregations: Option<IgnoredAny>,
+}
+
+#[derive(Deserialize)]
+struct CubeJoin {
+    name: String,
+    sql: String,
+    relationship: String,
+}
+
+#[derive(Deserialize)]
+struct CubeDimension {
+    name: String,
+    #[serde(default)]
+    sql: Option<String>,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default, alias = "primaryKey")]
+    primary_key: bool,
+    #[serde(default, alias = "subQuery")]
+    sub_query: bool,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CubeMeasure {
+    name: String,
+    #[serde(default)]
+    sql: Option<String>,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    filters: Vec<CubeFilter>,
+    #[serde(default)]
+    public: Option<bool>,
+    #[serde(default, alias = "rollingWindow")]
+    rolling_window: Option<IgnoredAny>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CubeFilter {
+    sql: String,
+}
+
+/// Convert a Cube data model (YAML or JSON) into view definitions.
+///
+/// # Errors
+///
+/// Only when `text` does not parse as a Cube model (or exceeds
+/// [`SemanticViewDefinition::YAML_SIZE_CAP`]); unmapped features are
+/// warnings.
+pub fn from_cube_yaml(text: &str) -> Result<Import, String> {
+    if text.len() > SemanticViewDefinition::YAML_SIZE_CAP {
+        return Err(format!(
+            "Cube model exceeds size limit ({} bytes > {} byte cap)",
+            text.len(),
+            SemanticViewDefinition::YAML_SIZE_CAP
+        ));
+    }
+    let schema: CubeSchema =
+        yaml_serde::from_str(text).map_err(|e| format!("invalid Cube model: {e}"))?;
+    let mut import = Import::default();
+    for cube in &schema.cubes {
+        if let Some(def) = cube_view(cube, &schema.cubes, &mut import.warnings) {
+            import.views.push((cube.name.clone(), def));
+        }
+    }
+    Ok(import)
+}
+
+fn cube_view(
+    cube: &Cube,
+    cubes: &[Cube],
+    warnings: &mut Vec<String>,
+) -> Option<SemanticViewDefinition> {
+    let name = &cube.name;
+    let Some(table) = cube_table(cube) else {
+        warnings.push(format!(
+            "cube '{name}' skipped: only sql_table or `SELECT * FROM <table>` sources are supported"
+        ));
+        return None;
+    };
+    for (present, what) in [
+        (cube.extends.is_some(), "extends"),
+        (cube.segments.is_some(), "segments"),
+        (cube.pre_aggregations.is_some(), "pre_aggregations"),
+    ] {
+        if present {
+            warnings.push(format!("cube '{name}': {what} not imported"));
+        }
+    }
+
+    let mut def = SemanticViewDefinition {
+        comment: cube.description.clone(),
+        tables: vec![TableRef {
+            alias: name.clone(),
+            table,
+            pk_columns: primary_key(cube),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    for d in &cube.dimensions {
+        let ctx = format!("cube '{name}': dimension '{}'", d.name);
+        if let Some(dim) = dimension(cube, cubes, d, d.name.clone(), &ctx, warnings) {
+            def.dimensions.push(dim);
+        }
+    }
+    for j in &cube.joins {
+        add_join(&mut def, cube, cubes, j, warnings);
+    }
+    for m in &cube.measures {
+        if let Err(reason) = add_measure(&mut def, cube, cubes, m) {
+            warnings.push(format!(
+                "cube '{name}': measure '{}' skipped: {reason}",
+                m.name
+            ));
+        }
+    }
+    Some(def)
+}
+
+/// The physical table behind a cube: `sql_table`, or the table of a plain
+/// `SELECT * FROM <table>` `sql`.
+fn cube_table(cube: &Cube) -> Option<String> {
+    if let Some(t) = &cube.sql_table {
+        return Some(t.trim().to_string());
+    }
+    let sql = cube.sql.as_deref()?.trim();
+    let words: Vec<&str> = sql.split_whitespace().collect();
+    match words.as_slice() {
+        [select, "*", from, table]
+            if select.eq_ignore_ascii_case("select") && from.eq_ignore_ascii_case("from") =>
+        {
+            Some((*table).to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Columns of the cube's `primary_key: true` dimensions (bare-column `sql`
+/// only; an expression cannot be a key column).
+fn primary_key(cube: &Cube) -> Vec<String> {
+    cube.dimensions
+        .iter()
+        .filter(|d| d.primary_key)
+        .filter_map(|d| {
+            let col = strip_self(cube, d.sql.as_deref().unwrap_or(&d.name));
+            is_column(col).then(|| col.to_string())
+        })
+        .collect()
+}
+
+/// `{CUBE}.x` / `${CUBE}.x` / `{<cube name>}.x` -> `x`; anything else as-is.
+fn strip_self<'a>(cube: &Cube, sql: &'a str) -> &'a str {
+    let sql = sql.trim();
+    match split_member(sql) {
+        Some((owner, col)) if owner == "CUBE" || owner == cube.name => col,
+        _ => sql,
+    }
+}
+
+/// Split `{owner}.column` (optionally `$`-prefixed) into its parts.
+fn split_member(sql: &str) -> Option<(&str, &str)> {
+    let rest = sql.strip_prefix('$').unwrap_or(sql).strip_prefix('{')?;
+    let (owner, col) = rest.split_once("}.")?;
+    (is_column(owner) && is_column(col)).then_some((owner, col))
+}
+
+fn is_column(s: &str) -> bool {
+    !s.is_empty() && !s.as_bytes()[0].is_ascii_digit() && s.bytes().all(crate::util::is_ident_byte)
+}
+
+/// Rewrite Cube member references in `sql`: `{CUBE}` / `${CUBE}` becomes
+/// `self_alias`, and `{x}` becomes `resolve(x)`. A bare column (no braces,
+/// no operators) is qualified with `self_alias`.
+fn translate(
+    sql: &str,
+    self_alias: &str,
+    resolve: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let sql = sql.trim();
+    if is_column(sql) {
+        return Ok(format!("{self_alias}.{sql}"));
+    }
+    let mut out = String::with_capacity(sql.len());
+    let mut rest = sql;
+    while let Some(open) = rest.find('{') {
+        let (before, after) = rest.split_at(open);
+        out.push_str(before.strip_suffix('$').unwrap_or(before));
+        let close = after
+            .find('}')
+            .ok_or_else(|| format!("unterminated '{{' in `{sql}`"))?;
+        let name = &after[1..close];
+        if name == "CUBE" {
+            out.push_str(self_alias);
+        } else {
+            let resolved = resolve(name)
+                .ok_or_else(|| format!("`{sql}` references unknown member '{{{name}}}'"))?;
+            out.push_str(&resolved);
+        }
+        rest = &after[close + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Resolve `{x}` in a dimension or column-level measure: another cube's name.
+fn resolve_cube(cubes: &[Cube], name: &str) -> Option<String> {
+    cubes
+        .iter()
+        .any(|c| c.name == name)
+        .then(|| name.to_string())
+}
+
+/// Convert one dimension of `owner`, named `name` in the view.
+fn dimension(
+    owner: &Cube,
+    cubes: &[Cube],
+    d: &CubeDimension,
+    name: String,
+    ctx: &str,
+    warnings: &mut Vec<String>,
+) -> Option<Dimension> {
+    let skip = |reason: &str, warnings: &mut Vec<String>| {
+        warnings.push(format!("{ctx} skipped: {reason}"));
+    };
+    if d.sub_query {
+        skip("sub_query dimensions are not supported", warnings);
+        return None;
+    }
+    if !matches!(d.kind.as_str(), "string" | "number" | "boolean" | "time") {
+        skip(
+            &format!("{} dimensions are not supported", d.kind),
+            warnings,
+        );
+        return None;
+    }
+    let Some(sql) = &d.sql else {
+        skip("no sql (case dimensions are not supported)", warnings);
+        return None;
+    };
+    match translate(sql, &owner.name, |n| resolve_cube(cubes, n)) {
+        Ok(expr) => Some(Dimension {
+            name,
+            expr,
+            source_table: Some(owner.name.clone()),
+            comment: d.description.clone(),
+            ..Default::default()
+        }),
+        Err(reason) => {
+            skip(&reason, warnings);
+            None
+        }
+    }
+}
+
+/// Add the table, relationship, and dimensions of one Cube join.
+fn add_join(
+    def: &mut SemanticViewDefinition,
+    cube: &Cube,
+    cubes: &[Cube],
+    j: &CubeJoin,
+    warnings: &mut Vec<String>,
+) {
+    let mut skip = |reason: &str| {
+        warnings.push(format!(
+            "cube '{}': join to '{}' skipped: {reason}",
+            cube.name, j.name
+        ));
+    };
+    let cardinality = match j.relationship.as_str() {
+        "many_to_one" | "belongs_to" | "manyToOne" | "belongsTo" => Cardinality::ManyToOne,
+        "one_to_one" | "has_one" | "oneToOne" | "hasOne" => Cardinality::OneToOne,
+        other => {
+            skip(&format!("{other} relationships are not supported"));
+            return;
+        }
+    };
+    let Some(target) = cubes.iter().find(|c| c.name == j.name) else {
+        skip("no cube with that name");
+        return;
+    };
+    if target.name == cube.name {
+        skip("self-joins are not supported");
+        return;
+    }
+    let Some(table) = cube_table(target) else {
+        skip("the joined cube has no sql_table");
+        return;
+    };
+    let Some((fk_columns, ref_columns)) = equi_join_columns(&j.sql, cube, target) else {
+        skip(&format!(
+            "`{}` is not an equality of {{CUBE}} and {{{}}} columns",
+            j.sql, target.name
+        ));
+        return;
+    };
+
+    let mut target_ref = TableRef {
+        alias: target.name.clone(),
+        table,
+        pk_columns: primary_key(target),
+        ..Default::default()
+    };
+    if target_ref.pk_columns != ref_columns {
+        target_ref.unique_constraints.push(ref_columns.clone());
+    }
+    let base = &mut def.tables[0];
+    if cardinality == Cardinality::OneToOne && base.pk_columns != fk_columns {
+        base.unique_constraints.push(fk_columns.clone());
+    }
+    def.tables.push(target_ref);
+    def.joins.push(Join {
+        table: target.name.clone(),
+        from_alias: cube.name.clone(),
+        fk_columns,
+        ref_columns,
+        name: Some(j.name.clone()),
+        cardinality,
+    });
+    for d in &target.dimensions {
+        let name = format!("{}__{}", target.name, d.name);
+        let ctx = format!("cube '{}': joined dimension '{name}'", cube.name);
+        if let Some(dim) = dimension(target, cubes, d, name, &ctx, warnings) {
+            def.dimensions.push(dim);
+        }
+    }
+}
+
+/// `{CUBE}.a = {target}.b AND ...` -> (`[a, ...]`, `[b, ...]`), either side
+/// order. `None` for anything that is not a conjunction of such equalities.
+fn equi_join_columns(sql: &str, cube: &Cube, target: &Cube) -> Option<(Vec<String>, Vec<String>)> {
+    let is_self = |o: &str| o == "CUBE" || o == cube.name;
+    let mut fk = Vec::new();
+    let mut rf = Vec::new();
+    let upper = sql.to_ascii_uppercase();
+    let mut start = 0;
+    let mut parts = Vec::new();
+    for (i, _) in upper.match_indices(" AND ") {
+        parts.push(&sql[start..i]);
+        start = i + " AND ".len();
+    }
+    parts.push(&sql[start..]);
+    for part in parts {
+        let (l, r) = part.split_once('=')?;
+        let (lo, lc) = split_member(l.trim())?;
+        let (ro, rc) = split_member(r.trim())?;
+        let (own, other) = if is_self(lo) && ro == target.name {
+            (lc, rc)
+        } else if is_self(ro) && lo == target.name {
+            (rc, lc)
+        } else {
+            return None;
+        };
+        fk.push(own.to_string());
+        rf.push(other.to_string());
+    }
+    Some((fk, rf))
+}
+
+/// Add one measure as a metric, or say why it cannot be.
+fn add_measure(
+    def: &mut SemanticViewDefinition,
+    cube: &Cube,
+    cubes: &[Cube],
+    m: &CubeMeasure,
+) -> Result<(), String> {
+    if m.rolling_window.is_some() {
+        return Err("rolling_window measures are not supported".to_string());
+    }
+    let column = |sql: &str| translate(sql, &cube.name, |n| resolve_cube(cubes, n));
+    let arg = || m.sql.as_deref().map(column).transpose();
+    let required = || arg()?.ok_or_else(|| format!("{} measures need sql", m.kind));
+    let (expr, source_table) = match m.kind.as_str() {
+        "count" => (
+            format!("COUNT({})", arg()?.unwrap_or_else(|| "*".to_string())),
+            Some(cube.name.clone()),
+        ),
+        "count_distinct" | "countDistinct" => (
+            format!("COUNT(DISTINCT {})", required()?),
+            Some(cube.name.clone()),
+        ),
+        "count_distinct_approx" | "countDistinctApprox" => (
+            format!("APPROX_COUNT_DISTINCT({})", required()?),
+            Some(cube.name.clone()),
+        ),
+        "sum" => (format!("SUM({})", required()?), Some(cube.name.clone())),
+        "avg" => (format!("AVG({})", required()?), Some(cube.name.clone())),
+        "min" => (format!("MIN({})", required()?), Some(cube.name.clone())),
+        "max" => (format!("MAX({})", required()?), Some(cube.name.clone())),
+        "number" => {
+            let sql = m.sql.as_deref().ok_or("number measures need sql")?;
+            let is_measure = |n: &str| cube.measures.iter().any(|x| x.name == n);
+            // `{revenue} / {count}`: a derived metric over this cube's measures.
+            (
+                translate(sql, &cube.name, |n| is_measure(n).then(|| n.to_string()))?,
+                None,
+            )
+        }
+        other => return Err(format!("{other} measures are not supported")),
+    };
+    let filter = if m.filters.is_empty() {
+        None
+    } else if source_table.is_none() {
+        return Err("filters on number measures are not supported".to_string());
+    } else {
+        let mut preds = m
+            .filters
+            .iter()
+            .map(|f| column(&f.sql))
+            .collect::<Result<Vec<_>, _>>()?;
+        Some(if preds.len() == 1 {
+            preds.remove(0)
+        } else {
+            preds
+                .iter()
+                .map(|p| format!("({p})"))
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        })
+    };
+    def.metrics.push(Metric {
+        name: m.name.clone(),
+        expr,
+        source_table,
+        comment: m.description.clone(),
+        access: if m.public == Some(false) {
+            AccessModifier::Private
+        } else {
+            AccessModifier::Public
+        },
+        filter,
+        ..Default::default()
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_cube_yaml;
+    use crate::model::{AccessModifier, Cardinality};
+
+    const MODEL: &str = r#"
+cubes:
+  - name: orders
+    sql_table: public.orders
+    description: One row per order.
+    joins:
+      - name: customers
+        sql: '{CUBE}.customer_id = {customers}.id'
+        relationship: many_to_one
+      - name: line_items
+        sql: '{CUBE}.id = {line_items}.order_id'
+        relationship: one_to_many
+    dimensions:
+      - name: id
+        sql: id
+        type: number
+        primary_key: true
+      - name: status
+        sql: '{CUBE}.status'
+        type: string
+      - name: location
+        type: geo
+        latitude: {sql: lat}
+        longitude: {sql: lon}
+    measures:
+      - name: count
+        type: count
+      - name: revenue
+        sql: amount
+        type: sum
+        description: Gross order value.
+      - name: completed_revenue
+        sql: amount
+        type: sum
+        filters:
+          - sql: "{CUBE}.status = 'completed'"
+      - name: aov
+        sql: '{revenue} / NULLIF({count}, 0)'
+        type: number
+      - name: revenue_7d
+        sql: amount
+        type: sum
+        rolling_window: {trailing: 7 day}
+      - name: internal_total
+        sql: amount
+        type: sum
+        public: false
+    segments:
+      - name: big
+        sql: '{CUBE}.amount > 100'
+  - name: customers
+    sql: SELECT * FROM public.customers
+    dimensions:
+      - name: id
+        sql: id
+        type: number
+        primary_key: true
+      - name: segment
+        sql: segment
+        type: string
+  - name: line_items
+    sql_table: public.line_items
+"#;
+
+    #[test]
+    fn cube_maps_to_view_with_join_and_metrics() {
+        let import = from_cube_yaml(MODEL).unwrap();
+        let names: Vec<&str> = import.views.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["orders", "customers", "line_items"]);
+
+        let orders = &import.views[0].1;
+        assert_eq!(orders.comment.as_deref(), Some("One row per order."));
+        assert_eq!(orders.tables[0].table, "public.orders");
+        assert_eq!(orders.tables[0].pk_columns, vec!["id"]);
+        assert_eq!(orders.tables[1].table, "public.customers");
+        assert_eq!(orders.joins.len(), 1);
+        let join = &orders.joins[0];
+        assert_eq!(join.name.as_deref(), Some("customers"));
+        assert_eq!(join.fk_columns, vec!["customer_id"]);
+        assert_eq!(join.ref_columns, vec!["id"]);
+        assert_eq!(join.cardinality, Cardinality::ManyToOne);
+
+        let dims: Vec<(&str, &str)> = orders
+            .dimensions
+            .iter()
+            .map(|d| (d.name.as_str(), d.expr.as_str()))
+            .collect();
+        assert_eq!(
+            dims,
+            vec![
+                ("id", "orders.id"),
+                ("status", "orders.status"),
+                ("customers__id", "customers.id"),
+                ("customers__segment", "customers.segment"),
+            ]
+        );
+
+        let metrics: Vec<(&str, &str, Option<&str>)> = orders
+            .metrics
+            .iter()
+            .map(|m| (m.name.as_str(), m.expr.as_str(), m.filter.as_deref()))
+            .collect();
+        assert_eq!(
+            metrics,
+            vec![
+                ("count", "COUNT(*)", None),
+                ("revenue", "SUM(orders.amount)", None),
+                (
+                    "completed_revenue",
+                    "SUM(orders.amount)",
+                    Some("orders.status = 'completed'")
+                ),
+                ("aov", "revenue / NULLIF(count, 0)", None),
+                ("internal_total", "SUM(orders.amount)", None),
+            ]
+        );
+        assert!(orders.metrics[3].source_table.is_none());
+        assert_eq!(orders.metrics[4].access, AccessModifier::Private);
+    }
+
+    #[test]
+    fn unmapped_features_are_reported() {
+        let warnings = from_cube_yaml(MODEL).unwrap().warnings;
+        assert_eq!(
+            warnings,
+            vec![
+                "cube 'orders': segments not imported",
+                "cube 'orders': dimension 'location' skipped: geo dimensions are not supported",
+                "cube 'orders': join to 'line_items' skipped: one_to_many relationships are not supported",
+                "cube 'orders': measure 'revenue_7d' skipped: rolling_window measures are not supported",
+            ]
+        );
+    }
+
+    #[test]
+    fn one_to_one_join_declares_unique_joining_columns() {
+        let model = r"
+cubes:
+  - name: users
+    sql_table: users
+    joins:
+      - name: profiles
+        sql: '{profiles}.user_id = {users}.profile_id'
+        relationship: one_to_one
+    dimensions:
+      - {name: id, sql: id, type: number, primary_key: true}
+  - name: profiles
+    sql_table: profiles
+    dimensions:
+      - {name: id, sql: id, type: number, primary_key: true}
+";
+        let import = from_cube_yaml(model).unwrap();
+        let users = &import.views[0].1;
+        assert_eq!(users.joins[0].cardinality, Cardinality::OneToOne);
+        assert_eq!(users.joins[0].fk_columns, vec!["profile_id"]);
+        assert_eq!(users.joins[0].ref_columns, vec!["user_id"]);
+        assert_eq!(users.tables[0].unique_constraints, vec![vec!["profile_id"]]);
+        assert_eq!(users.tables[1].unique_constraints, vec![vec!["user_id"]]);
+    }
+
+    #[test]
+    fn unsupported_join_sql_and_invalid_model() {
+        let model = r"
+cubes:
+  - name: a
+    sql_table: a
+    joins:
+      - {name: b, sql: '{CUBE}.x > {b}.y', relationship: belongs_to}
+  - name: b
+    sql_table: b
+";
+        let import = from_cube_yaml(model).unwrap();
+        assert!(import.views[0].1.joins.is_empty());
+        assert_eq!(import.warnings.len(), 1, "{:?}", import.warnings);
+        assert!(from_cube_yaml("cubes: 1").is_err());
+    }
+}
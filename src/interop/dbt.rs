@@ -0,0 +1,1073 @@
+//! Import of dbt semantic-layer YAML (`semantic_models:` / `metrics:`, as
+//! read by dbt's `MetricFlow`) into semantic view definitions.
+//!
+//! Each semantic model becomes one view named after it:
+//!
+//! - the model's `ref('...')` table is the base table, keyed by its
+//!   `primary` entity;
+//! - a `foreign` entity whose name is another model's `primary` entity joins
+//!   that model's table (relationship named after the entity), and the joined
+//!   model's dimensions are added as `<entity>__<dimension>`, the dbt
+//!   naming for dimensions reached through an entity;
+//! - `categorical` dimensions map as-is; `time` dimensions are truncated to
+//!   their `time_granularity` with `date_trunc`;
+//! - `simple` metrics become metrics on the view owning their measure, with
+//!   the measure's aggregation applied to its `expr`; `ratio` metrics become
+//!   derived metrics `numerator / NULLIF(denominator, 0)` when both sides land
+//!   in the same view.
+//!
+//! Anything else — other metric types, metric or input filters (Jinja), an
+//! aggregation with no SQL equivalent here, non-additive measures — is left
+//! out and reported in [`Import::warnings`] rather than approximated.
+//!
+//! [`to_dbt_yaml`] goes the other way, sharing the aggregation and time-grain
+//! mappings so that an imported view exports back to equivalent YAML.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Import;
+use crate::model::{Dimension, Join, Metric, SemanticViewDefinition, TableRef};
+
+// One set of types serves both directions: deserialized by the importer,
+// built and serialized by the exporter (optional fields are omitted when
+// unset so exported YAML stays minimal).
+
+#[derive(Serialize, Deserialize, Default)]
+struct DbtProject {
+    #[serde(default)]
+    semantic_models: Vec<DbtSemanticModel>,
+    #[serde(default)]
+    metrics: Vec<DbtMetric>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DbtSemanticModel {
+    name: String,
+    model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default)]
+    entities: Vec<DbtEntity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    dimensions: Vec<DbtDimension>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    measures: Vec<DbtMeasure>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DbtEntity {
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expr: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DbtDimension {
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expr: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    type_params: Option<DbtDimensionParams>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DbtDimensionParams {
+    #[serde(default)]
+    time_granularity: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DbtMeasure {
+    name: String,
+    agg: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expr: Option<yaml_serde::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    non_additive_dimension: Option<yaml_serde::Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DbtMetric {
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default)]
+    type_params: DbtMetricParams,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    filter: Option<yaml_serde::Value>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct DbtMetricParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    measure: Option<DbtInput>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    numerator: Option<DbtInput>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    denominator: Option<DbtInput>,
+    /// Derived metrics: the SQL over `metrics`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expr: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    metrics: Vec<DbtInput>,
+}
+
+/// A measure or metric input: a bare name, or `{name, filter, ...}`.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum DbtInput {
+    Name(String),
+    Detailed {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filter: Option<yaml_serde::Value>,
+    },
+}
+
+impl DbtInput {
+    fn name(&self) -> &str {
+        match self {
+            Self::Name(name) | Self::Detailed { name, .. } => name,
+        }
+    }
+
+    fn has_filter(&self) -> bool {
+        matches!(
+            self,
+            Self::Detailed {
+                filter: Some(_),
+                ..
+            }
+        )
+    }
+}
+
+/// dbt measure `agg` <-> the SQL wrapped around the measure's `expr`, as
+/// `(agg, prefix, suffix)`. Shared by both directions; the exporter tries
+/// entries in order, so a more specific prefix (`SUM(CASE WHEN `,
+/// `COUNT(DISTINCT `) precedes the one it extends.
+const AGGREGATIONS: &[(&str, &str, &str)] = &[
+    ("sum_boolean", "SUM(CASE WHEN ", " THEN 1 ELSE 0 END)"),
+    ("sum", "SUM(", ")"),
+    ("min", "MIN(", ")"),
+    ("max", "MAX(", ")"),
+    ("average", "AVG(", ")"),
+    ("median", "MEDIAN(", ")"),
+    ("count_distinct", "COUNT(DISTINCT ", ")"),
+    ("count", "COUNT(", ")"),
+];
+
+/// A `time` dimension's expression at `time_granularity` `grain`.
+fn truncate_time(grain: &str, column: &str) -> String {
+    format!("date_trunc('{grain}', {column})")
+}
+
+/// Inverse of [`truncate_time`]: `date_trunc('day', x)` -> `("day", "x")`.
+fn untruncate_time(expr: &str) -> Option<(&str, &str)> {
+    let head = "date_trunc('";
+    let expr = expr.trim();
+    if !expr.get(..head.len())?.eq_ignore_ascii_case(head) {
+        return None;
+    }
+    let (grain, rest) = expr[head.len()..].split_once("',")?;
+    let column = rest.strip_suffix(')')?.trim();
+    (is_ident(grain) && balanced(column)).then_some((grain, column))
+}
+
+fn is_ident(s: &str) -> bool {
+    !s.is_empty() && !s.as_bytes()[0].is_ascii_digit() && s.bytes().all(crate::util::is_ident_byte)
+}
+
+/// Whether parentheses in `s` never close below depth 0 and end at 0, i.e. it
+/// is one self-contained operand (ignores quoting; good enough for the
+/// shapes the exporter recognizes).
+fn balanced(s: &str) -> bool {
+    let mut depth = 0i32;
+    for b in s.bytes() {
+        match b {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth < 0 {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+    depth == 0
+}
+
+/// Convert a dbt semantic-layer YAML document into view definitions.
+///
+/// # Errors
+///
+/// Only when `yaml` does not parse as a dbt semantic-layer document (or
+/// exceeds [`SemanticViewDefinition::YAML_SIZE_CAP`]); unsupported constructs
+/// are warnings.
+pub fn from_dbt_yaml(yaml: &str) -> Result<Import, String> {
+    if yaml.len() > SemanticViewDefinition::YAML_SIZE_CAP {
+        return Err(format!(
+            "dbt YAML exceeds size limit ({} bytes > {} byte cap)",
+            yaml.len(),
+            SemanticViewDefinition::YAML_SIZE_CAP
+        ));
+    }
+    let project: DbtProject =
+        yaml_serde::from_str(yaml).map_err(|e| format!("invalid dbt semantic-layer YAML: {e}"))?;
+    Ok(convert(&project))
+}
+
+fn convert(project: &DbtProject) -> Import {
+    let mut import = Import::default();
+    let models = &project.semantic_models;
+
+    // primary entity name -> model index, for foreign-entity joins.
+    let mut by_primary: HashMap<&str, usize> = HashMap::new();
+    for (i, sm) in models.iter().enumerate() {
+        if let Some(e) = sm.entities.iter().find(|e| e.kind == "primary") {
+            by_primary.insert(e.name.as_str(), i);
+        }
+    }
+    // measure name -> owning model index.
+    let mut measure_owner: HashMap<&str, usize> = HashMap::new();
+    for (i, sm) in models.iter().enumerate() {
+        for m in &sm.measures {
+            measure_owner.insert(m.name.as_str(), i);
+        }
+    }
+
+    let mut views: Vec<SemanticViewDefinition> = models
+        .iter()
+        .map(|sm| model_view(sm, models, &by_primary, &mut import.warnings))
+        .collect();
+
+    // Simple metrics first so ratio metrics can refer to them.
+    let mut metric_view: HashMap<&str, usize> = HashMap::new();
+    for metric in project.metrics.iter().filter(|m| m.kind == "simple") {
+        let skip = |reason: &str, warnings: &mut Vec<String>| {
+            warnings.push(format!("metric '{}' skipped: {reason}", metric.name));
+        };
+        if metric.filter.is_some() {
+            skip("metric filters are not supported", &mut import.warnings);
+            continue;
+        }
+        let Some(input) = &metric.type_params.measure else {
+            skip("no type_params.measure", &mut import.warnings);
+            continue;
+        };
+        if input.has_filter() {
+            skip("measure filters are not supported", &mut import.warnings);
+            continue;
+        }
+        let Some(&owner) = measure_owner.get(input.name()) else {
+            skip(
+                &format!("measure '{}' is not defined", input.name()),
+                &mut import.warnings,
+            );
+            continue;
+        };
+        let sm = &models[owner];
+        let measure = sm
+            .measures
+            .iter()
+            .find(|m| m.name == input.name())
+            .expect("measure_owner indexes this model's measures");
+        if measure.non_additive_dimension.is_some() {
+            skip(
+                &format!("measure '{}' is non-additive", measure.name),
+                &mut import.warnings,
+            );
+            continue;
+        }
+        let expr = match aggregate(&sm.name, measure) {
+            Ok(expr) => expr,
+            Err(reason) => {
+                skip(&reason, &mut import.warnings);
+                continue;
+            }
+        };
+        views[owner].metrics.push(Metric {
+            name: metric.name.clone(),
+            expr,
+            source_table: Some(sm.name.clone()),
+            comment: metric.description.clone(),
+            ..Default::default()
+        });
+        metric_view.insert(metric.name.as_str(), owner);
+    }
+
+    for metric in &project.metrics {
+        match metric.kind.as_str() {
+            "simple" => {}
+            "ratio" => {
+                if let Err(reason) = add_ratio(metric, &metric_view, &mut views) {
+                    import
+                        .warnings
+                        .push(format!("metric '{}' skipped: {reason}", metric.name));
+                }
+            }
+            other => import.warnings.push(format!(
+                "metric '{}' skipped: {other} metrics are not supported",
+                metric.name
+            )),
+        }
+    }
+
+    import.views = models.iter().map(|sm| sm.name.clone()).zip(views).collect();
+    import
+}
+
+/// Tables, relationships, and dimensions of one semantic model's view.
+fn model_view(
+    sm: &DbtSemanticModel,
+    models: &[DbtSemanticModel],
+    by_primary: &HashMap<&str, usize>,
+    warnings: &mut Vec<String>,
+) -> SemanticViewDefinition {
+    let mut def = SemanticViewDefinition {
+        comment: sm.description.clone(),
+        tables: vec![model_table(sm, warnings)],
+        ..Default::default()
+    };
+    for d in &sm.dimensions {
+        if let Some(dim) = dimension(&sm.name, &sm.name, d, &d.name, warnings) {
+            def.dimensions.push(dim);
+        }
+    }
+
+    for entity in sm.entities.iter().filter(|e| e.kind == "foreign") {
+        let Some(&target) = by_primary.get(entity.name.as_str()) else {
+            warnings.push(format!(
+                "semantic model '{}': foreign entity '{}' matches no primary entity; not joined",
+                sm.name, entity.name
+            ));
+            continue;
+        };
+        let other = &models[target];
+        if other.name == sm.name {
+            continue;
+        }
+        let mut table = model_table(other, &mut Vec::new());
+        // Unique keys of the joined model are irrelevant to this view.
+        table.unique_constraints.clear();
+        let ref_columns = table.pk_columns.clone();
+        def.tables.push(table);
+        def.joins.push(Join {
+            table: other.name.clone(),
+            from_alias: sm.name.clone(),
+            fk_columns: vec![entity.expr.clone().unwrap_or_else(|| entity.name.clone())],
+            ref_columns,
+            name: Some(entity.name.clone()),
+            ..Default::default()
+        });
+        for d in &other.dimensions {
+            let name = format!("{}__{}", entity.name, d.name);
+            if let Some(dim) = dimension(&sm.name, &other.name, d, &name, warnings) {
+                def.dimensions.push(dim);
+            }
+        }
+    }
+    def
+}
+
+/// The table entry for a semantic model: `ref('x')` resolved to `x`, keyed by
+/// the primary entity, with `unique` entities as UNIQUE constraints.
+fn model_table(sm: &DbtSemanticModel, warnings: &mut Vec<String>) -> TableRef {
+    let key = |e: &DbtEntity| e.expr.clone().unwrap_or_else(|| e.name.clone());
+    let mut table = TableRef {
+        alias: sm.name.clone(),
+        table: ref_target(&sm.model).unwrap_or_else(|| {
+            warnings.push(format!(
+                "semantic model '{}': model '{}' is not a ref('...'); used verbatim as the table name",
+                sm.name, sm.model
+            ));
+            sm.model.clone()
+        }),
+        ..Default::default()
+    };
+    for e in &sm.entities {
+        match e.kind.as_str() {
+            "primary" => table.pk_columns = vec![key(e)],
+            "unique" => table.unique_constraints.push(vec![key(e)]),
+            "foreign" => {}
+            other => warnings.push(format!(
+                "semantic model '{}': {other} entity '{}' is not supported",
+                sm.name, e.name
+            )),
+        }
+    }
+    table
+}
+
+/// `ref('orders')` / `ref("orders")` -> `orders`.
+fn ref_target(model: &str) -> Option<String> {
+    let inner = model.trim().strip_prefix("ref(")?.strip_suffix(')')?.trim();
+    let name = inner
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')))?;
+    Some(name.to_string())
+}
+
+/// Qualify a bare column with `alias`; anything else is an expression and is
+/// kept verbatim.
+fn column_expr(alias: &str, expr: &str) -> String {
+    if is_ident(expr) {
+        format!("{alias}.{expr}")
+    } else {
+        expr.to_string()
+    }
+}
+
+/// Convert one dimension of the model aliased `table_alias`, named `name` in
+/// the view `view`. `None` (with a warning) for unsupported dimension types.
+fn dimension(
+    view: &str,
+    table_alias: &str,
+    d: &DbtDimension,
+    name: &str,
+    warnings: &mut Vec<String>,
+) -> Option<Dimension> {
+    let column = column_expr(table_alias, d.expr.as_deref().unwrap_or(&d.name));
+    let expr = match d.kind.as_str() {
+        "categorical" => column,
+        "time" => match d
+            .type_params
+            .as_ref()
+            .and_then(|p| p.time_granularity.as_deref())
+        {
+            Some(grain) => truncate_time(grain, &column),
+            None => column,
+        },
+        other => {
+            warnings.push(format!(
+                "view '{view}': dimension '{name}' skipped: {other} dimensions are not supported"
+            ));
+            return None;
+        }
+    };
+    Some(Dimension {
+        name: name.to_string(),
+        expr,
+        source_table: Some(table_alias.to_string()),
+        comment: d.description.clone(),
+        ..Default::default()
+    })
+}
+
+/// The aggregate SQL for a measure, or why it cannot be expressed.
+fn aggregate(alias: &str, measure: &DbtMeasure) -> Result<String, String> {
+    let raw = match &measure.expr {
+        None => measure.name.clone(),
+        Some(yaml_serde::Value::String(s)) => s.clone(),
+        Some(yaml_serde::Value::Number(n)) => n.to_string(),
+        Some(yaml_serde::Value::Bool(b)) => b.to_string(),
+        Some(_) => return Err(format!("measure '{}' has a non-scalar expr", measure.name)),
+    };
+    let col = column_expr(alias, &raw);
+    let (_, prefix, suffix) = AGGREGATIONS
+        .iter()
+        .find(|(agg, _, _)| *agg == measure.agg)
+        .ok_or_else(|| {
+            format!(
+                "measure '{}' uses the unsupported aggregation '{}'",
+                measure.name, measure.agg
+            )
+        })?;
+    Ok(format!("{prefix}{col}{suffix}"))
+}
+
+/// Add a ratio metric as a derived metric on the view holding both inputs.
+fn add_ratio(
+    metric: &DbtMetric,
+    metric_view: &HashMap<&str, usize>,
+    views: &mut [SemanticViewDefinition],
+) -> Result<(), String> {
+    if metric.filter.is_some() {
+        return Err("metric filters are not supported".to_string());
+    }
+    let params = &metric.type_params;
+    let (Some(num), Some(den)) = (&params.numerator, &params.denominator) else {
+        return Err("ratio metrics need a numerator and a denominator".to_string());
+    };
+    if num.has_filter() || den.has_filter() {
+        return Err("numerator / denominator filters are not supported".to_string());
+    }
+    let view_of = |input: &DbtInput| {
+        metric_view
+            .get(input.name())
+            .copied()
+            .ok_or_else(|| format!("'{}' is not an imported simple metric", input.name()))
+    };
+    let (num_view, den_view) = (view_of(num)?, view_of(den)?);
+    if num_view != den_view {
+        return Err(format!(
+            "'{}' and '{}' come from different semantic models",
+            num.name(),
+            den.name()
+        ));
+    }
+    views[num_view].metrics.push(Metric {
+        name: metric.name.clone(),
+        expr: format!("{} / NULLIF({}, 0)", num.name(), den.name()),
+        comment: metric.description.clone(),
+        ..Default::default()
+    });
+    Ok(())
+}
+
+/// Export a view as dbt semantic-layer YAML: the reverse of
+/// [`from_dbt_yaml`], and its round trip for the shapes that importer
+/// produces.
+///
+/// Every table entry becomes a semantic model (the base table's named after
+/// the view, the others after their alias) with a `primary` entity for a
+/// single-column PRIMARY KEY and `unique` entities for single-column UNIQUE
+/// keys. A relationship becomes a `foreign` entity on its from-side model,
+/// named after the relationship, matching the `primary` entity — renamed to
+/// the relationship — of the model it references. Dimensions go to the model
+/// of their source table (`<relationship>__` prefixes are dropped again), as
+/// `time` dimensions at the granularity of a `date_trunc('<grain>', ...)`
+/// expression and `categorical` otherwise. A metric that is one aggregate
+/// call over an expression becomes a measure with that `agg` and `expr` plus
+/// a `simple` metric (a `FILTER` moves into the measure as
+/// `CASE WHEN <filter> THEN <expr> END`); a derived metric becomes a `ratio`
+/// metric for `a / b` or `a / NULLIF(b, 0)` and a `derived` one otherwise.
+///
+/// What dbt cannot express — facts, materializations, window and
+/// semi-additive metrics, multi-column keys — is left out and listed as
+/// `# warning:` comment lines ahead of the YAML.
+///
+/// # Errors
+///
+/// Only if YAML serialization fails.
+pub fn to_dbt_yaml(view_name: &str, def: &SemanticViewDefinition) -> Result<String, String> {
+    let mut warnings = Vec::new();
+    let project = export(view_name, def, &mut warnings);
+    let yaml = yaml_serde::to_string(&project).map_err(|e| format!("dbt YAML export: {e}"))?;
+    let mut out = String::new();
+    for w in &warnings {
+        out.push_str("# warning: ");
+        out.push_str(&w.replace('\n', " "));
+        out.push('\n');
+    }
+    out.push_str(&yaml);
+    Ok(out)
+}
+
+fn export(view_name: &str, def: &SemanticViewDefinition, warnings: &mut Vec<String>) -> DbtProject {
+    let mut models = export_models(view_name, def, warnings);
+    export_dimensions(def, &mut models, warnings);
+    let metrics = export_metrics(def, &mut models, warnings);
+    if !def.facts.is_empty() {
+        warnings.push(format!(
+            "{} fact(s) not exported: dbt has no row-level facts",
+            def.facts.len()
+        ));
+    }
+    if !def.materializations.is_empty() {
+        warnings.push(format!(
+            "{} materialization(s) not exported",
+            def.materializations.len()
+        ));
+    }
+    DbtProject {
+        semantic_models: models,
+        metrics,
+    }
+}
+
+/// Index of the table a dimension / metric lives on (`None` source: base).
+fn table_index(def: &SemanticViewDefinition, source: Option<&String>) -> Option<usize> {
+    match source {
+        Some(s) => def
+            .tables
+            .iter()
+            .position(|t| crate::ident::ident_matches(&t.alias, s)),
+        None => Some(0),
+    }
+}
+
+/// One semantic model per table entry, with key and relationship entities.
+fn export_models(
+    view_name: &str,
+    def: &SemanticViewDefinition,
+    warnings: &mut Vec<String>,
+) -> Vec<DbtSemanticModel> {
+    let mut models: Vec<DbtSemanticModel> = def
+        .tables
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            // A joined table's primary entity is named after the relationship
+            // that reaches it, so the from-side foreign entity can match it.
+            let incoming = def
+                .joins
+                .iter()
+                .find(|j| crate::ident::ident_matches(&j.table, &t.alias));
+            let mut entities = Vec::new();
+            match t.pk_columns.as_slice() {
+                [] => {}
+                [col] => {
+                    let name = incoming
+                        .filter(|_| i > 0)
+                        .map_or_else(|| col.clone(), relationship_name);
+                    entities.push(entity(name, "primary", col));
+                }
+                _ => warnings.push(format!(
+                    "table '{}': multi-column PRIMARY KEY not exported",
+                    t.alias
+                )),
+            }
+            for uc in &t.unique_constraints {
+                if let [col] = uc.as_slice() {
+                    entities.push(entity(col.clone(), "unique", col));
+                }
+            }
+            DbtSemanticModel {
+                name: if i == 0 {
+                    view_name.to_string()
+                } else {
+                    t.alias.clone()
+                },
+                model: format!("ref('{}')", t.table),
+                description: if i == 0 {
+                    def.comment.clone()
+                } else {
+                    t.comment.clone()
+                },
+                entities,
+                dimensions: Vec::new(),
+                measures: Vec::new(),
+            }
+        })
+        .collect();
+
+    for j in &def.joins {
+        let from = def
+            .tables
+            .iter()
+            .position(|t| crate::ident::ident_matches(&t.alias, &j.from_alias));
+        match (from, j.fk_columns.as_slice()) {
+            (Some(i), [col]) => {
+                models[i]
+                    .entities
+                    .push(entity(relationship_name(j), "foreign", col));
+            }
+            _ => warnings.push(format!(
+                "relationship '{}' not exported: dbt entities join on a single column",
+                relationship_name(j)
+            )),
+        }
+    }
+    models
+}
+
+/// Each dimension onto its table's model.
+fn export_dimensions(
+    def: &SemanticViewDefinition,
+    models: &mut [DbtSemanticModel],
+    warnings: &mut Vec<String>,
+) {
+    for d in &def.dimensions {
+        let Some(i) = table_index(def, d.source_table.as_ref()) else {
+            warnings.push(format!("dimension '{}': unknown source table", d.name));
+            continue;
+        };
+        let alias = &def.tables[i].alias;
+        let name = def
+            .joins
+            .iter()
+            .filter(|j| crate::ident::ident_matches(&j.table, alias))
+            .find_map(|j| d.name.strip_prefix(&format!("{}__", relationship_name(j))))
+            .unwrap_or(&d.name)
+            .to_string();
+        let (kind, expr, type_params) = match untruncate_time(&d.expr) {
+            Some((grain, col)) => (
+                "time",
+                unqualify(col, alias),
+                Some(DbtDimensionParams {
+                    time_granularity: Some(grain.to_string()),
+                }),
+            ),
+            None => ("categorical", unqualify(&d.expr, alias), None),
+        };
+        models[i].dimensions.push(DbtDimension {
+            expr: (expr != name).then_some(expr),
+            name,
+            kind: kind.to_string(),
+            description: d.comment.clone(),
+            type_params,
+        });
+    }
+}
+
+/// Metrics, adding the measures behind `simple` ones to their models.
+fn export_metrics(
+    def: &SemanticViewDefinition,
+    models: &mut [DbtSemanticModel],
+    warnings: &mut Vec<String>,
+) -> Vec<DbtMetric> {
+    let mut metrics = Vec::new();
+    let metric_names: Vec<&str> = def.metrics.iter().map(|m| m.name.as_str()).collect();
+    for m in &def.metrics {
+        let mut skip = |reason: &str| {
+            warnings.push(format!("metric '{}' not exported: {reason}", m.name));
+        };
+        if m.window_spec.is_some() {
+            skip("window metrics have no dbt equivalent");
+            continue;
+        }
+        if !m.non_additive_by.is_empty() {
+            skip("semi-additive metrics are not exported");
+            continue;
+        }
+        if !m.using_relationships.is_empty() {
+            skip("USING relationships have no dbt equivalent");
+            continue;
+        }
+        let Some(i) = table_index(def, m.source_table.as_ref()) else {
+            skip("unknown source table");
+            continue;
+        };
+        let alias = def.tables[i].alias.clone();
+        let mut metric = DbtMetric {
+            name: m.name.clone(),
+            kind: String::new(),
+            description: m.comment.clone(),
+            type_params: DbtMetricParams::default(),
+            filter: None,
+        };
+        if let Some((agg, inner)) = split_aggregate(&m.expr) {
+            // `COUNT(*)`: dbt measures need a column expression.
+            let inner = if inner == "*" { "1" } else { inner };
+            let inner = match &m.filter {
+                Some(f) => format!("CASE WHEN {f} THEN {inner} END"),
+                None => inner.to_string(),
+            };
+            let expr = unqualify(&inner, &alias);
+            models[i].measures.push(DbtMeasure {
+                expr: (expr != m.name).then(|| yaml_serde::Value::String(expr)),
+                name: m.name.clone(),
+                agg: agg.to_string(),
+                non_additive_dimension: None,
+            });
+            metric.kind = "simple".to_string();
+            metric.type_params.measure = Some(DbtInput::Name(m.name.clone()));
+        } else if m.source_table.is_none() {
+            if let Some((num, den)) = split_ratio(&m.expr) {
+                metric.kind = "ratio".to_string();
+                metric.type_params.numerator = Some(DbtInput::Name(num.to_string()));
+                metric.type_params.denominator = Some(DbtInput::Name(den.to_string()));
+            } else {
+                let refs = crate::expr_tokens::reference_keys(&m.expr);
+                metric.kind = "derived".to_string();
+                metric.type_params.expr = Some(m.expr.clone());
+                metric.type_params.metrics = metric_names
+                    .iter()
+                    .filter(|n| refs.contains(&crate::ident::normalize_ident_part(n)))
+                    .map(|n| DbtInput::Name((*n).to_string()))
+                    .collect();
+            }
+        } else {
+            skip("not a single aggregate call");
+            continue;
+        }
+        metrics.push(metric);
+    }
+    metrics
+}
+
+fn relationship_name(j: &Join) -> String {
+    j.name.clone().unwrap_or_else(|| j.table.clone())
+}
+
+fn entity(name: String, kind: &str, column: &str) -> DbtEntity {
+    DbtEntity {
+        expr: (name != column).then(|| column.to_string()),
+        name,
+        kind: kind.to_string(),
+    }
+}
+
+/// `SUM(x)` -> `("sum", "x")` via [`AGGREGATIONS`], when the call spans the
+/// whole expression.
+fn split_aggregate(expr: &str) -> Option<(&'static str, &str)> {
+    let expr = expr.trim();
+    AGGREGATIONS.iter().find_map(|(agg, prefix, suffix)| {
+        let head = expr.get(..prefix.len())?;
+        let tail_at = expr.len().checked_sub(suffix.len())?;
+        if !head.eq_ignore_ascii_case(prefix) || !expr[tail_at..].eq_ignore_ascii_case(suffix) {
+            return None;
+        }
+        let inner = expr.get(prefix.len()..tail_at)?.trim();
+        (!inner.is_empty() && balanced(inner)).then_some((*agg, inner))
+    })
+}
+
+/// `a / b` or `a / NULLIF(b, 0)` over two bare names -> `(a, b)`.
+fn split_ratio(expr: &str) -> Option<(&str, &str)> {
+    let (num, den) = expr.split_once('/')?;
+    let (num, den) = (num.trim(), den.trim());
+    let den = den
+        .get(..7)
+        .filter(|h| h.eq_ignore_ascii_case("NULLIF("))
+        .and_then(|_| den[7..].strip_suffix(')'))
+        .and_then(|args| args.split_once(','))
+        .filter(|(_, zero)| zero.trim() == "0")
+        .map_or(den, |(d, _)| d.trim());
+    (is_ident(num) && is_ident(den)).then_some((num, den))
+}
+
+/// Drop the `alias.` qualifier from references in `expr` (dbt expressions
+/// are evaluated against the semantic model's own table).
+fn unqualify(expr: &str, alias: &str) -> String {
+    let want = crate::ident::normalize_ident_part(alias);
+    let mut out = String::with_capacity(expr.len());
+    let mut copied = 0;
+    for r in crate::expr_tokens::scan_references(expr) {
+        if let Some((head, rest)) = r.raw.split_once('.') {
+            if crate::ident::normalize_ident_part(head) == want {
+                out.push_str(&expr[copied..r.start]);
+                out.push_str(rest);
+                copied = r.end;
+            }
+        }
+    }
+    out.push_str(&expr[copied..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_dbt_yaml, to_dbt_yaml};
+
+    const PROJECT: &str = r#"
+semantic_models:
+  - name: orders
+    model: ref('fct_orders')
+    description: One row per order.
+    entities:
+      - name: order_id
+        type: primary
+      - name: customer
+        type: foreign
+        expr: customer_id
+    dimensions:
+      - name: ordered_at
+        type: time
+        type_params:
+          time_granularity: day
+      - name: status
+        type: categorical
+    measures:
+      - name: order_total
+        agg: sum
+        expr: amount
+      - name: order_count
+        agg: sum
+        expr: 1
+      - name: p90_amount
+        agg: percentile
+        expr: amount
+  - name: customers
+    model: ref('dim_customers')
+    entities:
+      - name: customer
+        type: primary
+        expr: id
+    dimensions:
+      - name: segment
+        type: categorical
+metrics:
+  - name: revenue
+    type: simple
+    description: Gross order value.
+    type_params:
+      measure: order_total
+  - name: orders
+    type: simple
+    type_params:
+      measure: order_count
+  - name: aov
+    type: ratio
+    type_params:
+      numerator: revenue
+      denominator: orders
+  - name: p90
+    type: simple
+    type_params:
+      measure: p90_amount
+  - name: revenue_growth
+    type: derived
+    type_params:
+      expr: revenue - 1
+  - name: big_revenue
+    type: simple
+    filter: "{{ Dimension('order_id__status') }} = 'big'"
+    type_params:
+      measure: order_total
+"#;
+
+    #[test]
+    fn models_become_views_with_joins_and_metrics() {
+        let import = from_dbt_yaml(PROJECT).unwrap();
+        assert_eq!(import.views.len(), 2);
+        let (name, orders) = &import.views[0];
+        assert_eq!(name, "orders");
+        assert_eq!(orders.tables[0].table, "fct_orders");
+        assert_eq!(orders.tables[0].pk_columns, vec!["order_id"]);
+        assert_eq!(orders.tables[1].table, "dim_customers");
+        assert_eq!(orders.joins[0].fk_columns, vec!["customer_id"]);
+        assert_eq!(orders.joins[0].ref_columns, vec!["id"]);
+
+        let dims: Vec<(&str, &str)> = orders
+            .dimensions
+            .iter()
+            .map(|d| (d.name.as_str(), d.expr.as_str()))
+            .collect();
+        assert_eq!(
+            dims,
+            vec![
+                ("ordered_at", "date_trunc('day', orders.ordered_at)"),
+                ("status", "orders.status"),
+                ("customer__segment", "customers.segment"),
+            ]
+        );
+        let metrics: Vec<(&str, &str)> = orders
+            .metrics
+            .iter()
+            .map(|m| (m.name.as_str(), m.expr.as_str()))
+            .collect();
+        assert_eq!(
+            metrics,
+            vec![
+                ("revenue", "SUM(orders.amount)"),
+                ("orders", "SUM(1)"),
+                ("aov", "revenue / NULLIF(orders, 0)"),
+            ]
+        );
+        assert!(orders.metrics[2].source_table.is_none());
+    }
+
+    #[test]
+    fn unsupported_constructs_are_warned_not_dropped_silently() {
+        let warnings = from_dbt_yaml(PROJECT).unwrap().warnings;
+        let expect = [
+            "metric 'p90' skipped: measure 'p90_amount' uses the unsupported aggregation 'percentile'",
+            "metric 'revenue_growth' skipped: derived metrics are not supported",
+            "metric 'big_revenue' skipped: metric filters are not supported",
+        ];
+        for w in expect {
+            assert!(
+                warnings.iter().any(|x| x == w),
+                "missing {w:?} in {warnings:?}"
+            );
+        }
+        assert_eq!(warnings.len(), expect.len(), "{warnings:?}");
+    }
+
+    #[test]
+    fn script_lists_warnings_then_one_statement_per_view() {
+        let sql = from_dbt_yaml(PROJECT).unwrap().to_sql().unwrap();
+        assert!(sql.starts_with("-- warning: metric 'p90' skipped"), "{sql}");
+        assert_eq!(sql.matches("CREATE OR REPLACE SEMANTIC VIEW").count(), 2);
+        assert_eq!(sql.matches(");\n").count(), 2);
+    }
+
+    #[test]
+    fn export_then_import_round_trips_an_imported_view() {
+        let import = from_dbt_yaml(PROJECT).unwrap();
+        let (name, orders) = &import.views[0];
+        let yaml = to_dbt_yaml(name, orders).unwrap();
+        assert!(!yaml.contains("# warning"), "{yaml}");
+        let again = from_dbt_yaml(&yaml).unwrap();
+        assert!(again.warnings.is_empty(), "{:?}", again.warnings);
+        assert_eq!(&again.views[0].0, name);
+        assert_eq!(&again.views[0].1, orders);
+    }
+
+    #[test]
+    fn export_maps_expressions_filters_and_derived_metrics() {
+        use crate::model::{Dimension, Fact, Metric, SemanticViewDefinition, TableRef};
+        let def = SemanticViewDefinition {
+            tables: vec![TableRef {
+                alias: "o".to_string(),
+                table: "orders".to_string(),
+                pk_columns: vec!["id".to_string()],
+                ..Default::default()
+            }],
+            dimensions: vec![Dimension {
+                name: "month".to_string(),
+                expr: "date_trunc('month', o.ordered_at)".to_string(),
+                source_table: Some("o".to_string()),
+                ..Default::default()
+            }],
+            metrics: vec![
+                Metric {
+                    name: "net".to_string(),
+                    expr: "SUM(o.price * o.qty)".to_string(),
+                    source_table: Some("o".to_string()),
+                    filter: Some("o.status = 'done'".to_string()),
+                    ..Default::default()
+                },
+                Metric {
+                    name: "buyers".to_string(),
+                    expr: "count(distinct o.customer_id)".to_string(),
+                    source_table: Some("o".to_string()),
+                    ..Default::default()
+                },
+                Metric {
+                    name: "net_per_buyer".to_string(),
+                    expr: "net / buyers".to_string(),
+                    ..Default::default()
+                },
+                Metric {
+                    name: "spread".to_string(),
+                    expr: "net - buyers * 2".to_string(),
+                    ..Default::default()
+                },
+            ],
+            facts: vec![Fact {
+                name: "line_total".to_string(),
+                expr: "o.price * o.qty".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let yaml = to_dbt_yaml("sales", &def).unwrap();
+        for needle in [
+            "# warning: 1 fact(s) not exported",
+            "name: sales\n",
+            "model: ref('orders')",
+            "time_granularity: month",
+            "expr: ordered_at",
+            "agg: sum\n    expr: CASE WHEN status = 'done' THEN price * qty END",
+            "agg: count_distinct\n    expr: customer_id",
+            "type: ratio",
+            "numerator: net",
+            "denominator: buyers",
+            "type: derived",
+            "expr: net - buyers * 2",
+        ] {
+            assert!(yaml.contains(needle), "missing {needle:?} in\n{yaml}");
+        }
+    }
+
+    #[test]
+    fn invalid_yaml_is_an_error() {
+        assert!(from_dbt_yaml("semantic_models: 3").is_err());
+        assert!(from_dbt_yaml("").unwrap().views.is_empty());
+    }
+}
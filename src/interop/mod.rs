@@ -0,0 +1,45 @@
+//! Conversions between semantic view definitions and other semantic-layer
+//! formats.
+//!
+//! Importers are lossy by design: whatever a source format can express that
+//! a semantic view cannot is skipped and described in [`Import::warnings`],
+//! never approximated.
+
+use crate::model::SemanticViewDefinition;
+
+pub mod cube;
+pub mod dbt;
+
+/// The views converted from a foreign semantic-layer document, in source
+/// order, and every construct that was skipped along the way.
+#[derive(Debug, Default)]
+pub struct Import {
+    pub views: Vec<(String, SemanticViewDefinition)>,
+    pub warnings: Vec<String>,
+}
+
+impl Import {
+    /// A SQL script recreating the imported views: one `-- warning:` comment
+    /// line per warning, then a `CREATE OR REPLACE SEMANTIC VIEW ...;`
+    /// statement per view.
+    ///
+    /// # Errors
+    ///
+    /// Propagates [`crate::render_ddl::render_create_ddl`] failures.
+    pub fn to_sql(&self) -> Result<String, String> {
+        let mut out = String::new();
+        for w in &self.warnings {
+            out.push_str("-- warning: ");
+            out.push_str(&w.replace('\n', " "));
+            out.push('\n');
+        }
+        for (name, def) in &self.views {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(crate::render_ddl::render_create_ddl(name, def)?.trim_end());
+            out.push_str(";\n");
+        }
+        Ok(out)
+    }
+}
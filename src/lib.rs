@@ -6,6 +6,7 @@ pub(crate) mod expr_tokens;
 pub mod ffi_util;
 pub mod graph;
 pub mod ident;
+pub mod interop;
 pub mod model;
 pub mod parse;
 // The `query` module itself is always compiled; its FFI-heavy submodules
@@ -15,6 +16,8 @@ pub mod parse;
 // / clippy / coverage (TC-8).
 pub mod query;
 pub mod render_ddl;
+pub mod render_dot;
+pub mod render_markdown;
 pub mod render_yaml;
 pub(crate) mod sql_lit;
 pub mod util;
@@ -414,7 +417,41 @@ mod extension {
             sv_register_read_yaml_from_semantic_view
         ),
         ("semantic_view", sv_register_semantic_view),
+        ("semantic_query_json", sv_register_semantic_query_json),
         ("explain_semantic_view", sv_register_explain_semantic_view),
+        ("semantic_view_sql", sv_register_semantic_view_sql),
+        ("minimal_repro_json", sv_register_minimal_repro_json),
+        (
+            "create_view_from_semantic",
+            sv_register_create_view_from_semantic
+        ),
+        (
+            "estimate_semantic_query",
+            sv_register_estimate_semantic_query
+        ),
+        (
+            "validate_semantic_query",
+            sv_register_validate_semantic_query
+        ),
+        (
+            "validate_semantic_views_json",
+            sv_register_validate_semantic_views_json
+        ),
+        ("semantic_view_tables", sv_register_semantic_view_tables),
+        ("semantic_view_tags", sv_register_semantic_view_tags),
+        (
+            "find_dimension_across_views",
+            sv_register_find_dimension_across_views
+        ),
+        ("semantic_view_graph", sv_register_semantic_view_graph),
+        ("semantic_view_doc", sv_register_semantic_view_doc),
+        ("define_from_dbt", sv_register_define_from_dbt),
+        (
+            "define_semantic_views_script",
+            sv_register_define_semantic_views_script
+        ),
+        ("semantic_view_to_dbt", sv_register_semantic_view_to_dbt),
+        ("settings", sv_register_settings),
     ];
 
     /// Decode a `[0u8; 1024]` registration error buffer into an owned `String`,
@@ -495,8 +532,15 @@ mod extension {
             })
             .is_ok_and(|s| s.eq_ignore_ascii_case("read_only"));
 
-        // Initialize the persistent catalog (schema + table + companion-file migration).
-        init_catalog(con, &db_path, is_read_only)?;
+        // Initialize the persistent catalog (schema + table + companion-file
+        // migration). LOAD has no channel for informational output, so the
+        // `CatalogLoad` summary only matters when it reports a companion file
+        // that conflicts with stored views: that fails the load, naming them,
+        // the same way an unreadable companion file does.
+        let load = init_catalog(con, &db_path, is_read_only)?;
+        if let Some(conflict) = load.companion_conflict() {
+            return Err(conflict.into());
+        }
 
         // Phase 65 Plan 06: H1 catalog_conn allocation RETIRED. The
         // parser_override path is now pure-SQL on the caller's connection
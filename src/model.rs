@@ -19,6 +19,9 @@
 //!   casing is now baked into stored JSON and the YAML export, so renaming a
 //!   variant or adding a rename attribute is a breaking format change.
 
+use std::collections::BTreeMap;
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 /// A table alias entry for the `tables` DDL parameter.
@@ -27,6 +30,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TableRef {
     pub alias: String,
+    /// Physical table name. Left empty on the base entry of a view that
+    /// reads from [`SemanticViewDefinition::base_sql`] instead.
+    #[serde(default)]
     pub table: String,
     /// Primary key columns for this table (Phase 24: PK/FK model).
     /// Old stored JSON without this field deserializes with empty Vec.
@@ -65,6 +71,36 @@ pub struct Dimension {
     /// If None, the inferred or fallback type is used.
     #[serde(default)]
     pub output_type: Option<String>,
+    /// Optional collation applied when grouping by this dimension, e.g.
+    /// `nocase` for case-insensitive grouping. The generated SQL emits
+    /// `expr COLLATE "<collation>"` for the select item the ordinal
+    /// `GROUP BY` points at. Validated as an identifier at CREATE time.
+    /// Old stored JSON without this field deserializes to None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collate: Option<String>,
+    /// Optional replacement for NULL and empty-string values, e.g.
+    /// `Unknown`. The generated SQL selects (and so groups by)
+    /// `COALESCE(NULLIF(expr, ''), '<coalesce_to>')`, with the value escaped
+    /// as a string literal. Old stored JSON without this field deserializes
+    /// to None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coalesce_to: Option<String>,
+    /// Optional replacement for NULL values only, e.g. `N/A` or `0`. The
+    /// generated SQL selects (and so groups by) `COALESCE(expr,
+    /// '<null_value>')`; the value is escaped as a string literal, which
+    /// `DuckDB` casts to the expression's type. Unlike `coalesce_to`, empty
+    /// strings are kept; CREATE rejects setting both.
+    /// Old stored JSON without this field deserializes to None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null_value: Option<String>,
+    /// Optional bins that turn the expression into a labelled bucket: the
+    /// generated SQL selects (and so groups by) `CASE WHEN expr <when> THEN
+    /// '<label>' ... END`, first matching bin wins, and rows matching no bin
+    /// are NULL. The wrap sits inside `coalesce_to` / `null_value`, so those
+    /// can label the unmatched rows. CREATE rejects an empty list.
+    /// Old stored JSON without this field deserializes to None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<Vec<BucketBin>>,
     /// Optional human-readable comment for this dimension.
     /// Old stored JSON without this field deserializes to None.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -75,6 +111,18 @@ pub struct Dimension {
     pub synonyms: Vec<String>,
 }
 
+/// One bin of a bucket dimension ([`Dimension::bucket`]).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BucketBin {
+    /// Condition on the dimension expression, written as the rest of a
+    /// predicate: `< 100`, `BETWEEN 100 AND 999`, `IN ('a', 'b')`.
+    pub when: String,
+    /// Value the dimension takes for rows matching this bin; emitted as an
+    /// escaped string literal.
+    pub label: String,
+}
+
 /// Sort order for NON ADDITIVE BY dimension ordering.
 /// Default: Asc (matches Snowflake default).
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -214,6 +262,26 @@ pub struct Metric {
     /// Not serialized when None to preserve backward-compatible JSON.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub window_spec: Option<WindowSpec>,
+    /// Optional row predicate for a base metric, emitted as
+    /// `<expr> FILTER (WHERE <filter>)`. Raw SQL, like `expr`; CREATE only
+    /// accepts it on a non-window base metric whose expression is a single
+    /// aggregate call.
+    /// Old stored JSON without this field deserializes to None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Render a `COUNT(DISTINCT x)` metric as `approx_count_distinct(x)`,
+    /// trading exactness for speed on large tables. CREATE only accepts it on
+    /// a base metric whose expression is a single `COUNT(DISTINCT ...)` call.
+    /// Old stored JSON without this field deserializes to false (exact).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub approx: bool,
+    /// Aggregate distinct input values only: `SUM(x)` is emitted as
+    /// `SUM(DISTINCT x)`. CREATE only accepts it on a base metric whose
+    /// expression is a single `COUNT`, `SUM`, `AVG`, `STRING_AGG`, `LIST`, or
+    /// `ARRAY_AGG` call over something other than `*`.
+    /// Old stored JSON without this field deserializes to false.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub distinct: bool,
 }
 
 impl Metric {
@@ -397,6 +465,48 @@ pub struct SemanticViewDefinition {
     /// Not serialized when empty to preserve backward-compatible JSON.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub materializations: Vec<Materialization>,
+    /// SQL query read in place of the base (first) table, emitted as
+    /// `FROM (<base_sql>) AS <base alias>`. When set, the base entry in
+    /// `tables` carries only the alias and keys, with an empty `table`.
+    /// Not serialized when absent to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_sql: Option<String>,
+    /// Name of a parent semantic view this one inherits tables,
+    /// relationships, dimensions, metrics, facts, and materializations from
+    /// (see [`SemanticViewDefinition::inherit_from`]). Resolved by the catalog
+    /// when the view is fetched for a query.
+    /// Not serialized when absent to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    /// Dimensions selected when a query names no dimension, metric, or fact.
+    /// Not serialized when empty to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_dimensions: Vec<String>,
+    /// Metrics selected when a query names no dimension, metric, or fact.
+    /// Not serialized when empty to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_metrics: Vec<String>,
+    /// Dimension that date-range macros (`LAST_30_DAYS`, `THIS_QUARTER`, ...)
+    /// in a query's dimension filters apply to.
+    /// Not serialized when absent to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_dimension: Option<String>,
+    /// SQL predicate every query of the view filters the base table by, e.g.
+    /// `o.tenant_id = current_setting('app.tenant')`. Applied by every
+    /// expansion path and never omitted; see `expand::select_spec`.
+    /// Not serialized when absent to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_security: Option<String>,
+    /// Free-form tags for organizing a catalog of views (`finance`,
+    /// `team:growth`), matched case-insensitively by [`Self::has_tag`].
+    /// Not serialized when empty to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Key-value labels for the same purpose (`owner: data-eng`). Kept
+    /// sorted by key so the stored JSON is stable.
+    /// Not serialized when empty to preserve backward-compatible JSON.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
     // AR-4 (PR-2): the parallel DDL-time type-inference vectors
     // `column_type_names` / `column_types_inferred` were removed here. They
     // were never populated for post-v0.10.0 rows (D-16/D-17 deferred type
@@ -444,6 +554,79 @@ impl SemanticViewDefinition {
             .map(|t| (t.alias.clone(), t.table.clone()))
             .collect()
     }
+
+    /// True when `tag` is one of the view's `tags`, compared ASCII
+    /// case-insensitively.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Merge in what this definition inherits from `parent` (`extends`).
+    ///
+    /// Each list keeps the parent's entries, in order, followed by the
+    /// child's new ones; a child entry with the same name (ASCII
+    /// case-insensitive; tables by alias, unnamed relationships by their
+    /// `from_alias -> table` pair) replaces the parent's in place. The parent's
+    /// base table therefore stays first unless the child redeclares its alias.
+    /// `base_sql` is inherited unless the child has its own or gives the base
+    /// table a physical name, the default dimensions and metrics when the
+    /// child declares none, and the time dimension when the child has none.
+    /// A parent's `row_security` is always kept, combined with any predicate
+    /// of the child's by `AND`, so extending a view can narrow its rows but
+    /// never widen them. The view comment, tags, labels, placement metadata,
+    /// and `extends` itself are not inherited.
+    pub fn inherit_from(&mut self, parent: &SemanticViewDefinition) {
+        fn merge<T: Clone>(child: &mut Vec<T>, parent: &[T], key: impl Fn(&T) -> String) {
+            let mut own = std::mem::take(child);
+            for inherited in parent {
+                let k = key(inherited);
+                match own.iter().position(|c| key(c) == k) {
+                    Some(i) => child.push(own.remove(i)),
+                    None => child.push(inherited.clone()),
+                }
+            }
+            child.append(&mut own);
+        }
+        merge(&mut self.tables, &parent.tables, |t| {
+            t.alias.to_ascii_lowercase()
+        });
+        merge(&mut self.joins, &parent.joins, |j| match &j.name {
+            Some(name) => name.to_ascii_lowercase(),
+            None => format!("{} -> {}", j.from_alias, j.table).to_ascii_lowercase(),
+        });
+        merge(&mut self.dimensions, &parent.dimensions, |d| {
+            d.name.to_ascii_lowercase()
+        });
+        merge(&mut self.metrics, &parent.metrics, |m| {
+            m.name.to_ascii_lowercase()
+        });
+        merge(&mut self.facts, &parent.facts, |f| {
+            f.name.to_ascii_lowercase()
+        });
+        merge(&mut self.materializations, &parent.materializations, |m| {
+            m.name.to_ascii_lowercase()
+        });
+        let base_is_query = self
+            .tables
+            .first()
+            .is_some_and(|t| t.table.trim().is_empty());
+        if self.base_sql.is_none() && base_is_query {
+            self.base_sql.clone_from(&parent.base_sql);
+        }
+        if self.default_dimensions.is_empty() && self.default_metrics.is_empty() {
+            self.default_dimensions
+                .clone_from(&parent.default_dimensions);
+            self.default_metrics.clone_from(&parent.default_metrics);
+        }
+        if self.time_dimension.is_none() {
+            self.time_dimension.clone_from(&parent.time_dimension);
+        }
+        self.row_security = match (parent.row_security.as_deref(), self.row_security.take()) {
+            (Some(p), Some(c)) => Some(format!("({p}) AND ({c})")),
+            (p, c) => c.or_else(|| p.map(str::to_string)),
+        };
+    }
 }
 
 impl SemanticViewDefinition {
@@ -487,6 +670,102 @@ impl SemanticViewDefinition {
     pub fn has_incomplete_relationships(&self) -> bool {
         self.joins.iter().any(|j| j.fk_columns.is_empty())
     }
+
+    /// Content fingerprint of the definition: FNV-1a 64 over its JSON
+    /// serialization with the placement metadata (`created_on`,
+    /// `database_name`, `schema_name`) cleared, rendered as 16 hex digits.
+    ///
+    /// Two definitions with the same checksum expand identically, so the
+    /// value ties generated SQL back to an exact definition version. It is
+    /// not cryptographic and only stable across releases that serialize the
+    /// model the same way.
+    #[must_use]
+    pub fn checksum(&self) -> String {
+        let mut content = self.clone();
+        content.created_on = None;
+        content.database_name = None;
+        content.schema_name = None;
+        let json = serde_json::to_string(&content).unwrap_or_default();
+        let hash = json.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |h, b| {
+            (h ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        format!("{hash:016x}")
+    }
+}
+
+/// A short human-readable summary for logs, test output, and error context —
+/// not a serialization (use JSON or YAML export for that). One line per
+/// section: base table, tables, relationships, dimensions, metrics (with any
+/// filter), facts, then row security and `extends` when set. Sections that
+/// are empty and optional are left out.
+///
+/// ```text
+/// base table: o AS orders
+/// tables (2): o AS orders, c AS customers
+/// relationships (1): o(customer_id) -> c
+/// dimensions (1): region
+/// metrics (2): revenue FILTER (WHERE o.status = 'paid'), order_count
+/// ```
+impl fmt::Display for SemanticViewDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn section<I: IntoIterator<Item = String>>(
+            f: &mut fmt::Formatter<'_>,
+            label: &str,
+            items: I,
+        ) -> fmt::Result {
+            let items: Vec<String> = items.into_iter().collect();
+            if items.is_empty() {
+                return write!(f, "\n{label} (0)");
+            }
+            write!(f, "\n{label} ({}): {}", items.len(), items.join(", "))
+        }
+        let table = |t: &TableRef| format!("{} AS {}", t.alias, t.table);
+
+        match (self.tables.first(), &self.base_sql) {
+            (Some(base), Some(sql)) => write!(f, "base table: {} AS ({sql})", base.alias)?,
+            (Some(base), None) => write!(f, "base table: {}", table(base))?,
+            (None, _) => f.write_str("base table: (none)")?,
+        }
+        section(f, "tables", self.tables.iter().map(table))?;
+        if !self.joins.is_empty() {
+            section(
+                f,
+                "relationships",
+                self.joins.iter().map(|j| {
+                    let name = j.name.as_ref().map_or(String::new(), |n| format!("{n}: "));
+                    format!(
+                        "{name}{}({}) -> {}",
+                        j.from_alias,
+                        j.fk_columns.join(", "),
+                        j.table
+                    )
+                }),
+            )?;
+        }
+        section(
+            f,
+            "dimensions",
+            self.dimensions.iter().map(|d| d.name.clone()),
+        )?;
+        section(
+            f,
+            "metrics",
+            self.metrics.iter().map(|m| match &m.filter {
+                Some(filter) => format!("{} FILTER (WHERE {filter})", m.name),
+                None => m.name.clone(),
+            }),
+        )?;
+        if !self.facts.is_empty() {
+            section(f, "facts", self.facts.iter().map(|x| x.name.clone()))?;
+        }
+        if let Some(predicate) = &self.row_security {
+            write!(f, "\nrow security: {predicate}")?;
+        }
+        if let Some(parent) = &self.extends {
+            write!(f, "\nextends: {parent}")?;
+        }
+        Ok(())
+    }
 }
 
 impl SemanticViewDefinition {
@@ -526,6 +805,89 @@ impl SemanticViewDefinition {
 mod tests {
     use super::*;
 
+    #[test]
+    fn checksum_ignores_placement_metadata_only() {
+        let def = SemanticViewDefinition::from_json(
+            "v",
+            r#"{"tables":[{"alias":"o","table":"orders"}],"dimensions":[],"metrics":[{"name":"n","expr":"count(*)"}]}"#,
+        )
+        .unwrap();
+        let sum = def.checksum();
+        assert_eq!(sum.len(), 16);
+
+        let mut placed = def.clone();
+        placed.created_on = Some("2026-01-01T00:00:00Z".to_string());
+        placed.database_name = Some("memory".to_string());
+        placed.schema_name = Some("main".to_string());
+        assert_eq!(placed.checksum(), sum);
+
+        let mut edited = def;
+        edited.metrics[0].expr = "count(o.id)".to_string();
+        assert_ne!(edited.checksum(), sum);
+    }
+
+    #[test]
+    fn display_summarizes_the_definition() {
+        let def = SemanticViewDefinition {
+            tables: vec![
+                TableRef {
+                    alias: "o".into(),
+                    table: "orders".into(),
+                    ..Default::default()
+                },
+                TableRef {
+                    alias: "c".into(),
+                    table: "customers".into(),
+                    ..Default::default()
+                },
+            ],
+            joins: vec![Join {
+                table: "c".into(),
+                from_alias: "o".into(),
+                fk_columns: vec!["customer_id".into()],
+                name: Some("order_customer".into()),
+                ..Default::default()
+            }],
+            dimensions: vec![Dimension {
+                name: "region".into(),
+                expr: "c.region".into(),
+                ..Default::default()
+            }],
+            metrics: vec![
+                Metric {
+                    name: "revenue".into(),
+                    expr: "sum(o.amount)".into(),
+                    filter: Some("o.status = 'paid'".into()),
+                    ..Default::default()
+                },
+                Metric {
+                    name: "order_count".into(),
+                    expr: "count(*)".into(),
+                    ..Default::default()
+                },
+            ],
+            row_security: Some("o.tenant_id = 1".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            def.to_string(),
+            "base table: o AS orders\n\
+             tables (2): o AS orders, c AS customers\n\
+             relationships (1): order_customer: o(customer_id) -> c\n\
+             dimensions (1): region\n\
+             metrics (2): revenue FILTER (WHERE o.status = 'paid'), order_count\n\
+             row security: o.tenant_id = 1"
+        );
+    }
+
+    #[test]
+    fn display_of_an_empty_definition_keeps_required_sections() {
+        assert_eq!(
+            SemanticViewDefinition::default().to_string(),
+            "base table: (none)\ntables (0)\ndimensions (0)\nmetrics (0)"
+        );
+    }
+
     // --- AR-4: schema_version probe + incomplete-relationship detection ---
 
     #[test]
@@ -724,6 +1086,14 @@ mod tests {
                 joins: vec![],
                 facts: vec![],
                 materializations: vec![],
+                base_sql: None,
+                extends: None,
+                default_dimensions: vec![],
+                default_metrics: vec![],
+                time_dimension: None,
+                row_security: None,
+                tags: vec![],
+                labels: BTreeMap::new(),
 
                 created_on: None,
                 database_name: None,
@@ -846,6 +1216,9 @@ mod tests {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             };
             let json = serde_json::to_string(&met).unwrap();
             assert!(json.contains("using_relationships"));
@@ -878,6 +1251,9 @@ mod tests {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             };
             let json = serde_json::to_string(&met).unwrap();
             assert!(
@@ -897,6 +1273,10 @@ mod tests {
                 expr: "region".to_string(),
                 source_table: None,
                 output_type: Some("BIGINT".to_string()),
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             };
@@ -918,6 +1298,9 @@ mod tests {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             };
             let json = serde_json::to_string(&met).unwrap();
             let rt: Metric = serde_json::from_str(&json).unwrap();
@@ -1110,6 +1493,43 @@ mod tests {
             );
         }
 
+        #[test]
+        fn tags_and_labels_roundtrip_and_default_empty() {
+            let json = r#"{"dimensions":[],"metrics":[],"tags":["finance","Team:Growth"],"labels":{"owner":"data-eng","domain":"sales"}}"#;
+            let def = SemanticViewDefinition::from_json("orders", json).unwrap();
+            assert_eq!(def.tags, vec!["finance", "Team:Growth"]);
+            assert_eq!(
+                def.labels.iter().collect::<Vec<_>>(),
+                vec![
+                    (&"domain".to_string(), &"sales".to_string()),
+                    (&"owner".to_string(), &"data-eng".to_string())
+                ]
+            );
+            let rt =
+                SemanticViewDefinition::from_json("orders", &serde_json::to_string(&def).unwrap())
+                    .unwrap();
+            assert_eq!(rt, def);
+
+            let plain =
+                SemanticViewDefinition::from_json("orders", r#"{"dimensions":[],"metrics":[]}"#)
+                    .unwrap();
+            assert!(plain.tags.is_empty() && plain.labels.is_empty());
+            let json = serde_json::to_string(&plain).unwrap();
+            assert!(!json.contains("tags") && !json.contains("labels"), "{json}");
+        }
+
+        #[test]
+        fn has_tag_ignores_case() {
+            let def = SemanticViewDefinition {
+                tags: vec!["finance".to_string(), "Team:Growth".to_string()],
+                ..Default::default()
+            };
+            assert!(def.has_tag("FINANCE"));
+            assert!(def.has_tag("team:growth"));
+            assert!(!def.has_tag("fin"));
+            assert!(!SemanticViewDefinition::default().has_tag("finance"));
+        }
+
         #[test]
         fn fact_output_type_roundtrip() {
             let fact = Fact {
@@ -1236,6 +1656,9 @@ mod tests {
                 access: AccessModifier::Private,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             };
             let json = serde_json::to_string(&met).unwrap();
             assert!(
@@ -1259,6 +1682,9 @@ mod tests {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             };
             let json = serde_json::to_string(&met).unwrap();
             assert!(
@@ -1284,6 +1710,10 @@ mod tests {
                 expr: "region".to_string(),
                 source_table: None,
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: Some("Geographic region".to_string()),
                 synonyms: vec!["area".to_string(), "territory".to_string()],
             };
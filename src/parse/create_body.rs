@@ -16,6 +16,8 @@
 //! re-exported under `#[cfg(test)]` so the parent module's test suite can
 //! exercise them directly.
 
+use std::collections::BTreeMap;
+
 use super::{CreateMode, DdlKind, RewriteAction};
 use crate::body_parser::parse_keyword_body;
 use crate::errors::ParseError;
@@ -218,6 +220,14 @@ fn rewrite_ddl_keyword_body(
         joins: keyword_body.relationships,
         facts: keyword_body.facts,
         materializations: keyword_body.materializations,
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
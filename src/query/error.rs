@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::expand::ExpandError;
+use crate::expand::{display_available, ExpandError};
 
 /// Errors that can occur when executing a semantic view query.
 #[derive(Debug)]
@@ -19,6 +19,9 @@ pub enum QueryError {
     /// rendering the diagnostic inside quotes followed by irrelevant
     /// "specify at least dimensions" advice).
     WildcardExpansion { view_name: String, detail: String },
+    /// The `semantic_query_json()` request did not deserialize into a
+    /// [`crate::expand::QueryRequest`]; `detail` is serde's message.
+    InvalidRequestJson { view_name: String, detail: String },
     /// The expansion engine returned an error.
     ExpandFailed { source: ExpandError },
     /// The expanded SQL failed to execute against `DuckDB`.
@@ -50,7 +53,7 @@ impl fmt::Display for QueryError {
                     write!(f, " Did you mean '{s}'?")?;
                 }
                 if !available.is_empty() {
-                    write!(f, " Available views: [{}].", available.join(", "))?;
+                    write!(f, " Available views: [{}].", display_available(available))?;
                 }
                 write!(
                     f,
@@ -70,6 +73,12 @@ impl fmt::Display for QueryError {
             Self::WildcardExpansion { view_name, detail } => {
                 write!(f, "semantic view '{view_name}': {detail}")
             }
+            Self::InvalidRequestJson { view_name, detail } => {
+                write!(
+                    f,
+                    "semantic view '{view_name}': invalid request JSON: {detail}"
+                )
+            }
             Self::ExpandFailed { source } => {
                 write!(f, "{source}")
             }
@@ -110,6 +119,72 @@ impl std::error::Error for QueryError {
     }
 }
 
+impl QueryError {
+    /// Stable machine-readable identifier for this error kind. Expansion
+    /// failures report the underlying [`ExpandError::code`], so callers see
+    /// e.g. `"UNKNOWN_METRIC"` rather than a generic expansion code.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ViewNotFound { .. } => "VIEW_NOT_FOUND",
+            Self::EmptyRequest { .. } => "EMPTY_REQUEST",
+            Self::WildcardExpansion { .. } => "WILDCARD_EXPANSION",
+            Self::InvalidRequestJson { .. } => "INVALID_REQUEST_JSON",
+            Self::ExpandFailed { source } => source.code(),
+            Self::SqlExecution { .. } => "SQL_EXECUTION",
+            Self::TypeMismatch { .. } => "TYPE_MISMATCH",
+        }
+    }
+
+    /// The full list of alternatives behind a not-found error: view names for
+    /// [`Self::ViewNotFound`], item names for an unknown dimension, metric, or
+    /// fact. Empty otherwise.
+    #[must_use]
+    pub fn available(&self) -> &[String] {
+        match self {
+            Self::ViewNotFound { available, .. } => available,
+            Self::ExpandFailed { source } => source.available(),
+            _ => &[],
+        }
+    }
+
+    /// The closest match to the name that was not found, if any.
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::ViewNotFound { suggestion, .. } => suggestion.as_deref(),
+            Self::ExpandFailed { source } => source.suggestion(),
+            _ => None,
+        }
+    }
+
+    /// `{"code": ..., "message": ..., "context": {...}}`, the same shape as
+    /// [`ExpandError::to_json`].
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        let context = match self {
+            Self::ViewNotFound { name, .. } => serde_json::json!({ "view_name": name }),
+            Self::EmptyRequest { view_name }
+            | Self::WildcardExpansion { view_name, .. }
+            | Self::InvalidRequestJson { view_name, .. } => {
+                serde_json::json!({ "view_name": view_name })
+            }
+            Self::ExpandFailed { source } => source.context(),
+            Self::SqlExecution { .. } => serde_json::json!({}),
+            Self::TypeMismatch {
+                column_index,
+                column_name,
+                ..
+            } => serde_json::json!({ "column_index": column_index, "column_name": column_name }),
+        };
+        serde_json::json!({
+            "code": self.code(),
+            "message": self.to_string(),
+            "context": context,
+        })
+    }
+}
+
 impl From<ExpandError> for QueryError {
     fn from(source: ExpandError) -> Self {
         Self::ExpandFailed { source }
@@ -155,4 +230,62 @@ mod tests {
             .to_string()
             .contains("DESCRIBE SEMANTIC VIEW orders"));
     }
+
+    #[test]
+    fn codes_are_stable() {
+        let not_found = QueryError::ViewNotFound {
+            name: "sales".to_string(),
+            suggestion: None,
+            available: vec![],
+        };
+        assert_eq!(not_found.code(), "VIEW_NOT_FOUND");
+        assert_eq!(not_found.to_json()["context"]["view_name"], "sales");
+        let expand: QueryError = ExpandError::CatalogPoisoned {
+            view_name: "sales".to_string(),
+        }
+        .into();
+        assert_eq!(expand.code(), "CATALOG_POISONED");
+        assert_eq!(
+            QueryError::SqlExecution {
+                expanded_sql: String::new(),
+                duckdb_error: String::new(),
+            }
+            .code(),
+            "SQL_EXECUTION"
+        );
+    }
+
+    #[test]
+    fn invalid_request_json_names_the_view_and_serde_detail() {
+        let detail = serde_json::from_str::<crate::expand::QueryRequest>(r#"{"metric": []}"#)
+            .unwrap_err()
+            .to_string();
+        let err = QueryError::InvalidRequestJson {
+            view_name: "orders".to_string(),
+            detail,
+        };
+        assert_eq!(err.code(), "INVALID_REQUEST_JSON");
+        assert!(
+            err.to_string().starts_with(
+                "semantic view 'orders': invalid request JSON: unknown field `metric`"
+            ),
+            "{err}"
+        );
+        assert_eq!(err.to_json()["context"]["view_name"], "orders");
+    }
+
+    #[test]
+    fn view_not_found_caps_listed_views() {
+        let available: Vec<String> = (0..25).map(|i| format!("view_{i}")).collect();
+        let err = QueryError::ViewNotFound {
+            name: "nope".to_string(),
+            suggestion: None,
+            available: available.clone(),
+        };
+        assert!(
+            err.to_string().contains("view_9, ... and 15 more]."),
+            "{err}"
+        );
+        assert_eq!(err.available().len(), 25);
+    }
 }
@@ -0,0 +1,339 @@
+//! Bounded LRU cache of expanded SQL for repeated `semantic_view()` binds.
+//!
+//! A dashboard re-issuing the same query re-runs definition parsing, wildcard
+//! expansion, and `expand()` on every bind. The bind still reads the view's
+//! stored JSON from the catalog on each call (that read is what keeps DDL
+//! transactional — see `crate::catalog`), but everything derived purely from
+//! that JSON and the request is memoized here.
+//!
+//! The key embeds the stored definition JSON itself rather than a version
+//! counter, so there is nothing to invalidate: redefining a view changes its
+//! JSON and misses, dropping it fails the catalog lookup before the cache is
+//! consulted, and a rolled-back `CREATE OR REPLACE` reads the old JSON and
+//! hits the old entry. The same property makes one process-wide cache safe to
+//! share across databases.
+//!
+//! Capacity comes from `SEMANTIC_VIEWS_EXPANSION_CACHE_SIZE` (entries, read
+//! once on first use; `0` disables caching) and defaults to
+//! [`DEFAULT_EXPANSION_CACHE_CAPACITY`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Entry capacity used when `SEMANTIC_VIEWS_EXPANSION_CACHE_SIZE` is unset or
+/// not a non-negative integer.
+pub const DEFAULT_EXPANSION_CACHE_CAPACITY: usize = 256;
+
+/// Environment variable overriding the process-wide cache capacity.
+pub const EXPANSION_CACHE_SIZE_ENV: &str = "SEMANTIC_VIEWS_EXPANSION_CACHE_SIZE";
+
+/// Expansions longer than this are not cached, so a handful of pathological
+/// requests cannot pin hundreds of megabytes (expansion itself allows up to
+/// [`crate::expand::DEFAULT_MAX_EXPANDED_SQL_BYTES`]).
+pub const MAX_CACHED_SQL_BYTES: usize = 1024 * 1024;
+
+/// Everything `expand()`'s output depends on. The name lists are the raw,
+/// pre-wildcard request — wildcard expansion is itself a function of the
+/// definition, so it is covered by `definition_json`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExpansionKey {
+    pub view_name: String,
+    pub definition_json: String,
+    pub dimensions: Vec<String>,
+    pub metrics: Vec<String>,
+    pub facts: Vec<String>,
+}
+
+/// Thread-safe LRU map from [`ExpansionKey`] to expanded SQL.
+///
+/// Binds run concurrently, so every access goes through one `Mutex`; `get`
+/// and `insert` are O(1) so the lock is held briefly. A poisoned lock
+/// degrades to a cache miss rather than failing the query.
+#[derive(Debug)]
+pub struct ExpansionCache {
+    capacity: usize,
+    inner: Mutex<Entries>,
+}
+
+/// Marks the end of the recency list.
+const NIL: usize = usize::MAX;
+
+/// A hash index over a fixed pool of slots threaded on a doubly linked
+/// recency list (`head` most, `tail` least recently used). Once the pool is
+/// full, an insert reuses the tail slot, so nothing is ever scanned.
+#[derive(Debug)]
+struct Entries {
+    index: HashMap<Arc<ExpansionKey>, usize>,
+    slots: Vec<Slot>,
+    head: usize,
+    tail: usize,
+}
+
+#[derive(Debug)]
+struct Slot {
+    key: Arc<ExpansionKey>,
+    sql: String,
+    prev: usize,
+    next: usize,
+}
+
+impl Default for Entries {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            slots: Vec::new(),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+}
+
+impl Entries {
+    fn unlink(&mut self, i: usize) {
+        let (prev, next) = (self.slots[i].prev, self.slots[i].next);
+        match prev {
+            NIL => self.head = next,
+            p => self.slots[p].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            n => self.slots[n].prev = prev,
+        }
+    }
+
+    fn push_front(&mut self, i: usize) {
+        self.slots[i].prev = NIL;
+        self.slots[i].next = self.head;
+        match self.head {
+            NIL => self.tail = i,
+            h => self.slots[h].prev = i,
+        }
+        self.head = i;
+    }
+
+    fn touch(&mut self, i: usize) {
+        if self.head != i {
+            self.unlink(i);
+            self.push_front(i);
+        }
+    }
+}
+
+/// Capacity for a `SEMANTIC_VIEWS_EXPANSION_CACHE_SIZE` value: the value
+/// itself when it parses as a non-negative integer, else
+/// [`DEFAULT_EXPANSION_CACHE_CAPACITY`].
+fn capacity_from_env(value: Option<&str>) -> usize {
+    value
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_EXPANSION_CACHE_CAPACITY)
+}
+
+impl ExpansionCache {
+    /// An empty cache holding at most `capacity` entries (`0` caches nothing).
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// The process-wide cache used by the `semantic_view()` bind.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        static CACHE: OnceLock<ExpansionCache> = OnceLock::new();
+        CACHE.get_or_init(|| {
+            let value = std::env::var(EXPANSION_CACHE_SIZE_ENV).ok();
+            Self::new(capacity_from_env(value.as_deref()))
+        })
+    }
+
+    /// The cached SQL for `key`, marking it most recently used.
+    #[must_use]
+    pub fn get(&self, key: &ExpansionKey) -> Option<String> {
+        if self.capacity == 0 {
+            return None;
+        }
+        let mut entries = self.inner.lock().ok()?;
+        let i = *entries.index.get(key)?;
+        entries.touch(i);
+        Some(entries.slots[i].sql.clone())
+    }
+
+    /// Cache `sql` for `key`, evicting the least recently used entry when
+    /// full. SQL over [`MAX_CACHED_SQL_BYTES`] is not stored.
+    pub fn insert(&self, key: ExpansionKey, sql: String) {
+        if self.capacity == 0 || sql.len() > MAX_CACHED_SQL_BYTES {
+            return;
+        }
+        let Ok(mut entries) = self.inner.lock() else {
+            return;
+        };
+        if let Some(&i) = entries.index.get(&key) {
+            entries.slots[i].sql = sql;
+            entries.touch(i);
+            return;
+        }
+        let key = Arc::new(key);
+        let i = if entries.slots.len() < self.capacity {
+            entries.slots.push(Slot {
+                key: Arc::clone(&key),
+                sql,
+                prev: NIL,
+                next: NIL,
+            });
+            entries.slots.len() - 1
+        } else {
+            let lru = entries.tail;
+            entries.unlink(lru);
+            let evicted = std::mem::replace(&mut entries.slots[lru].key, Arc::clone(&key));
+            entries.index.remove(&evicted);
+            entries.slots[lru].sql = sql;
+            lru
+        };
+        entries.index.insert(key, i);
+        entries.push_front(i);
+    }
+
+    /// Number of cached entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.lock().map_or(0, |e| e.index.len())
+    }
+
+    /// Whether the cache holds no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        capacity_from_env, ExpansionCache, ExpansionKey, DEFAULT_EXPANSION_CACHE_CAPACITY,
+        MAX_CACHED_SQL_BYTES,
+    };
+
+    fn key(view: &str, json: &str, dims: &[&str]) -> ExpansionKey {
+        ExpansionKey {
+            view_name: view.to_string(),
+            definition_json: json.to_string(),
+            dimensions: dims.iter().map(ToString::to_string).collect(),
+            metrics: vec!["revenue".to_string()],
+            facts: vec![],
+        }
+    }
+
+    #[test]
+    fn hit_requires_identical_definition_and_request() {
+        let cache = ExpansionCache::new(4);
+        cache.insert(key("v", "{}", &["region"]), "SELECT 1".to_string());
+
+        assert_eq!(
+            cache.get(&key("v", "{}", &["region"])).as_deref(),
+            Some("SELECT 1")
+        );
+        // Redefined view: different stored JSON, so the old SQL is unreachable.
+        assert!(cache.get(&key("v", r#"{"x":1}"#, &["region"])).is_none());
+        assert!(cache.get(&key("v", "{}", &["status"])).is_none());
+        assert!(cache.get(&key("w", "{}", &["region"])).is_none());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = ExpansionCache::new(2);
+        cache.insert(key("a", "{}", &[]), "A".to_string());
+        cache.insert(key("b", "{}", &[]), "B".to_string());
+        // Touch `a` so `b` is the eviction candidate.
+        assert!(cache.get(&key("a", "{}", &[])).is_some());
+        cache.insert(key("c", "{}", &[]), "C".to_string());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key("a", "{}", &[])).is_some());
+        assert!(cache.get(&key("b", "{}", &[])).is_none());
+        assert!(cache.get(&key("c", "{}", &[])).is_some());
+    }
+
+    #[test]
+    fn zero_capacity_disables_caching() {
+        let cache = ExpansionCache::new(0);
+        cache.insert(key("a", "{}", &[]), "A".to_string());
+        assert!(cache.is_empty());
+        assert!(cache.get(&key("a", "{}", &[])).is_none());
+    }
+
+    #[test]
+    fn env_size_zero_disables_the_cache() {
+        assert_eq!(capacity_from_env(Some("0")), 0);
+        assert_eq!(capacity_from_env(Some(" 16 ")), 16);
+        assert_eq!(
+            capacity_from_env(Some("-1")),
+            DEFAULT_EXPANSION_CACHE_CAPACITY
+        );
+        assert_eq!(
+            capacity_from_env(Some("lots")),
+            DEFAULT_EXPANSION_CACHE_CAPACITY
+        );
+        assert_eq!(capacity_from_env(None), DEFAULT_EXPANSION_CACHE_CAPACITY);
+
+        let cache = ExpansionCache::new(capacity_from_env(Some("0")));
+        for i in 0..3 {
+            let k = key(&format!("v{i}"), "{}", &[]);
+            cache.insert(k.clone(), "SELECT 1".to_string());
+            assert!(cache.get(&k).is_none());
+        }
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn reinsert_and_eviction_keep_recency_order() {
+        let cache = ExpansionCache::new(3);
+        for name in ["a", "b", "c"] {
+            cache.insert(key(name, "{}", &[]), name.to_uppercase());
+        }
+        // Re-inserting `a` replaces its SQL and makes it most recent.
+        cache.insert(key("a", "{}", &[]), "A2".to_string());
+        assert_eq!(cache.len(), 3);
+        // Evicts `b`, then `c`; `a` survives both.
+        cache.insert(key("d", "{}", &[]), "D".to_string());
+        cache.insert(key("e", "{}", &[]), "E".to_string());
+        assert_eq!(cache.len(), 3);
+        assert!(cache.get(&key("b", "{}", &[])).is_none());
+        assert!(cache.get(&key("c", "{}", &[])).is_none());
+        assert_eq!(cache.get(&key("a", "{}", &[])).as_deref(), Some("A2"));
+        // Capacity one: every insert replaces the single slot.
+        let single = ExpansionCache::new(1);
+        single.insert(key("x", "{}", &[]), "X".to_string());
+        single.insert(key("y", "{}", &[]), "Y".to_string());
+        assert_eq!(single.len(), 1);
+        assert!(single.get(&key("x", "{}", &[])).is_none());
+        assert_eq!(single.get(&key("y", "{}", &[])).as_deref(), Some("Y"));
+    }
+
+    #[test]
+    fn oversized_sql_is_not_cached() {
+        let cache = ExpansionCache::new(2);
+        cache.insert(key("a", "{}", &[]), "x".repeat(MAX_CACHED_SQL_BYTES + 1));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn concurrent_access_is_safe() {
+        let cache = ExpansionCache::new(8);
+        std::thread::scope(|s| {
+            for t in 0..4 {
+                let cache = &cache;
+                s.spawn(move || {
+                    for i in 0..50 {
+                        let k = key(&format!("v{}", i % 10), "{}", &[]);
+                        if cache.get(&k).is_none() {
+                            cache.insert(k, format!("SELECT {t}"));
+                        }
+                    }
+                });
+            }
+        });
+        assert!(cache.len() <= 8);
+    }
+}
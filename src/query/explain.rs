@@ -1,16 +1,12 @@
 use libduckdb_sys as ffi;
 
-use crate::catalog::CatalogReader;
+use crate::expand::expand;
 use crate::expand::find_routing_materialization_name;
-use crate::expand::{expand, QueryRequest};
-use crate::model::SemanticViewDefinition;
-use crate::util::suggest_closest;
 
 use super::error::QueryError;
-use crate::expand::wildcard::{expand_wildcards, WildcardItemType};
+use super::request::{lookup_request, query_request, RequestedView};
 
 use super::table_function::{execute_sql_raw, read_varchar_from_vector};
-use super::wire::parse_varchar_list;
 
 // ---------------------------------------------------------------------------
 // Phase 65 Plan 05 Task 5 (Wave 5) — sv_explain_semantic_view_bind_rust
@@ -42,6 +38,12 @@ use super::wire::parse_varchar_list;
 //     u32 byte_len (little-endian)
 //     byte_len bytes (UTF-8) — one explain-output line per row, single VARCHAR column
 //
+// `options` is a bit set decoded from explain-only named parameters:
+// `EXPLAIN_STRUCTURED` (`structured := true`) emits two columns per row,
+// the section name then the line; `EXPLAIN_ANALYZE` (`analyze := true`)
+// captures the plan with `EXPLAIN ANALYZE`, which runs the query. The other request-inspection dispatchers
+// take the same argument so the C++ side can share one dispatcher type.
+//
 // Return codes:
 //   0 — success; `(out_ptr, out_len)` populated.
 //   1 — user-visible error (catalog miss, validation, expand failure);
@@ -67,6 +69,7 @@ pub unsafe extern "C" fn sv_explain_semantic_view_bind_rust(
     metrics_len: usize,
     facts_ptr: *const u8,
     facts_len: usize,
+    options: u32,
     out_ptr: *mut *mut u8,
     out_len: *mut usize,
     error_buf: *mut u8,
@@ -93,6 +96,7 @@ pub unsafe extern "C" fn sv_explain_semantic_view_bind_rust(
                 metrics_len,
                 facts_ptr,
                 facts_len,
+                options,
             )
         },
     )
@@ -100,17 +104,14 @@ pub unsafe extern "C" fn sv_explain_semantic_view_bind_rust(
 
 /// Body for [`sv_explain_semantic_view_bind_rust`]: decode the request args,
 /// resolve + expand the view, capture its `EXPLAIN` plan, and serialize the
-/// annotated output as 1-column VARCHAR rows.
+/// annotated output as VARCHAR rows — one column, or `(section, line)` when
+/// `options` has [`EXPLAIN_STRUCTURED`](super::wire::EXPLAIN_STRUCTURED) set.
 ///
 /// # Safety
 ///
-/// Each `*_ptr` is either null or points to its paired `*_len` readable bytes.
-/// A null pointer is only meaningful with `*_len == 0` (an empty list / absent
-/// name); the decoders reject `(null, len > 0)` as FFI shape drift rather than
-/// dereferencing it. The borrowed connection must outlive the call (see the
-/// module borrow contract).
+/// Same contract as [`lookup_request`].
 #[cfg(feature = "extension")]
-#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+#[allow(clippy::too_many_arguments)]
 unsafe fn explain_semantic_view_bind_body(
     borrowed: &crate::ddl::read_ffi::BorrowedConnection,
     name_ptr: *const u8,
@@ -121,71 +122,31 @@ unsafe fn explain_semantic_view_bind_body(
     metrics_len: usize,
     facts_ptr: *const u8,
     facts_len: usize,
+    options: u32,
 ) -> Result<Vec<u8>, String> {
-    use crate::ddl::read_ffi::{probe_catalog_table_present, read_str_arg, serialize_varchar_rows};
-
-    let view_name_raw = read_str_arg(name_ptr, name_len, "view name")?;
-    let view_name = crate::ident::normalize_view_name(&view_name_raw)
-        .map_err(|e| format!("Invalid view name '{view_name_raw}': {e}"))?;
+    use super::wire::{explain_rows, EXPLAIN_ANALYZE, EXPLAIN_STRUCTURED};
+    use crate::ddl::read_ffi::serialize_varchar_rows;
 
-    let dimensions = parse_varchar_list(dims_ptr, dims_len)
-        .map_err(|detail| format!("malformed `dimensions` payload: {detail}"))?;
-    let metrics = parse_varchar_list(metrics_ptr, metrics_len)
-        .map_err(|detail| format!("malformed `metrics` payload: {detail}"))?;
-    let facts = parse_varchar_list(facts_ptr, facts_len)
-        .map_err(|detail| format!("malformed `facts` payload: {detail}"))?;
-
-    if dimensions.is_empty() && metrics.is_empty() && facts.is_empty() {
-        // Match the QueryError::EmptyRequest message rendered by the legacy
-        // VTab so phase57_introspection assertions stay byte-identical.
-        return Err(QueryError::EmptyRequest { view_name }.to_string());
-    }
-
-    // FF-9: surface a probe-query failure as an error distinct from "no
-    // views" instead of silently folding it into absence.
-    let present = probe_catalog_table_present(borrowed)?;
-    let reader = CatalogReader::new(borrowed, present);
-    let json_str = match reader.lookup(&view_name) {
-        Ok(Some(j)) => j,
-        Ok(None) => {
-            let available = reader.list_names().unwrap_or_default();
-            let suggestion = suggest_closest(&view_name, &available);
-            return Err(QueryError::ViewNotFound {
-                name: view_name,
-                suggestion,
-                available,
-            }
-            .to_string());
-        }
-        Err(e) => return Err(e),
-    };
-
-    let def = SemanticViewDefinition::from_json(&view_name, &json_str)?;
-
-    // R-3 (code-review 2026-07-11): wildcard failures render through
-    // QueryError::WildcardExpansion, matching semantic_view()'s wording.
-    let dimensions =
-        expand_wildcards(&dimensions, &def, &WildcardItemType::Dimension).map_err(|e| {
-            QueryError::WildcardExpansion {
-                view_name: view_name.clone(),
-                detail: e,
-            }
-            .to_string()
-        })?;
-    let metrics = expand_wildcards(&metrics, &def, &WildcardItemType::Metric).map_err(|e| {
-        QueryError::WildcardExpansion {
-            view_name: view_name.clone(),
-            detail: e,
-        }
-        .to_string()
-    })?;
-    let facts = expand_wildcards(&facts, &def, &WildcardItemType::Fact).map_err(|e| {
-        QueryError::WildcardExpansion {
-            view_name: view_name.clone(),
-            detail: e,
-        }
-        .to_string()
-    })?;
+    let RequestedView {
+        view_name,
+        def,
+        dimensions,
+        metrics,
+        facts,
+    } = lookup_request(
+        borrowed,
+        name_ptr,
+        name_len,
+        dims_ptr,
+        dims_len,
+        metrics_ptr,
+        metrics_len,
+        facts_ptr,
+        facts_len,
+    )?;
+    let req = query_request(&dimensions, &metrics, &facts);
+    let expanded_sql =
+        expand(&view_name, &def, &req).map_err(|e| QueryError::from(e).to_string())?;
 
     let mat_name = {
         // Resolve the requested names to their stored items with the SAME
@@ -219,105 +180,457 @@ unsafe fn explain_semantic_view_bind_body(
         find_routing_materialization_name(&def, &dim_refs, &met_refs).map(String::from)
     };
 
-    let req = QueryRequest {
-        dimensions: dimensions
-            .iter()
-            .map(|s| crate::expand::DimensionName::new(s.clone()))
-            .collect(),
-        metrics: metrics
-            .iter()
-            .map(|s| crate::expand::MetricName::new(s.clone()))
-            .collect(),
-        facts: facts
-            .iter()
-            .map(|s| crate::expand::FactName::new(s.clone()))
-            .collect(),
-    };
-    let expanded_sql =
-        expand(&view_name, &def, &req).map_err(|e| QueryError::from(e).to_string())?;
-
-    // Build the three-part output, identical to the legacy VTab so
-    // phase28_e2e / phase46_* / phase57_introspection / phase64
-    // assertions stay byte-identical.
-    let mut lines: Vec<String> = Vec::new();
-    lines.push(format!("-- Semantic View: {view_name}"));
-    lines.push(format!("-- Dimensions: {}", dimensions.join(", ")));
-    lines.push(format!("-- Metrics: {}", metrics.join(", ")));
+    // Build the three-part output. The combined layout is identical to the
+    // legacy VTab so phase28_e2e / phase46_* / phase57_introspection /
+    // phase64 assertions stay byte-identical.
+    let mut metadata: Vec<String> = Vec::new();
+    metadata.push(format!("-- Semantic View: {view_name}"));
+    metadata.push(format!("-- Dimensions: {}", dimensions.join(", ")));
+    metadata.push(format!("-- Metrics: {}", metrics.join(", ")));
     if !facts.is_empty() {
-        lines.push(format!("-- Facts: {}", facts.join(", ")));
+        metadata.push(format!("-- Facts: {}", facts.join(", ")));
     }
     match mat_name {
-        Some(ref n) => lines.push(format!("-- Materialization: {n}")),
-        None => lines.push("-- Materialization: none".to_string()),
+        Some(ref n) => metadata.push(format!("-- Materialization: {n}")),
+        None => metadata.push("-- Materialization: none".to_string()),
     }
-    lines.push(String::new());
-    lines.push("-- Expanded SQL:".to_string());
-    for sql_line in expanded_sql.lines() {
-        lines.push(sql_line.to_string());
+    // Provenance: ties the expanded SQL below to the exact stored definition.
+    if let Some(ref created_on) = def.created_on {
+        metadata.push(format!("-- Created: {created_on}"));
     }
-    lines.push(String::new());
-    lines.push("-- DuckDB Plan:".to_string());
-    let explain_lines = collect_explain_lines(borrowed, &expanded_sql);
-    lines.extend(explain_lines);
+    metadata.push(format!("-- Checksum: {}", def.checksum()));
+    let plan = collect_explain_lines(borrowed, &expanded_sql, options & EXPLAIN_ANALYZE != 0);
 
-    // Serialise as 1-column VARCHAR rows.
-    let rows: Vec<Vec<String>> = lines.into_iter().map(|l| vec![l]).collect();
+    let rows = explain_rows(
+        metadata,
+        &expanded_sql,
+        plan,
+        options & EXPLAIN_STRUCTURED != 0,
+    );
     serialize_varchar_rows(&rows)
 }
 
+// ---------------------------------------------------------------------------
+// semantic_view_sql — expanded SQL text only
+// ---------------------------------------------------------------------------
+//
+// `semantic_view_sql(view_name, dimensions := [...], metrics := [...],
+// facts := [...])` returns the `-- Expanded SQL:` section of
+// `explain_semantic_view()` as a single VARCHAR cell, with no header and no
+// EXPLAIN plan, so tooling can post-process it or wrap it in its own
+// `CREATE VIEW`. Same bind wiring, wire formats, and return codes as the
+// explain dispatcher above (the C++ side reuses `sv_serialise_string_list`
+// and `sv_emit_varchar_rows`).
+
+/// # Safety
+///
+/// Same contract as [`sv_explain_semantic_view_bind_rust`].
+#[cfg(feature = "extension")]
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn sv_semantic_view_sql_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    dims_ptr: *const u8,
+    dims_len: usize,
+    metrics_ptr: *const u8,
+    metrics_len: usize,
+    facts_ptr: *const u8,
+    facts_len: usize,
+    _options: u32,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    crate::ddl::read_ffi::run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_semantic_view_sql_bind_rust",
+        |borrowed| unsafe {
+            let view = lookup_request(
+                borrowed,
+                name_ptr,
+                name_len,
+                dims_ptr,
+                dims_len,
+                metrics_ptr,
+                metrics_len,
+                facts_ptr,
+                facts_len,
+            )?;
+            let expanded_sql = view.expand()?;
+            crate::ddl::read_ffi::serialize_varchar_rows(&[vec![expanded_sql]])
+        },
+    )
+}
+
+// ---------------------------------------------------------------------------
+// minimal_repro_json — trimmed definition for one query
+// ---------------------------------------------------------------------------
+//
+// `minimal_repro_json(view_name, dimensions := [...], metrics := [...],
+// facts := [...])` returns one VARCHAR row: the view's definition JSON trimmed
+// by `expand::minimal_definition` to the items, tables, and relationships the
+// request touches. Bug reports can attach it instead of the whole view. Same
+// bind wiring, wire formats, and return codes as the explain dispatcher.
+
+/// # Safety
+///
+/// Same contract as [`sv_explain_semantic_view_bind_rust`].
+#[cfg(feature = "extension")]
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn sv_minimal_repro_json_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    dims_ptr: *const u8,
+    dims_len: usize,
+    metrics_ptr: *const u8,
+    metrics_len: usize,
+    facts_ptr: *const u8,
+    facts_len: usize,
+    _options: u32,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    crate::ddl::read_ffi::run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_minimal_repro_json_bind_rust",
+        |borrowed| unsafe {
+            let view = lookup_request(
+                borrowed,
+                name_ptr,
+                name_len,
+                dims_ptr,
+                dims_len,
+                metrics_ptr,
+                metrics_len,
+                facts_ptr,
+                facts_len,
+            )?;
+            let trimmed = crate::expand::minimal_definition(
+                &view.view_name,
+                &view.def,
+                &view.query_request(),
+            )
+            .map_err(|e| QueryError::from(e).to_string())?;
+            let json = serde_json::to_string(&trimmed)
+                .map_err(|e| format!("failed to serialize definition: {e}"))?;
+            crate::ddl::read_ffi::serialize_varchar_rows(&[vec![json]])
+        },
+    )
+}
+
+// ---------------------------------------------------------------------------
+// create_view_from_semantic — CREATE VIEW statement for a query
+// ---------------------------------------------------------------------------
+//
+// `create_view_from_semantic(view_name, target_view_name, dimensions := [...],
+// metrics := [...], facts := [...])` returns one VARCHAR row holding
+// `CREATE VIEW "<target>" AS <expanded SQL>`, for persisting a query as a
+// native DuckDB view. It only builds the statement: executing it from inside
+// the bind would run DDL on a borrowed connection while DuckDB holds the
+// invoking query's locks, so the caller runs the returned text itself. The
+// dispatcher takes the target name as an extra argument after the view name;
+// the rest of the wire format is the explain dispatcher's, minus `options`.
+
+/// # Safety
+///
+/// Same contract as [`sv_explain_semantic_view_bind_rust`]; `target_ptr`
+/// points to `target_len` readable bytes.
+#[cfg(feature = "extension")]
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn sv_create_view_from_semantic_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    target_ptr: *const u8,
+    target_len: usize,
+    dims_ptr: *const u8,
+    dims_len: usize,
+    metrics_ptr: *const u8,
+    metrics_len: usize,
+    facts_ptr: *const u8,
+    facts_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    crate::ddl::read_ffi::run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_create_view_from_semantic_bind_rust",
+        |borrowed| unsafe {
+            let target =
+                crate::ddl::read_ffi::read_str_arg(target_ptr, target_len, "target view name")?;
+            if target.trim().is_empty() {
+                return Err("target view name must not be empty".to_string());
+            }
+            let view = lookup_request(
+                borrowed,
+                name_ptr,
+                name_len,
+                dims_ptr,
+                dims_len,
+                metrics_ptr,
+                metrics_len,
+                facts_ptr,
+                facts_len,
+            )?;
+            let expanded_sql = view.expand()?;
+            crate::ddl::read_ffi::serialize_varchar_rows(&[vec![
+                super::wire::create_view_statement(&target, &expanded_sql),
+            ]])
+        },
+    )
+}
+
+// ---------------------------------------------------------------------------
+// estimate_semantic_query — optimizer row estimate without running the query
+// ---------------------------------------------------------------------------
+//
+// `estimate_semantic_query(view_name, dimensions := [...], metrics := [...],
+// facts := [...])` expands the request, plans it with
+// `EXPLAIN (FORMAT JSON)`, and reports the root operator's estimated
+// cardinality. Same bind wiring and return codes as the explain dispatcher;
+// the payload is one row of two VARCHAR cells, `(estimate, message)`, which
+// the C++ side declares as `(BIGINT, VARCHAR)` with an empty cell read as
+// NULL. When the plan cannot be produced (a referenced table is missing) the
+// estimate is empty and the message says why, rather than failing the bind.
+
+/// # Safety
+///
+/// Same contract as [`sv_explain_semantic_view_bind_rust`].
+#[cfg(feature = "extension")]
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn sv_estimate_semantic_query_bind_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    dims_ptr: *const u8,
+    dims_len: usize,
+    metrics_ptr: *const u8,
+    metrics_len: usize,
+    facts_ptr: *const u8,
+    facts_len: usize,
+    _options: u32,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    crate::ddl::read_ffi::run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_estimate_semantic_query_bind_rust",
+        |borrowed| unsafe {
+            let view = lookup_request(
+                borrowed,
+                name_ptr,
+                name_len,
+                dims_ptr,
+                dims_len,
+                metrics_ptr,
+                metrics_len,
+                facts_ptr,
+                facts_len,
+            )?;
+            let expanded_sql = view.expand()?;
+            let explain_sql = format!("EXPLAIN (FORMAT JSON) {expanded_sql}");
+            let row = match collect_text_cells(borrowed, &explain_sql) {
+                Ok(cells) => match cells
+                    .iter()
+                    .find_map(|c| super::wire::estimated_cardinality(c))
+                {
+                    Some(estimate) => vec![estimate.to_string(), String::new()],
+                    None => vec![
+                        String::new(),
+                        "not available -- the plan reports no estimated cardinality".to_string(),
+                    ],
+                },
+                Err(err) => vec![String::new(), format!("not available -- {err}")],
+            };
+            crate::ddl::read_ffi::serialize_varchar_rows(&[row])
+        },
+    )
+}
+
+// ---------------------------------------------------------------------------
+// validate_semantic_query — dry-run pass/fail for one query
+// ---------------------------------------------------------------------------
+//
+// `validate_semantic_query(view, dimensions, metrics)` is a scalar: the C++
+// exec callback serialises each row's two LIST(VARCHAR) arguments with
+// `sv_serialise_string_list` and calls this dispatcher on a per-chunk
+// borrowed connection. The request goes through the same lookup and expand
+// as `explain_semantic_view()`, then the expanded SQL is planned with
+// `EXPLAIN` (catching unknown columns and type errors) but never run. The
+// payload is the bytes of `OK` or of the first failure's message, so a failing
+// query is a value (rc 0), not an error; rc 2 stays for internal failures.
+
+/// # Safety
+///
+/// Same contract as [`sv_explain_semantic_view_bind_rust`], without the facts
+/// list.
+#[cfg(feature = "extension")]
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn sv_validate_semantic_query_exec_rust(
+    conn: libduckdb_sys::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    dims_ptr: *const u8,
+    dims_len: usize,
+    metrics_ptr: *const u8,
+    metrics_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    crate::ddl::read_ffi::run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_validate_semantic_query_exec_rust",
+        |borrowed| unsafe {
+            let verdict = validate_query(
+                borrowed,
+                name_ptr,
+                name_len,
+                dims_ptr,
+                dims_len,
+                metrics_ptr,
+                metrics_len,
+            )
+            .map_or_else(|message| message, |()| "OK".to_string());
+            Ok(verdict.into_bytes())
+        },
+    )
+}
+
+/// Look up, expand, and plan one request; `Err` carries the message of the
+/// first step that failed, `QueryError` wording unchanged.
+///
+/// # Safety
+///
+/// Same contract as [`lookup_request`].
+#[cfg(feature = "extension")]
+unsafe fn validate_query(
+    borrowed: &crate::ddl::read_ffi::BorrowedConnection,
+    name_ptr: *const u8,
+    name_len: usize,
+    dims_ptr: *const u8,
+    dims_len: usize,
+    metrics_ptr: *const u8,
+    metrics_len: usize,
+) -> Result<(), String> {
+    let view = lookup_request(
+        borrowed,
+        name_ptr,
+        name_len,
+        dims_ptr,
+        dims_len,
+        metrics_ptr,
+        metrics_len,
+        std::ptr::null(),
+        0,
+    )?;
+    let expanded_sql = view.expand()?;
+    let explain_sql = super::wire::explain_statement(&expanded_sql, false);
+    let mut result = execute_sql_raw(borrowed.as_raw(), &explain_sql)?;
+    ffi::duckdb_destroy_result(&raw mut result);
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // EXPLAIN plan extraction
 // ---------------------------------------------------------------------------
 
-/// Execute `EXPLAIN {sql}` and return the plan as lines of text.
+/// Execute `EXPLAIN {sql}` (`EXPLAIN ANALYZE {sql}` with `analyze`) and
+/// return the plan as lines of text.
 ///
-/// If the EXPLAIN fails (e.g., referenced tables do not exist), returns
-/// a single fallback line with the error message.
+/// If the EXPLAIN fails (e.g., referenced tables do not exist, or under
+/// `analyze` the query itself errors at run time), returns a single fallback
+/// line with the error message.
 ///
 /// # Safety
 ///
 /// The underlying `duckdb_connection` accessed via `borrowed.as_raw()` must
 /// be valid for the lifetime of the borrow.
-#[allow(clippy::cast_possible_truncation)]
 unsafe fn collect_explain_lines(
     borrowed: &crate::ddl::read_ffi::BorrowedConnection,
     sql: &str,
+    analyze: bool,
 ) -> Vec<String> {
-    let explain_sql = format!("EXPLAIN {sql}");
-    let mut lines = Vec::new();
+    let explain_sql = super::wire::explain_statement(sql, analyze);
+    match collect_text_cells(borrowed, &explain_sql) {
+        Ok(cells) => cells
+            .iter()
+            .flat_map(|cell| cell.lines().map(str::to_string))
+            .collect(),
+        Err(err) => vec![format!("-- (not available -- {err})")],
+    }
+}
 
-    match execute_sql_raw(borrowed.as_raw(), &explain_sql) {
-        Ok(mut result) => {
-            let col_count = ffi::duckdb_column_count(&raw mut result) as usize;
-            let chunk_count = ffi::duckdb_result_chunk_count(result) as usize;
+/// Execute `sql` and return every non-empty cell of its result, row by row,
+/// read as VARCHAR.
+///
+/// # Safety
+///
+/// Same contract as [`collect_explain_lines`].
+#[allow(clippy::cast_possible_truncation)]
+unsafe fn collect_text_cells(
+    borrowed: &crate::ddl::read_ffi::BorrowedConnection,
+    sql: &str,
+) -> Result<Vec<String>, String> {
+    let mut result = execute_sql_raw(borrowed.as_raw(), sql)?;
+    let mut cells = Vec::new();
+    let col_count = ffi::duckdb_column_count(&raw mut result) as usize;
+    let chunk_count = ffi::duckdb_result_chunk_count(result) as usize;
 
-            for chunk_idx in 0..chunk_count {
-                let chunk = ffi::duckdb_result_get_chunk(result, chunk_idx as ffi::idx_t);
-                if chunk.is_null() {
-                    continue;
-                }
-                let row_count = ffi::duckdb_data_chunk_get_size(chunk) as usize;
+    for chunk_idx in 0..chunk_count {
+        let chunk = ffi::duckdb_result_get_chunk(result, chunk_idx as ffi::idx_t);
+        if chunk.is_null() {
+            continue;
+        }
+        let row_count = ffi::duckdb_data_chunk_get_size(chunk) as usize;
 
-                for row_idx in 0..row_count {
-                    for col_idx in 0..col_count {
-                        let s = read_varchar_from_vector(chunk, col_idx, row_idx);
-                        if !s.is_empty() {
-                            for plan_line in s.lines() {
-                                lines.push(plan_line.to_string());
-                            }
-                        }
-                    }
+        for row_idx in 0..row_count {
+            for col_idx in 0..col_count {
+                let s = read_varchar_from_vector(chunk, col_idx, row_idx);
+                if !s.is_empty() {
+                    cells.push(s);
                 }
-
-                ffi::duckdb_destroy_data_chunk(&mut { chunk });
             }
-
-            ffi::duckdb_destroy_result(&raw mut result);
-        }
-        Err(err) => {
-            lines.push(format!("-- (not available -- {err})"));
         }
+
+        ffi::duckdb_destroy_data_chunk(&mut { chunk });
     }
 
-    lines
+    ffi::duckdb_destroy_result(&raw mut result);
+    Ok(cells)
 }
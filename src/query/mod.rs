@@ -3,9 +3,15 @@ pub mod error;
 #[cfg(feature = "extension")]
 pub mod explain;
 #[cfg(feature = "extension")]
+pub(crate) mod request;
+#[cfg(feature = "extension")]
 pub mod table_function;
 
 // Pure wire-format / SQL-shape helpers, always compiled so they are covered by
 // the default `cargo test` / clippy / coverage runs even though the FFI
 // entrypoints that call them are `extension`-gated (TC-8).
 pub mod wire;
+
+// Process-wide cache of expanded SQL, keyed by the stored definition JSON so
+// it never serves a stale expansion. Pure, so it stays always-compiled too.
+pub mod expansion_cache;
@@ -0,0 +1,232 @@
+use crate::catalog::CatalogReader;
+use crate::expand::wildcard::{expand_wildcards, WildcardItemType};
+use crate::expand::{expand, DimensionName, FactName, MetricName, QueryRequest};
+use crate::model::SemanticViewDefinition;
+use crate::util::suggest_closest;
+
+use super::error::QueryError;
+use super::wire::parse_varchar_list;
+
+// ---------------------------------------------------------------------------
+// Shared `(view_name, dimensions, metrics, facts)` request resolution
+// ---------------------------------------------------------------------------
+//
+// The inspection table functions (`explain_semantic_view()`,
+// `semantic_view_sql()`, `minimal_repro_json()`) all take the same positional
+// view name and named LIST(VARCHAR) parameters as `semantic_view()`. Their
+// dispatchers decode the FFI arguments, look the view up, and expand
+// wildcards through `lookup_request` so name normalisation, catalog misses,
+// and wildcard failures render identically across all of them.
+
+/// A semantic view request resolved against the catalog: the normalised view
+/// name, its definition, and the wildcard-expanded requested names.
+pub(crate) struct RequestedView {
+    pub(crate) view_name: String,
+    pub(crate) def: SemanticViewDefinition,
+    pub(crate) dimensions: Vec<String>,
+    pub(crate) metrics: Vec<String>,
+    pub(crate) facts: Vec<String>,
+}
+
+impl RequestedView {
+    /// The [`QueryRequest`] for the requested names.
+    pub(crate) fn query_request(&self) -> QueryRequest {
+        query_request(&self.dimensions, &self.metrics, &self.facts)
+    }
+
+    /// Run `expand()` for the request, rendering failures through
+    /// [`QueryError::ExpandFailed`].
+    pub(crate) fn expand(&self) -> Result<String, String> {
+        expand(&self.view_name, &self.def, &self.query_request())
+            .map_err(|e| QueryError::from(e).to_string())
+    }
+}
+
+/// Build a [`QueryRequest`] from already wildcard-expanded name lists.
+pub(crate) fn query_request(
+    dimensions: &[String],
+    metrics: &[String],
+    facts: &[String],
+) -> QueryRequest {
+    QueryRequest {
+        dimensions: dimensions.iter().map(DimensionName::new).collect(),
+        metrics: metrics.iter().map(MetricName::new).collect(),
+        facts: facts.iter().map(FactName::new).collect(),
+        ..Default::default()
+    }
+}
+
+/// Decode the `(view_name, dimensions, metrics, facts)` FFI arguments, look
+/// the view up in the catalog, and expand wildcards.
+///
+/// Every failure renders through [`QueryError`] (or the catalog reader's own
+/// message), so callers surface the same wording as `semantic_view()`.
+///
+/// # Safety
+///
+/// Each `*_ptr` is either null or points to its paired `*_len` readable bytes.
+/// A null pointer is only meaningful with `*_len == 0` (an empty list / absent
+/// name); the decoders reject `(null, len > 0)` as FFI shape drift rather than
+/// dereferencing it. The borrowed connection must outlive the call (see the
+/// module borrow contract in `explain.rs`).
+#[allow(clippy::too_many_arguments)]
+pub(crate) unsafe fn lookup_request(
+    borrowed: &crate::ddl::read_ffi::BorrowedConnection,
+    name_ptr: *const u8,
+    name_len: usize,
+    dims_ptr: *const u8,
+    dims_len: usize,
+    metrics_ptr: *const u8,
+    metrics_len: usize,
+    facts_ptr: *const u8,
+    facts_len: usize,
+) -> Result<RequestedView, String> {
+    let view_name = read_view_name(name_ptr, name_len)?;
+
+    let dimensions = parse_varchar_list(dims_ptr, dims_len)
+        .map_err(|detail| format!("malformed `dimensions` payload: {detail}"))?;
+    let metrics = parse_varchar_list(metrics_ptr, metrics_len)
+        .map_err(|detail| format!("malformed `metrics` payload: {detail}"))?;
+    let facts = parse_varchar_list(facts_ptr, facts_len)
+        .map_err(|detail| format!("malformed `facts` payload: {detail}"))?;
+
+    let def = lookup_definition(borrowed, &view_name)?;
+
+    // An empty request selects the view's defaults, spelled out here so the
+    // inspection functions report the names actually queried.
+    let (dimensions, metrics) = if dimensions.is_empty() && metrics.is_empty() && facts.is_empty() {
+        (def.default_dimensions.clone(), def.default_metrics.clone())
+    } else {
+        (dimensions, metrics)
+    };
+    if dimensions.is_empty() && metrics.is_empty() && facts.is_empty() {
+        // Match the QueryError::EmptyRequest message rendered by the legacy
+        // VTab so phase57_introspection assertions stay byte-identical.
+        return Err(QueryError::EmptyRequest { view_name }.to_string());
+    }
+
+    // R-3 (code-review 2026-07-11): wildcard failures render through
+    // QueryError::WildcardExpansion, matching semantic_view()'s wording.
+    let wildcard_err = |e: String| {
+        QueryError::WildcardExpansion {
+            view_name: view_name.clone(),
+            detail: e,
+        }
+        .to_string()
+    };
+    let dimensions =
+        expand_wildcards(&dimensions, &def, &WildcardItemType::Dimension).map_err(wildcard_err)?;
+    let metrics =
+        expand_wildcards(&metrics, &def, &WildcardItemType::Metric).map_err(wildcard_err)?;
+    let facts = expand_wildcards(&facts, &def, &WildcardItemType::Fact).map_err(wildcard_err)?;
+
+    Ok(RequestedView {
+        view_name,
+        def,
+        dimensions,
+        metrics,
+        facts,
+    })
+}
+
+/// Decode and normalise the positional view-name argument.
+///
+/// # Safety
+///
+/// `name_ptr` is either null or points to `name_len` readable bytes.
+unsafe fn read_view_name(name_ptr: *const u8, name_len: usize) -> Result<String, String> {
+    let view_name_raw = crate::ddl::read_ffi::read_str_arg(name_ptr, name_len, "view name")?;
+    crate::ident::normalize_view_name(&view_name_raw)
+        .map_err(|e| format!("Invalid view name '{view_name_raw}': {e}"))
+}
+
+/// Fetch and parse `view_name`'s definition with its `extends` chain merged,
+/// rendering a miss as [`QueryError::ViewNotFound`] with a suggestion.
+///
+/// # Safety
+///
+/// The borrowed connection must outlive the call.
+unsafe fn lookup_definition(
+    borrowed: &crate::ddl::read_ffi::BorrowedConnection,
+    view_name: &str,
+) -> Result<SemanticViewDefinition, String> {
+    // FF-9: surface a probe-query failure as an error distinct from "no
+    // views" instead of silently folding it into absence.
+    let present = crate::ddl::read_ffi::probe_catalog_table_present(borrowed)?;
+    let reader = CatalogReader::new(borrowed, present);
+    let Some(json_str) = reader.lookup_resolved(view_name)? else {
+        let available = reader.list_names().unwrap_or_default();
+        let suggestion = suggest_closest(view_name, &available);
+        return Err(QueryError::ViewNotFound {
+            name: view_name.to_string(),
+            suggestion,
+            available,
+        }
+        .to_string());
+    };
+    SemanticViewDefinition::from_json(view_name, &json_str)
+}
+
+/// Decode the `(view_name, request_json)` arguments of
+/// `semantic_query_json()`, look the view up, and expand the wildcards in the
+/// request's names. Returns the normalised view name, its definition, and
+/// the request, ready for `expand()`.
+///
+/// The JSON is parsed before the catalog is read, so a malformed request is
+/// reported as [`QueryError::InvalidRequestJson`] whether or not the view
+/// exists. An empty request is left for `expand()`, which selects the
+/// view's defaults or raises `EmptyRequest`.
+///
+/// # Safety
+///
+/// `name_ptr` and `json_ptr` are each either null or point to their paired
+/// `*_len` readable bytes. The borrowed connection must outlive the call.
+pub(crate) unsafe fn lookup_json_request(
+    borrowed: &crate::ddl::read_ffi::BorrowedConnection,
+    name_ptr: *const u8,
+    name_len: usize,
+    json_ptr: *const u8,
+    json_len: usize,
+) -> Result<(String, SemanticViewDefinition, QueryRequest), String> {
+    let view_name = read_view_name(name_ptr, name_len)?;
+    let request_json = crate::ddl::read_ffi::read_str_arg(json_ptr, json_len, "request JSON")?;
+    let mut req: QueryRequest = serde_json::from_str(&request_json).map_err(|e| {
+        QueryError::InvalidRequestJson {
+            view_name: view_name.clone(),
+            detail: e.to_string(),
+        }
+        .to_string()
+    })?;
+
+    let def = lookup_definition(borrowed, &view_name)?;
+
+    let wildcard_err = |e: String| {
+        QueryError::WildcardExpansion {
+            view_name: view_name.clone(),
+            detail: e,
+        }
+        .to_string()
+    };
+    req.dimensions =
+        expand_names(&req.dimensions, &def, &WildcardItemType::Dimension).map_err(wildcard_err)?;
+    req.metrics =
+        expand_names(&req.metrics, &def, &WildcardItemType::Metric).map_err(wildcard_err)?;
+    req.facts = expand_names(&req.facts, &def, &WildcardItemType::Fact).map_err(wildcard_err)?;
+    Ok((view_name, def, req))
+}
+
+/// [`expand_wildcards`] over typed request names.
+fn expand_names<N>(
+    names: &[N],
+    def: &SemanticViewDefinition,
+    kind: &WildcardItemType,
+) -> Result<Vec<N>, String>
+where
+    N: std::ops::Deref<Target = str> + From<String>,
+{
+    let raw: Vec<String> = names.iter().map(|n| String::from(&**n)).collect();
+    Ok(expand_wildcards(&raw, def, kind)?
+        .into_iter()
+        .map(N::from)
+        .collect())
+}
@@ -9,6 +9,7 @@ use crate::model::SemanticViewDefinition;
 use crate::util::suggest_closest;
 
 use super::error::QueryError;
+use super::expansion_cache::{ExpansionCache, ExpansionKey};
 use super::wire::{build_execution_sql, parse_varchar_list, serialize_register_payload};
 
 // ---------------------------------------------------------------------------
@@ -136,15 +137,15 @@ unsafe fn semantic_view_bind_body(
     let facts = parse_varchar_list(facts_ptr, facts_len)
         .map_err(|detail| format!("malformed `facts` payload: {detail}"))?;
 
-    if dimensions.is_empty() && metrics.is_empty() && facts.is_empty() {
-        return Err(QueryError::EmptyRequest { view_name }.to_string());
-    }
+    // An empty request is not rejected here: the view may declare default
+    // dimensions / metrics, which `expand()` selects. Without them it raises
+    // `EmptyRequest`, which renders exactly like `QueryError::EmptyRequest`.
 
     // FF-9: surface a probe-query failure as an error distinct from "no
     // views" instead of silently folding it into absence.
     let present = probe_catalog_table_present(borrowed)?;
     let reader = CatalogReader::new(borrowed, present);
-    let json_str = match reader.lookup(&view_name) {
+    let json_str = match reader.lookup_resolved(&view_name) {
         Ok(Some(j)) => j,
         Ok(None) => {
             let available = reader.list_names().unwrap_or_default();
@@ -159,26 +160,136 @@ unsafe fn semantic_view_bind_body(
         Err(e) => return Err(e),
     };
 
-    let def = SemanticViewDefinition::from_json(&view_name, &json_str)?;
+    let cache = ExpansionCache::global();
+    let cache_key = ExpansionKey {
+        view_name: view_name.clone(),
+        definition_json: json_str,
+        dimensions,
+        metrics,
+        facts,
+    };
+    let expanded_sql = if let Some(sql) = cache.get(&cache_key) {
+        sql
+    } else {
+        let sql = expand_request(&cache_key)?;
+        cache.insert(cache_key, sql.clone());
+        sql
+    };
+
+    register_payload(borrowed, &expanded_sql)
+}
+
+/// Infer the output schema of `expanded_sql` and serialize the register
+/// payload (see the wire format above) that the `semantic_view()` family of
+/// C++ binds declares its columns from.
+///
+/// # Safety
+///
+/// The borrowed connection must outlive the call.
+#[cfg(feature = "extension")]
+unsafe fn register_payload(
+    borrowed: &crate::ddl::read_ffi::BorrowedConnection,
+    expanded_sql: &str,
+) -> Result<Vec<u8>, String> {
+    // Type inference: a LIMIT-0 probe on the per-call connection yields
+    // the output column names + types. The probe runs on `conn`, not a
+    // long-lived handle (H2). AR-4 (PR-2) removed the DDL-time
+    // persisted-types fast path (`column_type_names` / `column_types_inferred`
+    // were dead for post-v0.10 rows) — every row now infers at read time,
+    // matching Plan 03 D-16, so this is a single unconditional probe.
+    let (column_names, column_type_ids): (Vec<String>, Vec<u32>) = {
+        let limit0_sql = format!("{expanded_sql} LIMIT 0");
+        // Phase 65.1 Plan 11 / WR-08 / D-15: surface probe failures via
+        // the error message. No silent vec![0u32; names.len()] fallback to
+        // DUCKDB_TYPE_INVALID — that masked broken FACTS expressions behind a
+        // VARCHAR placeholder at query time.
+        let (names, types) = try_infer_schema(borrowed, &limit0_sql).map_err(|msg| {
+            format!(
+                "semantic_view: type inference failed for query \
+                 `{limit0_sql}`: {msg}"
+            )
+        })?;
+        let type_ids: Vec<u32> = types.iter().map(|t| normalize_type_id(*t)).collect();
+        (names, type_ids)
+    };
+
+    // Build execution SQL with casts where needed (HUGEINT→BIGINT etc).
+    let execution_sql = build_execution_sql(expanded_sql, &column_names, &column_type_ids);
+
+    // Serialise schema + execution_sql into a flat binary buffer.
+    serialize_register_payload(&column_names, &column_type_ids, &execution_sql)
+}
+
+/// Dispatcher for `semantic_query_json(view_name, request_json)`: the whole
+/// [`QueryRequest`] — ordering of names, filters, grouping mode, aliases —
+/// arrives as one JSON object instead of named parameters. Returns the same
+/// register payload as [`sv_semantic_view_bind_rust`], so the C++ side shares
+/// its init and exec callbacks.
+///
+/// Unlike `semantic_view()` these binds skip the [`ExpansionCache`], whose
+/// key covers only the requested names.
+///
+/// # Safety
+///
+/// `conn` is a borrowed handle (do NOT disconnect). `name_ptr` and `json_ptr`
+/// must point to `name_len` / `json_len` UTF-8 bytes.
+#[cfg(feature = "extension")]
+#[no_mangle]
+pub unsafe extern "C" fn sv_semantic_query_json_bind_rust(
+    conn: ffi::duckdb_connection,
+    name_ptr: *const u8,
+    name_len: usize,
+    json_ptr: *const u8,
+    json_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    error_buf: *mut u8,
+    error_buf_len: usize,
+) -> u8 {
+    crate::ddl::read_ffi::run_dispatcher(
+        conn,
+        out_ptr,
+        out_len,
+        error_buf,
+        error_buf_len,
+        "sv_semantic_query_json_bind_rust",
+        |borrowed| unsafe {
+            let (view_name, def, req) = super::request::lookup_json_request(
+                borrowed, name_ptr, name_len, json_ptr, json_len,
+            )?;
+            let expanded_sql =
+                expand(&view_name, &def, &req).map_err(|e| QueryError::from(e).to_string())?;
+            register_payload(borrowed, &expanded_sql)
+        },
+    )
+}
+
+/// Parse the cached-key definition, expand wildcards, and run `expand()`:
+/// everything in the bind that depends only on the stored JSON and the raw
+/// request, i.e. the work an [`ExpansionCache`] hit skips.
+#[cfg(feature = "extension")]
+fn expand_request(key: &ExpansionKey) -> Result<String, String> {
+    let view_name = key.view_name.as_str();
+    let def = SemanticViewDefinition::from_json(view_name, &key.definition_json)?;
 
-    let dimensions =
-        expand_wildcards(&dimensions, &def, &WildcardItemType::Dimension).map_err(|e| {
+    let dimensions = expand_wildcards(&key.dimensions, &def, &WildcardItemType::Dimension)
+        .map_err(|e| {
             QueryError::WildcardExpansion {
-                view_name: view_name.clone(),
+                view_name: view_name.to_string(),
                 detail: e,
             }
             .to_string()
         })?;
-    let metrics = expand_wildcards(&metrics, &def, &WildcardItemType::Metric).map_err(|e| {
+    let metrics = expand_wildcards(&key.metrics, &def, &WildcardItemType::Metric).map_err(|e| {
         QueryError::WildcardExpansion {
-            view_name: view_name.clone(),
+            view_name: view_name.to_string(),
             detail: e,
         }
         .to_string()
     })?;
-    let facts = expand_wildcards(&facts, &def, &WildcardItemType::Fact).map_err(|e| {
+    let facts = expand_wildcards(&key.facts, &def, &WildcardItemType::Fact).map_err(|e| {
         QueryError::WildcardExpansion {
-            view_name: view_name.clone(),
+            view_name: view_name.to_string(),
             detail: e,
         }
         .to_string()
@@ -197,37 +308,9 @@ unsafe fn semantic_view_bind_body(
             .iter()
             .map(|s| crate::expand::FactName::new(s.clone()))
             .collect(),
+        ..Default::default()
     };
-    let expanded_sql =
-        expand(&view_name, &def, &req).map_err(|e| QueryError::from(e).to_string())?;
-
-    // Type inference: a LIMIT-0 probe on the per-call connection yields
-    // the output column names + types. The probe runs on `conn`, not a
-    // long-lived handle (H2). AR-4 (PR-2) removed the DDL-time
-    // persisted-types fast path (`column_type_names` / `column_types_inferred`
-    // were dead for post-v0.10 rows) — every row now infers at read time,
-    // matching Plan 03 D-16, so this is a single unconditional probe.
-    let (column_names, column_type_ids): (Vec<String>, Vec<u32>) = {
-        let limit0_sql = format!("{expanded_sql} LIMIT 0");
-        // Phase 65.1 Plan 11 / WR-08 / D-15: surface probe failures via
-        // the error message. No silent vec![0u32; names.len()] fallback to
-        // DUCKDB_TYPE_INVALID — that masked broken FACTS expressions behind a
-        // VARCHAR placeholder at query time.
-        let (names, types) = try_infer_schema(borrowed, &limit0_sql).map_err(|msg| {
-            format!(
-                "semantic_view: type inference failed for query \
-                 `{limit0_sql}`: {msg}"
-            )
-        })?;
-        let type_ids: Vec<u32> = types.iter().map(|t| normalize_type_id(*t)).collect();
-        (names, type_ids)
-    };
-
-    // Build execution SQL with casts where needed (HUGEINT→BIGINT etc).
-    let execution_sql = build_execution_sql(&expanded_sql, &column_names, &column_type_ids);
-
-    // Serialise schema + execution_sql into a flat binary buffer.
-    serialize_register_payload(&column_names, &column_type_ids, &execution_sql)
+    expand(view_name, &def, &req).map_err(|e| QueryError::from(e).to_string())
 }
 
 // ---------------------------------------------------------------------------
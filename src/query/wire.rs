@@ -220,6 +220,14 @@ pub fn build_execution_sql(
     )
 }
 
+/// The statement `create_view_from_semantic()` returns: the expanded SQL
+/// wrapped as `CREATE VIEW "<target>" AS <sql>`. The target is a single
+/// identifier, quoted with [`quote_ident`], so a dot is part of the name.
+#[must_use]
+pub fn create_view_statement(target: &str, sql: &str) -> String {
+    format!("CREATE VIEW {} AS {sql}", quote_ident(target))
+}
+
 /// Serialize the inferred schema + execution SQL into the flat register wire
 /// format consumed by the C++ `semantic_view` bind:
 ///
@@ -272,6 +280,85 @@ pub fn serialize_register_payload(
     Ok(buf)
 }
 
+/// `options` bit the C++ `explain_semantic_view` bind sets for
+/// `structured := true`.
+pub const EXPLAIN_STRUCTURED: u32 = 1;
+
+/// `options` bit the C++ `explain_semantic_view` bind sets for
+/// `analyze := true`.
+pub const EXPLAIN_ANALYZE: u32 = 1 << 1;
+
+/// The statement `explain_semantic_view()` runs to capture the plan section:
+/// `EXPLAIN ANALYZE` executes the query to report real timings and row
+/// counts, plain `EXPLAIN` only plans it.
+#[must_use]
+pub fn explain_statement(sql: &str, analyze: bool) -> String {
+    if analyze {
+        format!("EXPLAIN ANALYZE {sql}")
+    } else {
+        format!("EXPLAIN {sql}")
+    }
+}
+
+/// The optimizer's row estimate for a query, read from its
+/// `EXPLAIN (FORMAT JSON)` plan: the root operator's `Estimated Cardinality`,
+/// or the first one found following first children down when the root does
+/// not report one. `None` when the text is not such a plan.
+///
+/// `DuckDB` writes the value as a string (`"12"`); a bare number is accepted
+/// too.
+#[must_use]
+pub fn estimated_cardinality(plan_json: &str) -> Option<u64> {
+    let plan: serde_json::Value = serde_json::from_str(plan_json).ok()?;
+    let mut node = plan.as_array()?.first()?;
+    loop {
+        let estimate = node
+            .get("extra_info")
+            .and_then(|info| info.get("Estimated Cardinality"));
+        if let Some(value) = estimate {
+            return match value {
+                serde_json::Value::String(s) => s.trim().parse().ok(),
+                other => other.as_u64(),
+            };
+        }
+        node = node.get("children")?.as_array()?.first()?;
+    }
+}
+
+/// Lay out the three parts of an `explain_semantic_view()` result as rows.
+///
+/// Combined (the default) is one column: the metadata lines, then the
+/// `-- Expanded SQL:` and `-- DuckDB Plan:` sections each after a blank line.
+/// Structured is two columns, `(section, line)`, with `section` one of
+/// `metadata` / `expanded_sql` / `plan` and no marker or separator rows, so
+/// `WHERE section = 'expanded_sql'` yields exactly the SQL lines.
+#[must_use]
+pub fn explain_rows(
+    metadata: Vec<String>,
+    expanded_sql: &str,
+    plan: Vec<String>,
+    structured: bool,
+) -> Vec<Vec<String>> {
+    let sql = expanded_sql.lines().map(str::to_string);
+    if structured {
+        let tag = |section: &str, line: String| vec![section.to_string(), line];
+        return metadata
+            .into_iter()
+            .map(|l| tag("metadata", l))
+            .chain(sql.map(|l| tag("expanded_sql", l)))
+            .chain(plan.into_iter().map(|l| tag("plan", l)))
+            .collect();
+    }
+    metadata
+        .into_iter()
+        .chain([String::new(), "-- Expanded SQL:".to_string()])
+        .chain(sql)
+        .chain([String::new(), "-- DuckDB Plan:".to_string()])
+        .chain(plan)
+        .map(|l| vec![l])
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -482,6 +569,20 @@ mod tests {
         );
     }
 
+    // -- create_view_statement -------------------------------------------
+
+    #[test]
+    fn create_view_statement_quotes_the_target() {
+        assert_eq!(
+            create_view_statement("daily \"rev\"", "SELECT\n    1"),
+            "CREATE VIEW \"daily \"\"rev\"\"\" AS SELECT\n    1"
+        );
+        assert_eq!(
+            create_view_statement("main.v", "SELECT 1"),
+            "CREATE VIEW \"main.v\" AS SELECT 1"
+        );
+    }
+
     // -- serialize_register_payload --------------------------------------
 
     #[test]
@@ -519,4 +620,86 @@ mod tests {
         .unwrap_err();
         assert!(err.contains("disagrees with type id count"), "got: {err}");
     }
+
+    #[test]
+    fn estimated_cardinality_reads_root_estimate() {
+        let plan = r#"[{"name": "PROJECTION", "children": [{"name": "HASH_GROUP_BY",
+            "children": [], "extra_info": {"Estimated Cardinality": "40"}}],
+            "extra_info": {"Projections": ["sum"], "Estimated Cardinality": "12"}}]"#;
+        assert_eq!(estimated_cardinality(plan), Some(12));
+    }
+
+    #[test]
+    fn estimated_cardinality_descends_when_root_has_none() {
+        let plan = r#"[{"name": "RESULT_COLLECTOR", "children": [{"name": "SEQ_SCAN",
+            "children": [], "extra_info": {"Estimated Cardinality": 7}}], "extra_info": {}}]"#;
+        assert_eq!(estimated_cardinality(plan), Some(7));
+    }
+
+    #[test]
+    fn estimated_cardinality_rejects_other_text() {
+        assert_eq!(estimated_cardinality("physical_plan"), None);
+        assert_eq!(estimated_cardinality("[]"), None);
+        assert_eq!(
+            estimated_cardinality(r#"[{"name": "X", "children": [], "extra_info": {}}]"#),
+            None
+        );
+    }
+
+    #[test]
+    fn explain_statement_prefix_follows_analyze() {
+        assert_eq!(explain_statement("SELECT 1", false), "EXPLAIN SELECT 1");
+        assert_eq!(
+            explain_statement("SELECT 1", true),
+            "EXPLAIN ANALYZE SELECT 1"
+        );
+    }
+
+    #[test]
+    fn explain_rows_combined_keeps_section_markers() {
+        let rows = explain_rows(
+            vec!["-- Semantic View: v".to_string()],
+            "SELECT\n    1",
+            vec!["PLAN".to_string()],
+            false,
+        );
+        let lines: Vec<&str> = rows.iter().map(|r| r[0].as_str()).collect();
+        assert_eq!(
+            lines,
+            [
+                "-- Semantic View: v",
+                "",
+                "-- Expanded SQL:",
+                "SELECT",
+                "    1",
+                "",
+                "-- DuckDB Plan:",
+                "PLAN",
+            ]
+        );
+        assert!(rows.iter().all(|r| r.len() == 1));
+    }
+
+    #[test]
+    fn explain_rows_structured_tags_each_line() {
+        let rows = explain_rows(
+            vec!["-- Semantic View: v".to_string()],
+            "SELECT\n    1",
+            vec!["PLAN".to_string()],
+            true,
+        );
+        let pairs: Vec<(&str, &str)> = rows
+            .iter()
+            .map(|r| (r[0].as_str(), r[1].as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("metadata", "-- Semantic View: v"),
+                ("expanded_sql", "SELECT"),
+                ("expanded_sql", "    1"),
+                ("plan", "PLAN"),
+            ]
+        );
+    }
 }
@@ -8,6 +8,7 @@
 //! This module is always compiled (not feature-gated) so that unit tests
 //! can run under `cargo test` without the `extension` feature.
 
+use crate::expr_tokens::{count_distinct_arg, distinct_call};
 use crate::model::{AccessModifier, NullsOrder, SemanticViewDefinition, SortOrder};
 
 /// SQL single-quote escaping: `'` -> `''`.
@@ -371,7 +372,24 @@ fn emit_metrics(out: &mut String, def: &SemanticViewDefinition) {
             // Reconstruct the OVER clause from parsed WindowSpec for normalized formatting
             out.push_str(&render_window_spec(ws));
         } else {
-            out.push_str(&metric.expr);
+            // No DDL keyword for `approx` or `distinct`: the rewritten call
+            // re-parses to the same aggregate.
+            if let Some(arg) = count_distinct_arg(&metric.expr).filter(|_| metric.approx) {
+                out.push_str("approx_count_distinct(");
+                out.push_str(arg);
+                out.push(')');
+            } else if let Some(call) = distinct_call(&metric.expr).filter(|_| metric.distinct) {
+                out.push_str(&call);
+            } else {
+                out.push_str(&metric.expr);
+            }
+            // No DDL keyword for a structured filter: it is rendered inline,
+            // which re-parses to the equivalent single expression.
+            if let Some(ref filter) = metric.filter {
+                out.push_str(" FILTER (WHERE ");
+                out.push_str(filter);
+                out.push(')');
+            }
         }
         emit_comment(out, metric.comment.as_deref());
         emit_synonyms(out, &metric.synonyms);
@@ -421,17 +439,31 @@ fn emit_materializations(out: &mut String, def: &SemanticViewDefinition) {
 }
 
 /// Reconstruct a `CREATE OR REPLACE SEMANTIC VIEW` DDL statement from a stored
-/// definition. Returns `Err` for legacy definitions (empty `tables` vec).
+/// definition. Returns `Err` for legacy definitions (empty `tables` vec) and
+/// for views using `base_sql` or `extends`, which have no DDL spelling.
 ///
 /// The output follows body parser clause ordering:
 /// TABLES -> RELATIONSHIPS -> FACTS -> DIMENSIONS -> METRICS
 pub fn render_create_ddl(name: &str, def: &SemanticViewDefinition) -> Result<String, String> {
+    if let Some(parent) = &def.extends {
+        return Err(format!(
+            "semantic view extends '{parent}', which CREATE SEMANTIC VIEW ... AS cannot \
+             express; use READ_YAML_FROM_SEMANTIC_VIEW to export it"
+        ));
+    }
     if def.tables.is_empty() {
         return Err(
             "Legacy definition format; please re-create using CREATE OR REPLACE SEMANTIC VIEW"
                 .to_string(),
         );
     }
+    if def.base_sql.is_some() {
+        return Err(
+            "semantic view reads from base_sql, which CREATE SEMANTIC VIEW ... AS cannot \
+             express; use READ_YAML_FROM_SEMANTIC_VIEW to export it"
+                .to_string(),
+        );
+    }
 
     let mut out = String::with_capacity(512);
 
@@ -721,6 +753,31 @@ mod tests {
         assert!(ddl.contains("PRIVATE o.revenue AS SUM(o.amount)"));
     }
 
+    #[test]
+    fn test_metric_filter_rendered_inline() {
+        let mut def = minimal_def();
+        def.metrics[0].filter = Some("o.status = 'completed'".to_string());
+        let ddl = render_create_ddl("fv", &def).unwrap();
+        assert!(ddl.contains("o.revenue AS SUM(o.amount) FILTER (WHERE o.status = 'completed')"));
+    }
+
+    #[test]
+    fn test_approx_metric_rendered_inline() {
+        let mut def = minimal_def();
+        def.metrics[0].expr = "COUNT(DISTINCT o.customer_id)".to_string();
+        def.metrics[0].approx = true;
+        let ddl = render_create_ddl("av", &def).unwrap();
+        assert!(ddl.contains("o.revenue AS approx_count_distinct(o.customer_id)"));
+    }
+
+    #[test]
+    fn test_distinct_metric_rendered_inline() {
+        let mut def = minimal_def();
+        def.metrics[0].distinct = true;
+        let ddl = render_create_ddl("dv", &def).unwrap();
+        assert!(ddl.contains("o.revenue AS SUM(DISTINCT o.amount)"), "{ddl}");
+    }
+
     #[test]
     fn test_using_relationships() {
         let mut def = minimal_def();
@@ -784,6 +841,22 @@ mod tests {
         assert!(result.unwrap_err().contains("Legacy definition format"));
     }
 
+    #[test]
+    fn test_base_sql_has_no_ddl_form() {
+        let mut def = minimal_def();
+        def.tables[0].table.clear();
+        def.base_sql = Some("SELECT * FROM orders".to_string());
+        let err = render_create_ddl("sub", &def).unwrap_err();
+        assert!(err.contains("READ_YAML_FROM_SEMANTIC_VIEW"), "{err}");
+
+        let child = SemanticViewDefinition {
+            extends: Some("sub".to_string()),
+            ..Default::default()
+        };
+        let err = render_create_ddl("child", &child).unwrap_err();
+        assert!(err.starts_with("semantic view extends 'sub'"), "{err}");
+    }
+
     #[test]
     fn test_omits_relationships_when_empty() {
         let def = minimal_def();
@@ -0,0 +1,123 @@
+//! Graphviz DOT export of a semantic view's relationship graph.
+//!
+//! One node per `TABLES` entry and one directed edge per relationship, from
+//! the foreign-key side to the referenced table, labelled with the
+//! relationship name and the `ON` condition expansion generates for it. The
+//! base table (first entry, the `FROM` of every query) is drawn bold. A view
+//! without relationships renders as its lone base node. The render logic
+//! lives here (always compiled, unit-tested under `cargo test`); the
+//! extension-only scalar wrapper lives in [`crate::ddl::view_graph`].
+
+use std::fmt::Write as _;
+
+use crate::expand::synthesize_on_clause;
+use crate::model::SemanticViewDefinition;
+
+/// Render `def` as a DOT `digraph` named after the view.
+#[must_use]
+pub fn render_dot(view_name: &str, def: &SemanticViewDefinition) -> String {
+    let mut out = format!("digraph {} {{\n", dot_id(view_name));
+    out.push_str("  node [shape=box];\n");
+    for (i, t) in def.tables.iter().enumerate() {
+        let label = if t.alias.eq_ignore_ascii_case(&t.table) {
+            t.table.clone()
+        } else {
+            format!("{} ({})", t.table, t.alias)
+        };
+        let style = if i == 0 { ", style=bold" } else { "" };
+        let _ = writeln!(
+            out,
+            "  {} [label={}{style}];",
+            dot_id(&t.alias),
+            dot_id(&label)
+        );
+    }
+    for join in &def.joins {
+        let on = synthesize_on_clause(join, &def.tables);
+        let label = match &join.name {
+            Some(name) => format!("{name}\n{on}"),
+            None => on,
+        };
+        let _ = writeln!(
+            out,
+            "  {} -> {} [label={}];",
+            dot_id(&join.from_alias),
+            dot_id(&join.table),
+            dot_id(&label)
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// A DOT double-quoted ID: `"` and `\` escaped, newlines as `\n`.
+fn dot_id(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_dot;
+    use crate::model::{Join, SemanticViewDefinition, TableRef};
+
+    fn table(alias: &str, name: &str) -> TableRef {
+        TableRef {
+            alias: alias.to_string(),
+            table: name.to_string(),
+            pk_columns: vec!["id".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn nodes_and_labelled_edges() {
+        let def = SemanticViewDefinition {
+            tables: vec![table("o", "orders"), table("c", "customers")],
+            joins: vec![Join {
+                table: "c".to_string(),
+                from_alias: "o".to_string(),
+                fk_columns: vec!["customer_id".to_string()],
+                ref_columns: vec!["id".to_string()],
+                name: Some("order_customer".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let dot = render_dot("sales", &def);
+        let expected = [
+            r#"digraph "sales" {"#,
+            r#"  "o" [label="orders (o)", style=bold];"#,
+            r#"  "c" [label="customers (c)"];"#,
+            r#"  "o" -> "c" [label="order_customer\n\"o\".\"customer_id\" = \"c\".\"id\""];"#,
+        ];
+        for line in expected {
+            assert!(dot.lines().any(|l| l == line), "missing {line}:\n{dot}");
+        }
+        assert!(dot.ends_with("}\n"), "{dot}");
+    }
+
+    #[test]
+    fn view_without_joins_is_a_single_node() {
+        let def = SemanticViewDefinition {
+            tables: vec![table("orders", "orders")],
+            ..Default::default()
+        };
+        let dot = render_dot("solo", &def);
+        assert!(
+            dot.contains("\"orders\" [label=\"orders\", style=bold];"),
+            "{dot}"
+        );
+        assert!(!dot.contains("->"), "{dot}");
+    }
+}
@@ -0,0 +1,224 @@
+//! Markdown documentation of a semantic view, for data-catalog sites.
+//!
+//! Sections, in order: the view name and comment, the base table, then
+//! tables of relationships, dimensions, metrics (with their `FILTER`
+//! condition), and facts; empty sections are omitted. Items appear in
+//! declaration order and nothing time- or connection-dependent is rendered,
+//! so regenerating the document for an unchanged view yields identical bytes.
+//! The render logic lives here (always compiled, unit-tested under
+//! `cargo test`); the extension-only scalar wrapper lives in
+//! [`crate::ddl::view_doc`].
+
+use std::fmt::Write as _;
+
+use crate::expand::synthesize_on_clause;
+use crate::model::{AccessModifier, SemanticViewDefinition};
+
+/// Render `def` as a Markdown document titled `view_name`.
+#[must_use]
+pub fn render_markdown(view_name: &str, def: &SemanticViewDefinition) -> String {
+    let mut out = format!("# {view_name}\n\n");
+    if let Some(comment) = def.comment.as_deref().filter(|c| !c.is_empty()) {
+        let _ = writeln!(out, "{comment}\n");
+    }
+    if let Some(base) = def.tables.first() {
+        let _ = writeln!(out, "**Base table:** {}\n", code(&base.table));
+    }
+
+    if !def.joins.is_empty() {
+        let rows = def.joins.iter().map(|j| {
+            vec![
+                j.name.clone().unwrap_or_default(),
+                code(&j.from_alias),
+                code(&j.table),
+                code(&synthesize_on_clause(j, &def.tables)),
+            ]
+        });
+        section(
+            &mut out,
+            "Relationships",
+            &["Name", "From", "To", "On"],
+            rows,
+        );
+    }
+    if !def.dimensions.is_empty() {
+        let rows = def.dimensions.iter().map(|d| {
+            vec![
+                d.name.clone(),
+                code(&d.expr),
+                d.comment.clone().unwrap_or_default(),
+            ]
+        });
+        section(
+            &mut out,
+            "Dimensions",
+            &["Name", "Expression", "Description"],
+            rows,
+        );
+    }
+    if !def.metrics.is_empty() {
+        let rows = def.metrics.iter().map(|m| {
+            let name = if m.access == AccessModifier::Private {
+                format!("{} (private)", m.name)
+            } else {
+                m.name.clone()
+            };
+            vec![
+                name,
+                code(&m.expr),
+                m.filter.as_deref().map(code).unwrap_or_default(),
+                m.comment.clone().unwrap_or_default(),
+            ]
+        });
+        section(
+            &mut out,
+            "Metrics",
+            &["Name", "Expression", "Filter", "Description"],
+            rows,
+        );
+    }
+    if !def.facts.is_empty() {
+        let rows = def.facts.iter().map(|f| {
+            vec![
+                f.name.clone(),
+                code(&f.expr),
+                f.comment.clone().unwrap_or_default(),
+            ]
+        });
+        section(
+            &mut out,
+            "Facts",
+            &["Name", "Expression", "Description"],
+            rows,
+        );
+    }
+    // Exactly one trailing newline, whichever section came last.
+    while out.ends_with("\n\n") {
+        out.pop();
+    }
+    out
+}
+
+/// Append a `## title` section holding a pipe table.
+fn section(
+    out: &mut String,
+    title: &str,
+    header: &[&str],
+    rows: impl Iterator<Item = Vec<String>>,
+) {
+    let _ = writeln!(out, "## {title}\n");
+    let _ = writeln!(out, "| {} |", header.join(" | "));
+    let _ = writeln!(out, "|{}", "---|".repeat(header.len()));
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|c| cell(c)).collect();
+        let _ = writeln!(out, "| {} |", cells.join(" | "));
+    }
+    out.push('\n');
+}
+
+/// A table cell: `|` escaped and line breaks as `<br>`, so multi-line
+/// comments and expressions stay inside their row.
+fn cell(s: &str) -> String {
+    s.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// An inline code span, widened to double backticks when `s` itself
+/// contains one.
+fn code(s: &str) -> String {
+    if s.contains('`') {
+        format!("`` {s} ``")
+    } else {
+        format!("`{s}`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_markdown;
+    use crate::model::{Dimension, Join, Metric, SemanticViewDefinition, TableRef};
+
+    fn def() -> SemanticViewDefinition {
+        SemanticViewDefinition {
+            comment: Some("Order revenue by customer.".to_string()),
+            tables: vec![
+                TableRef {
+                    alias: "o".to_string(),
+                    table: "orders".to_string(),
+                    pk_columns: vec!["id".to_string()],
+                    ..Default::default()
+                },
+                TableRef {
+                    alias: "c".to_string(),
+                    table: "customers".to_string(),
+                    pk_columns: vec!["id".to_string()],
+                    ..Default::default()
+                },
+            ],
+            joins: vec![Join {
+                table: "c".to_string(),
+                from_alias: "o".to_string(),
+                fk_columns: vec!["customer_id".to_string()],
+                ref_columns: vec!["id".to_string()],
+                name: Some("order_customer".to_string()),
+                ..Default::default()
+            }],
+            dimensions: vec![Dimension {
+                name: "segment".to_string(),
+                expr: "c.segment".to_string(),
+                comment: Some("Tier | from CRM".to_string()),
+                ..Default::default()
+            }],
+            metrics: vec![Metric {
+                name: "eu_revenue".to_string(),
+                expr: "sum(o.amount)".to_string(),
+                filter: Some("o.region = 'EU'".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn renders_sections_in_order() {
+        let expected = "\
+# sales
+
+Order revenue by customer.
+
+**Base table:** `orders`
+
+## Relationships
+
+| Name | From | To | On |
+|---|---|---|---|
+| order_customer | `o` | `c` | `\"o\".\"customer_id\" = \"c\".\"id\"` |
+
+## Dimensions
+
+| Name | Expression | Description |
+|---|---|---|
+| segment | `c.segment` | Tier \\| from CRM |
+
+## Metrics
+
+| Name | Expression | Filter | Description |
+|---|---|---|---|
+| eu_revenue | `sum(o.amount)` | `o.region = 'EU'` |  |
+";
+        assert_eq!(render_markdown("sales", &def()), expected);
+    }
+
+    #[test]
+    fn output_is_deterministic_and_skips_empty_sections() {
+        let mut d = def();
+        d.joins.clear();
+        d.metrics.clear();
+        let doc = render_markdown("sales", &d);
+        assert_eq!(doc, render_markdown("sales", &d));
+        assert!(!doc.contains("## Relationships"), "{doc}");
+        assert!(!doc.contains("## Metrics"), "{doc}");
+        assert!(doc.ends_with("Tier \\| from CRM |\n"), "{doc}");
+    }
+}
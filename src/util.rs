@@ -4,28 +4,113 @@
 //! Extracted from `expand.rs` to break the expand <-> graph circular dependency.
 //! Both `expand` and `graph` modules import from here.
 
-/// Suggest the closest matching name from `available` using Levenshtein distance.
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Similarity measure used to pick "did you mean" suggestions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SuggestionAlgorithm {
+    /// Edit distance of at most 3 characters. The default.
+    Levenshtein,
+    /// Jaro-Winkler similarity of at least `threshold` (0.0 to 1.0). A shared
+    /// prefix raises the score, so `reg` ranks `regional` above `leg`.
+    JaroWinkler { threshold: f64 },
+}
+
+/// Jaro-Winkler threshold used until `semantic_views_suggestion_threshold`
+/// is set.
+pub const DEFAULT_JARO_WINKLER_THRESHOLD: f64 = 0.8;
+
+/// Process-wide suggestion setting, written by the GLOBAL-only
+/// `semantic_views_suggestion_algorithm` / `semantic_views_suggestion_threshold`
+/// extension options. The threshold is kept while Levenshtein is selected so
+/// the two options can be set in either order.
+static USE_JARO_WINKLER: AtomicBool = AtomicBool::new(false);
+static JARO_WINKLER_THRESHOLD_BITS: AtomicU64 =
+    AtomicU64::new(DEFAULT_JARO_WINKLER_THRESHOLD.to_bits());
+
+/// The algorithm [`suggest_closest`] currently uses.
+#[must_use]
+pub fn suggestion_algorithm() -> SuggestionAlgorithm {
+    if USE_JARO_WINKLER.load(Ordering::Relaxed) {
+        SuggestionAlgorithm::JaroWinkler {
+            threshold: f64::from_bits(JARO_WINKLER_THRESHOLD_BITS.load(Ordering::Relaxed)),
+        }
+    } else {
+        SuggestionAlgorithm::Levenshtein
+    }
+}
+
+/// Select the algorithm by setting name: `levenshtein` or `jaro_winkler`
+/// (case-insensitive).
+pub fn set_suggestion_algorithm(name: &str) -> Result<(), String> {
+    let jaro_winkler = match name.to_ascii_lowercase().as_str() {
+        "levenshtein" => false,
+        "jaro_winkler" => true,
+        _ => {
+            return Err(format!(
+                "unknown suggestion algorithm '{name}'; expected 'levenshtein' or 'jaro_winkler'"
+            ))
+        }
+    };
+    USE_JARO_WINKLER.store(jaro_winkler, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Set the minimum Jaro-Winkler similarity a suggestion needs.
+pub fn set_suggestion_threshold(threshold: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(format!(
+            "suggestion threshold must be between 0 and 1, got {threshold}"
+        ));
+    }
+    JARO_WINKLER_THRESHOLD_BITS.store(threshold.to_bits(), Ordering::Relaxed);
+    Ok(())
+}
+
+/// Suggest the closest matching name from `available` using the configured
+/// [`SuggestionAlgorithm`] (Levenshtein unless changed; see
+/// [`suggest_closest_with`]).
+#[must_use]
+pub fn suggest_closest(name: &str, available: &[String]) -> Option<String> {
+    suggest_closest_with(name, available, suggestion_algorithm())
+}
+
+/// Suggest the closest matching name from `available` using `algorithm`.
 ///
-/// Returns `Some(name)` (with original casing) if the best match has an edit
-/// distance of 3 or fewer characters. Returns `None` if no candidate is close
+/// Returns `Some(name)` (with original casing) for the best candidate within
+/// the algorithm's cutoff — an edit distance of 3 or fewer characters for
+/// Levenshtein, a similarity of at least `threshold` for Jaro-Winkler. Ties
+/// go to the earliest candidate. Returns `None` if no candidate is close
 /// enough. Both the query and candidates are lowercased for comparison.
 #[must_use]
-pub fn suggest_closest(name: &str, available: &[String]) -> Option<String> {
+pub fn suggest_closest_with(
+    name: &str,
+    available: &[String],
+    algorithm: SuggestionAlgorithm,
+) -> Option<String> {
     let query = name.to_ascii_lowercase();
-    let mut best: Option<(usize, &str)> = None;
-    for candidate in available {
-        let dist = strsim::levenshtein(&query, &candidate.to_ascii_lowercase());
-        if dist <= 3 {
-            if let Some((best_dist, _)) = best {
-                if dist < best_dist {
+    match algorithm {
+        SuggestionAlgorithm::Levenshtein => {
+            let mut best: Option<(usize, &str)> = None;
+            for candidate in available {
+                let dist = strsim::levenshtein(&query, &candidate.to_ascii_lowercase());
+                if dist <= 3 && best.is_none_or(|(best_dist, _)| dist < best_dist) {
                     best = Some((dist, candidate));
                 }
-            } else {
-                best = Some((dist, candidate));
             }
+            best.map(|(_, s)| s.to_string())
+        }
+        SuggestionAlgorithm::JaroWinkler { threshold } => {
+            let mut best: Option<(f64, &str)> = None;
+            for candidate in available {
+                let sim = strsim::jaro_winkler(&query, &candidate.to_ascii_lowercase());
+                if sim >= threshold && best.is_none_or(|(best_sim, _)| sim > best_sim) {
+                    best = Some((sim, candidate));
+                }
+            }
+            best.map(|(_, s)| s.to_string())
         }
     }
-    best.map(|(_, s)| s.to_string())
 }
 
 /// Is `b` an identifier-continuation byte?
@@ -320,11 +405,54 @@ pub fn extract_single_quoted_prefix(input: &str) -> Result<(String, usize), Sing
     Err(SingleQuoteError::Unterminated)
 }
 
+/// Case-insensitive SQL `LIKE` match (`ILIKE` semantics): `%` matches any
+/// run of characters, `_` exactly one character, everything else itself.
+/// No escape character, matching `DuckDB`'s `LIKE` without `ESCAPE`.
+#[must_use]
+pub fn ilike_matches(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    // Greedy two-pointer match, backtracking to the most recent `%`.
+    let (mut t, mut p) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '_' || pattern[p] == text[t]) {
+            t += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == '%' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((sp, st)) = star {
+            p = sp + 1;
+            t = st + 1;
+            star = Some((sp, st + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '%')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn ilike_matches_wildcards_case_insensitively() {
+        assert!(ilike_matches("orders_v", "ord%"));
+        assert!(ilike_matches("ORDERS_V", "ord%"));
+        assert!(ilike_matches("orders", "%DER%"));
+        assert!(ilike_matches("ab", "a_"));
+        assert!(ilike_matches("", "%"));
+        assert!(ilike_matches("a%b", "a%b"));
+        assert!(!ilike_matches("customers", "ord%"));
+        assert!(!ilike_matches("abc", "a_"));
+        assert!(!ilike_matches("order", "orders"));
+        assert!(ilike_matches("xaxb", "%a%b"));
+        assert!(!ilike_matches("xaxbc", "%a%b"));
+    }
+
     // -------------------------------------------------------------------
     // byte_offset_within tests
     // -------------------------------------------------------------------
@@ -585,6 +713,74 @@ mod tests {
         }
     }
 
+    // -------------------------------------------------------------------
+    // suggest_closest_with algorithm comparison
+    // -------------------------------------------------------------------
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| (*s).to_string()).collect()
+    }
+
+    const JARO_WINKLER: SuggestionAlgorithm = SuggestionAlgorithm::JaroWinkler {
+        threshold: DEFAULT_JARO_WINKLER_THRESHOLD,
+    };
+
+    #[test]
+    fn algorithms_compared_on_shared_candidates() {
+        let candidates = names(&["leg", "regional", "region_code", "revenue"]);
+        let both = |q: &str| {
+            (
+                suggest_closest_with(q, &candidates, SuggestionAlgorithm::Levenshtein),
+                suggest_closest_with(q, &candidates, JARO_WINKLER),
+            )
+        };
+        let some = |s: &str| Some(s.to_string());
+
+        // A truncated prefix: Levenshtein prefers the short unrelated name,
+        // Jaro-Winkler the prefix match.
+        assert_eq!(both("reg"), (some("leg"), some("regional")));
+        // A transposition: both agree.
+        assert_eq!(both("regoinal"), (some("regional"), some("regional")));
+        // A prefix four characters short of the name: too many edits for
+        // Levenshtein, which falls back to a nearby unrelated name.
+        assert_eq!(both("rev"), (some("leg"), some("revenue")));
+        // Unrelated input: neither suggests.
+        assert_eq!(both("zzzz"), (None, None));
+    }
+
+    #[test]
+    fn jaro_winkler_threshold_is_respected() {
+        let candidates = names(&["regional"]);
+        let at = |threshold| {
+            suggest_closest_with(
+                "reg",
+                &candidates,
+                SuggestionAlgorithm::JaroWinkler { threshold },
+            )
+        };
+        assert_eq!(at(0.8).as_deref(), Some("regional"));
+        assert_eq!(at(0.9), None);
+        // Only an exact (case-insensitive) match reaches 1.0.
+        let exact = SuggestionAlgorithm::JaroWinkler { threshold: 1.0 };
+        assert_eq!(
+            suggest_closest_with("REGIONAL", &candidates, exact).as_deref(),
+            Some("regional")
+        );
+    }
+
+    #[test]
+    fn suggestion_setting_rejects_bad_values() {
+        assert!(set_suggestion_algorithm("soundex")
+            .unwrap_err()
+            .contains("'levenshtein' or 'jaro_winkler'"));
+        assert!(set_suggestion_threshold(1.5)
+            .unwrap_err()
+            .contains("between 0 and 1"));
+        assert!(set_suggestion_threshold(f64::NAN).is_err());
+        // Rejected values leave the default in place.
+        assert_eq!(suggestion_algorithm(), SuggestionAlgorithm::Levenshtein);
+    }
+
     // -------------------------------------------------------------------
     // suggest_closest property tests
     // -------------------------------------------------------------------
@@ -22,6 +22,8 @@ connection) and skips migration entirely when the primary is in-memory.
       migrated into the in-memory primary.
   T2: file-backed PRIMARY with its own companion still migrates correctly
       (regression guard: the legitimate primary migration is preserved).
+  T3: a companion whose definition of a view differs from the stored row
+      fails LOAD naming that view, imports nothing, and is left in place.
 
 Exit codes: 0 = all passed, 1 = at least one failed.
 """
@@ -146,6 +148,55 @@ def test_primary_companion_still_migrates() -> None:
         shutil.rmtree(d, ignore_errors=True)
 
 
+def test_conflicting_companion_fails_load() -> None:
+    """A companion that disagrees with a stored view must not overwrite it:
+    LOAD fails naming the view, and both the row and the file survive."""
+    import duckdb
+
+    d = tempfile.mkdtemp()
+    primary = os.path.join(d, "main.db")
+    companion = primary + ".semantic_views"
+    config = {"allow_unsigned_extensions": "true", "extension_directory": EXT_DIR}
+    stored = json.dumps(
+        {"tables": [{"alias": "s", "table": "s"}], "dimensions": [], "metrics": []}
+    )
+    c0 = duckdb.connect(primary, config=config)
+    try:
+        _install_and_load(c0)
+        c0.execute(
+            "INSERT INTO semantic_layer._definitions VALUES ('legacy_view', ?)",
+            [stored],
+        )
+    finally:
+        c0.close()
+    payload = _companion_payload()
+    payload["other_view"] = payload["legacy_view"]
+    with open(companion, "w") as f:
+        json.dump(payload, f)
+
+    conn = duckdb.connect(primary, config=config)
+    try:
+        try:
+            _install_and_load(conn)
+        except duckdb.Error as e:
+            assert "legacy_view" in str(e) and "other_view" not in str(e), (
+                f"LOAD error should name only the conflicting view, got: {e}"
+            )
+        else:
+            raise AssertionError("LOAD should fail on a conflicting companion file")
+
+        assert os.path.exists(companion), "conflicting companion must be left in place"
+        rows = conn.execute(
+            "SELECT name, definition FROM semantic_layer._definitions ORDER BY name"
+        ).fetchall()
+        assert rows == [("legacy_view", stored)], (
+            f"nothing should be imported over or beside the stored row, got {rows}"
+        )
+    finally:
+        conn.close()
+        shutil.rmtree(d, ignore_errors=True)
+
+
 if __name__ == "__main__":
     results = [
         run_test("test_attached_companion_untouched", test_attached_companion_untouched),
@@ -153,6 +204,9 @@ if __name__ == "__main__":
             "test_primary_companion_still_migrates",
             test_primary_companion_still_migrates,
         ),
+        run_test(
+            "test_conflicting_companion_fails_load", test_conflicting_companion_fails_load
+        ),
     ]
     passed = sum(results)
     total = len(results)
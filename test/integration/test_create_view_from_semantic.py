@@ -0,0 +1,146 @@
+#!/usr/bin/env python3
+# /// script
+# dependencies = ["duckdb==1.5.4"]
+# requires-python = ">=3.10"
+# ///
+"""
+End-to-end test for create_view_from_semantic(): the returned statement must
+run and the view it creates must return what semantic_view() returns.
+
+test/sql/create_view_from_semantic.test checks the text of the statement, but
+sqllogictest cannot execute a statement read from a query result. This suite
+does that from the client, which is also how the function is meant to be
+used: the statement is built during bind, so the caller runs it separately.
+
+Scenarios:
+  A. dimensions + metrics — view rows == semantic_view() rows.
+  B. metrics only (global aggregate) — same comparison.
+  C. a target name holding a dot and a double quote — the view is created
+     under exactly that name in the current schema.
+  D. the view keeps working after the semantic view is dropped (it stores
+     the expanded SQL, not a reference to the definition).
+
+Usage:
+    uv run test/integration/test_create_view_from_semantic.py
+
+Exit codes:
+    0 = all scenarios passed
+    1 = at least one scenario failed
+"""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).resolve().parent))
+from test_ducklake_helpers import get_ext_dir, get_extension_path
+
+
+def run_tests() -> int:
+    import duckdb
+
+    ext_dir = get_ext_dir()
+    ext_path = get_extension_path()
+    if not ext_path.exists():
+        print(f"ERROR: extension not found at {ext_path}")
+        print("Run `just build` first.")
+        return 1
+
+    conn = duckdb.connect(
+        config={
+            "allow_unsigned_extensions": "true",
+            "extension_directory": ext_dir,
+        }
+    )
+    conn.execute(f"FORCE INSTALL '{ext_path}'")
+    conn.execute("LOAD semantic_views")
+
+    conn.execute(
+        "CREATE TABLE cvfs_orders ("
+        "id INTEGER PRIMARY KEY, region VARCHAR, amount DECIMAL(10,2))"
+    )
+    conn.execute(
+        "INSERT INTO cvfs_orders VALUES "
+        "(1, 'EMEA', 100.00), (2, 'APAC', 200.00), (3, 'EMEA', 50.00)"
+    )
+    conn.execute(
+        """
+        CREATE SEMANTIC VIEW cvfs_view AS
+          TABLES (o AS cvfs_orders PRIMARY KEY (id))
+          DIMENSIONS (o.region AS o.region)
+          METRICS (o.total_revenue AS SUM(o.amount), o.order_count AS COUNT(*))
+        """
+    )
+
+    failures = 0
+
+    def check(label: str, ok: bool, detail: str = "") -> None:
+        nonlocal failures
+        status = "PASS" if ok else "FAIL"
+        print(f"  {status}: {label}" + (f" — {detail}" if detail else ""))
+        if not ok:
+            failures += 1
+
+    def create_view(target: str, request: str) -> None:
+        target_literal = "'" + target.replace("'", "''") + "'"
+        stmt = conn.execute(
+            f"SELECT create_view_sql FROM create_view_from_semantic("
+            f"'cvfs_view', {target_literal}, {request})"
+        ).fetchone()[0]
+        conn.execute(stmt)
+
+    # --- Scenario A: dimensions + metrics ---------------------------------
+    print("Scenario A: dimensions + metrics")
+    request = "dimensions := ['region'], metrics := ['total_revenue', 'order_count']"
+    create_view("cvfs_by_region", request)
+    via_view = conn.execute(
+        "SELECT * FROM cvfs_by_region ORDER BY region"
+    ).fetchall()
+    via_sv = conn.execute(
+        f"SELECT * FROM semantic_view('cvfs_view', {request}) ORDER BY region"
+    ).fetchall()
+    check("view rows == semantic_view() rows", via_view == via_sv, f"{via_view} vs {via_sv}")
+    check("two regions", len(via_view) == 2, f"got {len(via_view)}")
+    columns = [d[0] for d in conn.execute("SELECT * FROM cvfs_by_region").description]
+    check(
+        "view columns named after the requested items",
+        columns == ["region", "total_revenue", "order_count"],
+        f"got {columns}",
+    )
+
+    # --- Scenario B: metrics only -----------------------------------------
+    print("Scenario B: metrics only")
+    request = "metrics := ['total_revenue']"
+    create_view("cvfs_total", request)
+    via_view = conn.execute("SELECT * FROM cvfs_total").fetchall()
+    via_sv = conn.execute(f"SELECT * FROM semantic_view('cvfs_view', {request})").fetchall()
+    check("view rows == semantic_view() rows", via_view == via_sv, f"{via_view} vs {via_sv}")
+
+    # --- Scenario C: target is one identifier -----------------------------
+    print("Scenario C: target name with a dot and a quote")
+    create_view('main.say "hi"', "metrics := ['order_count']")
+    found = conn.execute(
+        "SELECT count(*) FROM duckdb_views() "
+        "WHERE view_name = 'main.say \"hi\"' AND schema_name = 'main'"
+    ).fetchone()[0]
+    check("view created under the literal name", found == 1, f"got {found}")
+    count = conn.execute('SELECT order_count FROM "main.say ""hi"""').fetchone()[0]
+    check("view is queryable", count == 3, f"got {count}")
+
+    # --- Scenario D: independent of the semantic view ----------------------
+    print("Scenario D: view outlives the semantic view")
+    conn.execute("DROP SEMANTIC VIEW cvfs_view")
+    rows = conn.execute("SELECT count(*) FROM cvfs_by_region").fetchone()[0]
+    check("view still returns rows after DROP SEMANTIC VIEW", rows == 2, f"got {rows}")
+
+    print()
+    if failures:
+        print(f"FAILED: {failures} assertion(s)")
+        return 1
+    print("ALL PASSED")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(run_tests())
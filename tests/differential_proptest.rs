@@ -21,6 +21,8 @@
 //! test_differential.py`, extended per T-1); this test adds schema + data +
 //! query randomization for the core path and runs under plain `cargo test`.
 
+use std::collections::BTreeMap;
+
 use proptest::prelude::*;
 use semantic_views::expand::{expand, DimensionName, MetricName, QueryRequest};
 use semantic_views::model::{AccessModifier, Dimension, Metric, SemanticViewDefinition, TableRef};
@@ -152,6 +154,10 @@ fn build_def(s: &Schema) -> SemanticViewDefinition {
             expr: format!("d{i}"),
             source_table: None,
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         })
@@ -171,6 +177,9 @@ fn build_def(s: &Schema) -> SemanticViewDefinition {
             access: AccessModifier::Public,
             non_additive_by: vec![],
             window_spec: None,
+            filter: None,
+            approx: false,
+            distinct: false,
         })
         .collect();
     SemanticViewDefinition {
@@ -187,6 +196,14 @@ fn build_def(s: &Schema) -> SemanticViewDefinition {
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -242,7 +259,7 @@ proptest! {
                 .iter()
                 .map(|i| MetricName::new(format!("m{i}")))
                 .collect(),
-            facts: vec![],
+            ..Default::default()
         };
 
         let expanded = expand("t_diff", &def, &req)
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use proptest::prelude::*;
 use semantic_views::expand::{expand, DimensionName, MetricName, QueryRequest};
 use semantic_views::model::{
@@ -26,6 +28,10 @@ fn simple_definition() -> SemanticViewDefinition {
                 source_table: None,
 
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -35,6 +41,10 @@ fn simple_definition() -> SemanticViewDefinition {
                 source_table: None,
 
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -44,6 +54,10 @@ fn simple_definition() -> SemanticViewDefinition {
                 source_table: None,
 
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -60,6 +74,9 @@ fn simple_definition() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
             Metric {
                 name: "order_count".to_string(),
@@ -72,6 +89,9 @@ fn simple_definition() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
             Metric {
                 name: "avg_amount".to_string(),
@@ -84,12 +104,23 @@ fn simple_definition() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
         ],
 
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -135,6 +166,10 @@ fn joined_definition() -> SemanticViewDefinition {
                 source_table: None,
 
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -144,6 +179,10 @@ fn joined_definition() -> SemanticViewDefinition {
                 source_table: Some("customers".to_string()),
 
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -153,6 +192,10 @@ fn joined_definition() -> SemanticViewDefinition {
                 source_table: None,
 
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -162,6 +205,10 @@ fn joined_definition() -> SemanticViewDefinition {
                 source_table: Some("products".to_string()),
 
                 output_type: None,
+                collate: None,
+                coalesce_to: None,
+                null_value: None,
+                bucket: None,
                 comment: None,
                 synonyms: vec![],
             },
@@ -178,6 +225,9 @@ fn joined_definition() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
             Metric {
                 name: "customer_count".to_string(),
@@ -190,6 +240,9 @@ fn joined_definition() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
             Metric {
                 name: "product_count".to_string(),
@@ -202,6 +255,9 @@ fn joined_definition() -> SemanticViewDefinition {
                 access: AccessModifier::Public,
                 non_additive_by: vec![],
                 window_spec: None,
+                filter: None,
+                approx: false,
+                distinct: false,
             },
         ],
 
@@ -227,6 +283,14 @@ fn joined_definition() -> SemanticViewDefinition {
         ],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
 
         created_on: None,
         database_name: None,
@@ -312,7 +376,7 @@ fn arb_query_request(def: &SemanticViewDefinition) -> impl Strategy<Value = Quer
         .prop_map(|(dims, mets)| QueryRequest {
             dimensions: dims.into_iter().map(DimensionName::new).collect(),
             metrics: mets.into_iter().map(MetricName::new).collect(),
-            facts: vec![],
+            ..Default::default()
         })
 }
 
@@ -517,9 +581,8 @@ proptest! {
     ) {
         let def = simple_definition();
         let req = QueryRequest {
-            dimensions: vec![],
             metrics: metrics.iter().map(MetricName::new).collect(),
-            facts: vec![],
+            ..Default::default()
         };
         let sql = expand("test", &def, &req).unwrap();
 
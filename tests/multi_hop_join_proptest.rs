@@ -33,6 +33,8 @@
 //!    reach `w`; a multi-hop resolution bug (dropped intermediate, wrong join
 //!    order, wrong ON columns) surfaces as invalid SQL or a non-zero diff.
 
+use std::collections::BTreeMap;
+
 use proptest::prelude::*;
 use semantic_views::expand::{expand, DimensionName, MetricName, QueryRequest};
 use semantic_views::model::{
@@ -144,6 +146,10 @@ fn build_def() -> SemanticViewDefinition {
         expr: expr.to_string(),
         source_table: Some(source.to_string()),
         output_type: None,
+        collate: None,
+        coalesce_to: None,
+        null_value: None,
+        bucket: None,
         comment: None,
         synonyms: vec![],
     };
@@ -163,6 +169,9 @@ fn build_def() -> SemanticViewDefinition {
         access: AccessModifier::Public,
         non_additive_by: vec![],
         window_spec: None,
+        filter: None,
+        approx: false,
+        distinct: false,
     };
     let metrics = vec![
         base_metric("sv", "sum(t.v)", Some("t")),
@@ -195,6 +204,14 @@ fn build_def() -> SemanticViewDefinition {
         joins,
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -310,7 +327,7 @@ proptest! {
                 .iter()
                 .map(|&i| MetricName::new(METS[i]))
                 .collect(),
-            facts: vec![],
+            ..Default::default()
         };
 
         let selects_ancestor_metric = case
@@ -33,6 +33,8 @@
 //!   NA dim (`ts`) is itself queried the metric is effectively regular (a plain
 //!   `GROUP BY` aggregate), which the oracle mirrors.
 
+use std::collections::BTreeMap;
+
 use proptest::prelude::*;
 use semantic_views::expand::{expand, DimensionName, MetricName, QueryRequest};
 use semantic_views::model::{
@@ -122,6 +124,10 @@ fn build_def(order: SortOrder) -> SemanticViewDefinition {
             expr: "s.entity".to_string(),
             source_table: Some("s".to_string()),
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         },
@@ -130,6 +136,10 @@ fn build_def(order: SortOrder) -> SemanticViewDefinition {
             expr: "s.ts".to_string(),
             source_table: Some("s".to_string()),
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         },
@@ -155,6 +165,9 @@ fn build_def(order: SortOrder) -> SemanticViewDefinition {
             },
         }],
         window_spec: None,
+        filter: None,
+        approx: false,
+        distinct: false,
     }];
     SemanticViewDefinition {
         tables,
@@ -163,6 +176,14 @@ fn build_def(order: SortOrder) -> SemanticViewDefinition {
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -306,7 +327,7 @@ proptest! {
         let req = QueryRequest {
             dimensions: case.sel_dims.iter().map(|&i| DimensionName::new(DIMS[i])).collect(),
             metrics: case.sel_metrics.iter().map(|&i| MetricName::new(METS[i])).collect(),
-            facts: vec![],
+            ..Default::default()
         };
 
         // Single table, no joins -> no fan trap; every query is accepted.
@@ -29,6 +29,8 @@
 //!    uses). This guards the fix against over-rejecting safe queries and pins
 //!    the generated join SQL as correct.
 
+use std::collections::BTreeMap;
+
 use proptest::prelude::*;
 use semantic_views::expand::{expand, DimensionName, MetricName, QueryRequest};
 use semantic_views::model::{
@@ -134,6 +136,10 @@ fn build_def() -> SemanticViewDefinition {
             expr: "t.d".to_string(),
             source_table: Some("t".to_string()),
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         },
@@ -142,6 +148,10 @@ fn build_def() -> SemanticViewDefinition {
             expr: "u.ucat".to_string(),
             source_table: Some("u".to_string()),
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         },
@@ -157,6 +167,9 @@ fn build_def() -> SemanticViewDefinition {
         access: AccessModifier::Public,
         non_additive_by: vec![],
         window_spec: None,
+        filter: None,
+        approx: false,
+        distinct: false,
     };
     let metrics = vec![
         base_metric("sv", "sum(t.v)", Some("t")),
@@ -178,6 +191,14 @@ fn build_def() -> SemanticViewDefinition {
         joins,
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -278,7 +299,7 @@ proptest! {
                 .iter()
                 .map(|&i| MetricName::new(METS[i]))
                 .collect(),
-            facts: vec![],
+            ..Default::default()
         };
 
         let selects_parent_metric = case.sel_metrics.iter().any(|&i| METS[i] == "sw");
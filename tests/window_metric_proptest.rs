@@ -34,6 +34,8 @@
 //! NULLS-placed RANK windows); the partition/grain derivation is the piece with
 //! no prior randomized coverage.
 
+use std::collections::BTreeMap;
+
 use proptest::prelude::*;
 use semantic_views::expand::{expand, DimensionName, MetricName, QueryRequest};
 use semantic_views::model::{
@@ -182,6 +184,10 @@ fn build_def(func: WFunc, mode: &PartMode) -> SemanticViewDefinition {
             expr: dim_col(i),
             source_table: Some("s".to_string()),
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment: None,
             synonyms: vec![],
         })
@@ -211,6 +217,9 @@ fn build_def(func: WFunc, mode: &PartMode) -> SemanticViewDefinition {
             order_by: vec![],
             frame_clause: None,
         }),
+        filter: None,
+        approx: false,
+        distinct: false,
     }];
     SemanticViewDefinition {
         tables,
@@ -219,6 +228,14 @@ fn build_def(func: WFunc, mode: &PartMode) -> SemanticViewDefinition {
         joins: vec![],
         facts: vec![],
         materializations: vec![],
+        base_sql: None,
+        extends: None,
+        default_dimensions: vec![],
+        default_metrics: vec![],
+        time_dimension: None,
+        row_security: None,
+        tags: vec![],
+        labels: BTreeMap::new(),
         created_on: None,
         database_name: None,
         schema_name: None,
@@ -319,7 +336,7 @@ proptest! {
             dimensions: case.sel_dims.iter().map(|&i| DimensionName::new(dim_name(i))).collect(),
             // Always query the (single) window metric so the window path fires.
             metrics: vec![MetricName::new("w")],
-            facts: vec![],
+            ..Default::default()
         };
 
         // Single table, no joins -> no fan trap; every generated query (whose
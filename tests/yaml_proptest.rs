@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use proptest::prelude::*;
 use semantic_views::model::{
     AccessModifier, Cardinality, Dimension, Fact, Join, Materialization, Metric, NonAdditiveDim,
@@ -104,6 +106,10 @@ fn arb_dimension() -> impl Strategy<Value = Dimension> {
             expr,
             source_table,
             output_type: None,
+            collate: None,
+            coalesce_to: None,
+            null_value: None,
+            bucket: None,
             comment,
             synonyms,
         })
@@ -188,6 +194,9 @@ fn arb_metric() -> impl Strategy<Value = Metric> {
                     access,
                     non_additive_by,
                     window_spec,
+                    filter: None,
+                    approx: false,
+                    distinct: false,
                 }
             },
         )
@@ -270,6 +279,14 @@ fn arb_definition() -> impl Strategy<Value = SemanticViewDefinition> {
                     joins,
                     facts,
                     materializations,
+                    base_sql: None,
+                    extends: None,
+                    default_dimensions: vec![],
+                    default_metrics: vec![],
+                    time_dimension: None,
+                    row_security: None,
+                    tags: vec![],
+                    labels: BTreeMap::new(),
                     created_on: None,
                     database_name: None,
                     schema_name: None,